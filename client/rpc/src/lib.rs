@@ -20,14 +20,24 @@ use std::sync::Arc;
 use anyhow::anyhow;
 use app_promotion_rpc::AppPromotionApi as AppPromotionRuntimeApi;
 pub use app_promotion_unique_rpc::AppPromotionApiServer;
+use collator_selection_rpc::CollatorSelectionApi as CollatorSelectionRuntimeApi;
+pub use collator_selection_unique_rpc::CollatorSelectionApiServer;
+use inflation_rpc::InflationApi as InflationRuntimeApi;
+pub use inflation_unique_rpc::InflationApiServer;
 use jsonrpsee::{core::RpcResult as Result, proc_macros::rpc};
 use parity_scale_codec::Decode;
 use sp_api::{ApiExt, BlockT, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::{AtLeast32BitUnsigned, Member};
+use sp_core::{Bytes, H160, U256};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Member},
+	Perbill,
+};
 use up_data_structs::{
-	CollectionId, CollectionLimits, CollectionStats, Property, PropertyKeyPermission,
-	RpcCollection, TokenChild, TokenData, TokenId,
+	CollatorStats, CollectionAdminRoles, CollectionId, CollectionLimits, CollectionStats,
+	PendingCollectionOwner, Property, PropertyKeyPermission, RewardDestination, RpcCollection,
+	SponsorBudgetLeft, SponsoredFees, SponsoringDryRun, TokenChild, TokenData, TokenId,
+	TokenTreeNode,
 };
 use up_rpc::UniqueApi as UniqueRuntimeApi;
 
@@ -99,6 +109,18 @@ pub trait UniqueApi<BlockHash, CrossAccountId, AccountId> {
 		at: Option<BlockHash>,
 	) -> Result<Vec<TokenChild>>;
 
+	/// Get the tree of tokens nested into the token, optionally limited in depth.
+	/// Token properties are optionally limited to the provided keys.
+	#[method(name = "unique_tokenTree")]
+	fn token_tree(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		max_depth: Option<u32>,
+		keys: Option<Vec<String>>,
+		at: Option<BlockHash>,
+	) -> Result<Vec<TokenTreeNode<CrossAccountId>>>;
+
 	/// Get collection properties, optionally limited to the provided keys.
 	#[method(name = "unique_collectionProperties")]
 	fn collection_properties(
@@ -179,6 +201,14 @@ pub trait UniqueApi<BlockHash, CrossAccountId, AccountId> {
 		at: Option<BlockHash>,
 	) -> Result<Vec<CrossAccountId>>;
 
+	/// Get the list of admin accounts of a collection together with their roles.
+	#[method(name = "unique_adminlistWithRoles")]
+	fn adminlist_with_roles(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(CrossAccountId, CollectionAdminRoles)>>;
+
 	/// Get the list of accounts allowed to operate within a collection.
 	#[method(name = "unique_allowlist")]
 	fn allowlist(
@@ -208,6 +238,14 @@ pub trait UniqueApi<BlockHash, CrossAccountId, AccountId> {
 		at: Option<BlockHash>,
 	) -> Result<Option<RpcCollection<AccountId>>>;
 
+	/// Get the proposed owner of a collection, awaiting acceptance.
+	#[method(name = "unique_collectionPendingOwner")]
+	fn collection_pending_owner(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> Result<Option<PendingCollectionOwner<AccountId, u32>>>;
+
 	/// Get chain stats about collections.
 	#[method(name = "unique_collectionStats")]
 	fn collection_stats(&self, at: Option<BlockHash>) -> Result<CollectionStats>;
@@ -222,6 +260,55 @@ pub trait UniqueApi<BlockHash, CrossAccountId, AccountId> {
 		at: Option<BlockHash>,
 	) -> Result<Option<u64>>;
 
+	/// Get the fees the collection sponsor is still ready to pay in the current budget period.
+	#[method(name = "unique_sponsorBudgetLeft")]
+	fn sponsor_budget_left(
+		&self,
+		collection: CollectionId,
+		account: CrossAccountId,
+		at: Option<BlockHash>,
+	) -> Result<Option<SponsorBudgetLeft<String>>>;
+
+	/// Check whether the encoded signed extrinsic would be sponsored, by whom and with what fee.
+	#[method(name = "unique_sponsoringDryRun")]
+	fn sponsoring_dry_run(
+		&self,
+		extrinsic: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<SponsoringDryRun<CrossAccountId, String>>;
+
+	/// Check whether the EVM call would be sponsored, by whom and with what fee.
+	#[method(name = "unique_evmSponsoringDryRun")]
+	fn evm_sponsoring_dry_run(
+		&self,
+		source: H160,
+		target: H160,
+		input: Bytes,
+		gas_limit: u64,
+		max_fee_per_gas: U256,
+		at: Option<BlockHash>,
+	) -> Result<SponsoringDryRun<CrossAccountId, String>>;
+
+	/// Get the fees paid by the sponsor within the era, or within the current one.
+	#[method(name = "unique_sponsorFeesPaid")]
+	fn sponsor_fees_paid(
+		&self,
+		sponsor: CrossAccountId,
+		era: Option<u32>,
+		at: Option<BlockHash>,
+	) -> Result<String>;
+
+	/// Get the fees paid for the calls of the sponsored collection or contract within the era,
+	/// or within the current one, together with the `top` sponsored users.
+	#[method(name = "unique_sponsoredFees")]
+	fn sponsored_fees(
+		&self,
+		target: H160,
+		era: Option<u32>,
+		top: u32,
+		at: Option<BlockHash>,
+	) -> Result<SponsoredFees<CrossAccountId, String>>;
+
 	/// Get effective collection limits. If not explicitly set, get the chain defaults.
 	#[method(name = "unique_effectiveCollectionLimits")]
 	fn effective_collection_limits(
@@ -264,13 +351,14 @@ mod app_promotion_unique_rpc {
 			at: Option<BlockHash>,
 		) -> Result<String>;
 
-		///Returns the total amount of staked tokens per block when staked.
+		///Returns the total amount of staked tokens per block when staked,
+		/// along with the lock tier and unlock block of locked stakes.
 		#[method(name = "appPromotion_totalStakedPerBlock")]
 		fn total_staked_per_block(
 			&self,
 			staker: CrossAccountId,
 			at: Option<BlockHash>,
-		) -> Result<Vec<(BlockNumber, String)>>;
+		) -> Result<Vec<(BlockNumber, String, Option<(u8, BlockNumber)>)>>;
 
 		/// Returns the total amount of tokens pending withdrawal from staking.
 		#[method(name = "appPromotion_pendingUnstake")]
@@ -287,6 +375,94 @@ mod app_promotion_unique_rpc {
 			staker: CrossAccountId,
 			at: Option<BlockHash>,
 		) -> Result<Vec<(BlockNumber, String)>>;
+
+		/// Returns the collections/contracts the stake was delegated to by the staker, with the delegated amounts.
+		#[method(name = "appPromotion_delegatedStake")]
+		fn delegated_stake(
+			&self,
+			staker: CrossAccountId,
+			at: Option<BlockHash>,
+		) -> Result<Vec<(H160, String)>>;
+
+		/// Returns the total stake delegated to the collection/contract.
+		#[method(name = "appPromotion_targetDelegatedStake")]
+		fn target_delegated_stake(&self, target: H160, at: Option<BlockHash>) -> Result<String>;
+
+		/// Returns the amount the pallet may spend within a sponsoring era on the calls of the collection/contract.
+		#[method(name = "appPromotion_sponsoringAllowance")]
+		fn sponsoring_allowance(&self, target: H160, at: Option<BlockHash>) -> Result<String>;
+
+		/// Returns the interest paid to the staker for the last recalculation periods, per relay block of the recalculation.
+		#[method(name = "appPromotion_payoutHistory")]
+		fn payout_history(
+			&self,
+			staker: CrossAccountId,
+			at: Option<BlockHash>,
+		) -> Result<Vec<(BlockNumber, String)>>;
+
+		/// Returns the relay block of the next recalculation and the interest the staker will receive in it at the current rate.
+		#[method(name = "appPromotion_projectedPayout")]
+		fn projected_payout(
+			&self,
+			staker: CrossAccountId,
+			at: Option<BlockHash>,
+		) -> Result<(BlockNumber, String)>;
+
+		/// Returns the treasury balance the interest is paid from, the interest paid per recalculation interval at the current rate,
+		/// and the number of relay blocks the treasury balance lasts at this rate.
+		#[method(name = "appPromotion_rewardPotRunway")]
+		fn reward_pot_runway(
+			&self,
+			at: Option<BlockHash>,
+		) -> Result<(String, String, Option<BlockNumber>)>;
+
+		/// Returns where the interest accrued by the stakes of the staker is paid.
+		#[method(name = "appPromotion_rewardDestination")]
+		fn reward_destination(
+			&self,
+			staker: CrossAccountId,
+			at: Option<BlockHash>,
+		) -> Result<RewardDestination<AccountId>>;
+	}
+}
+
+mod inflation_unique_rpc {
+	use super::*;
+
+	#[rpc(server)]
+	#[async_trait]
+	pub trait InflationApi<BlockHash, BlockNumber, AccountId> {
+		/// Returns the beneficiaries of the minted inflation with their shares.
+		#[method(name = "inflation_inflationSplit")]
+		fn inflation_split(&self, at: Option<BlockHash>) -> Result<Vec<(AccountId, Perbill)>>;
+
+		/// Returns the recalculation relay block, the starting year total issuance and the block inflation
+		/// for the last inflation recalculations, oldest first.
+		#[method(name = "inflation_issuanceHistory")]
+		fn issuance_history(
+			&self,
+			at: Option<BlockHash>,
+		) -> Result<Vec<(BlockNumber, String, String)>>;
+	}
+}
+
+mod collator_selection_unique_rpc {
+	use super::*;
+
+	#[rpc(server)]
+	#[async_trait]
+	pub trait CollatorSelectionApi<BlockHash, BlockNumber, AccountId> {
+		/// Returns the authored blocks and the rewards of the collator.
+		#[method(name = "collatorSelection_collatorStats")]
+		fn collator_stats(
+			&self,
+			collator: AccountId,
+			at: Option<BlockHash>,
+		) -> Result<CollatorStats<BlockNumber, String>>;
+
+		/// Returns the license holders waiting for a candidate slot, in the order of promotion.
+		#[method(name = "collatorSelection_waitingCandidates")]
+		fn waiting_candidates(&self, at: Option<BlockHash>) -> Result<Vec<AccountId>>;
 	}
 }
 
@@ -321,6 +497,18 @@ define_struct_for_server_api! {
 	}
 }
 
+define_struct_for_server_api! {
+	Inflation {
+		client: Arc<Client>
+	}
+}
+
+define_struct_for_server_api! {
+	CollatorSelection {
+		client: Arc<Client>
+	}
+}
+
 macro_rules! pass_method {
 	(
 		$method_name:ident(
@@ -372,6 +560,18 @@ macro_rules! app_promotion_api {
 	};
 }
 
+macro_rules! inflation_api {
+	() => {
+		dyn InflationRuntimeApi<Block, BlockNumber, AccountId>
+	};
+}
+
+macro_rules! collator_selection_api {
+	() => {
+		dyn CollatorSelectionRuntimeApi<Block, BlockNumber, AccountId>
+	};
+}
+
 #[allow(deprecated)]
 impl<C, Block, CrossAccountId, AccountId>
 	UniqueApiServer<<Block as BlockT>::Hash, CrossAccountId, AccountId> for Unique<C, Block>
@@ -398,6 +598,14 @@ where
 		topmost_token_owner(collection: CollectionId, token: TokenId) -> Option<CrossAccountId>, unique_api
 	);
 	pass_method!(token_children(collection: CollectionId, token: TokenId) -> Vec<TokenChild>, unique_api);
+	pass_method!(token_tree(
+		collection: CollectionId,
+		token: TokenId,
+		max_depth: Option<u32>,
+
+		#[map = string_keys_to_bytes_keys]
+		keys: Option<Vec<String>>
+	) -> Vec<TokenTreeNode<CrossAccountId>>, unique_api);
 	pass_method!(total_supply(collection: CollectionId) -> u32, unique_api);
 	pass_method!(account_balance(collection: CollectionId, account: CrossAccountId) -> u32, unique_api);
 	pass_method!(balance(collection: CollectionId, account: CrossAccountId, token: TokenId) -> String => |v| v.to_string(), unique_api);
@@ -439,6 +647,7 @@ where
 	}
 
 	pass_method!(adminlist(collection: CollectionId) -> Vec<CrossAccountId>, unique_api);
+	pass_method!(adminlist_with_roles(collection: CollectionId) -> Vec<(CrossAccountId, CollectionAdminRoles)>, unique_api);
 	pass_method!(allowlist(collection: CollectionId) -> Vec<CrossAccountId>, unique_api);
 	pass_method!(allowed(collection: CollectionId, user: CrossAccountId) -> bool, unique_api);
 	pass_method!(last_token_id(collection: CollectionId) -> TokenId, unique_api);
@@ -453,11 +662,51 @@ where
 			} else {
 				Ok(None)
 			}
-		}
+		};
+		changed_in 5, collection_by_id_before_version_5(collection) => |value| Ok(value.map(Into::into))
 	);
+	pass_method!(collection_pending_owner(collection: CollectionId) -> Option<PendingCollectionOwner<AccountId, u32>>, unique_api);
 	pass_method!(collection_stats() -> CollectionStats, unique_api);
 	pass_method!(next_sponsored(collection: CollectionId, account: CrossAccountId, token: TokenId) -> Option<u64>, unique_api);
-	pass_method!(effective_collection_limits(collection_id: CollectionId) -> Option<CollectionLimits>, unique_api);
+	pass_method!(
+		sponsor_budget_left(collection: CollectionId, account: CrossAccountId) -> Option<SponsorBudgetLeft<String>> => |o| o.map(|left| SponsorBudgetLeft {
+			period: left.period.map(|fee| fee.to_string()),
+			user: left.user.map(|fee| fee.to_string()),
+			period_end: left.period_end,
+		}),
+		unique_api
+	);
+	pass_method!(
+		sponsoring_dry_run(#[map = |extrinsic: Bytes| extrinsic.0] extrinsic: Bytes) -> SponsoringDryRun<CrossAccountId, String> => fee_to_string,
+		unique_api
+	);
+	pass_method!(
+		evm_sponsoring_dry_run(
+			source: H160,
+			target: H160,
+			#[map = |input: Bytes| input.0] input: Bytes,
+			gas_limit: u64,
+			max_fee_per_gas: U256,
+		) -> SponsoringDryRun<CrossAccountId, String> => fee_to_string,
+		unique_api
+	);
+	pass_method!(sponsor_fees_paid(sponsor: CrossAccountId, era: Option<u32>) -> String => |fee| fee.to_string(), unique_api);
+	pass_method!(
+		sponsored_fees(target: H160, era: Option<u32>, top: u32) -> SponsoredFees<CrossAccountId, String> => |fees| SponsoredFees {
+			era: fees.era,
+			total: fees.total.to_string(),
+			top_users: fees
+				.top_users
+				.into_iter()
+				.map(|(user, fee)| (user, fee.to_string()))
+				.collect(),
+		},
+		unique_api
+	);
+	pass_method!(
+		effective_collection_limits(collection_id: CollectionId) -> Option<CollectionLimits>, unique_api;
+		changed_in 5, effective_collection_limits_before_version_5(collection_id) => |value| Ok(value.map(Into::into))
+	);
 	pass_method!(total_pieces(collection_id: CollectionId, token_id: TokenId) -> Option<String> => |o| o.map(|number| number.to_string()) , unique_api);
 	pass_method!(token_owners(collection: CollectionId, token: TokenId) -> Vec<CrossAccountId>, unique_api);
 	pass_method!(allowance_for_all(collection: CollectionId, owner: CrossAccountId, operator: CrossAccountId) -> bool, unique_api);
@@ -479,17 +728,82 @@ where
 	C::Api: AppPromotionRuntimeApi<Block, BlockNumber, CrossAccountId, AccountId>,
 {
 	pass_method!(total_staked(staker: Option<CrossAccountId>) -> String => |v| v.to_string(), app_promotion_api);
-	pass_method!(total_staked_per_block(staker: CrossAccountId) -> Vec<(BlockNumber, String)> =>
+	pass_method!(total_staked_per_block(staker: CrossAccountId) -> Vec<(BlockNumber, String, Option<(u8, BlockNumber)>)> =>
 		|v| v
 		.into_iter()
-		.map(|(b, a)| (b, a.to_string()))
-		.collect::<Vec<_>>(), app_promotion_api);
+		.map(|(b, a, lock)| (b, a.to_string(), lock))
+		.collect::<Vec<_>>(), app_promotion_api;
+		changed_in 4, total_staked_per_block_before_version_4(staker) => |v| Ok(v
+			.into_iter()
+			.map(|(b, a)| (b, a, None))
+			.collect::<Vec<_>>())
+	);
 	pass_method!(pending_unstake(staker: Option<CrossAccountId>) -> String => |v| v.to_string(), app_promotion_api);
 	pass_method!(pending_unstake_per_block(staker: CrossAccountId) -> Vec<(BlockNumber, String)> =>
 		|v| v
 		.into_iter()
 		.map(|(b, a)| (b, a.to_string()))
 		.collect::<Vec<_>>(), app_promotion_api);
+	pass_method!(delegated_stake(staker: CrossAccountId) -> Vec<(H160, String)> =>
+		|v| v
+		.into_iter()
+		.map(|(t, a)| (t, a.to_string()))
+		.collect::<Vec<_>>(), app_promotion_api);
+	pass_method!(target_delegated_stake(target: H160) -> String => |v| v.to_string(), app_promotion_api);
+	pass_method!(sponsoring_allowance(target: H160) -> String => |v| v.to_string(), app_promotion_api);
+	pass_method!(payout_history(staker: CrossAccountId) -> Vec<(BlockNumber, String)> =>
+		|v| v
+		.into_iter()
+		.map(|(b, a)| (b, a.to_string()))
+		.collect::<Vec<_>>(), app_promotion_api);
+	pass_method!(projected_payout(staker: CrossAccountId) -> (BlockNumber, String) =>
+		|(b, a)| (b, a.to_string()), app_promotion_api);
+	pass_method!(reward_pot_runway() -> (String, String, Option<BlockNumber>) =>
+		|(pot, interval_payout, runway)| (pot.to_string(), interval_payout.to_string(), runway), app_promotion_api);
+	pass_method!(reward_destination(staker: CrossAccountId) -> RewardDestination<AccountId>, app_promotion_api);
+}
+
+impl<C, Block, BlockNumber, AccountId>
+	inflation_unique_rpc::InflationApiServer<<Block as BlockT>::Hash, BlockNumber, AccountId>
+	for Inflation<C, Block>
+where
+	Block: BlockT,
+	BlockNumber: Decode + Member + AtLeast32BitUnsigned,
+	AccountId: Decode,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: InflationRuntimeApi<Block, BlockNumber, AccountId>,
+{
+	pass_method!(inflation_split() -> Vec<(AccountId, Perbill)>, inflation_api);
+	pass_method!(issuance_history() -> Vec<(BlockNumber, String, String)> =>
+		|v| v
+		.into_iter()
+		.map(|(b, issuance, inflation)| (b, issuance.to_string(), inflation.to_string()))
+		.collect::<Vec<_>>(), inflation_api);
+}
+
+impl<C, Block, BlockNumber, AccountId>
+	collator_selection_unique_rpc::CollatorSelectionApiServer<
+		<Block as BlockT>::Hash,
+		BlockNumber,
+		AccountId,
+	> for CollatorSelection<C, Block>
+where
+	Block: BlockT,
+	BlockNumber: Decode + Member + AtLeast32BitUnsigned,
+	AccountId: Decode,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: CollatorSelectionRuntimeApi<Block, BlockNumber, AccountId>,
+{
+	pass_method!(collator_stats(collator: AccountId) -> CollatorStats<BlockNumber, String> =>
+		|s| CollatorStats {
+			session_blocks: s.session_blocks,
+			last_authored_block: s.last_authored_block,
+			last_rewarded_session: s.last_rewarded_session,
+			last_session_blocks: s.last_session_blocks,
+			last_session_reward: s.last_session_reward.to_string(),
+			total_reward: s.total_reward.to_string(),
+		}, collator_selection_api);
+	pass_method!(waiting_candidates() -> Vec<AccountId>, collator_selection_api);
 }
 
 fn token_data_internal<Block, Client, AccountId, CrossAccountId>(
@@ -541,6 +855,16 @@ where
 		.map_err(|e| anyhow!("runtime error: {e:?}"))?)
 }
 
+fn fee_to_string<AccountId>(
+	dry_run: SponsoringDryRun<AccountId>,
+) -> SponsoringDryRun<AccountId, String> {
+	SponsoringDryRun {
+		sponsor: dry_run.sponsor,
+		fee: dry_run.fee.to_string(),
+		reason: dry_run.reason,
+	}
+}
+
 fn string_keys_to_bytes_keys(keys: Option<Vec<String>>) -> Option<Vec<Vec<u8>>> {
 	keys.map(|keys| keys.into_iter().map(|key| key.into_bytes()).collect())
 }
@@ -555,12 +879,13 @@ fn decode_collection_from_bytes<T: parity_scale_codec::Decode>(
 fn detect_type_and_decode_collection<AccountId: Decode>(
 	bytes: &[u8],
 ) -> core::result::Result<RpcCollection<AccountId>, parity_scale_codec::Error> {
-	use up_data_structs::{CollectionVersion1, RpcCollectionVersion1};
+	use up_data_structs::{CollectionVersion1, RpcCollectionVersion1, RpcCollectionVersion2};
 
-	decode_collection_from_bytes::<RpcCollection<AccountId>>(bytes)
+	decode_collection_from_bytes::<RpcCollectionVersion2<AccountId>>(bytes)
+		.map(|col| col.into())
 		.or_else(|_| {
 			decode_collection_from_bytes::<RpcCollectionVersion1<AccountId>>(bytes)
-				.map(|col| col.into())
+				.map(|col| RpcCollectionVersion2::from(col).into())
 		})
 		.or_else(|_| {
 			decode_collection_from_bytes::<CollectionVersion1<AccountId>>(bytes)
@@ -572,7 +897,7 @@ fn detect_type_and_decode_collection<AccountId: Decode>(
 mod tests {
 	use hex_literal::hex;
 	use parity_scale_codec::IoReader;
-	use up_data_structs::{CollectionVersion1, RawEncoded};
+	use up_data_structs::{CollectionVersion1, RawEncoded, RpcCollectionVersion2};
 
 	use super::*;
 
@@ -594,7 +919,7 @@ mod tests {
 
 	#[test]
 	fn decoding_rpc_collection_v2() {
-		decode_collection_from_bytes::<RpcCollection<[u8; 32]>>(
+		decode_collection_from_bytes::<RpcCollectionVersion2<[u8; 32]>>(
 			ENCODED_RPC_COLLECTION_V2.as_slice(),
 		)
 		.unwrap();
@@ -612,6 +937,6 @@ mod tests {
 		let vec = RawEncoded::decode(&mut bytes).unwrap();
 		println!("{:?}", vec.len());
 		let mut bytes = IoReader(vec.as_slice());
-		RpcCollection::<[u8; 32]>::decode(&mut bytes).unwrap();
+		RpcCollectionVersion2::<[u8; 32]>::decode(&mut bytes).unwrap();
 	}
 }
@@ -99,6 +99,41 @@ export interface ICollectionLimits {
   ownerCanTransfer?: boolean | null;
   ownerCanDestroy?: boolean | null;
   transfersEnabled?: boolean | null;
+  sponsorBurnTimeout?: number | null;
+  sponsorAllowanceForAllTimeout?: number | null;
+  sponsorRepartitionTimeout?: number | null;
+}
+
+export interface ISponsorBudget {
+  period: number;
+  periodLimit: bigint | null;
+  userLimit: bigint | null;
+}
+
+export interface IPublicMintSponsoring {
+  period: number;
+  mintsPerUser: number;
+  maxPropertiesSize: number;
+}
+
+export interface ISponsorBudgetLeft {
+  period: bigint | null;
+  user: bigint | null;
+  periodEnd: number | null;
+}
+
+export type TSponsoringReason = 'Sponsored' | 'Unsigned' | 'NoSponsor' | 'NotEligible' | 'BudgetExhausted' | 'GasPriceNotSponsored' | 'SponsorBalanceTooLow';
+
+export interface ISponsoringDryRun {
+  sponsor: ICrossAccountId | null;
+  fee: bigint;
+  reason: TSponsoringReason;
+}
+
+export interface ISponsoredFees {
+  era: number;
+  total: bigint;
+  topUsers: {user: ICrossAccountId, fee: bigint}[];
 }
 
 export interface INestingPermissions {
@@ -197,6 +232,25 @@ export interface ISubstrateBalance {
 export interface IStakingInfo {
   block: bigint,
   amount: bigint,
+  lock?: {tier: number, unlockBlock: bigint},
+}
+
+export interface IStakeDelegation {
+  target: string,
+  amount: bigint,
+}
+
+export type TRewardDestination = 'Compound' | 'Liquid' | {Account: string};
+
+export interface IStakingPayout {
+  recalculationBlock: bigint,
+  amount: bigint,
+}
+
+export interface IRewardPotRunway {
+  pot: bigint,
+  intervalPayout: bigint,
+  runwayBlocks: bigint | null,
 }
 
 export interface IPovInfo {
@@ -430,6 +430,14 @@ class CollatorSelectionGroup extends HelperGroup<UniqueHelper> {
     return (await this.helper.callRpc('api.query.configuration.collatorSelectionLicenseBondOverride')).toBigInt();
   }
 
+  setMaxStrikes(signer: TSigner, maxStrikes: number | null) {
+    return this.helper.executeExtrinsic(signer, 'api.tx.configuration.setCollatorSelectionMaxStrikes', [maxStrikes]);
+  }
+
+  async getMaxStrikes(): Promise<number> {
+    return (await this.helper.callRpc('api.query.configuration.collatorSelectionMaxStrikesOverride')).toNumber();
+  }
+
   obtainLicense(signer: TSigner) {
     return this.helper.executeExtrinsic(signer, 'api.tx.collatorSelection.getLicense', []);
   }
@@ -457,6 +465,42 @@ class CollatorSelectionGroup extends HelperGroup<UniqueHelper> {
   async getCandidates(): Promise<string[]> {
     return (await this.helper.callRpc('api.query.collatorSelection.candidates')).map((x: any) => x.toHuman());
   }
+
+  joinWaitingList(signer: TSigner) {
+    return this.helper.executeExtrinsic(signer, 'api.tx.collatorSelection.joinWaitingList', []);
+  }
+
+  leaveWaitingList(signer: TSigner) {
+    return this.helper.executeExtrinsic(signer, 'api.tx.collatorSelection.leaveWaitingList', []);
+  }
+
+  async getWaitingCandidates(): Promise<string[]> {
+    return (await this.helper.callRpc('api.rpc.collatorSelection.waitingCandidates', [])).map((x: any) => x.toHuman());
+  }
+
+  minDelegation(): bigint {
+    return (this.helper.getApi().consts.collatorSelection.minDelegation as any).toBigInt();
+  }
+
+  delegate(signer: TSigner, candidate: string, amount: bigint) {
+    return this.helper.executeExtrinsic(signer, 'api.tx.collatorSelection.delegate', [candidate, amount]);
+  }
+
+  undelegate(signer: TSigner, candidate: string, amount: bigint) {
+    return this.helper.executeExtrinsic(signer, 'api.tx.collatorSelection.undelegate', [candidate, amount]);
+  }
+
+  withdrawUnbonded(signer: TSigner) {
+    return this.helper.executeExtrinsic(signer, 'api.tx.collatorSelection.withdrawUnbonded', []);
+  }
+
+  setCommission(signer: TSigner, commission: number) {
+    return this.helper.executeExtrinsic(signer, 'api.tx.collatorSelection.setCommission', [commission]);
+  }
+
+  async getTotalBacking(address: string): Promise<bigint> {
+    return (await this.helper.callRpc('api.query.collatorSelection.totalBacking', [address])).toBigInt();
+  }
 }
 
 export class DevUniqueHelper extends UniqueHelper {
@@ -535,6 +579,8 @@ export class DevUniqueHelper extends UniqueHelper {
       rpc: {
         unique: defs.unique.rpc,
         appPromotion: defs.appPromotion.rpc,
+        inflation: defs.inflation.rpc,
+        collatorSelection: defs.collatorSelection.rpc,
         povinfo: defs.povinfo.rpc,
         eth: {
           feeHistory: {
@@ -26,7 +26,16 @@ import type {
   ILogger,
   INestingPermissions,
   IProperty,
+  IPublicMintSponsoring,
+  ISponsorBudget,
+  ISponsorBudgetLeft,
+  ISponsoredFees,
+  ISponsoringDryRun,
   IStakingInfo,
+  IStakeDelegation,
+  IStakingPayout,
+  IRewardPotRunway,
+  TRewardDestination,
   ISubstrateBalance,
   IToken,
   ITokenPropertyPermission,
@@ -655,6 +664,74 @@ export class ChainHelperBase {
     }
   }
 
+  /**
+   * Check whether the extrinsic would be sponsored, without sending it.
+   *
+   * @param signer keyring of the caller
+   * @param apiCall extrinsic to check, e.g. 'api.tx.unique.transfer'
+   * @param params extrinsic parameters
+   * @example await getSponsoringDryRun(aliceKeyring, 'api.tx.unique.transfer', [{Substrate: bob.address}, 10, 1, 1])
+   * @returns the sponsor, the fee and the reason why the sponsor was or wasn't chosen
+   */
+  async getSponsoringDryRun(signer: TSigner, apiCall: string, params: any[]): Promise<ISponsoringDryRun> {
+    const tx = await this.signTransactionWithoutSending(signer, this.constructApiCall(apiCall, params));
+    return this.parseSponsoringDryRun(await this.callRpc('api.rpc.unique.sponsoringDryRun', [tx]));
+  }
+
+  /**
+   * Check whether the EVM call would be sponsored, without sending it.
+   *
+   * @param source address of the caller
+   * @param target address of the called contract
+   * @param input call data
+   * @param gasLimit gas limit of the call
+   * @param maxFeePerGas gas price of the call
+   * @returns the sponsor, the fee and the reason why the sponsor was or wasn't chosen
+   */
+  async getEvmSponsoringDryRun(source: string, target: string, input: string, gasLimit: bigint, maxFeePerGas: bigint): Promise<ISponsoringDryRun> {
+    return this.parseSponsoringDryRun(await this.callRpc('api.rpc.unique.evmSponsoringDryRun', [source, target, input, gasLimit, maxFeePerGas]));
+  }
+
+  private parseSponsoringDryRun(dryRun: any): ISponsoringDryRun {
+    return {
+      sponsor: dryRun.sponsor.isSome ? CrossAccountId.fromLowerCaseKeys(dryRun.sponsor.unwrap().toJSON()).toICrossAccountId() : null,
+      fee: dryRun.fee.toBigInt(),
+      reason: dryRun.reason.type,
+    };
+  }
+
+  /**
+   * Get the fees paid by the sponsor within the era.
+   *
+   * @param sponsor address of the sponsor
+   * @param era era of the sponsoring statistics, the current one if not specified
+   * @returns total fee paid within the era
+   */
+  async getSponsorFeesPaid(sponsor: ICrossAccountId, era: number | null = null): Promise<bigint> {
+    return (await this.callRpc('api.rpc.unique.sponsorFeesPaid', [sponsor, era])).toBigInt();
+  }
+
+  /**
+   * Get the fees paid for the calls of the sponsored collection or contract within the era.
+   *
+   * @param target address of the sponsored collection or contract
+   * @param era era of the sponsoring statistics, the current one if not specified
+   * @param top number of the users, on behalf of which the most was paid, to return
+   * @example await getSponsoredFees('0x17c4e6453cc49aaaaeaca894e6d9683e0000000a', null, 5)
+   * @returns total fee paid within the era, and the top sponsored users
+   */
+  async getSponsoredFees(target: string, era: number | null = null, top = 10): Promise<ISponsoredFees> {
+    const fees = await this.callRpc('api.rpc.unique.sponsoredFees', [target, era, top]);
+    return {
+      era: fees.era.toNumber(),
+      total: fees.total.toBigInt(),
+      topUsers: fees.topUsers.map(([user, fee]: any) => ({
+        user: CrossAccountId.fromLowerCaseKeys(user.toJSON()).toICrossAccountId(),
+        fee: fee.toBigInt(),
+      })),
+    };
+  }
+
   constructApiCall(apiCall: string, params: any[]) {
     if(!apiCall.startsWith('api.')) throw Error(`Invalid api call: ${apiCall}`);
     let call = this.getApi() as any;
@@ -952,6 +1029,25 @@ class CollectionGroup extends HelperGroup<UniqueHelper> {
     return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionDestroyed');
   }
 
+  /**
+   * Starts destroying the collection if the signer has sufficient permissions, even if the collection still has tokens.
+   * The tokens are burnt in the following blocks.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @example await helper.collection.beginBurn(aliceKeyring, 3);
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async beginBurn(signer: TSigner, collectionId: number): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.beginDestroyCollection', [collectionId],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionDestructionStarted');
+  }
+
   /**
    * Sets the sponsor for the collection (Requires the Substrate address). Needs confirmation by the sponsor.
    *
@@ -1007,6 +1103,81 @@ class CollectionGroup extends HelperGroup<UniqueHelper> {
     return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionSponsorRemoved');
   }
 
+  /**
+   * Limits the fees paid by the collection sponsor, per period and per user.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param budget new limits of the sponsor fees, or null to remove the limits
+   * @example setSponsorBudget(aliceKeyring, 10, {period: 100, periodLimit: 10n ** 18n, userLimit: null})
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async setSponsorBudget(signer: TSigner, collectionId: number, budget: ISponsorBudget | null): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.setCollectionSponsorBudget', [collectionId, budget],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'SponsorBudgetSet');
+  }
+
+  /**
+   * Sponsors the tokens minted by allowlisted non-admin users, while the collection mint mode is enabled.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param sponsoring per-user mint quota and initial properties size limit, or null to stop sponsoring public mints
+   * @example setPublicMintSponsoring(aliceKeyring, 10, {period: 7200, mintsPerUser: 5, maxPropertiesSize: 256})
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async setPublicMintSponsoring(signer: TSigner, collectionId: number, sponsoring: IPublicMintSponsoring | null): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.setPublicMintSponsoring', [collectionId, sponsoring],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'PublicMintSponsoringSet');
+  }
+
+  /**
+   * Sponsors the calls to the tokens nested in the tokens of the collection, if their own collections have no sponsor.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param enabled whether the nested tokens should be sponsored
+   * @example setNestedTokensSponsoring(aliceKeyring, 10, true)
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async setNestedTokensSponsoring(signer: TSigner, collectionId: number, enabled: boolean): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.setNestedTokensSponsoring', [collectionId, enabled],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'NestedTokensSponsoringSet');
+  }
+
+  /**
+   * Get the fees the collection sponsor is still ready to pay in the current budget period.
+   *
+   * @param collectionId ID of collection
+   * @param addressObj address of the user
+   * @example await getSponsorBudgetLeft(10, {Substrate: '5DfhbVfww7ThF8q6f3...'})
+   * @returns fees left in total and for the user, or null if the sponsor budget is not limited
+   */
+  async getSponsorBudgetLeft(collectionId: number, addressObj: ICrossAccountId): Promise<ISponsorBudgetLeft | null> {
+    const left = (await this.helper.callRpc('api.rpc.unique.sponsorBudgetLeft', [collectionId, addressObj])).unwrapOr(null);
+    if(left === null) return null;
+    return {
+      period: left.period.isSome ? left.period.unwrap().toBigInt() : null,
+      user: left.user.isSome ? left.user.unwrap().toBigInt() : null,
+      periodEnd: left.periodEnd.isSome ? left.periodEnd.unwrap().toNumber() : null,
+    };
+  }
+
   /**
    * Sets the limits of the collection. At least one limit must be specified for a correct call.
    *
@@ -1035,11 +1206,12 @@ class CollectionGroup extends HelperGroup<UniqueHelper> {
   }
 
   /**
-   * Changes the owner of the collection to the new Substrate address.
+   * Proposes the new Substrate address to become the owner of the collection, without expiry.
+   * Deprecated, use ```proposeOwner```. The new owner has to accept the ownership.
    *
    * @param signer keyring of signer
    * @param collectionId ID of collection
-   * @param ownerAddress substrate address of new owner
+   * @param ownerAddress substrate address of proposed owner
    * @example changeOwner(aliceKeyring, 10, "5DyN4Y92vZCjv38fg...")
    * @returns ```true``` if extrinsic success, otherwise ```false```
    */
@@ -1050,9 +1222,77 @@ class CollectionGroup extends HelperGroup<UniqueHelper> {
       true,
     );
 
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionOwnerProposed');
+  }
+
+  /**
+   * Proposes the new Substrate address to become the owner of the collection.
+   * The ownership is handed over only after the proposed account accepts it.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param ownerAddress substrate address of proposed owner
+   * @param expiresAt the last block at which the proposal can be accepted
+   * @example proposeOwner(aliceKeyring, 10, "5DyN4Y92vZCjv38fg...")
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async proposeOwner(signer: TSigner, collectionId: number, ownerAddress: TSubstrateAccount, expiresAt: number | null = null): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.proposeCollectionOwner', [collectionId, ownerAddress, expiresAt],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionOwnerProposed');
+  }
+
+  /**
+   * Accepts the proposed ownership of the collection on behalf of the signer.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @example acceptOwnership(bobKeyring, 10)
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async acceptOwnership(signer: TSigner, collectionId: number): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.acceptCollectionOwnership', [collectionId],
+      true,
+    );
+
     return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionOwnerChanged');
   }
 
+  /**
+   * Cancels the pending ownership transfer of the collection.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @example cancelOwnerProposal(aliceKeyring, 10)
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async cancelOwnerProposal(signer: TSigner, collectionId: number): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.cancelCollectionOwnerProposal', [collectionId],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionOwnerProposalCancelled');
+  }
+
+  /**
+   * Get the proposed owner of the collection, awaiting acceptance
+   *
+   * @param collectionId ID of collection
+   * @example await getPendingOwner(2)
+   * @returns object with the proposed owner and the expiration block, or null if there is no proposal
+   */
+  async getPendingOwner(collectionId: number): Promise<{owner: string, expiresAt: number | null} | null> {
+    return (await this.helper.callRpc('api.rpc.unique.collectionPendingOwner', [collectionId])).toJSON();
+  }
+
   /**
    * Adds a collection administrator.
    *
@@ -1091,6 +1331,38 @@ class CollectionGroup extends HelperGroup<UniqueHelper> {
     return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionAdminRemoved');
   }
 
+  /**
+   * Restricts a collection administrator to a subset of administrative roles.
+   *
+   * @param signer keyring of signer
+   * @param collectionId ID of collection
+   * @param adminAddressObj Administrator address (substrate or ethereum)
+   * @param roles bit set of roles: 1 - minter, 2 - metadata editor, 4 - allowlist manager, 8 - sponsor manager, 16 - limit manager
+   * @example setAdminRoles(aliceKeyring, 10, {Substrate: "5DyN4Y92vZCjv38fg..."}, 1)
+   * @returns ```true``` if extrinsic success, otherwise ```false```
+   */
+  async setAdminRoles(signer: TSigner, collectionId: number, adminAddressObj: ICrossAccountId, roles: number): Promise<boolean> {
+    const result = await this.helper.executeExtrinsic(
+      signer,
+      'api.tx.unique.setCollectionAdminRoles', [collectionId, adminAddressObj, roles],
+      true,
+    );
+
+    return this.helper.util.findCollectionInEvents(result.result.events, collectionId, 'common', 'CollectionAdminRolesSet');
+  }
+
+  /**
+   * Get the collection administrators together with their roles.
+   *
+   * @param collectionId ID of collection
+   * @example await getAdminsWithRoles(1)
+   * @returns array of administrator addresses and their role bit sets
+   */
+  async getAdminsWithRoles(collectionId: number): Promise<[ICrossAccountId, number][]> {
+    const admins = (await this.helper.callRpc('api.rpc.unique.adminlistWithRoles', [collectionId])).toJSON() as [any, number][];
+    return admins.map(([address, roles]) => [CrossAccountId.fromLowerCaseKeys(address).toICrossAccountId(), roles]);
+  }
+
   /**
    * Check if user is in allow list.
    *
@@ -2777,6 +3049,21 @@ class StakingGroup extends HelperGroup<UniqueHelper> {
     return true;
   }
 
+  /**
+   * Stake tokens for App Promotion with a lock-period tier
+   * @param signer keyring of signer
+   * @param amountToStake amount of tokens to stake
+   * @param tier index of the lock tier
+   * @returns
+   */
+  async stakeLocked(signer: TSigner, amountToStake: bigint, tier: number): Promise<boolean> {
+    await this.helper.executeExtrinsic(
+      signer, 'api.tx.appPromotion.stakeLocked',
+      [amountToStake, tier], true,
+    );
+    return true;
+  }
+
   /**
    * Unstake all staked tokens
    * @param signer keyring of signer
@@ -2836,9 +3123,10 @@ class StakingGroup extends HelperGroup<UniqueHelper> {
    */
   async getTotalStakedPerBlock(address: ICrossAccountId): Promise<IStakingInfo[]> {
     const rawTotalStakerdPerBlock = await this.helper.callRpc('api.rpc.appPromotion.totalStakedPerBlock', [address]);
-    return rawTotalStakerdPerBlock.map(([block, amount]: any[]) => ({
+    return rawTotalStakerdPerBlock.map(([block, amount, lock]: any[]) => ({
       block: block.toBigInt(),
       amount: amount.toBigInt(),
+      ...(lock.isSome ? {lock: {tier: lock.unwrap()[0].toNumber(), unlockBlock: lock.unwrap()[1].toBigInt()}} : {}),
     }));
   }
 
@@ -2864,6 +3152,131 @@ class StakingGroup extends HelperGroup<UniqueHelper> {
     }));
     return result;
   }
+
+  /**
+   * Delegate a part of the stake to a collection or contract sponsored by App Promotion
+   * @param signer keyring of signer
+   * @param target address of the collection or contract
+   * @param amount amount of tokens to delegate
+   * @returns
+   */
+  async delegateStake(signer: TSigner, target: string, amount: bigint): Promise<boolean> {
+    await this.helper.executeExtrinsic(
+      signer, 'api.tx.appPromotion.delegateStake',
+      [target, amount], true,
+    );
+    return true;
+  }
+
+  /**
+   * Withdraw a part of the stake delegated to a collection or contract
+   * @param signer keyring of signer
+   * @param target address of the collection or contract
+   * @param amount amount of tokens to withdraw
+   * @returns
+   */
+  async undelegateStake(signer: TSigner, target: string, amount: bigint): Promise<boolean> {
+    await this.helper.executeExtrinsic(
+      signer, 'api.tx.appPromotion.undelegateStake',
+      [target, amount], true,
+    );
+    return true;
+  }
+
+  /**
+   * Get the collections and contracts the stake was delegated to
+   * @param address substrate address
+   * @returns array of delegations. `target` - address of the collection or contract. `amount` - the number of tokens delegated to it
+   */
+  async getDelegatedStake(address: ICrossAccountId): Promise<IStakeDelegation[]> {
+    const rawDelegations = await this.helper.callRpc('api.rpc.appPromotion.delegatedStake', [address]);
+    return rawDelegations.map(([target, amount]: any[]) => ({
+      target: target.toString(),
+      amount: amount.toBigInt(),
+    }));
+  }
+
+  /**
+   * Get the total stake delegated to a collection or contract
+   * @param target address of the collection or contract
+   * @returns total delegated amount
+   */
+  async getTargetDelegatedStake(target: string): Promise<bigint> {
+    return (await this.helper.callRpc('api.rpc.appPromotion.targetDelegatedStake', [target])).toBigInt();
+  }
+
+  /**
+   * Get the amount App Promotion may spend within a sponsoring era on the calls of a collection or contract
+   * @param target address of the collection or contract
+   * @returns sponsoring allowance
+   */
+  async getSponsoringAllowance(target: string): Promise<bigint> {
+    return (await this.helper.callRpc('api.rpc.appPromotion.sponsoringAllowance', [target])).toBigInt();
+  }
+
+  /**
+   * Get the interest paid to the address for the last recalculation periods
+   * @param address substrate or ethereum address
+   * @returns array of the recalculation relay blocks with the paid amounts
+   */
+  async getPayoutHistory(address: ICrossAccountId): Promise<IStakingPayout[]> {
+    const rawHistory = await this.helper.callRpc('api.rpc.appPromotion.payoutHistory', [address]);
+    return rawHistory.map(([block, amount]: any[]) => ({
+      recalculationBlock: block.toBigInt(),
+      amount: amount.toBigInt(),
+    }));
+  }
+
+  /**
+   * Get the interest the address will receive in the next recalculation at the current rate
+   * @param address substrate or ethereum address
+   * @returns relay block of the next recalculation with the projected amount
+   */
+  async getProjectedPayout(address: ICrossAccountId): Promise<IStakingPayout> {
+    const [block, amount] = await this.helper.callRpc('api.rpc.appPromotion.projectedPayout', [address]);
+    return {
+      recalculationBlock: block.toBigInt(),
+      amount: amount.toBigInt(),
+    };
+  }
+
+  /**
+   * Set where the interest accrued by the stakes of the signer is paid
+   * @param signer keyring of signer
+   * @param destination 'Compound' to add the interest to the stakes, 'Liquid' to pay it to the signer, or an account to pay it to
+   * @returns
+   */
+  async setRewardDestination(signer: TSigner, destination: TRewardDestination): Promise<boolean> {
+    await this.helper.executeExtrinsic(
+      signer, 'api.tx.appPromotion.setRewardDestination',
+      [destination], true,
+    );
+    return true;
+  }
+
+  /**
+   * Get where the interest accrued by the stakes of the address is paid
+   * @param address substrate or ethereum address
+   * @returns reward destination
+   */
+  async getRewardDestination(address: ICrossAccountId): Promise<TRewardDestination> {
+    const destination = await this.helper.callRpc('api.rpc.appPromotion.rewardDestination', [address]);
+    if(destination.isAccount) return {Account: destination.asAccount.toString()};
+    return destination.type;
+  }
+
+  /**
+   * Get the treasury balance App Promotion pays the interest from, and how long it lasts
+   * @returns the balance, the interest paid per recalculation interval and the number of relay blocks the balance lasts
+   */
+  async getRewardPotRunway(): Promise<IRewardPotRunway> {
+    const [pot, intervalPayout, runwayBlocks] = await this.helper.callRpc('api.rpc.appPromotion.rewardPotRunway', []);
+    return {
+      pot: pot.toBigInt(),
+      intervalPayout: intervalPayout.toBigInt(),
+      runwayBlocks: runwayBlocks.isSome ? runwayBlocks.unwrap().toBigInt() : null,
+    };
+  }
 }
 
 
@@ -3038,6 +3451,22 @@ export class UniqueBaseCollection {
     return await this.helper.collection.removeSponsor(signer, this.collectionId);
   }
 
+  async setSponsorBudget(signer: TSigner, budget: ISponsorBudget | null) {
+    return await this.helper.collection.setSponsorBudget(signer, this.collectionId, budget);
+  }
+
+  async getSponsorBudgetLeft(addressObj: ICrossAccountId) {
+    return await this.helper.collection.getSponsorBudgetLeft(this.collectionId, addressObj);
+  }
+
+  async setPublicMintSponsoring(signer: TSigner, sponsoring: IPublicMintSponsoring | null) {
+    return await this.helper.collection.setPublicMintSponsoring(signer, this.collectionId, sponsoring);
+  }
+
+  async setNestedTokensSponsoring(signer: TSigner, enabled: boolean) {
+    return await this.helper.collection.setNestedTokensSponsoring(signer, this.collectionId, enabled);
+  }
+
   async setLimits(signer: TSigner, limits: ICollectionLimits) {
     return await this.helper.collection.setLimits(signer, this.collectionId, limits);
   }
@@ -3046,10 +3475,34 @@ export class UniqueBaseCollection {
     return await this.helper.collection.changeOwner(signer, this.collectionId, ownerAddress);
   }
 
+  async proposeOwner(signer: TSigner, ownerAddress: TSubstrateAccount, expiresAt: number | null = null) {
+    return await this.helper.collection.proposeOwner(signer, this.collectionId, ownerAddress, expiresAt);
+  }
+
+  async acceptOwnership(signer: TSigner) {
+    return await this.helper.collection.acceptOwnership(signer, this.collectionId);
+  }
+
+  async cancelOwnerProposal(signer: TSigner) {
+    return await this.helper.collection.cancelOwnerProposal(signer, this.collectionId);
+  }
+
+  async getPendingOwner() {
+    return await this.helper.collection.getPendingOwner(this.collectionId);
+  }
+
   async addAdmin(signer: TSigner, adminAddressObj: ICrossAccountId) {
     return await this.helper.collection.addAdmin(signer, this.collectionId, adminAddressObj);
   }
 
+  async setAdminRoles(signer: TSigner, adminAddressObj: ICrossAccountId, roles: number) {
+    return await this.helper.collection.setAdminRoles(signer, this.collectionId, adminAddressObj, roles);
+  }
+
+  async getAdminsWithRoles() {
+    return await this.helper.collection.getAdminsWithRoles(this.collectionId);
+  }
+
   async addToAllowList(signer: TSigner, addressObj: ICrossAccountId) {
     return await this.helper.collection.addToAllowList(signer, this.collectionId, addressObj);
   }
@@ -3085,6 +3538,10 @@ export class UniqueBaseCollection {
   async burn(signer: TSigner) {
     return await this.helper.collection.burn(signer, this.collectionId);
   }
+
+  async beginBurn(signer: TSigner) {
+    return await this.helper.collection.beginBurn(signer, this.collectionId);
+  }
 }
 
 export class UniqueNFTCollection extends UniqueBaseCollection {
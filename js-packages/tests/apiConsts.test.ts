@@ -39,6 +39,9 @@ const DEFAULT_COLLETCTION_LIMIT = {
   ownerCanTransfer: false,
   ownerCanDestroy: true,
   transfersEnabled: true,
+  sponsorBurnTimeout: '5',
+  sponsorAllowanceForAllTimeout: '5',
+  sponsorRepartitionTimeout: '5',
 };
 
 describe('integration test: API UNIQUE consts', () => {
@@ -35,11 +35,62 @@ describe('Integration Test changeCollectionOwner(collection_id, new_owner):', ()
     expect(beforeChanging?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(alice.address));
 
     await collection.changeOwner(alice, bob.address);
+    await collection.acceptOwnership(bob);
     const afterChanging = await helper.collection.getData(collection.collectionId);
     expect(afterChanging?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(bob.address));
   });
 });
 
+describe('Integration Test proposeCollectionOwner(collection_id, new_owner, expires_at):', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+  let charlie: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    });
+  });
+
+  itSub('Proposed owner becomes the owner only after acceptance', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+
+    await collection.proposeOwner(alice, bob.address);
+    expect((await collection.getData())?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(alice.address));
+    expect(helper.address.normalizeSubstrate((await collection.getPendingOwner())!.owner)).to.be.equal(helper.address.normalizeSubstrate(bob.address));
+
+    await expect(collection.acceptOwnership(charlie)).to.be.rejectedWith(/common\.AcceptOwnershipFail/);
+    await collection.acceptOwnership(bob);
+
+    expect((await collection.getData())?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(bob.address));
+    expect(await collection.getPendingOwner()).to.be.null;
+  });
+
+  itSub('Pending ownership transfer can be cancelled by the owner', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+
+    await collection.proposeOwner(alice, bob.address);
+    await expect(collection.cancelOwnerProposal(bob)).to.be.rejectedWith(/common\.NoPermission/);
+    await collection.cancelOwnerProposal(alice);
+
+    expect(await collection.getPendingOwner()).to.be.null;
+    await expect(collection.acceptOwnership(bob)).to.be.rejectedWith(/common\.AcceptOwnershipFail/);
+  });
+
+  itSub('Expired proposal can not be accepted', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
+
+    const expiresAt = await helper.chain.getLatestBlockNumber() + 2;
+    await collection.proposeOwner(alice, bob.address, expiresAt);
+    expect((await collection.getPendingOwner())!.expiresAt).to.be.equal(expiresAt);
+    await helper.wait.forParachainBlockNumber(expiresAt + 1);
+
+    await expect(collection.acceptOwnership(bob)).to.be.rejectedWith(/common\.OwnershipProposalExpired/);
+    expect((await collection.getData())?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(alice.address));
+  });
+});
+
 describe('Integration Test changeCollectionOwner(collection_id, new_owner) special checks for exOwner:', () => {
   let alice: IKeyringPair;
   let bob: IKeyringPair;
@@ -56,6 +107,7 @@ describe('Integration Test changeCollectionOwner(collection_id, new_owner) speci
     const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
 
     await collection.changeOwner(alice, bob.address);
+    await collection.acceptOwnership(bob);
 
     const changeOwnerTx = () => collection.changeOwner(alice, alice.address);
     await expect(changeOwnerTx()).to.be.rejectedWith(/common\.NoPermission/);
@@ -67,6 +119,7 @@ describe('Integration Test changeCollectionOwner(collection_id, new_owner) speci
   itSub('New collectionOwner has access to sponsorship management operations in the collection', async ({helper}) => {
     const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
     await collection.changeOwner(alice, bob.address);
+    await collection.acceptOwnership(bob);
 
     const afterChanging = await helper.collection.getData(collection.collectionId);
     expect(afterChanging?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(bob.address));
@@ -96,7 +149,9 @@ describe('Integration Test changeCollectionOwner(collection_id, new_owner) speci
   itSub('New collectionOwner has access to changeCollectionOwner', async ({helper}) => {
     const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
     await collection.changeOwner(alice, bob.address);
+    await collection.acceptOwnership(bob);
     await collection.changeOwner(bob, charlie.address);
+    await collection.acceptOwnership(charlie);
     const collectionData = await collection.getData();
     expect(collectionData?.normalizedOwner).to.be.equal(helper.address.normalizeSubstrate(charlie.address));
   });
@@ -136,6 +191,7 @@ describe('Negative Integration Test changeCollectionOwner(collection_id, new_own
   itSub('Former collectionOwner not allowed to sponsorship management operations in the collection', async ({helper}) => {
     const collection = await helper.nft.mintCollection(alice, {name: 'col', description: 'descr', tokenPrefix: 'COL'});
     await collection.changeOwner(alice, bob.address);
+    await collection.acceptOwnership(bob);
 
     const changeOwnerTx = () => collection.changeOwner(alice, alice.address);
     await expect(changeOwnerTx()).to.be.rejectedWith(/common\.NoPermission/);
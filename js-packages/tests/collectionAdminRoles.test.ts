@@ -0,0 +1,68 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {itSub, usingPlaygrounds, expect} from './util/index.js';
+
+const MINTER = 1;
+const ALL_ROLES = 31;
+
+describe('Collection admin roles', () => {
+  let donor: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (_, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+    });
+  });
+
+  itSub('Admin is granted all roles by default', async ({helper}) => {
+    const [alice, bob] = await helper.arrange.createAccounts([10n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+
+    await collection.addAdmin(alice, {Substrate: bob.address});
+
+    expect(await collection.getAdminsWithRoles()).to.be.deep.equal([[{Substrate: bob.address}, ALL_ROLES]]);
+  });
+
+  itSub('Minter admin can mint, but cannot manage the collection', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([10n, 10n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await collection.setAdminRoles(alice, {Substrate: bob.address}, MINTER);
+    expect(await collection.getAdminsWithRoles()).to.be.deep.equal([[{Substrate: bob.address}, MINTER]]);
+
+    const token = await collection.mintToken(bob, {Substrate: charlie.address});
+    expect(await token.getOwner()).to.be.deep.equal({Substrate: charlie.address});
+
+    await expect(collection.setSponsor(bob, charlie.address)).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.setLimits(bob, {accountTokenOwnershipLimit: 1})).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.addToAllowList(bob, {Substrate: charlie.address})).to.be.rejectedWith(/common\.NoPermission/);
+  });
+
+  itSub('Only owner can set roles of an existing admin', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([10n, 10n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+
+    await expect(collection.setAdminRoles(alice, {Substrate: bob.address}, MINTER)).to.be.rejectedWith(/common\.UserIsNotCollectionAdmin/);
+
+    await collection.addAdmin(alice, {Substrate: bob.address});
+    await expect(collection.setAdminRoles(bob, {Substrate: bob.address}, MINTER)).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.setAdminRoles(charlie, {Substrate: bob.address}, MINTER)).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(collection.setAdminRoles(alice, {Substrate: bob.address}, 128)).to.be.rejectedWith(/common\.UnknownAdminRole/);
+  });
+});
@@ -19,11 +19,12 @@ import {itSub, expect, usingPlaygrounds, Pallets} from './util/index.js';
 
 describe('integration test: ext. destroyCollection():', () => {
   let alice: IKeyringPair;
+  let bob: IKeyringPair;
 
   before(async () => {
     await usingPlaygrounds(async (helper, privateKey) => {
       const donor = await privateKey({url: import.meta.url});
-      [alice] = await helper.arrange.createAccounts([100n], donor);
+      [alice, bob] = await helper.arrange.createAccounts([100n, 100n], donor);
     });
   });
 
@@ -54,6 +55,22 @@ describe('integration test: ext. destroyCollection():', () => {
     await collection.burn(alice);
     expect(await collection.getData()).to.be.null;
   });
+  itSub('Non-empty collection can be destroyed in the following blocks', async ({helper}) => {
+    const collection = await helper.nft.mintCollection(alice, {
+      name: 'test',
+      description: 'test',
+      tokenPrefix: 'test',
+    });
+    const token = await collection.mintToken(alice, {Substrate: alice.address});
+
+    expect(await collection.beginBurn(alice)).to.be.true;
+    expect(await collection.getData()).to.be.null;
+    await expect(token.transfer(alice, {Substrate: bob.address})).to.be.rejectedWith(/common\.CollectionNotFound/);
+
+    await helper.wait.newBlocks(2);
+    expect((await helper.getApi().query.common.destroyingCollections(collection.collectionId)).isNone).to.be.true;
+    expect(await token.doesExist()).to.be.false;
+  });
 });
 
 describe('(!negative test!) integration test: ext. destroyCollection():', () => {
@@ -12,6 +12,29 @@ interface ERC165 is Dummy {
 	function supportsInterface(bytes4 interfaceID) external view returns (bool);
 }
 
+/// @title A contract that allows to equip nested tokens into slots of their parent tokens.
+/// @dev the ERC-165 identifier for this interface is 0xa73deffa
+interface TokenSlots is Dummy, ERC165 {
+	/// @notice Equip a token nested directly into `tokenId` into the slot of it.
+	/// @dev Throws error if `msg.sender` is not the owner of `tokenId`.
+	/// @param tokenId ID of the parent token.
+	/// @param slot Slot name, defined by the collection.
+	/// @param childCollection Address of the nested token collection.
+	/// @param childTokenId ID of the nested token.
+	/// @dev EVM selector for this function is: 0x3c004d6a,
+	///  or in textual repr: equip(uint256,string,address,uint256)
+	function equip(uint256 tokenId, string memory slot, address childCollection, uint256 childTokenId) external;
+
+	/// @notice Remove a token from the slot of `tokenId`, keeping it nested.
+	/// @dev Throws error if `msg.sender` is not the owner of `tokenId`.
+	/// @param tokenId ID of the parent token.
+	/// @param childCollection Address of the equipped token collection.
+	/// @param childTokenId ID of the equipped token.
+	/// @dev EVM selector for this function is: 0x9b3da290,
+	///  or in textual repr: unequip(uint256,address,uint256)
+	function unequip(uint256 tokenId, address childCollection, uint256 childTokenId) external;
+}
+
 /// @dev inlined interface
 interface ERC721TokenEvent {
 	event TokenChanged(uint256 indexed tokenId);
@@ -118,7 +141,7 @@ enum TokenPermissionField {
 }
 
 /// @title A contract that allows you to work with collections.
-/// @dev the ERC-165 identifier for this interface is 0xb34d97e9
+/// @dev the ERC-165 identifier for this interface is 0x38df6317
 interface Collection is Dummy, ERC165 {
 	// /// Set collection property.
 	// ///
@@ -365,10 +388,11 @@ interface Collection is Dummy, ERC165 {
 	///  or in textual repr: collectionOwner()
 	function collectionOwner() external view returns (CrossAddress memory);
 
-	// /// Changes collection owner to another account
+	// /// Propose another account to become the collection owner
 	// ///
-	// /// @dev Owner can be changed only by current owner
-	// /// @param newOwner new owner account
+	// /// @dev Deprecated, use `proposeCollectionOwnerCross`. The ownership is handed over
+	// ///  only after the proposed account accepts it.
+	// /// @param newOwner Proposed owner account
 	// /// @dev EVM selector for this function is: 0x4f53e226,
 	// ///  or in textual repr: changeCollectionOwner(address)
 	// function changeCollectionOwner(address newOwner) external;
@@ -381,13 +405,67 @@ interface Collection is Dummy, ERC165 {
 	///  or in textual repr: collectionAdmins()
 	function collectionAdmins() external view returns (CrossAddress[] memory);
 
-	/// Changes collection owner to another account
+	/// Propose another account to become the collection owner
 	///
-	/// @dev Owner can be changed only by current owner
-	/// @param newOwner new owner cross account
+	/// @dev Deprecated, use `proposeCollectionOwnerCross`. The ownership is handed over
+	///  only after the proposed account accepts it.
+	/// @param newOwner Proposed owner cross account
 	/// @dev EVM selector for this function is: 0x6496c497,
 	///  or in textual repr: changeCollectionOwnerCross((address,uint256))
 	function changeCollectionOwnerCross(CrossAddress memory newOwner) external;
+
+	/// Propose another account to become the collection owner.
+	///
+	/// @dev The ownership is handed over only after the proposed account accepts it.
+	/// @param newOwner Proposed owner cross account
+	/// @param expiresAt The last block at which the proposal can be accepted, or 0 if it does not expire
+	/// @dev EVM selector for this function is: 0xb05f0cc4,
+	///  or in textual repr: proposeCollectionOwnerCross((address,uint256),uint32)
+	function proposeCollectionOwnerCross(CrossAddress memory newOwner, uint32 expiresAt) external;
+
+	/// Whether there is a pending owner.
+	/// @dev EVM selector for this function is: 0x8f17f795,
+	///  or in textual repr: hasCollectionPendingOwner()
+	function hasCollectionPendingOwner() external view returns (bool);
+
+	/// Get proposed owner.
+	///
+	/// @return Cross account of the proposed owner, or zero address if there is none.
+	/// @dev EVM selector for this function is: 0xe71220dd,
+	///  or in textual repr: collectionPendingOwner()
+	function collectionPendingOwner() external view returns (CrossAddress memory);
+
+	/// Accept the collection ownership.
+	///
+	/// @dev The caller must be proposed as the owner with `proposeCollectionOwnerCross` first.
+	/// @dev EVM selector for this function is: 0xb48773ac,
+	///  or in textual repr: acceptCollectionOwnership()
+	function acceptCollectionOwnership() external;
+
+	/// Cancel the pending collection ownership transfer.
+	///
+	/// @dev Can only be called by the current owner.
+	/// @dev EVM selector for this function is: 0xe117ff57,
+	///  or in textual repr: cancelCollectionOwnerProposal()
+	function cancelCollectionOwnerProposal() external;
+
+	/// Set the roles granted to a collection admin.
+	///
+	/// @dev Can only be called by the collection owner.
+	/// @param admin Cross account administrator address
+	/// @param roles Bit set of the roles: 1 - minter, 2 - metadata editor,
+	///  4 - allowlist manager, 8 - sponsor manager, 16 - limit manager
+	/// @dev EVM selector for this function is: 0xcc3dc830,
+	///  or in textual repr: setCollectionAdminRolesCross((address,uint256),uint8)
+	function setCollectionAdminRolesCross(CrossAddress memory admin, uint8 roles) external;
+
+	/// Get the roles granted to a collection admin.
+	///
+	/// @param admin Cross account administrator address
+	/// @return Bit set of the roles, or 0 if the account is not an admin
+	/// @dev EVM selector for this function is: 0xca656bb9,
+	///  or in textual repr: collectionAdminRoles((address,uint256))
+	function collectionAdminRoles(CrossAddress memory admin) external view returns (uint8);
 }
 
 /// Cross account struct
@@ -851,5 +929,6 @@ interface UniqueNFT is
 	ERC721Burnable,
 	ERC721Metadata,
 	Collection,
-	TokenProperties
+	TokenProperties,
+	TokenSlots
 {}
@@ -384,6 +384,8 @@ describe('Change owner tests', () => {
     const collectionEvm = await helper.ethNativeContract.collection(collectionAddress, 'nft', owner, true);
 
     await collectionEvm.methods.changeCollectionOwner(newOwner).send();
+    expect(await collectionEvm.methods.isOwnerOrAdmin(newOwner).call()).to.be.false;
+    await collectionEvm.methods.acceptCollectionOwnership().send({from: newOwner});
 
     expect(await collectionEvm.methods.isOwnerOrAdmin(owner).call()).to.be.false;
     expect(await collectionEvm.methods.isOwnerOrAdmin(newOwner).call()).to.be.true;
@@ -433,12 +435,14 @@ describe('Change substrate owner tests', () => {
 
     // Can set ethereum owner:
     await collectionEvm.methods.changeCollectionOwnerCross(ownerCrossEth).send({from: owner});
+    await collectionEvm.methods.acceptCollectionOwnership().send({from: ownerEth});
     expect(await collectionEvm.methods.isOwnerOrAdminCross(ownerCrossEth).call()).to.be.true;
     expect(await helper.collection.getData(collectionId))
       .to.have.property('normalizedOwner').that.is.eq(helper.address.ethToSubstrate(ownerEth));
 
     // Can set Substrate owner:
     await collectionEvm.methods.changeCollectionOwnerCross(ownerCrossSub).send({from: ownerEth});
+    await helper.collection.acceptOwnership(ownerSub, collectionId);
     expect(await collectionEvm.methods.isOwnerOrAdminCross(ownerCrossSub).call()).to.be.true;
     expect(await helper.collection.getData(collectionId))
       .to.have.property('normalizedOwner').that.is.eq(helper.address.normalizeSubstrate(ownerSub.address));
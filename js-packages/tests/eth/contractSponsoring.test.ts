@@ -394,6 +394,37 @@ describe('Sponsoring EVM contracts', () => {
     expect(await helper.balance.getEthereum(caller)).to.be.not.equal(originalCallerBalance);
   });
 
+  itEth('Sponsoring is limited with per-user quota, the sender pays the commission when quota is exhausted', async ({helper}) => {
+    const owner = await helper.eth.createAccountWithBalance(donor);
+    const sponsor = await helper.eth.createAccountWithBalance(donor);
+    const caller = await helper.eth.createAccountWithBalance(donor);
+    const helpers = await helper.ethNativeContract.contractHelpers(owner);
+    const flipper = await helper.eth.deployFlipper(owner);
+
+    await helpers.methods.setSponsoringMode(flipper.options.address, SponsoringMode.Generous).send({from: owner});
+    await helpers.methods.setSponsoringRateLimit(flipper.options.address, 0).send({from: owner});
+    await helpers.methods.setSponsoringQuota(flipper.options.address, 1000, 2).send({from: owner});
+    expect(await helpers.methods.sponsoringQuota(flipper.options.address).call()).to.be.like({window: '1000', calls: '2'});
+
+    await helpers.methods.setSponsor(flipper.options.address, sponsor).send();
+    await helpers.methods.confirmSponsorship(flipper.options.address).send({from: sponsor});
+
+    const originalCallerBalance = await helper.balance.getEthereum(caller);
+    expect(await helpers.methods.sponsoredCallsLeft(flipper.options.address, caller).call()).to.be.equal('2');
+    await flipper.methods.flip().send({from: caller});
+    await flipper.methods.flip().send({from: caller});
+    expect(await helper.balance.getEthereum(caller)).to.be.equal(originalCallerBalance);
+    expect(await helpers.methods.sponsoredCallsLeft(flipper.options.address, caller).call()).to.be.equal('0');
+
+    const sponsorBalance = await helper.balance.getEthereum(sponsor);
+    await flipper.methods.flip().send({from: caller});
+    expect(await helper.balance.getEthereum(sponsor)).to.be.equal(sponsorBalance);
+    expect(await helper.balance.getEthereum(caller) < originalCallerBalance).to.be.true;
+
+    await helpers.methods.setSponsoringQuota(flipper.options.address, 0, 0).send({from: owner});
+    expect(await helpers.methods.sponsoredCallsLeft(flipper.options.address, caller).call()).to.be.equal('4294967295');
+  });
+
   // TODO: Find a way to calculate default rate limit
   itEth('Default rate limit equal 7200', async ({helper}) => {
     const owner = await helper.eth.createAccountWithBalance(donor);
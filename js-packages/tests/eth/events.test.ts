@@ -263,7 +263,7 @@ async function testCollectionOwnerChanged(helper: EthUniqueHelper, mode: TCollec
   collectionHelper.events.allEvents((_: any, event: any) => {
     ethEvents.push(event);
   });
-  const {unsubscribe, collectedEvents: subEvents} = await helper.subscribeEvents([{section: 'common', names: ['CollectionOwnerChanged']}]);
+  const {unsubscribe, collectedEvents: subEvents} = await helper.subscribeEvents([{section: 'common', names: ['CollectionOwnerProposed']}]);
   {
     await collection.methods.changeCollectionOwnerCross(newOwner).send({from: owner});
     await helper.wait.newBlocks(1);
@@ -275,7 +275,7 @@ async function testCollectionOwnerChanged(helper: EthUniqueHelper, mode: TCollec
         },
       },
     ]);
-    expect(subEvents).to.containSubset([{method: 'CollectionOwnerChanged'}]);
+    expect(subEvents).to.containSubset([{method: 'CollectionOwnerProposed'}]);
   }
   unsubscribe();
 }
@@ -0,0 +1,68 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {usingPlaygrounds} from '../util/index.js';
+import {itEth, expect} from './util/index.js';
+
+describe('Sponsoring statistics', () => {
+  let donor: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (_, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+    });
+  });
+
+  itEth('Fees paid for substrate calls are accounted', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+    await collection.setSponsor(alice, bob.address);
+    await collection.confirmSponsorship(bob);
+    const token = await collection.mintToken(alice, {Substrate: charlie.address});
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+
+    const bobBalanceBefore = await helper.balance.getSubstrate(bob.address);
+    await token.transfer(charlie, {Substrate: alice.address});
+    const paid = bobBalanceBefore - await helper.balance.getSubstrate(bob.address);
+
+    expect(await helper.getSponsorFeesPaid({Substrate: bob.address})).to.be.equal(paid);
+    const fees = await helper.getSponsoredFees(collectionAddress);
+    expect(fees.total).to.be.equal(paid);
+    expect(fees.topUsers).to.be.deep.equal([{user: {Substrate: charlie.address}, fee: paid}]);
+  });
+
+  itEth('Fees paid for EVM calls are accounted', async ({helper}) => {
+    const [alice] = await helper.arrange.createAccounts([100n], donor);
+    const collection = await helper.nft.mintCollection(alice, {permissions: {mintMode: true}});
+    await collection.setSponsor(alice, alice.address);
+    await collection.confirmSponsorship(alice);
+    const collectionAddress = helper.ethAddress.fromCollectionId(collection.collectionId);
+
+    const minter = helper.eth.createAccount();
+    await collection.addToAllowList(alice, {Ethereum: minter});
+    const contract = await helper.ethNativeContract.collection(collectionAddress, 'nft', minter);
+
+    const feesBefore = await helper.getSponsorFeesPaid({Substrate: alice.address});
+    await contract.methods.mint(minter).send();
+    const paid = await helper.getSponsorFeesPaid({Substrate: alice.address}) - feesBefore;
+
+    expect(paid > 0n).to.be.true;
+    const fees = await helper.getSponsoredFees(collectionAddress, null, 1);
+    expect(fees.total).to.be.equal(paid);
+    expect(fees.topUsers).to.be.deep.equal([{user: {Ethereum: minter.toLowerCase()}, fee: paid}]);
+  });
+});
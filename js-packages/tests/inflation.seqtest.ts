@@ -58,6 +58,48 @@ describe('integration test: Inflation', () => {
     expect(Math.abs(Number(expectedInflation))).to.be.lessThanOrEqual(tolerance);
   });
 
+  itSub('Inflation recalculations are recorded', async ({helper}) => {
+    const history = (await helper.callRpc('api.rpc.inflation.issuanceHistory', []) as any).toJSON();
+    expect(history).to.not.be.empty;
+
+    const [block, totalIssuance, blockInflation] = history[history.length - 1];
+    expect(block).to.be.equal((await helper.callRpc('api.query.inflation.startBlock', []) as any).toNumber());
+    expect(BigInt(totalIssuance)).to.be.equal((await helper.callRpc('api.query.inflation.startingYearTotalIssuance', []) as any).toBigInt());
+    expect(BigInt(blockInflation)).to.be.equal((await helper.callRpc('api.query.inflation.blockInflation', []) as any).toBigInt());
+
+    const split = (await helper.callRpc('api.rpc.inflation.inflationSplit', []) as any).toJSON();
+    expect(split).to.have.length(1);
+    expect(split[0][1]).to.be.equal(1_000_000_000);
+  });
+
+  itSub('Inflation schedule can be changed by sudo only', async ({helper}) => {
+    const [bob] = await helper.arrange.createAccounts([10n], superuser);
+    const schedule = (await helper.callRpc('api.query.inflation.schedule', []) as any).toJSON();
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.inflation.setInflationSchedule', [schedule])).to.be.rejectedWith(/BadOrigin/);
+    await expect(helper.executeExtrinsic(superuser, 'api.tx.sudo.sudo', [helper.constructApiCall('api.tx.inflation.setInflationSchedule', [{...schedule, yearLength: 0}])])).to.be.rejectedWith(/inflation\.InvalidYearLength/);
+
+    // Re-apply the current schedule, it takes effect at the next recalculation
+    await expect(helper.executeExtrinsic(superuser, 'api.tx.sudo.sudo', [helper.constructApiCall('api.tx.inflation.setInflationSchedule', [schedule])])).to.not.be.rejected;
+    const pendingSchedule = (await helper.callRpc('api.query.inflation.pendingSchedule', []) as any).toJSON();
+    expect(pendingSchedule).to.be.deep.equal(schedule);
+  });
+
+  itSub('Inflation split can be changed by sudo only', async ({helper}) => {
+    const [bob] = await helper.arrange.createAccounts([10n], superuser);
+    const split = [[TREASURY, 500_000_000], [bob.address, 500_000_000]];
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.inflation.setInflationSplit', [split])).to.be.rejectedWith(/BadOrigin/);
+    await expect(helper.executeExtrinsic(superuser, 'api.tx.sudo.sudo', [helper.constructApiCall('api.tx.inflation.setInflationSplit', [[[TREASURY, 500_000_000]]])])).to.be.rejectedWith(/inflation\.InvalidInflationSplit/);
+
+    await expect(helper.executeExtrinsic(superuser, 'api.tx.sudo.sudo', [helper.constructApiCall('api.tx.inflation.setInflationSplit', [split])])).to.not.be.rejected;
+    expect((await helper.callRpc('api.query.inflation.inflationSplit', []) as any).toJSON()).to.have.length(2);
+
+    // The whole inflation goes to the treasury again
+    await expect(helper.executeExtrinsic(superuser, 'api.tx.sudo.sudo', [helper.constructApiCall('api.tx.inflation.setInflationSplit', [[]])])).to.not.be.rejected;
+    expect((await helper.callRpc('api.query.inflation.inflationSplit', []) as any).toJSON()).to.be.empty;
+  });
+
   // (1) - inflation is broken on purpose, see relevant changes in pallet_inflation config
   itSub('Inflation happens after inflation block interval', async ({helper}) => {
     const api = helper.getApi();
@@ -447,6 +447,9 @@ describe('Effective collection limits (NFT)', () => {
       expect(limits.ownerCanTransfer).to.be.true;
       expect(limits.ownerCanDestroy).to.be.true;
       expect(limits.transfersEnabled).to.be.true;
+      expect(limits.sponsorBurnTimeout).to.be.eq(5);
+      expect(limits.sponsorAllowanceForAllTimeout).to.be.eq(5);
+      expect(limits.sponsorRepartitionTimeout).to.be.eq(5);
     }
 
     {
@@ -0,0 +1,76 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {itSub, usingPlaygrounds, expect} from './util/index.js';
+import {UniqueHelper} from '@unique/playgrounds/unique.js';
+
+describe('Public mint sponsoring', () => {
+  let donor: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (_, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+    });
+  });
+
+  async function publicCollection(helper: UniqueHelper, owner: IKeyringPair, sponsor: IKeyringPair, minter: IKeyringPair) {
+    const collection = await helper.nft.mintCollection(owner, {
+      permissions: {access: 'AllowList', mintMode: true},
+      tokenPropertyPermissions: [{key: 'data', permission: {mutable: false, collectionAdmin: true, tokenOwner: true}}],
+    });
+    await collection.setSponsor(owner, sponsor.address);
+    await collection.confirmSponsorship(sponsor);
+    await collection.addToAllowList(owner, {Substrate: minter.address});
+    return collection;
+  }
+
+  itSub('Mints are sponsored within the per-user quota', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await publicCollection(helper, alice, bob, charlie);
+    await collection.setPublicMintSponsoring(bob, {period: 0, mintsPerUser: 2, maxPropertiesSize: 64});
+
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await collection.mintToken(charlie);
+    await collection.mintMultipleTokens(charlie, [{owner: {Substrate: charlie.address}}]);
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore);
+
+    await collection.mintToken(charlie);
+    expect(await helper.balance.getSubstrate(charlie.address) < charlieBalanceBefore).to.be.true;
+  });
+
+  itSub('Mints with large initial properties are not sponsored', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await publicCollection(helper, alice, bob, charlie);
+    await collection.setPublicMintSponsoring(bob, {period: 0, mintsPerUser: 10, maxPropertiesSize: 8});
+
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await collection.mintToken(charlie, {Substrate: charlie.address}, [{key: 'data', value: 'abc'}]);
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore);
+
+    await collection.mintToken(charlie, {Substrate: charlie.address}, [{key: 'data', value: 'too large'}]);
+    expect(await helper.balance.getSubstrate(charlie.address) < charlieBalanceBefore).to.be.true;
+  });
+
+  itSub('Only sponsor or admins can set public mint sponsoring', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await publicCollection(helper, alice, bob, charlie);
+
+    await expect(collection.setPublicMintSponsoring(charlie, {period: 0, mintsPerUser: 1, maxPropertiesSize: 0}))
+      .to.be.rejectedWith(/common\.NoPermission/);
+    expect(await collection.setPublicMintSponsoring(alice, null)).to.be.true;
+  });
+});
@@ -0,0 +1,87 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {itSub, usingPlaygrounds, expect} from './util/index.js';
+
+describe('Collection sponsor budget', () => {
+  let donor: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (_, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+    });
+  });
+
+  itSub('Sponsored fees are counted against the budget', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+    await collection.setSponsor(alice, bob.address);
+    await collection.confirmSponsorship(bob);
+
+    expect(await collection.getSponsorBudgetLeft({Substrate: charlie.address})).to.be.null;
+
+    const limit = 100n * helper.balance.getOneTokenNominal();
+    await collection.setSponsorBudget(bob, {period: 0, periodLimit: limit, userLimit: limit});
+    expect(await collection.getSponsorBudgetLeft({Substrate: charlie.address})).to.be.deep.equal({period: limit, user: limit, periodEnd: null});
+
+    const token = await collection.mintToken(alice, {Substrate: charlie.address});
+    const bobBalanceBefore = await helper.balance.getSubstrate(bob.address);
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await token.transfer(charlie, {Substrate: alice.address});
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore);
+
+    const left = await collection.getSponsorBudgetLeft({Substrate: charlie.address});
+    expect(left!.period! < limit).to.be.true;
+    // The budget is charged with the fee actually paid by the sponsor
+    expect(limit - left!.period!).to.be.equal(bobBalanceBefore - await helper.balance.getSubstrate(bob.address));
+    expect(left!.user).to.be.equal(left!.period);
+    expect((await collection.getSponsorBudgetLeft({Substrate: alice.address}))!.user).to.be.equal(limit);
+  });
+
+  itSub('Calls are not sponsored once the user budget is exhausted', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+    await collection.setSponsor(alice, bob.address);
+    await collection.confirmSponsorship(bob);
+    await collection.setSponsorBudget(alice, {period: 1000, periodLimit: null, userLimit: 1n});
+
+    const token = await collection.mintToken(alice, {Substrate: charlie.address});
+    const bobBalanceBefore = await helper.balance.getSubstrate(bob.address);
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await token.transfer(charlie, {Substrate: alice.address});
+
+    expect(await helper.balance.getSubstrate(bob.address)).to.be.equal(bobBalanceBefore);
+    expect(await helper.balance.getSubstrate(charlie.address) < charlieBalanceBefore).to.be.true;
+    const left = await collection.getSponsorBudgetLeft({Substrate: charlie.address});
+    expect(left!.user).to.be.equal(1n);
+    expect(left!.periodEnd).to.be.not.null;
+  });
+
+  itSub('Only the sponsor or the collection managers can set the budget', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+    await collection.setSponsor(alice, bob.address);
+    await collection.confirmSponsorship(bob);
+
+    await expect(collection.setSponsorBudget(charlie, {period: 0, periodLimit: 1n, userLimit: null}))
+      .to.be.rejectedWith(/common\.NoPermission/);
+
+    await collection.setSponsorBudget(bob, {period: 0, periodLimit: 1n, userLimit: null});
+    await collection.setSponsorBudget(alice, null);
+    expect(await collection.getSponsorBudgetLeft({Substrate: charlie.address})).to.be.null;
+  });
+});
@@ -0,0 +1,75 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, usingPlaygrounds} from './util/index.js';
+import {UniqueHelper} from '@unique/playgrounds/unique.js';
+
+async function createPool(helper: UniqueHelper, manager: IKeyringPair) {
+  const result = await helper.executeExtrinsic(manager, 'api.tx.sponsorPool.createPool', [], true);
+  const event = result.result.events.find(({event: {section, method}}) => section === 'sponsorPool' && method === 'PoolCreated')!;
+  return {poolId: parseInt(event.event.data[0].toString(), 10), poolAccount: event.event.data[2].toString()};
+}
+
+describe('Sponsor pools', () => {
+  let donor: IKeyringPair;
+  let nominal: bigint;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+      nominal = helper.balance.getOneTokenNominal();
+    });
+  });
+
+  itSub('Pool sponsors the collection after the manager confirms it', async ({helper}) => {
+    const [alice, bob, charlie, dave] = await helper.arrange.createAccounts([100n, 100n, 100n, 10n], donor);
+    const {poolId, poolAccount} = await createPool(helper, bob);
+    await helper.executeExtrinsic(bob, 'api.tx.sponsorPool.deposit', [poolId, 50n * nominal], true);
+    await helper.executeExtrinsic(charlie, 'api.tx.sponsorPool.deposit', [poolId, 50n * nominal], true);
+
+    const collection = await helper.nft.mintCollection(alice);
+    await collection.setSponsor(alice, poolAccount);
+    await expect(helper.executeExtrinsic(charlie, 'api.tx.sponsorPool.confirmCollectionSponsorship', [poolId, collection.collectionId], true))
+      .to.be.rejectedWith(/sponsorPool\.NoPermission/);
+    await helper.executeExtrinsic(bob, 'api.tx.sponsorPool.confirmCollectionSponsorship', [poolId, collection.collectionId], true);
+    expect((await collection.getData())?.raw.sponsorship).to.be.deep.equal({Confirmed: poolAccount});
+
+    const token = await collection.mintToken(alice, {Substrate: dave.address});
+    const daveBalanceBefore = await helper.balance.getSubstrate(dave.address);
+    await token.transfer(dave, {Substrate: alice.address});
+    expect(await helper.balance.getSubstrate(dave.address)).to.be.equal(daveBalanceBefore);
+    expect(await helper.balance.getSubstrate(poolAccount) < 100n * nominal).to.be.true;
+  });
+
+  itSub('Contributors withdraw their unspent shares', async ({helper}) => {
+    const [bob, charlie] = await helper.arrange.createAccounts([100n, 100n], donor);
+    const {poolId, poolAccount} = await createPool(helper, bob);
+    await helper.executeExtrinsic(bob, 'api.tx.sponsorPool.deposit', [poolId, 30n * nominal], true);
+    await helper.executeExtrinsic(charlie, 'api.tx.sponsorPool.deposit', [poolId, 10n * nominal], true);
+
+    const shares = (await helper.getApi().query.sponsorPool.shares(poolId, charlie.address)).shares.toBigInt();
+    expect(shares).to.be.equal(10n * nominal);
+    await expect(helper.executeExtrinsic(charlie, 'api.tx.sponsorPool.withdraw', [poolId, shares + 1n], true))
+      .to.be.rejectedWith(/sponsorPool\.InsufficientShares/);
+
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await helper.executeExtrinsic(charlie, 'api.tx.sponsorPool.withdraw', [poolId, shares], true);
+    const fee = 2n * nominal;
+    expect(await helper.balance.getSubstrate(charlie.address) > charlieBalanceBefore + 10n * nominal - fee).to.be.true;
+    expect(await helper.balance.getSubstrate(poolAccount)).to.be.equal(30n * nominal);
+  });
+});
@@ -0,0 +1,67 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, Pallets, usingPlaygrounds} from './util/index.js';
+
+describe('Sponsored burns, allowances for all and repartitions', () => {
+  let donor: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (_, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+    });
+  });
+
+  itSub('NFT burns are sponsored once per timeout', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice, {limits: {sponsorBurnTimeout: 1000}});
+    await collection.setSponsor(alice, bob.address);
+    await collection.confirmSponsorship(bob);
+    const [first, second] = await collection.mintMultipleTokens(alice, [{owner: {Substrate: charlie.address}}, {owner: {Substrate: charlie.address}}]);
+
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await first.burn(charlie);
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore);
+
+    await second.burn(charlie);
+    expect(await helper.balance.getSubstrate(charlie.address) < charlieBalanceBefore).to.be.true;
+  });
+
+  itSub('Setting allowance for all is sponsored', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+    await collection.setSponsor(alice, bob.address);
+    await collection.confirmSponsorship(bob);
+
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await helper.nft.setAllowanceForAll(charlie, collection.collectionId, {Substrate: alice.address}, true);
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore);
+  });
+
+  itSub.ifWithPallets('RFT repartition is sponsored for the owner of all pieces', [Pallets.ReFungible], async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await helper.rft.mintCollection(alice);
+    await collection.setSponsor(alice, bob.address);
+    await collection.confirmSponsorship(bob);
+    const token = await collection.mintToken(alice, 10n, {Substrate: charlie.address});
+
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await token.repartition(charlie, 20n);
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore);
+    expect(await token.getTotalPieces()).to.be.equal(20n);
+  });
+});
@@ -0,0 +1,65 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {itSub, usingPlaygrounds, expect} from './util/index.js';
+
+describe('Sponsoring dry run', () => {
+  let donor: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (_, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+    });
+  });
+
+  itSub('Reports the sponsor without consuming the sponsoring', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+    await collection.setSponsor(alice, bob.address);
+    await collection.confirmSponsorship(bob);
+    const token = await collection.mintToken(alice, {Substrate: charlie.address});
+
+    const transfer = [{Substrate: alice.address}, collection.collectionId, token.tokenId, 1];
+    const dryRun = await helper.getSponsoringDryRun(charlie, 'api.tx.unique.transfer', transfer);
+    expect(dryRun.reason).to.be.equal('Sponsored');
+    expect(dryRun.sponsor).to.be.deep.equal({Substrate: bob.address});
+    expect(dryRun.fee > 0n).to.be.true;
+
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    await token.transfer(charlie, {Substrate: alice.address});
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore);
+  });
+
+  itSub('Explains why the call is not sponsored', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 10n], donor);
+    const collection = await helper.nft.mintCollection(alice);
+    const token = await collection.mintToken(alice, {Substrate: charlie.address});
+    const transfer = [{Substrate: alice.address}, collection.collectionId, token.tokenId, 1];
+
+    expect(await helper.getSponsoringDryRun(charlie, 'api.tx.unique.transfer', transfer))
+      .to.be.deep.include({sponsor: null, reason: 'NoSponsor'});
+
+    await collection.setSponsor(alice, bob.address);
+    await collection.confirmSponsorship(bob);
+    await collection.setSponsorBudget(bob, {period: 0, periodLimit: null, userLimit: 1n});
+    expect(await helper.getSponsoringDryRun(charlie, 'api.tx.unique.transfer', transfer))
+      .to.be.deep.include({sponsor: null, reason: 'BudgetExhausted'});
+
+    expect(await helper.getSponsoringDryRun(charlie, 'api.tx.balances.transferKeepAlive', [alice.address, 1n]))
+      .to.be.deep.include({sponsor: null, reason: 'NotEligible'});
+  });
+});
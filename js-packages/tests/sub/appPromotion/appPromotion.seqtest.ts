@@ -15,9 +15,11 @@
 // along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
 
 import type {IKeyringPair} from '@polkadot/types/types';
-import {itSub, usingPlaygrounds, Pallets, requirePalletsOrSkip} from '../../util/index.js';
+import {itSub, usingPlaygrounds, Pallets, requirePalletsOrSkip, LOCKING_PERIOD} from '../../util/index.js';
 import {expect} from '../../eth/util/index.js';
 
+const TREASURY = '5EYCAe5ijiYfyeZ2JJCGq56LmPyNRAKzpG4QkoQkkQNB5e6Z';
+
 let superuser: IKeyringPair;
 let donor: IKeyringPair;
 let palletAdmin: IKeyringPair;
@@ -78,5 +80,78 @@ describe('App promotion', () => {
       await expect(helper.signTransaction(newAdmin, api.tx.appPromotion.sponsorCollection(collection.collectionId))).to.be.fulfilled;
     });
   });
+
+  describe('on_idle payouts', () => {
+    itSub('pay out stakers without the admin', async ({helper}) => {
+      const api = helper.getApi();
+      const [staker] = await helper.arrange.createAccounts([1000n], donor);
+      const nominal = helper.balance.getOneTokenNominal();
+      await helper.signTransaction(superuser, api.tx.sudo.sudo(api.tx.configuration.setAppPromotionIdlePayoutWeight(null)));
+
+      await helper.staking.stake(staker, 100n * nominal);
+      const [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(stake.block - stake.block % LOCKING_PERIOD + 2n * LOCKING_PERIOD);
+      await helper.wait.newBlocks(2);
+
+      const [paidStake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      expect(paidStake.amount > 100n * nominal).to.be.true;
+
+      await helper.staking.unstakeAll(staker);
+    });
+  });
+
+  describe('lock tiers', () => {
+    const tiers = [
+      // 1.5x rewards for 1000 blocks, 10% penalty on early unstake
+      {lockPeriod: 1000, rewardMultiplier: 1_500_000_000_000_000_000n, earlyUnstakePenalty: 100_000_000},
+    ];
+
+    after(async function () {
+      await usingPlaygrounds(async (helper) => {
+        if(helper.fetchMissingPalletNames([Pallets.AppPromotion]).length != 0) return;
+        const api = helper.getApi();
+        await helper.signTransaction(superuser, api.tx.sudo.sudo(api.tx.appPromotion.setLockTiers([])));
+      });
+    });
+
+    itSub('can be set by sudo only', async ({helper}) => {
+      const api = helper.getApi();
+      const [nonAdmin] = await helper.arrange.createAccounts([10n], donor);
+      await expect(helper.signTransaction(nonAdmin, api.tx.appPromotion.setLockTiers(tiers))).to.be.rejected;
+      await expect(helper.signTransaction(superuser, api.tx.sudo.sudo(api.tx.appPromotion.setLockTiers(tiers)))).to.be.fulfilled;
+    });
+
+    itSub('locked stake shows its tier and unlock block', async ({helper}) => {
+      const api = helper.getApi();
+      const [staker] = await helper.arrange.createAccounts([1000n], donor);
+      const nominal = helper.balance.getOneTokenNominal();
+      await helper.signTransaction(superuser, api.tx.sudo.sudo(api.tx.appPromotion.setLockTiers(tiers)));
+
+      await expect(helper.staking.stakeLocked(staker, 100n * nominal, 1)).to.be.rejectedWith('appPromotion.LockTierNotFound');
+      await helper.staking.stakeLocked(staker, 100n * nominal, 0);
+      await helper.staking.stake(staker, 100n * nominal);
+
+      const [locked, unlocked] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      expect(locked.lock).to.be.deep.equal({tier: 0, unlockBlock: locked.block + 1000n});
+      expect(unlocked.lock).to.be.undefined;
+
+      await helper.staking.unstakeAll(staker);
+    });
+
+    itSub('early unstake pays the penalty to the treasury', async ({helper}) => {
+      const api = helper.getApi();
+      const [staker] = await helper.arrange.createAccounts([1000n], donor);
+      const nominal = helper.balance.getOneTokenNominal();
+      await helper.signTransaction(superuser, api.tx.sudo.sudo(api.tx.appPromotion.setLockTiers(tiers)));
+
+      await helper.staking.stakeLocked(staker, 100n * nominal, 0);
+      const treasuryBalanceBefore = await helper.balance.getSubstrate(TREASURY);
+      await helper.staking.unstakeAll(staker);
+      const treasuryBalanceAfter = await helper.balance.getSubstrate(TREASURY);
+
+      expect(await helper.staking.getPendingUnstake({Substrate: staker.address})).to.be.equal(90n * nominal);
+      expect(treasuryBalanceAfter - treasuryBalanceBefore >= 10n * nominal).to.be.true;
+    });
+  });
 });
 
@@ -15,10 +15,7 @@
 // along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
 
 import type {IKeyringPair} from '@polkadot/types/types';
-import {
-  itSub, usingPlaygrounds, Pallets, requirePalletsOrSkip, LOCKING_PERIOD, UNLOCKING_PERIOD,
-} from '../../util/index.js';
-import {DevUniqueHelper} from '@unique/playgrounds/unique.dev.js';
+import {itSub, usingPlaygrounds, Pallets, requirePalletsOrSkip} from '../../util/index.js';
 import {itEth, expect, SponsoringMode} from '../../eth/util/index.js';
 
 let donor: IKeyringPair;
@@ -40,9 +37,6 @@ async function getAccounts(accountsNumber: number, balance?: bigint) {
   usedAccounts.push(...accs);
   return accs;
 }
-// App promotion periods:
-// LOCKING_PERIOD = 12 blocks of relay
-// UNLOCKING_PERIOD = 6 blocks of parachain
 
 describe('App promotion', () => {
   before(async function () {
@@ -769,148 +763,63 @@ describe('App promotion', () => {
     });
   });
 
-  describe('payoutStakers', () => {
-    itSub('can not be called by non admin', async ({helper}) => {
-      const [nonAdmin] = await getAccounts(1);
-      await expect(helper.admin.payoutStakers(nonAdmin, 100)).to.be.rejectedWith('appPromotion.NoPermission');
-    });
-
-    itSub('should increase total staked', async ({helper}) => {
-      const [staker] = await getAccounts(1);
-      const totalStakedBefore = await helper.staking.getTotalStaked();
-      await helper.staking.stake(staker, 100n * nominal);
-
-      // Wait for rewards and pay
-      const [stakedInBlock] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stakedInBlock.block));
-
-      const payout = await helper.admin.payoutStakers(palletAdmin, 100);
-      const totalPayout = payout.reduce((prev, payout) => prev + payout.payout, 0n);
-      const stakerReward = payout.find(p => p.staker === staker.address);
-
-      expect(stakerReward?.payout).to.eq(calculateIncome(100n * nominal) - (100n * nominal));
+  describe('stake delegation', () => {
+    itEth('should delegate a part of the stake to a sponsored collection', async ({helper}) => {
+      const [collectionOwner, staker] = await getAccounts(2);
+      const collection = await helper.nft.mintCollection(collectionOwner);
+      await helper.executeExtrinsic(palletAdmin, 'api.tx.appPromotion.sponsorCollection', [collection.collectionId], true);
+      const target = helper.ethAddress.fromCollectionId(collection.collectionId);
 
-      const totalStakedAfter = await helper.staking.getTotalStaked();
-      expect(totalStakedAfter).to.equal(totalStakedBefore + (100n * nominal) + totalPayout);
-      // staker can unstake
-      await helper.staking.unstakeAll(staker);
-      expect(await helper.staking.getTotalStaked()).to.be.equal(totalStakedAfter - calculateIncome(100n * nominal));
-    });
-
-    itSub('should credit 0.05% for staking period', async ({helper}) => {
-      const [staker] = await getAccounts(1);
-
-      await waitPromotionPeriodDoesntEnd(helper);
-
-      await helper.staking.stake(staker, 100n * nominal);
       await helper.staking.stake(staker, 200n * nominal);
+      await helper.staking.delegateStake(staker, target, 150n * nominal);
+      await expect(helper.staking.delegateStake(staker, target, 51n * nominal)).to.be.rejectedWith('appPromotion.InsufficientStakedBalance');
 
-      // wait rewards are available:
-      const [_stake1, stake2] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake2.block));
-
-      const payoutToStaker = (await helper.admin.payoutStakers(palletAdmin, 100)).find((payout) => payout.staker === staker.address)!.payout;
-      expect(payoutToStaker + 300n * nominal).to.equal(calculateIncome(300n * nominal));
-
-      const totalStakedPerBlock = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      const income1 = calculateIncome(100n * nominal);
-      const income2 = calculateIncome(200n * nominal);
-      expect(totalStakedPerBlock[0].amount).to.equal(income1);
-      expect(totalStakedPerBlock[1].amount).to.equal(income2);
-
-      const stakerBalance = await helper.balance.getSubstrateFull(staker.address);
-      expect(stakerBalance).to.contain({frozen: income1 + income2, reserved: 0n});
-      expect(stakerBalance.free / nominal).to.eq(999n);
-    });
-
-    itSub('shoud be paid for more than one period if payments was missed', async ({helper}) => {
-      const [staker] = await getAccounts(1);
-
-      await helper.staking.stake(staker, 100n * nominal);
-      // wait for two rewards are available:
-      let [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block) + LOCKING_PERIOD);
-
-      await helper.admin.payoutStakers(palletAdmin, 100);
-      [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      const frozenBalanceShouldBe = calculateIncome(100n * nominal, 2);
-      expect(stake.amount).to.be.equal(frozenBalanceShouldBe);
-
-      const stakerFullBalance = await helper.balance.getSubstrateFull(staker.address);
-
-      expect(stakerFullBalance).to.contain({reserved: 0n, frozen: frozenBalanceShouldBe});
-    });
-
-    itSub('should not be credited for pending-unstaked tokens', async ({helper}) => {
-      // staker unstakes before rewards been payed
-      const [staker] = await getAccounts(1);
-      await helper.staking.stake(staker, 100n * nominal);
-      const [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block) + LOCKING_PERIOD);
-      await helper.staking.unstakeAll(staker);
-
-      // so he did not receive any rewards
-      const totalBalanceBefore = await helper.balance.getSubstrate(staker.address);
-      await helper.admin.payoutStakers(palletAdmin, 100);
-      const totalBalanceAfter = await helper.balance.getSubstrate(staker.address);
+      expect(await helper.staking.getDelegatedStake({Substrate: staker.address})).to.be.deep.equal([{target: target.toLowerCase(), amount: 150n * nominal}]);
+      expect(await helper.staking.getTargetDelegatedStake(target)).to.be.equal(150n * nominal);
 
-      expect(totalBalanceBefore).to.be.equal(totalBalanceAfter);
+      await helper.staking.undelegateStake(staker, target, 50n * nominal);
+      expect(await helper.staking.getTargetDelegatedStake(target)).to.be.equal(100n * nominal);
     });
 
-    itSub('should bring compound interest', async ({helper}) => {
-      const [staker] = await getAccounts(1);
+    itEth('can not delegate to a collection not sponsored by the pallet', async ({helper}) => {
+      const [collectionOwner, staker] = await getAccounts(2);
+      const collection = await helper.nft.mintCollection(collectionOwner);
+      const target = helper.ethAddress.fromCollectionId(collection.collectionId);
 
       await helper.staking.stake(staker, 100n * nominal);
-
-      let [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block));
-
-      await helper.admin.payoutStakers(palletAdmin, 100);
-      [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      expect(stake.amount).to.equal(calculateIncome(100n * nominal));
-
-      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block) + LOCKING_PERIOD);
-      await helper.admin.payoutStakers(palletAdmin, 100);
-      [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      expect(stake.amount).to.equal(calculateIncome(100n * nominal, 2));
+      await expect(helper.staking.delegateStake(staker, target, 100n * nominal)).to.be.rejectedWith('appPromotion.SponsorNotSet');
     });
 
-    itSub('can calculate reward for tiny stake', async ({helper}) => {
-      const [staker] = await getAccounts(1);
-      await helper.staking.stake(staker, 100n * nominal);
-      await helper.staking.stake(staker, 100n * nominal);
-      await helper.staking.unstakePartial(staker, 100n * nominal - 1n);
+    itEth('should reduce delegations on unstake', async ({helper}) => {
+      const [collectionOwner, staker] = await getAccounts(2);
+      const collection = await helper.nft.mintCollection(collectionOwner);
+      await helper.executeExtrinsic(palletAdmin, 'api.tx.appPromotion.sponsorCollection', [collection.collectionId], true);
+      const target = helper.ethAddress.fromCollectionId(collection.collectionId);
 
-      const [_stake1, stake2] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake2.block));
+      await helper.staking.stake(staker, 400n * nominal);
+      await helper.staking.delegateStake(staker, target, 400n * nominal);
+      await helper.staking.unstakePartial(staker, 100n * nominal);
+      expect(await helper.staking.getTargetDelegatedStake(target)).to.be.equal(300n * nominal);
 
-      const stakerPayout = await payUntilRewardFor(staker.address, helper);
-      expect(stakerPayout.stake).to.eq(100n * nominal + 1n);
+      await helper.staking.unstakeAll(staker);
+      expect(await helper.staking.getDelegatedStake({Substrate: staker.address})).to.be.deep.equal([]);
     });
 
-    itSub('can eventually pay all rewards', async ({helper}) => {
-      const stakers = await getAccounts(30);
-      // Create 30 stakes:
-      await Promise.all(stakers.map(staker => helper.staking.stake(staker, 100n * nominal)));
+    itEth('should allocate the sponsoring budget in proportion to the delegated stake', async ({helper}) => {
+      const [collectionOwner, staker] = await getAccounts(2);
+      const collection = await helper.nft.mintCollection(collectionOwner);
+      await helper.executeExtrinsic(palletAdmin, 'api.tx.appPromotion.sponsorCollection', [collection.collectionId], true);
+      const target = helper.ethAddress.fromCollectionId(collection.collectionId);
+      const otherTarget = helper.ethAddress.fromCollectionId(collection.collectionId + 1);
 
-      let unstakingTxs = [];
-      for(const staker of stakers) {
-        if(unstakingTxs.length == 3) {
-          await Promise.all(unstakingTxs);
-          unstakingTxs = [];
-        }
-        unstakingTxs.push(helper.staking.unstakePartial(staker, 100n * nominal - 1n));
-      }
-
-      const [staker] = await getAccounts(1);
       await helper.staking.stake(staker, 100n * nominal);
-      const [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
-      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block));
+      const totalStaked = await helper.staking.getTotalStaked();
+      const budget = await helper.staking.getSponsoringAllowance(target);
+      await helper.staking.delegateStake(staker, target, 100n * nominal);
 
-      let payouts;
-      do {
-        payouts = await helper.admin.payoutStakers(palletAdmin, 20);
-      } while(payouts.length !== 0);
+      expect(await helper.staking.getSponsoringAllowance(target)).to.be.equal(budget);
+      expect(await helper.staking.getSponsoringAllowance(otherTarget) < budget).to.be.true;
+      expect(await helper.staking.getSponsoringAllowance(otherTarget) >= budget * (totalStaked - 100n * nominal) / totalStaked - 1n).to.be.true;
     });
   });
 
@@ -946,63 +855,5 @@ describe('App promotion', () => {
       expect(stakerEvents).to.eq(staker.address);
       expect(stakedEvents).to.eq(100n * nominal);
     });
-
-    // Flaky
-    itSub.skip('payoutStakers', async ({helper}) => {
-      const [staker1, staker2] = await getAccounts(2);
-      const STAKE1 = 100n * nominal;
-      const STAKE2 = 200n * nominal;
-      await helper.staking.stake(staker1, STAKE1);
-      await helper.staking.stake(staker2, STAKE2);
-
-      const [stake2] = await helper.staking.getTotalStakedPerBlock({Substrate: staker2.address});
-      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake2.block));
-
-      const results = await helper.admin.payoutStakers(palletAdmin, 100);
-      const stakersEvents = results.filter(ev => ev.staker === staker1.address || ev.staker === staker2.address);
-      expect(stakersEvents).has.length(2);
-      expect(stakersEvents).has.not.ordered.members([
-        {staker: staker1.address, stake: STAKE1, payout: calculateIncome(STAKE1) - STAKE1},
-        {staker: staker2.address, stake: STAKE2, payout: calculateIncome(STAKE2) - STAKE2},
-      ]);
-    });
   });
 });
-
-
-// Sometimes is is required to make a cycle in order for the payment to be calculated for a specific account
-async function payUntilRewardFor(account: string, helper: DevUniqueHelper) {
-  for(let i = 0; i < 3; i++) {
-    const payouts = await helper.admin.payoutStakers(palletAdmin, 100);
-    const accountPayout = payouts.find(p => p.staker === account);
-    if(accountPayout) return accountPayout;
-  }
-  throw Error(`Cannot find payout for ${account}`);
-}
-
-function calculateIncome(base: bigint, iter = 0, calcPeriod: bigint = UNLOCKING_PERIOD): bigint {
-  const DAY = 7200n;
-  const ACCURACY = 1_000_000_000n;
-  // 453_256n / 1_000_000_000n = 0.0453256% /day
-  const income = base + base * (ACCURACY * (calcPeriod * 453_256n) / (1_000_000_000n * DAY)) / ACCURACY ;
-
-  if(iter > 1) {
-    return calculateIncome(income, iter - 1, calcPeriod);
-  } else return income;
-}
-
-function rewardAvailableInBlock(stakedInBlock: bigint) {
-  if(stakedInBlock % LOCKING_PERIOD === 0n) return stakedInBlock + LOCKING_PERIOD;
-  return (stakedInBlock - stakedInBlock % LOCKING_PERIOD) + (LOCKING_PERIOD * 2n);
-}
-
-// Wait while promotion period less than specified block, to avoid boundary cases
-// 0 if this should be the beginning of the period.
-async function waitPromotionPeriodDoesntEnd(helper: DevUniqueHelper, waitBlockLessThan = LOCKING_PERIOD / 3n) {
-  const relayBlockNumber = (await helper.callRpc('api.query.parachainSystem.validationData', [])).value.relayParentNumber.toNumber(); // await helper.chain.getLatestBlockNumber();
-  const currentPeriodBlock = BigInt(relayBlockNumber) % LOCKING_PERIOD;
-
-  if(currentPeriodBlock > waitBlockLessThan) {
-    await helper.wait.forRelayBlockNumber(BigInt(relayBlockNumber) + LOCKING_PERIOD - currentPeriodBlock);
-  }
-}
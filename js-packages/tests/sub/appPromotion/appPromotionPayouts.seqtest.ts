@@ -0,0 +1,354 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {
+  itSub, usingPlaygrounds, Pallets, requirePalletsOrSkip, LOCKING_PERIOD, UNLOCKING_PERIOD,
+} from '../../util/index.js';
+import {DevUniqueHelper} from '@unique/playgrounds/unique.dev.js';
+import {expect} from '../../eth/util/index.js';
+
+// Default configuration of the maximum payout batch
+const MAX_STAKERS_PER_CALCULATION = 100n;
+
+let superuser: IKeyringPair;
+let donor: IKeyringPair;
+let palletAdmin: IKeyringPair;
+let nominal: bigint;
+let accounts: IKeyringPair[];
+let usedAccounts: IKeyringPair[] = [];
+
+async function getAccounts(accountsNumber: number) {
+  const accs = accounts.splice(0, accountsNumber);
+  usedAccounts.push(...accs);
+  return accs;
+}
+// App promotion periods:
+// LOCKING_PERIOD = 12 blocks of relay
+// UNLOCKING_PERIOD = 6 blocks of parachain
+
+// The rewards are expected to be paid by the explicit payoutStakers calls only,
+// so the on_idle payouts are disabled for the whole chain while these tests run
+describe('App promotion payouts', () => {
+  before(async function () {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      requirePalletsOrSkip(this, helper, [Pallets.AppPromotion]);
+      superuser = await privateKey('//Alice');
+      donor = await privateKey({url: import.meta.url});
+      palletAdmin = await privateKey('//PromotionAdmin');
+
+      const api = helper.getApi();
+      await helper.signTransaction(superuser, api.tx.sudo.sudo(api.tx.appPromotion.setAdminAddress({Substrate: palletAdmin.address})));
+      await helper.signTransaction(superuser, api.tx.sudo.sudo(api.tx.configuration.setAppPromotionIdlePayoutWeight({refTime: 0, proofSize: 0})));
+
+      nominal = helper.balance.getOneTokenNominal();
+
+      const accountBalances = new Array(50).fill(1000n);
+      accounts = await helper.arrange.createAccounts(accountBalances, donor); // create accounts-pool to speed up tests
+    });
+  });
+
+  after(async function () {
+    await usingPlaygrounds(async (helper) => {
+      if(helper.fetchMissingPalletNames([Pallets.AppPromotion]).length != 0) return;
+      const api = helper.getApi();
+      await helper.signTransaction(superuser, api.tx.sudo.sudo(api.tx.configuration.setAppPromotionIdlePayoutWeight(null)));
+    });
+  });
+
+  afterEach(async () => {
+    await usingPlaygrounds(async (helper) => {
+      let unstakeTxs = [];
+      for(const account of usedAccounts) {
+        if(unstakeTxs.length === 3) {
+          await Promise.all(unstakeTxs);
+          unstakeTxs = [];
+        }
+        unstakeTxs.push(helper.staking.unstakeAll(account));
+      }
+      await Promise.all(unstakeTxs);
+      usedAccounts = [];
+      // Make sure previousCalculatedRecord is None to avoid problem with payout stakers;
+      await helper.admin.payoutStakers(palletAdmin, 100);
+      expect((await helper.getApi().query.appPromotion.previousCalculatedRecord() as any).isNone).to.be.true;
+    });
+  });
+
+  describe('payoutStakers', () => {
+    itSub('can be called by non admin for a tip', async ({helper}) => {
+      const [staker, nonAdmin] = await getAccounts(2);
+      await helper.staking.stake(staker, 100n * nominal);
+
+      const [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block));
+
+      // The batch of a single staker gets the part of the tip for the batch of MAX_STAKERS_PER_CALCULATION stakers
+      const payoutResult = await helper.executeExtrinsic(nonAdmin, 'api.tx.appPromotion.payoutStakers', [1], true);
+      const tip = payoutResult.result.events.find(e => e.event.method === 'PayoutTipPaid');
+      expect(tip?.event.data[0].toString()).to.eq(nonAdmin.address);
+      expect((tip?.event.data[1] as any).toBigInt()).to.eq(nominal / 10n / MAX_STAKERS_PER_CALCULATION);
+
+      // no tip if there is nothing to pay out
+      await expect(helper.executeExtrinsic(nonAdmin, 'api.tx.appPromotion.payoutStakers', [100], true)).to.be.rejectedWith('appPromotion.NothingToPayout');
+    });
+
+    itSub('should increase total staked', async ({helper}) => {
+      const [staker] = await getAccounts(1);
+      const totalStakedBefore = await helper.staking.getTotalStaked();
+      await helper.staking.stake(staker, 100n * nominal);
+
+      // Wait for rewards and pay
+      const [stakedInBlock] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stakedInBlock.block));
+
+      const payout = await helper.admin.payoutStakers(palletAdmin, 100);
+      const totalPayout = payout.reduce((prev, payout) => prev + payout.payout, 0n);
+      const stakerReward = payout.find(p => p.staker === staker.address);
+
+      expect(stakerReward?.payout).to.eq(calculateIncome(100n * nominal) - (100n * nominal));
+
+      const totalStakedAfter = await helper.staking.getTotalStaked();
+      expect(totalStakedAfter).to.equal(totalStakedBefore + (100n * nominal) + totalPayout);
+      // staker can unstake
+      await helper.staking.unstakeAll(staker);
+      expect(await helper.staking.getTotalStaked()).to.be.equal(totalStakedAfter - calculateIncome(100n * nominal));
+    });
+
+    itSub('should credit 0.05% for staking period', async ({helper}) => {
+      const [staker] = await getAccounts(1);
+
+      await waitPromotionPeriodDoesntEnd(helper);
+
+      await helper.staking.stake(staker, 100n * nominal);
+      await helper.staking.stake(staker, 200n * nominal);
+
+      // wait rewards are available:
+      const [_stake1, stake2] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake2.block));
+
+      const payoutToStaker = (await helper.admin.payoutStakers(palletAdmin, 100)).find((payout) => payout.staker === staker.address)!.payout;
+      expect(payoutToStaker + 300n * nominal).to.equal(calculateIncome(300n * nominal));
+
+      const totalStakedPerBlock = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      const income1 = calculateIncome(100n * nominal);
+      const income2 = calculateIncome(200n * nominal);
+      expect(totalStakedPerBlock[0].amount).to.equal(income1);
+      expect(totalStakedPerBlock[1].amount).to.equal(income2);
+
+      const stakerBalance = await helper.balance.getSubstrateFull(staker.address);
+      expect(stakerBalance).to.contain({frozen: income1 + income2, reserved: 0n});
+      expect(stakerBalance.free / nominal).to.eq(999n);
+    });
+
+    itSub('shoud be paid for more than one period if payments was missed', async ({helper}) => {
+      const [staker] = await getAccounts(1);
+
+      await helper.staking.stake(staker, 100n * nominal);
+      // wait for two rewards are available:
+      let [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block) + LOCKING_PERIOD);
+
+      await helper.admin.payoutStakers(palletAdmin, 100);
+      [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      const frozenBalanceShouldBe = calculateIncome(100n * nominal, 2);
+      expect(stake.amount).to.be.equal(frozenBalanceShouldBe);
+
+      const stakerFullBalance = await helper.balance.getSubstrateFull(staker.address);
+
+      expect(stakerFullBalance).to.contain({reserved: 0n, frozen: frozenBalanceShouldBe});
+    });
+
+    itSub('should not be credited for pending-unstaked tokens', async ({helper}) => {
+      // staker unstakes before rewards been payed
+      const [staker] = await getAccounts(1);
+      await helper.staking.stake(staker, 100n * nominal);
+      const [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block) + LOCKING_PERIOD);
+      await helper.staking.unstakeAll(staker);
+
+      // so he did not receive any rewards
+      const totalBalanceBefore = await helper.balance.getSubstrate(staker.address);
+      await helper.admin.payoutStakers(palletAdmin, 100);
+      const totalBalanceAfter = await helper.balance.getSubstrate(staker.address);
+
+      expect(totalBalanceBefore).to.be.equal(totalBalanceAfter);
+    });
+
+    itSub('should match the projection and be recorded in the payout history', async ({helper}) => {
+      const [staker] = await getAccounts(1);
+      await helper.staking.stake(staker, 100n * nominal);
+
+      const [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      const rewardBlock = rewardAvailableInBlock(stake.block);
+      await helper.wait.forRelayBlockNumber(rewardBlock - LOCKING_PERIOD);
+
+      const projected = await helper.staking.getProjectedPayout({Substrate: staker.address});
+      expect(projected).to.deep.equal({recalculationBlock: rewardBlock, amount: calculateIncome(100n * nominal) - 100n * nominal});
+
+      const runway = await helper.staking.getRewardPotRunway();
+      expect(runway.intervalPayout > 0n).to.be.true;
+      expect(runway.runwayBlocks).to.not.be.null;
+
+      await helper.wait.forRelayBlockNumber(rewardBlock);
+      const stakerPayout = await payUntilRewardFor(staker.address, helper);
+
+      expect(stakerPayout.payout).to.eq(projected.amount);
+      expect(await helper.staking.getPayoutHistory({Substrate: staker.address})).to.deep.equal([projected]);
+
+      await helper.staking.unstakeAll(staker);
+      expect(await helper.staking.getPayoutHistory({Substrate: staker.address})).to.be.empty;
+    });
+
+    itSub('should pay liquid rewards to the reward destination', async ({helper}) => {
+      const [staker, payee] = await getAccounts(2);
+      await helper.staking.stake(staker, 100n * nominal);
+      expect(await helper.staking.getRewardDestination({Substrate: staker.address})).to.eq('Compound');
+
+      await helper.staking.setRewardDestination(staker, {Account: payee.address});
+      expect(await helper.staking.getRewardDestination({Substrate: staker.address})).to.deep.eq({Account: payee.address});
+
+      const [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block));
+
+      const payeeBalanceBefore = await helper.balance.getSubstrate(payee.address);
+      const stakerPayout = await payUntilRewardFor(staker.address, helper);
+      expect(stakerPayout.payout).to.eq(calculateIncome(100n * nominal) - 100n * nominal);
+
+      // the stake is not increased, the reward is paid to the payee
+      const [stakeAfter] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      expect(stakeAfter.amount).to.eq(100n * nominal);
+      expect(await helper.balance.getSubstrate(payee.address)).to.eq(payeeBalanceBefore + stakerPayout.payout);
+      expect(await helper.balance.getSubstrateFull(staker.address)).to.contain({frozen: 100n * nominal});
+
+      await helper.staking.setRewardDestination(staker, 'Compound');
+    });
+
+    itSub('should bring compound interest', async ({helper}) => {
+      const [staker] = await getAccounts(1);
+
+      await helper.staking.stake(staker, 100n * nominal);
+
+      let [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block));
+
+      await helper.admin.payoutStakers(palletAdmin, 100);
+      [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      expect(stake.amount).to.equal(calculateIncome(100n * nominal));
+
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block) + LOCKING_PERIOD);
+      await helper.admin.payoutStakers(palletAdmin, 100);
+      [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      expect(stake.amount).to.equal(calculateIncome(100n * nominal, 2));
+    });
+
+    itSub('can calculate reward for tiny stake', async ({helper}) => {
+      const [staker] = await getAccounts(1);
+      await helper.staking.stake(staker, 100n * nominal);
+      await helper.staking.stake(staker, 100n * nominal);
+      await helper.staking.unstakePartial(staker, 100n * nominal - 1n);
+
+      const [_stake1, stake2] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake2.block));
+
+      const stakerPayout = await payUntilRewardFor(staker.address, helper);
+      expect(stakerPayout.stake).to.eq(100n * nominal + 1n);
+    });
+
+    itSub('can eventually pay all rewards', async ({helper}) => {
+      const stakers = await getAccounts(30);
+      // Create 30 stakes:
+      await Promise.all(stakers.map(staker => helper.staking.stake(staker, 100n * nominal)));
+
+      let unstakingTxs = [];
+      for(const staker of stakers) {
+        if(unstakingTxs.length == 3) {
+          await Promise.all(unstakingTxs);
+          unstakingTxs = [];
+        }
+        unstakingTxs.push(helper.staking.unstakePartial(staker, 100n * nominal - 1n));
+      }
+
+      const [staker] = await getAccounts(1);
+      await helper.staking.stake(staker, 100n * nominal);
+      const [stake] = await helper.staking.getTotalStakedPerBlock({Substrate: staker.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake.block));
+
+      let payouts;
+      do {
+        payouts = await helper.admin.payoutStakers(palletAdmin, 20);
+      } while(payouts.length !== 0);
+    });
+  });
+
+  describe('events', () => {
+    // Flaky
+    itSub.skip('payoutStakers', async ({helper}) => {
+      const [staker1, staker2] = await getAccounts(2);
+      const STAKE1 = 100n * nominal;
+      const STAKE2 = 200n * nominal;
+      await helper.staking.stake(staker1, STAKE1);
+      await helper.staking.stake(staker2, STAKE2);
+
+      const [stake2] = await helper.staking.getTotalStakedPerBlock({Substrate: staker2.address});
+      await helper.wait.forRelayBlockNumber(rewardAvailableInBlock(stake2.block));
+
+      const results = await helper.admin.payoutStakers(palletAdmin, 100);
+      const stakersEvents = results.filter(ev => ev.staker === staker1.address || ev.staker === staker2.address);
+      expect(stakersEvents).has.length(2);
+      expect(stakersEvents).has.not.ordered.members([
+        {staker: staker1.address, stake: STAKE1, payout: calculateIncome(STAKE1) - STAKE1},
+        {staker: staker2.address, stake: STAKE2, payout: calculateIncome(STAKE2) - STAKE2},
+      ]);
+    });
+  });
+});
+
+// Sometimes is is required to make a cycle in order for the payment to be calculated for a specific account
+async function payUntilRewardFor(account: string, helper: DevUniqueHelper) {
+  for(let i = 0; i < 3; i++) {
+    const payouts = await helper.admin.payoutStakers(palletAdmin, 100);
+    const accountPayout = payouts.find(p => p.staker === account);
+    if(accountPayout) return accountPayout;
+  }
+  throw Error(`Cannot find payout for ${account}`);
+}
+
+function calculateIncome(base: bigint, iter = 0, calcPeriod: bigint = UNLOCKING_PERIOD): bigint {
+  const DAY = 7200n;
+  const ACCURACY = 1_000_000_000n;
+  // 453_256n / 1_000_000_000n = 0.0453256% /day
+  const income = base + base * (ACCURACY * (calcPeriod * 453_256n) / (1_000_000_000n * DAY)) / ACCURACY ;
+
+  if(iter > 1) {
+    return calculateIncome(income, iter - 1, calcPeriod);
+  } else return income;
+}
+
+function rewardAvailableInBlock(stakedInBlock: bigint) {
+  if(stakedInBlock % LOCKING_PERIOD === 0n) return stakedInBlock + LOCKING_PERIOD;
+  return (stakedInBlock - stakedInBlock % LOCKING_PERIOD) + (LOCKING_PERIOD * 2n);
+}
+
+// Wait while promotion period less than specified block, to avoid boundary cases
+// 0 if this should be the beginning of the period.
+async function waitPromotionPeriodDoesntEnd(helper: DevUniqueHelper, waitBlockLessThan = LOCKING_PERIOD / 3n) {
+  const relayBlockNumber = (await helper.callRpc('api.query.parachainSystem.validationData', [])).value.relayParentNumber.toNumber(); // await helper.chain.getLatestBlockNumber();
+  const currentPeriodBlock = BigInt(relayBlockNumber) % LOCKING_PERIOD;
+
+  if(currentPeriodBlock > waitBlockLessThan) {
+    await helper.wait.forRelayBlockNumber(BigInt(relayBlockNumber) + LOCKING_PERIOD - currentPeriodBlock);
+  }
+}
@@ -128,6 +128,20 @@ describe('Integration Test: Collator Selection', () => {
       expect(lastGammaBlock >= lastBlockNumber || lastDeltaBlock >= lastBlockNumber).to.be.true;
     });
 
+    itSub('Collators are rewarded at the session end for the blocks they authored', async ({helper}) => {
+      await helper.wait.newSessions(1);
+
+      const session = (await helper.callRpc('api.query.session.currentIndex', [])).toNumber();
+      const stats = await Promise.all([gammaNode, deltaNode]
+        .map(async node => (await helper.callRpc('api.rpc.collatorSelection.collatorStats', [node]) as any).toJSON()));
+
+      for(const stat of stats) {
+        expect(stat.lastRewardedSession).to.be.lessThan(session);
+        expect(BigInt(stat.totalReward) >= BigInt(stat.lastSessionReward)).to.be.true;
+      }
+      expect(stats.some(stat => stat.sessionBlocks > 0 || stat.lastSessionBlocks > 0)).to.be.true;
+    });
+
     after(async () => {
       await resetInvulnerables();
     });
@@ -182,6 +196,29 @@ describe('Integration Test: Collator Selection', () => {
         expect(balance.reserved).to.be.equal(previousBalance.reserved);
         expect(balance.free > previousBalance.free - licenseBond);
       });
+
+      itSub('Can delegate to a license holder and undelegate', async ({helper}) => {
+        const candidate = crowd.pop()!;
+        const minDelegation = helper.collatorSelection.minDelegation();
+        const [delegator] = await helper.arrange.createAccounts([2n * minDelegation / helper.balance.getOneTokenNominal()], superuser);
+
+        await helper.collatorSelection.obtainLicense(candidate);
+        await helper.collatorSelection.setCommission(candidate, 100_000_000);
+
+        // the delegated funds back the license holder
+        await helper.collatorSelection.delegate(delegator, candidate.address, minDelegation);
+        expect(await helper.collatorSelection.getTotalBacking(candidate.address)).to.be.equal(licenseBond + minDelegation);
+        expect((await helper.balance.getSubstrateFull(delegator.address)).reserved).to.be.equal(minDelegation);
+
+        // the undelegated funds stay held until the unbonding delay passes
+        await helper.collatorSelection.undelegate(delegator, candidate.address, minDelegation);
+        expect(await helper.collatorSelection.getTotalBacking(candidate.address)).to.be.equal(licenseBond);
+        expect((await helper.balance.getSubstrateFull(delegator.address)).reserved).to.be.equal(minDelegation);
+        await expect(helper.collatorSelection.withdrawUnbonded(delegator))
+          .to.be.rejectedWith(/collatorSelection.NothingToWithdraw/);
+
+        await helper.collatorSelection.releaseLicense(candidate);
+      });
     });
 
     describe('Negative', () => {
@@ -206,6 +243,12 @@ describe('Integration Test: Collator Selection', () => {
           .to.be.rejectedWith(/collatorSelection.NoLicense/);
       });
 
+      itSub('Cannot delegate to an account without a license', async ({helper}) => {
+        const [candidate, delegator] = [crowd.pop()!, crowd.pop()!];
+        await expect(helper.collatorSelection.delegate(delegator, candidate.address, helper.collatorSelection.minDelegation()))
+          .to.be.rejectedWith(/collatorSelection.NoLicense/);
+      });
+
       itSub('Cannot force revoke a license as non-sudo', async ({helper}) => {
         const account = crowd.pop()!;
         await helper.collatorSelection.obtainLicense(account);
@@ -244,6 +287,28 @@ describe('Integration Test: Collator Selection', () => {
         expect(await helper.collatorSelection.getCandidates()).to.be.deep.equal([]);
       });
 
+      itSub('Can join and leave the waiting list', async ({helper}) => {
+        const [first, second] = [crowd.pop()!, crowd.pop()!];
+        await helper.collatorSelection.obtainLicense(first);
+        await helper.collatorSelection.obtainLicense(second);
+
+        await helper.collatorSelection.joinWaitingList(first);
+        await helper.collatorSelection.joinWaitingList(second);
+        expect(await helper.collatorSelection.getWaitingCandidates()).to.be.deep.equal([first.address, second.address]);
+
+        // Only the first waiting account may onboard directly
+        await expect(helper.collatorSelection.onboard(second))
+          .to.be.rejectedWith(/collatorSelection.WaitingListNotEmpty/);
+
+        await helper.collatorSelection.leaveWaitingList(first);
+        expect(await helper.collatorSelection.getWaitingCandidates()).to.be.deep.equal([second.address]);
+
+        // Onboarding directly takes the account off the waiting list
+        await helper.collatorSelection.onboard(second);
+        expect(await helper.collatorSelection.getWaitingCandidates()).to.be.deep.equal([]);
+        await helper.collatorSelection.offboard(second);
+      });
+
       itSub('Penalizes and forfeits license from faulty collators', async ({helper}) => {
         // This one shouldn't even be able to produce blocks.
         const account = crowd.pop()!;
@@ -251,14 +316,17 @@ describe('Integration Test: Collator Selection', () => {
         await helper.collatorSelection.onboard(account);
         expect(await helper.collatorSelection.getCandidates()).to.contain(account.address);
 
-        // Wait for 3 new sessions before checking that the collator will be kicked:
-        // one to get collator onboarded, and another two for the collator to fail
-        await helper.wait.newSessions(3);
+        // Wait for new sessions before checking that the collator will be kicked:
+        // one to get collator onboarded, another two for the collator to fail,
+        // and one more for each strike it survives
+        const maxStrikes = await helper.collatorSelection.getMaxStrikes();
+        await helper.wait.newSessions(2 + maxStrikes);
 
         expect(await helper.collatorSelection.getCandidates()).to.not.contain(account.address);
         expect(await helper.collatorSelection.hasLicense(account.address)).to.be.equal(0n);
 
-        // The account's reserved funds get slashed as a penalty
+        // The strikes slash a part of the account's reserved funds as a penalty,
+        // the rest is returned with the license
         const balance = await helper.balance.getSubstrateFull(account.address);
         expect(balance.reserved).to.be.equal(0n);
         expect(balance.free < 100n - licenseBond);
@@ -285,6 +353,19 @@ describe('Integration Test: Collator Selection', () => {
           .to.be.rejectedWith(/collatorSelection.NotCandidate/);
       });
 
+      itSub('Cannot join the waiting list without a license', async ({helper}) => {
+        const account = crowd.pop()!;
+        await expect(helper.collatorSelection.joinWaitingList(account))
+          .to.be.rejectedWith(/collatorSelection.NoLicense/);
+      });
+
+      itSub('Cannot leave the waiting list while not waiting', async ({helper}) => {
+        const account = crowd.pop()!;
+        await helper.collatorSelection.obtainLicense(account);
+        await expect(helper.collatorSelection.leaveWaitingList(account))
+          .to.be.rejectedWith(/collatorSelection.NotWaiting/);
+      });
+
       itSub('Cannot onboard while already onboarded', async ({helper}) => {
         const account = crowd.pop()!;
         await helper.collatorSelection.obtainLicense(account);
@@ -50,6 +50,21 @@ describe('Graphs', () => {
     });
   });
 
+  itSub('Token tree can be queried for a complex graph', async ({helper}) => {
+    const [collection, tokens] = await buildComplexObjectGraph(helper, alice);
+
+    const tree = (await helper.callRpc('api.rpc.unique.tokenTree', [collection.collectionId, tokens[0].tokenId])).toJSON();
+    expect(tree).to.have.length(8);
+    expect(tree[0]).to.deep.include({token: {collection: collection.collectionId, token: tokens[0].tokenId}, parent: null, depth: 0});
+    expect(tree.find((node: any) => node.token.token === tokens[6].tokenId)).to.deep.include({
+      parent: {collection: collection.collectionId, token: tokens[5].tokenId},
+      depth: 4,
+    });
+
+    const shallowTree = (await helper.callRpc('api.rpc.unique.tokenTree', [collection.collectionId, tokens[0].tokenId, 2])).toJSON();
+    expect(shallowTree.map((node: any) => node.token.token)).to.have.members([0, 1, 2, 4].map(i => tokens[i].tokenId));
+  });
+
   itSub('Ouroboros can\'t be created in a complex graph', async ({helper}) => {
     const [collection, tokens] = await buildComplexObjectGraph(helper, alice);
 
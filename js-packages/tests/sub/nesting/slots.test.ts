@@ -0,0 +1,101 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, usingPlaygrounds} from '../../util/index.js';
+import {CrossAccountId} from '@unique/playgrounds/unique.js';
+
+describe('Integration Test: Slots', () => {
+  let alice: IKeyringPair;
+  let bob: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (helper, privateKey) => {
+      const donor = await privateKey({url: import.meta.url});
+      [alice, bob] = await helper.arrange.createAccounts([200n, 50n], donor);
+    });
+  });
+
+  itSub('Tokens can be equipped into a slot and unequipped', async ({helper}) => {
+    const parentCollection = await helper.nft.mintCollection(alice, {permissions: {nesting: {tokenOwner: true}}});
+    const childCollection = await helper.nft.mintCollection(alice);
+    await helper.executeExtrinsic(alice, 'api.tx.structure.setCollectionSlots', [
+      parentCollection.collectionId,
+      [{name: 'hand', allowedCollections: [childCollection.collectionId], maxCount: 1}],
+    ], true);
+
+    const parent = await parentCollection.mintToken(alice);
+    const child = await childCollection.mintToken(alice, parent.nestingAccount());
+    const secondChild = await childCollection.mintToken(alice, parent.nestingAccount());
+
+    await helper.executeExtrinsic(alice, 'api.tx.structure.equip', [
+      parent.collectionId, parent.tokenId, 'hand', child.collectionId, child.tokenId,
+    ], true);
+    expect((await helper.callRpc('api.query.structure.equippedIn', [child.collectionId, child.tokenId])).toHuman())
+      .to.be.deep.equal([parent.collectionId.toString(), parent.tokenId.toString(), 'hand']);
+
+    await expect(helper.executeExtrinsic(alice, 'api.tx.structure.equip', [
+      parent.collectionId, parent.tokenId, 'hand', secondChild.collectionId, secondChild.tokenId,
+    ], true)).to.be.rejectedWith(/structure\.SlotIsFull/);
+
+    // Transferring the token out of its parent frees the slot
+    await child.transferFrom(alice, parent.nestingAccount(), {Substrate: alice.address});
+    expect((await helper.callRpc('api.query.structure.equippedIn', [child.collectionId, child.tokenId])).isNone).to.be.true;
+
+    await helper.executeExtrinsic(alice, 'api.tx.structure.equip', [
+      parent.collectionId, parent.tokenId, 'hand', secondChild.collectionId, secondChild.tokenId,
+    ], true);
+    await helper.executeExtrinsic(alice, 'api.tx.structure.unequip', [
+      secondChild.collectionId, secondChild.tokenId,
+    ], true);
+    expect((await helper.callRpc('api.query.structure.equippedIn', [secondChild.collectionId, secondChild.tokenId])).isNone).to.be.true;
+    expect(await secondChild.getOwner()).to.be.deep.equal(CrossAccountId.toLowerCase(parent.nestingAccount()));
+  });
+
+  itSub('Nesting is restricted to collections allowed in slots', async ({helper}) => {
+    const parentCollection = await helper.nft.mintCollection(alice, {permissions: {nesting: {tokenOwner: true}}});
+    const allowedCollection = await helper.nft.mintCollection(alice);
+    const otherCollection = await helper.nft.mintCollection(alice);
+    await helper.executeExtrinsic(alice, 'api.tx.structure.setCollectionSlots', [
+      parentCollection.collectionId,
+      [{name: 'hand', allowedCollections: [allowedCollection.collectionId], maxCount: 2}],
+    ], true);
+
+    const parent = await parentCollection.mintToken(alice);
+    await expect(allowedCollection.mintToken(alice, parent.nestingAccount())).to.be.fulfilled;
+    await expect(otherCollection.mintToken(alice, parent.nestingAccount()))
+      .to.be.rejectedWith(/structure\.NoSlotForSourceCollection/);
+  });
+
+  itSub('Only the owner of the parent token can equip', async ({helper}) => {
+    const parentCollection = await helper.nft.mintCollection(alice, {permissions: {nesting: {tokenOwner: true}}});
+    const childCollection = await helper.nft.mintCollection(alice);
+    await helper.executeExtrinsic(alice, 'api.tx.structure.setCollectionSlots', [
+      parentCollection.collectionId,
+      [{name: 'hand', allowedCollections: [childCollection.collectionId], maxCount: 1}],
+    ], true);
+
+    const parent = await parentCollection.mintToken(alice);
+    const child = await childCollection.mintToken(alice, parent.nestingAccount());
+
+    await expect(helper.executeExtrinsic(bob, 'api.tx.structure.equip', [
+      parent.collectionId, parent.tokenId, 'hand', child.collectionId, child.tokenId,
+    ], true)).to.be.rejectedWith(/common\.NoPermission/);
+    await expect(helper.executeExtrinsic(bob, 'api.tx.structure.setCollectionSlots', [
+      parentCollection.collectionId, [],
+    ], true)).to.be.rejectedWith(/common\.NoPermission/);
+  });
+});
@@ -0,0 +1,96 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+import type {IKeyringPair} from '@polkadot/types/types';
+import {expect, itSub, usingPlaygrounds} from '../../util/index.js';
+import {CrossAccountId, UniqueHelper} from '@unique/playgrounds/unique.js';
+
+describe('Nested tokens sponsoring', () => {
+  let donor: IKeyringPair;
+
+  before(async () => {
+    await usingPlaygrounds(async (_, privateKey) => {
+      donor = await privateKey({url: import.meta.url});
+    });
+  });
+
+  async function equippedCharacters(helper: UniqueHelper, alice: IKeyringPair, bob: IKeyringPair, charlie: IKeyringPair) {
+    const characters = await helper.nft.mintCollection(alice, {permissions: {nesting: {tokenOwner: true}}});
+    await characters.setSponsor(alice, bob.address);
+    await characters.confirmSponsorship(bob);
+    const [first, second] = await characters.mintMultipleTokens(alice, [
+      {owner: {Substrate: charlie.address}},
+      {owner: {Substrate: charlie.address}},
+    ]);
+
+    const equipment = await helper.nft.mintCollection(charlie);
+    const sword = await equipment.mintToken(charlie);
+    await sword.nest(charlie, first);
+    return {characters, first, second, sword};
+  }
+
+  itSub('Moves of nested tokens are paid by the topmost collection sponsor', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    const {characters, first, second, sword} = await equippedCharacters(helper, alice, bob, charlie);
+    await characters.setNestedTokensSponsoring(bob, true);
+
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    const bobBalanceBefore = await helper.balance.getSubstrate(bob.address);
+    await sword.transferFrom(charlie, first.nestingAccount(), second.nestingAccount());
+
+    expect(await sword.getOwner()).to.be.deep.equal(CrossAccountId.toLowerCase(second.nestingAccount()));
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore);
+    expect(await helper.balance.getSubstrate(bob.address) < bobBalanceBefore).to.be.true;
+  });
+
+  itSub('Equipment moves are paid by the topmost collection sponsor', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    const {characters, first, sword} = await equippedCharacters(helper, alice, bob, charlie);
+    await characters.setNestedTokensSponsoring(bob, true);
+    await helper.executeExtrinsic(alice, 'api.tx.structure.setCollectionSlots', [
+      characters.collectionId,
+      [{name: 'hand', allowedCollections: [sword.collectionId], maxCount: 1}],
+    ], true);
+
+    const charlieBalanceBefore = await helper.balance.getSubstrate(charlie.address);
+    const bobBalanceBefore = await helper.balance.getSubstrate(bob.address);
+    await helper.executeExtrinsic(charlie, 'api.tx.structure.equip', [
+      first.collectionId, first.tokenId, 'hand', sword.collectionId, sword.tokenId,
+    ], true);
+
+    expect((await helper.callRpc('api.query.structure.equippedIn', [sword.collectionId, sword.tokenId])).isSome).to.be.true;
+    expect(await helper.balance.getSubstrate(charlie.address)).to.be.equal(charlieBalanceBefore);
+    expect(await helper.balance.getSubstrate(bob.address) < bobBalanceBefore).to.be.true;
+  });
+
+  itSub('Nested tokens are not sponsored without opt-in', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    const {first, second, sword} = await equippedCharacters(helper, alice, bob, charlie);
+
+    const bobBalanceBefore = await helper.balance.getSubstrate(bob.address);
+    await sword.transferFrom(charlie, first.nestingAccount(), second.nestingAccount());
+
+    expect(await helper.balance.getSubstrate(bob.address)).to.be.equal(bobBalanceBefore);
+  });
+
+  itSub('Only sponsor or admins can enable nested tokens sponsoring', async ({helper}) => {
+    const [alice, bob, charlie] = await helper.arrange.createAccounts([100n, 100n, 100n], donor);
+    const {characters} = await equippedCharacters(helper, alice, bob, charlie);
+
+    await expect(characters.setNestedTokensSponsoring(charlie, true)).to.be.rejectedWith(/common\.NoPermission/);
+    expect(await characters.setNestedTokensSponsoring(alice, true)).to.be.true;
+  });
+});
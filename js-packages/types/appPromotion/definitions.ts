@@ -40,9 +40,9 @@ export default {
       'u128',
     ),
     totalStakedPerBlock: fun(
-      'Returns the total amount of staked tokens per block when staked',
+      'Returns the total amount of staked tokens per block when staked, with the lock tier and unlock block of locked stakes',
       [crossAccountParam('staker')],
-      'Vec<(u32, u128)>',
+      'Vec<(u32, u128, Option<(u8, u32)>)>',
     ),
     pendingUnstake: fun(
       'Returns the total amount of unstaked tokens',
@@ -54,5 +54,40 @@ export default {
       [crossAccountParam('staker')],
       'Vec<(u32, u128)>',
     ),
+    delegatedStake: fun(
+      'Returns the collections/contracts the stake was delegated to by the staker, with the delegated amounts',
+      [crossAccountParam('staker')],
+      'Vec<(H160, u128)>',
+    ),
+    targetDelegatedStake: fun(
+      'Returns the total stake delegated to the collection/contract',
+      [{name: 'target', type: 'H160'}],
+      'u128',
+    ),
+    sponsoringAllowance: fun(
+      'Returns the amount the pallet may spend within a sponsoring era on the calls of the collection/contract',
+      [{name: 'target', type: 'H160'}],
+      'u128',
+    ),
+    payoutHistory: fun(
+      'Returns the interest paid to the staker for the last recalculation periods, per relay block of the recalculation',
+      [crossAccountParam('staker')],
+      'Vec<(u32, u128)>',
+    ),
+    projectedPayout: fun(
+      'Returns the relay block of the next recalculation and the interest the staker will receive in it at the current rate',
+      [crossAccountParam('staker')],
+      '(u32, u128)',
+    ),
+    rewardPotRunway: fun(
+      'Returns the treasury balance the interest is paid from, the interest paid per recalculation interval, and the number of relay blocks the balance lasts',
+      [],
+      '(u128, u128, Option<u32>)',
+    ),
+    rewardDestination: fun(
+      'Returns where the interest accrued by the stakes of the staker is paid',
+      [crossAccountParam('staker')],
+      'UpDataStructsRewardDestination',
+    ),
   },
 };
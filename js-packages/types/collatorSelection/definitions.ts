@@ -0,0 +1,54 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+type RpcParam = {
+  name: string;
+  type: string;
+  isOptional?: true;
+};
+
+const atParam = {name: 'at', type: 'Hash', isOptional: true};
+
+const fun = (description: string, params: RpcParam[], type: string) => ({
+  description,
+  params: [...params, atParam],
+  type,
+});
+
+export default {
+  types: {
+    UpDataStructsCollatorStats: {
+      sessionBlocks: 'u32',
+      lastAuthoredBlock: 'Option<u32>',
+      lastRewardedSession: 'u32',
+      lastSessionBlocks: 'u32',
+      lastSessionReward: 'u128',
+      totalReward: 'u128',
+    },
+  },
+  rpc: {
+    collatorStats: fun(
+      'Returns the authored blocks and the rewards of the collator',
+      [{name: 'collator', type: 'AccountId32'}],
+      'UpDataStructsCollatorStats',
+    ),
+    waitingCandidates: fun(
+      'Returns the license holders waiting for a candidate slot, in the order of promotion',
+      [],
+      'Vec<AccountId32>',
+    ),
+  },
+};
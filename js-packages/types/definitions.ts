@@ -16,5 +16,7 @@
 
 export {default as unique} from './unique/definitions.js';
 export {default as appPromotion} from './appPromotion/definitions.js';
+export {default as inflation} from './inflation/definitions.js';
+export {default as collatorSelection} from './collatorSelection/definitions.js';
 export {default as povinfo} from './povinfo/definitions.js';
 export {default as default} from './default/definitions.js';
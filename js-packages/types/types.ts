@@ -2,6 +2,8 @@
 /* eslint-disable */
 
 export * from './appPromotion/types.js';
+export * from './collatorSelection/types.js';
+export * from './inflation/types.js';
 export * from './default/types.js';
 export * from './povinfo/types.js';
 export * from './unique/types.js';
@@ -35,7 +35,46 @@ const fun = (description: string, params: RpcParam[], type: string) => ({
 });
 
 export default {
-  types: {},
+  types: {
+    UpDataStructsTokenTreeNode: {
+      token: 'UpDataStructsTokenChild',
+      parent: 'Option<UpDataStructsTokenChild>',
+      depth: 'u32',
+      owner: `Option<${CROSS_ACCOUNT_ID_TYPE}>`,
+      properties: 'Vec<UpDataStructsProperty>',
+    },
+    UpDataStructsCollectionAdminRoles: 'u8',
+    UpDataStructsPendingCollectionOwner: {
+      owner: 'AccountId32',
+      expiresAt: 'Option<u32>',
+    },
+    UpDataStructsSponsorBudgetLeft: {
+      period: 'Option<u128>',
+      user: 'Option<u128>',
+      periodEnd: 'Option<u32>',
+    },
+    UpDataStructsSponsoringReason: {
+      _enum: [
+        'Sponsored',
+        'Unsigned',
+        'NoSponsor',
+        'NotEligible',
+        'BudgetExhausted',
+        'GasPriceNotSponsored',
+        'SponsorBalanceTooLow',
+      ],
+    },
+    UpDataStructsSponsoringDryRun: {
+      sponsor: `Option<${CROSS_ACCOUNT_ID_TYPE}>`,
+      fee: 'u128',
+      reason: 'UpDataStructsSponsoringReason',
+    },
+    UpDataStructsSponsoredFees: {
+      era: 'u32',
+      total: 'u128',
+      topUsers: `Vec<(${CROSS_ACCOUNT_ID_TYPE}, u128)>`,
+    },
+  },
   rpc: {
     accountTokens: fun(
       'Get tokens owned by an account in a collection',
@@ -73,6 +112,11 @@ export default {
       [collectionParam, tokenParam],
       'Vec<UpDataStructsTokenChild>',
     ),
+    tokenTree: fun(
+      'Get the tree of tokens nested into the token, optionally limited in depth',
+      [collectionParam, tokenParam, {name: 'maxDepth', type: 'Option<u32>', isOptional: true}, propertyKeysParam],
+      'Vec<UpDataStructsTokenTreeNode>',
+    ),
 
     collectionProperties: fun(
       'Get collection properties, optionally limited to the provided keys',
@@ -133,6 +177,11 @@ export default {
       [collectionParam],
       'Vec<PalletEvmAccountBasicCrossAccountIdRepr>',
     ),
+    adminlistWithRoles: fun(
+      'Get the list of admin accounts of a collection together with their roles',
+      [collectionParam],
+      'Vec<(PalletEvmAccountBasicCrossAccountIdRepr, UpDataStructsCollectionAdminRoles)>',
+    ),
     allowlist: fun(
       'Get the list of accounts allowed to operate within a collection',
       [collectionParam],
@@ -154,6 +203,11 @@ export default {
       [collectionParam],
       'Option<UpDataStructsRpcCollection>',
     ),
+    collectionPendingOwner: fun(
+      'Get the proposed owner of a collection, awaiting acceptance',
+      [collectionParam],
+      'Option<UpDataStructsPendingCollectionOwner>',
+    ),
     collectionStats: fun(
       'Get chain stats about collections',
       [],
@@ -165,6 +219,37 @@ export default {
       [collectionParam, crossAccountParam(), tokenParam],
       'Option<u64>',
     ),
+    sponsorBudgetLeft: fun(
+      'Get the fees the collection sponsor is still ready to pay in the current budget period',
+      [collectionParam, crossAccountParam()],
+      'Option<UpDataStructsSponsorBudgetLeft>',
+    ),
+    sponsoringDryRun: fun(
+      'Check whether the encoded signed extrinsic would be sponsored, by whom and with what fee',
+      [{name: 'extrinsic', type: 'Bytes'}],
+      'UpDataStructsSponsoringDryRun',
+    ),
+    evmSponsoringDryRun: fun(
+      'Check whether the EVM call would be sponsored, by whom and with what fee',
+      [
+        {name: 'source', type: 'H160'},
+        {name: 'target', type: 'H160'},
+        {name: 'input', type: 'Bytes'},
+        {name: 'gasLimit', type: 'u64'},
+        {name: 'maxFeePerGas', type: 'U256'},
+      ],
+      'UpDataStructsSponsoringDryRun',
+    ),
+    sponsorFeesPaid: fun(
+      'Get the fees paid by the sponsor within the era, or within the current one',
+      [crossAccountParam('sponsor'), {name: 'era', type: 'Option<u32>'}],
+      'u128',
+    ),
+    sponsoredFees: fun(
+      'Get the fees paid for the calls of the sponsored collection or contract within the era, or within the current one, together with the top sponsored users',
+      [{name: 'target', type: 'H160'}, {name: 'era', type: 'Option<u32>'}, {name: 'top', type: 'u32'}],
+      'UpDataStructsSponsoredFees',
+    ),
     effectiveCollectionLimits: fun(
       'Get effective collection limits',
       [collectionParam],
@@ -92,7 +92,10 @@ where
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	#[cfg(feature = "pov-estimate")]
 	use uc_rpc::pov_estimate::{PovEstimate, PovEstimateApiServer};
-	use uc_rpc::{AppPromotion, AppPromotionApiServer, Unique, UniqueApiServer};
+	use uc_rpc::{
+		AppPromotion, AppPromotionApiServer, CollatorSelection, CollatorSelectionApiServer,
+		Inflation, InflationApiServer, Unique, UniqueApiServer,
+	};
 
 	let FullDeps {
 		client,
@@ -114,7 +117,11 @@ where
 
 	io.merge(Unique::new(client.clone()).into_rpc())?;
 
-	io.merge(AppPromotion::new(client).into_rpc())?;
+	io.merge(AppPromotion::new(client.clone()).into_rpc())?;
+
+	io.merge(Inflation::new(client.clone()).into_rpc())?;
+
+	io.merge(CollatorSelection::new(client).into_rpc())?;
 
 	#[cfg(feature = "pov-estimate")]
 	io.merge(
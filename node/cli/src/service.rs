@@ -210,6 +210,8 @@ ez_bounds!(
 		+ sp_api::ApiExt<Block>
 		+ up_rpc::UniqueApi<Block, Runtime::CrossAccountId, AccountId>
 		+ app_promotion_rpc::AppPromotionApi<Block, BlockNumber, Runtime::CrossAccountId, AccountId>
+		+ inflation_rpc::InflationApi<Block, BlockNumber, AccountId>
+		+ collator_selection_rpc::CollatorSelectionApi<Block, BlockNumber, AccountId>
 		+ up_pov_estimate_rpc::PovEstimateApi<Block>
 		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
 		+ sp_api::Metadata<Block>
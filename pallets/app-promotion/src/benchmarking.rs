@@ -19,7 +19,7 @@
 use frame_benchmarking::v2::*;
 use frame_support::traits::{
 	fungible::{Inspect, Mutate, Unbalanced},
-	OnInitialize,
+	ConstU32, OnInitialize,
 };
 use frame_system::{pallet_prelude::*, RawOrigin};
 use pallet_evm::account::CrossAccountId;
@@ -28,11 +28,14 @@ use pallet_unique::benchmarking::create_nft_collection;
 use sp_core::{Get, H160};
 use sp_runtime::{
 	traits::{BlockNumberProvider, Bounded},
-	Perbill,
+	BoundedVec, FixedPointNumber, FixedU128, Perbill,
 };
 use sp_std::{iter::Sum, vec, vec::Vec};
 
-use super::{BalanceOf, Call, Config, Pallet, Staked, PENDING_LIMIT_PER_BLOCK};
+use super::{
+	BalanceOf, Call, Config, Delegations, LockTier, LockTiers, Pallet, RewardDestination,
+	RewardDestinations, StakeLocks, Staked, MAX_LOCK_TIERS, PENDING_LIMIT_PER_BLOCK,
+};
 use crate::{pallet, Pallet as PromototionPallet};
 
 const SEED: u32 = 0;
@@ -58,6 +61,56 @@ where
 	Ok(pallet_admin)
 }
 
+fn lock_tiers<T: Config>() -> BoundedVec<LockTier<BlockNumberFor<T>>, ConstU32<MAX_LOCK_TIERS>>
+where
+	BlockNumberFor<T>: From<u32>,
+{
+	(0..MAX_LOCK_TIERS)
+		.map(|i| LockTier {
+			lock_period: (100 * (i + 1)).into(),
+			reward_multiplier: FixedU128::saturating_from_rational(10 + i, 10),
+			early_unstake_penalty: Perbill::from_percent(10),
+		})
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("the number of tiers is within the limit")
+}
+
+fn sponsored_collection_with_staker<T>() -> Result<(T::AccountId, H160), sp_runtime::DispatchError>
+where
+	T: Config + pallet_unique::Config + pallet_evm_migration::Config,
+	BlockNumberFor<T>: From<u32> + Into<u32>,
+	BalanceOf<T>: Sum + From<u128>,
+{
+	let pallet_admin = set_admin::<T>()?;
+	let owner: T::AccountId = account("owner", 0, SEED);
+	<T as Config>::Currency::write_balance(
+		&owner,
+		Perbill::from_rational(1u32, 2) * BalanceOf::<T>::max_value(),
+	)?;
+	let collection = create_nft_collection::<T>(owner)?;
+	PromototionPallet::<T>::sponsor_collection(RawOrigin::Signed(pallet_admin).into(), collection)?;
+
+	let staker: T::AccountId = account("staker", 0, SEED);
+	<T as Config>::Currency::write_balance(
+		&staker,
+		Perbill::from_rational(1u32, 2) * BalanceOf::<T>::max_value(),
+	)?;
+	(1..11).try_for_each(|i| {
+		<frame_system::Pallet<T>>::set_block_number(i.into());
+		T::RelayBlockNumberProvider::set_block_number((2 * i).into());
+		PromototionPallet::<T>::stake(
+			RawOrigin::Signed(staker.clone()).into(),
+			Into::<BalanceOf<T>>::into(100u128) * T::Nominal::get(),
+		)
+	})?;
+
+	Ok((
+		staker,
+		pallet_common::eth::collection_id_to_address(collection),
+	))
+}
+
 #[benchmarks(
 	where T:  Config + pallet_unique::Config + pallet_evm_migration::Config ,
 		BlockNumberFor<T>: From<u32> + Into<u32>,
@@ -350,4 +403,92 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn delegate_stake() -> Result<(), BenchmarkError> {
+		let (staker, target) = sponsored_collection_with_staker::<T>()?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(staker.clone()),
+			target,
+			Into::<BalanceOf<T>>::into(100u128) * T::Nominal::get(),
+		);
+
+		assert_eq!(<Delegations<T>>::get(&staker).len(), 1);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn undelegate_stake() -> Result<(), BenchmarkError> {
+		let (staker, target) = sponsored_collection_with_staker::<T>()?;
+		let amount = Into::<BalanceOf<T>>::into(100u128) * T::Nominal::get();
+		PromototionPallet::<T>::delegate_stake(
+			RawOrigin::Signed(staker.clone()).into(),
+			target,
+			amount,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(staker.clone()), target, amount);
+
+		assert!(<Delegations<T>>::get(&staker).is_empty());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_lock_tiers() -> Result<(), BenchmarkError> {
+		let tiers = lock_tiers::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, tiers);
+
+		assert_eq!(<LockTiers<T>>::get().len(), MAX_LOCK_TIERS as usize);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn stake_locked() -> Result<(), BenchmarkError> {
+		<LockTiers<T>>::set(lock_tiers::<T>());
+		let caller = account::<T::AccountId>("caller", 0, SEED);
+		let share = Perbill::from_rational(1u32, 10);
+
+		let _ = <T as Config>::Currency::write_balance(
+			&caller,
+			Perbill::from_rational(1u32, 2) * BalanceOf::<T>::max_value(),
+		);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			share * <T as Config>::Currency::total_balance(&caller),
+			0,
+		);
+
+		assert_eq!(<StakeLocks<T>>::iter_prefix((&caller,)).count(), 1);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_reward_destination() -> Result<(), BenchmarkError> {
+		let caller = account::<T::AccountId>("caller", 0, SEED);
+		let payee = account::<T::AccountId>("payee", 0, SEED);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			RewardDestination::Account(payee.clone()),
+		);
+
+		assert_eq!(
+			<RewardDestinations<T>>::get(&caller),
+			RewardDestination::Account(payee)
+		);
+
+		Ok(())
+	}
 }
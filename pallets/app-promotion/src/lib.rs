@@ -42,6 +42,23 @@
 //! - [`sponsor_contract`][`Pallet::sponsor_contract`] - sets the pallet to be the sponsor for the contract.
 //! - [`stop_sponsoring_contract`][`Pallet::stop_sponsoring_contract`] - removes the pallet as the sponsor for the contract.
 //! - [`payout_stakers`][`Pallet::payout_stakers`] - recalculates interest for the specified number of stakers.
+//!   Anyone may call it for a tip, the recalculation is also performed automatically in the `on_idle` hook.
+//! - [`delegate_stake`][`Pallet::delegate_stake`] - delegates a part of the stake to a sponsored collection/contract.
+//! - [`undelegate_stake`][`Pallet::undelegate_stake`] - withdraws a part of the stake delegated to a collection/contract.
+//! - [`set_lock_tiers`][`Pallet::set_lock_tiers`] - sets the tiers of the locked stakes.
+//! - [`stake_locked`][`Pallet::stake_locked`] - stakes the amount of native tokens, locked for the period of the tier.
+//! - [`set_reward_destination`][`Pallet::set_reward_destination`] - sets where the interest of the staker is paid.
+//!
+//! ## Lock tiers
+//! Besides the flexible stakes, the stake may be locked for the period of one of the [`LockTiers`].
+//! While locked, the stake accrues the interest multiplied by the tier multiplier. If the locked stake
+//! is unstaked before the lock expires, the penalty of the tier is paid to the treasury.
+//!
+//! ## Delegation
+//! Stakers may delegate parts of their stake to the collections and contracts sponsored by the pallet.
+//! Within each sponsoring statistics era, the fees paid by the pallet for the calls of a collection/contract
+//! are limited by its share of the pallet balance, which is proportional to the stake delegated to it
+//! together with the stake not delegated to anyone (see [`Pallet::sponsoring_allowance`]).
 //!
 
 // #![recursion_limit = "1024"]
@@ -50,6 +67,7 @@
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migration;
 pub mod types;
 pub mod weights;
 
@@ -57,10 +75,10 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	pallet_prelude::*,
-	storage::Key,
+	storage::{with_storage_layer, Key},
 	traits::{
 		fungible::{Inspect, InspectFreeze, Mutate, MutateFreeze},
-		tokens::Balance,
+		tokens::{Balance, Fortitude, Preservation},
 		Get,
 	},
 	weights::Weight,
@@ -68,11 +86,16 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
+use pallet_common::eth::map_eth_to_id;
+use pallet_configuration::AppPromotionIdlePayoutWeightOverride;
 use pallet_evm::account::CrossAccountId;
 use parity_scale_codec::EncodeLike;
 use sp_core::H160;
 use sp_runtime::{
-	traits::{AccountIdConversion, BlockNumberProvider, CheckedAdd, CheckedSub, Zero},
+	traits::{
+		AccountIdConversion, BlockNumberProvider, CheckedAdd, CheckedSub, SaturatedConversion,
+		Saturating, Zero,
+	},
 	ArithmeticError, DispatchError, Perbill,
 };
 use sp_std::{borrow::ToOwned, cell::RefCell, iter::Sum, vec, vec::Vec};
@@ -81,6 +104,14 @@ use up_data_structs::CollectionId;
 use weights::WeightInfo;
 
 const PENDING_LIMIT_PER_BLOCK: u32 = 3;
+/// Maximum number of collections/contracts a staker may delegate the stake to.
+pub const MAX_DELEGATIONS_PER_STAKER: u32 = 10;
+/// Maximum number of the lock tiers.
+pub const MAX_LOCK_TIERS: u32 = 8;
+/// Maximum lock period of a lock tier, in relay blocks (about 5 years of 6-second blocks).
+pub const MAX_LOCK_PERIOD: u32 = 5 * 365 * 24 * 60 * 10;
+/// Number of the last recalculation periods for which the payouts to a staker are stored.
+pub const MAX_PAYOUT_HISTORY: u32 = 30;
 
 type BalanceOf<T> =
 	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -138,6 +169,12 @@ pub mod pallet {
 		/// Maintenance mode status.
 		type IsMaintenanceModeEnabled: Get<bool>;
 
+		/// Amount paid from the treasury to a non-admin account that triggered a payout batch
+		/// of the maximum size, the tip for a smaller batch is reduced proportionally
+		/// to the number of the stakers recalculated in it.
+		#[pallet::constant]
+		type PayoutTip: Get<BalanceOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -148,7 +185,10 @@ pub mod pallet {
 		type RuntimeEvent: IsType<<Self as frame_system::Config>::RuntimeEvent> + From<Event<Self>>;
 	}
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::event]
@@ -188,6 +228,55 @@ pub mod pallet {
 		/// # Arguments
 		/// * AccountId: account address of the admin
 		SetAdmin(T::AccountId),
+
+		/// A part of the stake was delegated to a collection/contract
+		///
+		/// # Arguments
+		/// * AccountId: account of the staker
+		/// * H160: address of the collection/contract
+		/// * Balance: delegated amount
+		StakeDelegated(T::AccountId, H160, BalanceOf<T>),
+
+		/// A part of the stake delegated to a collection/contract was withdrawn
+		///
+		/// # Arguments
+		/// * AccountId: account of the staker
+		/// * H160: address of the collection/contract
+		/// * Balance: withdrawn amount
+		StakeUndelegated(T::AccountId, H160, BalanceOf<T>),
+
+		/// Locked staking was performed
+		///
+		/// # Arguments
+		/// * AccountId: account of the staker
+		/// * Balance: staking amount
+		/// * u8: index of the lock tier
+		/// * BlockNumber: relay block, starting from which the stake is unlocked
+		StakeLocked(T::AccountId, BalanceOf<T>, u8, BlockNumberFor<T>),
+
+		/// The locked stake was unstaked before the lock expired
+		///
+		/// # Arguments
+		/// * AccountId: account of the staker
+		/// * Balance: penalty paid to the treasury
+		EarlyUnstakePenalty(T::AccountId, BalanceOf<T>),
+
+		/// The lock tiers were set
+		LockTiersSet,
+
+		/// The tip for the triggered payout batch was paid
+		///
+		/// # Arguments
+		/// * AccountId: account that triggered the payout
+		/// * Balance: tip amount
+		PayoutTipPaid(T::AccountId, BalanceOf<T>),
+
+		/// The reward destination of the staker was set
+		///
+		/// # Arguments
+		/// * AccountId: account of the staker
+		/// * RewardDestination: where the interest of the staker is paid
+		RewardDestinationSet(T::AccountId, RewardDestination<T::AccountId>),
 	}
 
 	#[pallet::error]
@@ -206,6 +295,18 @@ pub mod pallet {
 		InsufficientStakedBalance,
 		/// Errors caused by incorrect state of a staker in context of the pallet.
 		InconsistencyState,
+		/// The staker has delegated the stake to [`MAX_DELEGATIONS_PER_STAKER`] collections/contracts already.
+		TooManyDelegations,
+		/// Errors caused by insufficient stake delegated to the collection/contract.
+		InsufficientDelegatedStake,
+		/// The lock tier doesn't exist.
+		LockTierNotFound,
+		/// The lock period of a tier is zero or exceeds [`MAX_LOCK_PERIOD`].
+		InvalidLockPeriod,
+		/// Locked and flexible stakes, or stakes of different tiers, can't be made in the same relay block.
+		StakeLockConflict,
+		/// No stake is due for the interest recalculation.
+		NothingToPayout,
 	}
 
 	/// Stores the total staked amount.
@@ -260,8 +361,92 @@ pub mod pallet {
 	pub type PreviousCalculatedRecord<T: Config> =
 		StorageValue<Value = (T::AccountId, BlockNumberFor<T>), QueryKind = OptionQuery>;
 
+	/// Stores the stake delegated by a staker to the collections/contracts sponsored by the pallet.
+	///
+	/// * **Key** - Staker account.
+	/// * **Value** - Addresses of the collections/contracts with the amounts delegated to them.
+	#[pallet::storage]
+	pub type Delegations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(H160, BalanceOf<T>), ConstU32<MAX_DELEGATIONS_PER_STAKER>>,
+		ValueQuery,
+	>;
+
+	/// Stores the total stake delegated to a collection/contract.
+	///
+	/// * **Key** - Address of the collection/contract.
+	/// * **Value** - Delegated amount.
+	#[pallet::storage]
+	pub type DelegatedStake<T: Config> =
+		StorageMap<_, Twox64Concat, H160, BalanceOf<T>, ValueQuery>;
+
+	/// Stores the total delegated amount.
+	#[pallet::storage]
+	pub type TotalDelegated<T: Config> = StorageValue<Value = BalanceOf<T>, QueryKind = ValueQuery>;
+
+	/// Stores the tiers available for the locked stakes.
+	#[pallet::storage]
+	pub type LockTiers<T: Config> = StorageValue<
+		Value = BoundedVec<LockTier<BlockNumberFor<T>>, ConstU32<MAX_LOCK_TIERS>>,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Stores the locks of the stakes.
+	///
+	/// * **Key1** - Staker account.
+	/// * **Key2** - Relay block number when the stake was made.
+	/// * **Value** - Lock of the stake.
+	#[pallet::storage]
+	pub type StakeLocks<T: Config> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, T::AccountId>,
+			Key<Twox64Concat, BlockNumberFor<T>>,
+		),
+		Value = StakeLock<BlockNumberFor<T>>,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Stores the recalculation block at which the current pass of the payouts over all stakers was started.
+	#[pallet::storage]
+	pub type PayoutPassStartedAt<T: Config> =
+		StorageValue<Value = BlockNumberFor<T>, QueryKind = OptionQuery>;
+
+	/// Stores the recalculation block for which the interest has been recalculated for all stakers.
+	/// The `on_idle` payouts are skipped until the next recalculation block.
+	#[pallet::storage]
+	pub type PayoutCompletedFor<T: Config> =
+		StorageValue<Value = BlockNumberFor<T>, QueryKind = OptionQuery>;
+
+	/// Stores the interest paid to a staker for the last [`MAX_PAYOUT_HISTORY`] recalculation periods.
+	/// The history is cleared when the staker unstakes everything.
+	///
+	/// * **Key** - Staker account.
+	/// * **Value** - Relay blocks of the recalculations with the amounts paid in them.
+	#[pallet::storage]
+	pub type PayoutHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(BlockNumberFor<T>, BalanceOf<T>), ConstU32<MAX_PAYOUT_HISTORY>>,
+		ValueQuery,
+	>;
+
+	/// Stores where the interest of a staker is paid.
+	///
+	/// * **Key** - Staker account.
+	/// * **Value** - Reward destination, [`RewardDestination::Compound`] by default.
+	#[pallet::storage]
+	pub type RewardDestinations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, RewardDestination<T::AccountId>, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migration::migrate_to_v1::<T>()
+		}
+
 		/// Block overflow is impossible due to the fact that the unstake algorithm in on_initialize
 		/// implies the execution of a strictly limited number of relatively lightweight operations.
 		/// A separate benchmark has been implemented to scale the weight depending on the number of pendings.
@@ -293,6 +478,12 @@ pub mod pallet {
 
 			<T as Config>::WeightInfo::on_initialize(counter)
 		}
+
+		/// Recalculates interest for the stakers, continuing from the [`PreviousCalculatedRecord`],
+		/// within the weight budget set in the configuration pallet.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::payout_stakers_on_idle(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -334,69 +525,91 @@ pub mod pallet {
 		pub fn stake(staker: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
 			let staker_id = ensure_signed(staker)?;
 
-			ensure!(
-				StakesPerAccount::<T>::get(&staker_id) < 10,
-				Error::<T>::NoPermission
-			);
+			Self::stake_internal(staker_id, amount, None)
+		}
 
-			ensure!(
-				amount >= <BalanceOf<T>>::from(100u128) * T::Nominal::get(),
-				ArithmeticError::Underflow
-			);
-			let config = <PalletConfiguration<T>>::get();
+		/// Stakes the amount of native tokens, locked for the period of the tier.
+		/// While locked, the stake accrues the interest multiplied by the tier multiplier.
+		/// The maximum number of stakes for a staker is 10.
+		///
+		/// # Arguments
+		///
+		/// * `amount`: in native tokens.
+		/// * `tier`: index of the tier in [`LockTiers`].
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::stake_locked())]
+		pub fn stake_locked(
+			staker: OriginFor<T>,
+			amount: BalanceOf<T>,
+			tier: u8,
+		) -> DispatchResult {
+			let staker_id = ensure_signed(staker)?;
+
+			let lock_tier = *<LockTiers<T>>::get()
+				.get(tier as usize)
+				.ok_or(<Error<T>>::LockTierNotFound)?;
+
+			Self::stake_internal(staker_id, amount, Some((tier, lock_tier)))
+		}
 
-			let balance = <<T as Config>::Currency as Inspect<T::AccountId>>::balance(&staker_id);
+		/// Sets the tiers available for the locked stakes.
+		/// The existing locked stakes keep the terms of their tiers.
+		///
+		/// The lock period of each tier must be non-zero and no longer than [`MAX_LOCK_PERIOD`].
+		///
+		/// # Permissions
+		///
+		/// * Sudo
+		///
+		/// # Arguments
+		///
+		/// * `tiers`: lock tiers.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_lock_tiers())]
+		pub fn set_lock_tiers(
+			origin: OriginFor<T>,
+			tiers: BoundedVec<LockTier<BlockNumberFor<T>>, ConstU32<MAX_LOCK_TIERS>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
 
-			// checks that we can freeze `amount` on the `staker` account.
 			ensure!(
-				amount
-					<= match Self::get_frozen_balance(&staker_id) {
-						Some(frozen_by_pallet) => balance
-							.checked_sub(&frozen_by_pallet)
-							.ok_or(ArithmeticError::Underflow)?,
-						None => balance,
-					},
-				ArithmeticError::Underflow
+				tiers.iter().all(|tier| !tier.lock_period.is_zero()
+					&& tier.lock_period <= MAX_LOCK_PERIOD.into()),
+				<Error<T>>::InvalidLockPeriod
 			);
 
-			Self::add_freeze_balance(&staker_id, amount)?;
+			<LockTiers<T>>::set(tiers);
 
-			let block_number = T::RelayBlockNumberProvider::current_block_number();
+			Self::deposit_event(Event::LockTiersSet);
 
-			// Calculation of the number of recalculation periods,
-			// after how much the first interest calculation should be performed for the stake
-			let recalculate_after_interval: BlockNumberFor<T> =
-				if block_number % config.recalculation_interval == 0u32.into() {
-					1u32.into()
-				} else {
-					2u32.into()
-				};
-
-			// Сalculation of the number of the relay block
-			// in which it is necessary to accrue remuneration for the stake.
-			let recalc_block = (block_number / config.recalculation_interval
-				+ recalculate_after_interval)
-				* config.recalculation_interval;
-
-			<Staked<T>>::insert((&staker_id, block_number), {
-				let mut balance_and_recalc_block = <Staked<T>>::get((&staker_id, block_number));
-				balance_and_recalc_block.0 = balance_and_recalc_block
-					.0
-					.checked_add(&amount)
-					.ok_or(ArithmeticError::Overflow)?;
-				balance_and_recalc_block.1 = recalc_block;
-				balance_and_recalc_block
-			});
+			Ok(())
+		}
 
-			<TotalStaked<T>>::set(
-				<TotalStaked<T>>::get()
-					.checked_add(&amount)
-					.ok_or(ArithmeticError::Overflow)?,
-			);
+		/// Sets where the interest accrued by the stakes of the staker is paid.
+		/// By default, the interest is added to the stakes.
+		///
+		/// # Permissions
+		///
+		/// * Staker
+		///
+		/// # Arguments
+		///
+		/// * `destination`: the new reward destination.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_destination())]
+		pub fn set_reward_destination(
+			staker: OriginFor<T>,
+			destination: RewardDestination<T::AccountId>,
+		) -> DispatchResult {
+			let staker_id = ensure_signed(staker)?;
 
-			StakesPerAccount::<T>::mutate(&staker_id, |stakes| *stakes += 1);
+			if destination.is_compound() {
+				<RewardDestinations<T>>::remove(&staker_id);
+			} else {
+				<RewardDestinations<T>>::insert(&staker_id, destination.clone());
+			}
 
-			Self::deposit_event(Event::Stake(staker_id, amount));
+			Self::deposit_event(Event::RewardDestinationSet(staker_id, destination));
 
 			Ok(())
 		}
@@ -544,144 +757,52 @@ pub mod pallet {
 		/// will continue the recalculation, from those stakers for whom this
 		/// was not perform in last call.
 		///
+		/// The recalculation is also performed automatically in the `on_idle` hook,
+		/// so the extrinsic may be used to speed it up. If called not by the admin,
+		/// the caller receives the part of [`Config::PayoutTip`] from the treasury,
+		/// proportional to the part of the recalculated stakers in the maximum batch,
+		/// provided that at least one stake was recalculated.
+		///
 		/// # Permissions
 		///
-		/// * Pallet admin
+		/// * Any signed account
 		///
 		/// # Arguments
 		///
 		/// * `stakers_number`: the number of stakers for which recalculation will be performed
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::payout_stakers(stakers_number.unwrap_or(DEFAULT_NUMBER_PAYOUTS) as u32))]
-		pub fn payout_stakers(admin: OriginFor<T>, stakers_number: Option<u8>) -> DispatchResult {
-			let admin_id = ensure_signed(admin)?;
-
-			ensure!(
-				admin_id == Admin::<T>::get().ok_or(Error::<T>::AdminNotSet)?,
-				Error::<T>::NoPermission
-			);
+		pub fn payout_stakers(origin: OriginFor<T>, stakers_number: Option<u8>) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
 			let config = <PalletConfiguration<T>>::get();
 
-			let mut stakers_number = stakers_number.unwrap_or(DEFAULT_NUMBER_PAYOUTS);
+			let stakers_number = stakers_number.unwrap_or(DEFAULT_NUMBER_PAYOUTS);
 
 			ensure!(
 				stakers_number <= config.max_stakers_per_calculation && stakers_number != 0,
 				Error::<T>::NoPermission
 			);
 
-			// calculate the number of the current recalculation block,
-			// this is necessary in order to understand which stakers we should calculate interest
-			let current_recalc_block = Self::get_current_recalc_block(
-				T::RelayBlockNumberProvider::current_block_number(),
-				&config,
-			);
-
-			// calculate the number of the next recalculation block,
-			// this value is set for the stakers to whom the recalculation will be performed
-			let next_recalc_block = current_recalc_block + config.recalculation_interval;
-
-			let storage_iterator =
-				Self::get_next_calculated_key().map_or(Staked::<T>::iter(), Staked::<T>::iter_from);
-
-			PreviousCalculatedRecord::<T>::set(None);
-
-			{
-				// Address handled in the last payout loop iteration (below)
-				let last_id = RefCell::new(None);
-				// Block number (as a part of the key) for which calculation was performed in the last payout loop iteration
-				let mut last_staked_calculated_block = Default::default();
-				// Reward balance for the address in the iteration
-				let income_acc = RefCell::new(BalanceOf::<T>::default());
-				// Staked balance for the address in the iteration (before stake is recalculated)
-				let amount_acc = RefCell::new(BalanceOf::<T>::default());
-
-				// This closure is used to finalize handling single staker address in each of the two conditions: (1) when we break out of the payout
-				// loop because we reached the number of stakes for rewarding, (2) When all stakes by the single address are handled and the payout
-				// loop switches to handling the next staker address:
-				//   1. Transfer full reward amount to the payee
-				//   2. Lock the reward in staking lock
-				//   3. Update TotalStaked amount
-				//   4. Issue StakingRecalculation event
-				let flush_stake = || -> DispatchResult {
-					if let Some(last_id) = &*last_id.borrow() {
-						if !income_acc.borrow().is_zero() {
-							// TO-DO: When moving to ED>0, reconsider the value of preservation
-							<<T as Config>::Currency as Mutate<T::AccountId>>::transfer(
-								&T::TreasuryAccountId::get(),
-								last_id,
-								*income_acc.borrow(),
-								frame_support::traits::tokens::Preservation::Protect,
-							)?;
-
-							Self::add_freeze_balance(last_id, *income_acc.borrow())?;
-							<TotalStaked<T>>::try_mutate(|staked| -> DispatchResult {
-								*staked = staked
-									.checked_add(&*income_acc.borrow())
-									.ok_or(ArithmeticError::Overflow)?;
-								Ok(())
-							})?;
-
-							Self::deposit_event(Event::StakingRecalculation(
-								last_id.clone(),
-								*amount_acc.borrow(),
-								*income_acc.borrow(),
-							));
-						}
-
-						*income_acc.borrow_mut() = BalanceOf::<T>::default();
-						*amount_acc.borrow_mut() = BalanceOf::<T>::default();
-					}
-					Ok(())
-				};
-
-				// Reward payment loop. Should loop for no more than config.max_stakers_per_calculation
-				// iterations in one extrinsic call
-				//
-				// stakers_number - keeps the remaining number of iterations (staker addresses to handle)
-				// next_recalc_block_for_stake - is taken from the state and stores the starting relay block from which reward should be paid out
-				// income_acc - stores the reward amount to pay to the staker address (accumulates over all address stake records)
-				for ((current_id, staked_block), (amount, next_recalc_block_for_stake)) in
-					storage_iterator
-				{
-					// last_id is not equal current_id when we switch to handling a new staker address
-					// or just start handling the very first address. In the latter case last_id will be None and
-					// flush_stake will do nothing
-					if last_id.borrow().as_ref() != Some(&current_id) {
-						if stakers_number > 0 {
-							flush_stake()?;
-							*last_id.borrow_mut() = Some(current_id.clone());
-							stakers_number -= 1;
-						}
-						// Break out if we reached the address limit
-						else {
-							if let Some(staker) = &*last_id.borrow() {
-								// Save the last calculated record to pick up in the next extrinsic call
-								PreviousCalculatedRecord::<T>::set(Some((
-									staker.clone(),
-									last_staked_calculated_block,
-								)));
-							}
-							break;
-						};
-					};
-
-					// Increase accumulated reward for current address and update current staking record, i.e. (address, staked_block) -> amount
-					if current_recalc_block >= next_recalc_block_for_stake {
-						*amount_acc.borrow_mut() += amount;
-						Self::recalculate_and_insert_stake(
-							&current_id,
-							staked_block,
-							next_recalc_block,
-							amount,
-							((current_recalc_block - next_recalc_block_for_stake)
-								/ config.recalculation_interval)
-								.into() + 1,
-							&mut *income_acc.borrow_mut(),
-						);
-					}
-					last_staked_calculated_block = staked_block;
+			let recalculated = Self::payout_stakers_batch(stakers_number)?;
+
+			if Admin::<T>::get().as_ref() != Some(&caller) {
+				ensure!(recalculated != 0, Error::<T>::NothingToPayout);
+
+				// Splitting the batches doesn't increase the total tip
+				let tip = Perbill::from_rational(
+					recalculated,
+					u32::from(config.max_stakers_per_calculation),
+				)
+				.mul_floor(T::PayoutTip::get());
+				if !tip.is_zero() {
+					<<T as Config>::Currency as Mutate<T::AccountId>>::transfer(
+						&T::TreasuryAccountId::get(),
+						&caller,
+						tip,
+						Preservation::Protect,
+					)?;
+					Self::deposit_event(Event::PayoutTipPaid(caller, tip));
 				}
-				flush_stake()?;
 			}
 
 			Ok(())
@@ -731,6 +852,86 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Delegates a part of the stake to the collection/contract sponsored by the pallet.
+		/// The total amount delegated by the staker can't exceed the staked amount.
+		///
+		/// # Arguments
+		///
+		/// * `target`: address of the collection/contract.
+		/// * `amount`: delegated amount.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::delegate_stake())]
+		pub fn delegate_stake(
+			staker: OriginFor<T>,
+			target: H160,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let staker_id = ensure_signed(staker)?;
+
+			if amount.is_zero() {
+				return Ok(());
+			}
+
+			ensure!(
+				Self::target_sponsor(target)?.ok_or(<Error<T>>::SponsorNotSet)?
+					== Self::account_id(),
+				<Error<T>>::NoPermission
+			);
+
+			let mut delegations = <Delegations<T>>::get(&staker_id);
+			let delegated = delegations
+				.iter()
+				.try_fold(amount, |acc, (_, amount)| acc.checked_add(amount))
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				delegated <= Self::total_staked_by_id(&staker_id).unwrap_or_default(),
+				<Error<T>>::InsufficientStakedBalance
+			);
+
+			match delegations.iter_mut().find(|(t, _)| *t == target) {
+				Some((_, delegated_to_target)) => {
+					*delegated_to_target = delegated_to_target
+						.checked_add(&amount)
+						.ok_or(ArithmeticError::Overflow)?
+				}
+				None => delegations
+					.try_push((target, amount))
+					.map_err(|_| <Error<T>>::TooManyDelegations)?,
+			}
+			let target_delegated = <DelegatedStake<T>>::get(target)
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			let total_delegated = <TotalDelegated<T>>::get()
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			<Delegations<T>>::insert(&staker_id, delegations);
+			<DelegatedStake<T>>::insert(target, target_delegated);
+			<TotalDelegated<T>>::put(total_delegated);
+
+			Self::deposit_event(Event::StakeDelegated(staker_id, target, amount));
+
+			Ok(())
+		}
+
+		/// Withdraws a part of the stake delegated to the collection/contract.
+		///
+		/// # Arguments
+		///
+		/// * `target`: address of the collection/contract.
+		/// * `amount`: withdrawn amount.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::undelegate_stake())]
+		pub fn undelegate_stake(
+			staker: OriginFor<T>,
+			target: H160,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let staker_id = ensure_signed(staker)?;
+
+			Self::undelegate_internal(&staker_id, target, amount)
+		}
 	}
 }
 
@@ -765,11 +966,17 @@ impl<T: Config> Pallet<T> {
 		// checks that we can do unstake in the block
 		ensure!(!pendings.is_full(), Error::<T>::PendingForBlockOverflow);
 
-		let mut stakes = Staked::<T>::iter_prefix((&staker_id,)).collect::<Vec<_>>();
+		let now = T::RelayBlockNumberProvider::current_block_number();
+		let mut stakes = Staked::<T>::iter_prefix((&staker_id,))
+			.map(|(block, (balance, _))| {
+				let lock = <StakeLocks<T>>::get((&staker_id, block)).filter(|l| l.is_locked(&now));
+				(block, balance, lock)
+			})
+			.collect::<Vec<_>>();
 
 		let total_staked = stakes
 			.iter()
-			.fold(<BalanceOf<T>>::default(), |acc, (_, (balance, _))| {
+			.fold(<BalanceOf<T>>::default(), |acc, (_, balance, _)| {
 				acc + *balance
 			});
 
@@ -784,31 +991,35 @@ impl<T: Config> Pallet<T> {
 				.ok_or(ArithmeticError::Underflow)?,
 		);
 
-		stakes.sort_by_key(|(block, _)| *block);
+		// The stakes which are not locked are unstaked first, to avoid the penalties
+		stakes.sort_by_key(|(block, _, lock)| (lock.is_some(), *block));
 
 		let mut acc_amount = unstaked_balance;
 		let mut will_deleted_stakes_count = 0u8;
+		let mut penalty = <BalanceOf<T>>::default();
 
 		let changed_stakes = stakes
 			.into_iter()
-			.map_while(|(block, (balance_per_block, _))| {
+			.map_while(|(block, balance_per_block, lock)| {
 				if acc_amount == <BalanceOf<T>>::default() {
 					return None;
 				}
-				if acc_amount < balance_per_block {
-					let res = (block, balance_per_block - acc_amount);
-					acc_amount = <BalanceOf<T>>::default();
-					Some(res)
-				} else {
-					acc_amount -= balance_per_block;
+				let unstaked = acc_amount.min(balance_per_block);
+				acc_amount -= unstaked;
+				if let Some(lock) = lock {
+					penalty += lock.early_unstake_penalty.mul_floor(unstaked);
+				}
+				if unstaked == balance_per_block {
 					will_deleted_stakes_count += 1;
-					Some((block, <BalanceOf<T>>::default()))
 				}
+				Some((block, balance_per_block - unstaked))
 			})
 			.collect::<Vec<_>>();
 
+		Self::charge_early_unstake_penalty(&staker_id, penalty)?;
+
 		pendings
-			.try_push((staker_id.clone(), unstaked_balance))
+			.try_push((staker_id.clone(), unstaked_balance - penalty))
 			.map_err(|_| Error::<T>::PendingForBlockOverflow)?;
 
 		StakesPerAccount::<T>::try_mutate(&staker_id, |stakes| -> DispatchResult {
@@ -823,6 +1034,7 @@ impl<T: Config> Pallet<T> {
 			.for_each(|(staked_block, current_stake_state)| {
 				if current_stake_state == Default::default() {
 					<Staked<T>>::remove((&staker_id, staked_block));
+					<StakeLocks<T>>::remove((&staker_id, staked_block));
 				} else {
 					<Staked<T>>::mutate((&staker_id, staked_block), |(old_stake_state, _)| {
 						*old_stake_state = current_stake_state
@@ -832,11 +1044,349 @@ impl<T: Config> Pallet<T> {
 
 		<PendingUnstake<T>>::insert(unpending_block, pendings);
 
+		Self::trim_delegations(&staker_id, total_staked - unstaked_balance)?;
+
 		Self::deposit_event(Event::Unstake(staker_id, unstaked_balance));
 
 		Ok(())
 	}
 
+	/// Stakes the amount of native tokens, optionally locked for the period of the tier.
+	///
+	/// - `staker`: staker account.
+	/// - `amount`: amount of staked funds.
+	/// - `lock`: index and terms of the lock tier.
+	fn stake_internal(
+		staker_id: T::AccountId,
+		amount: BalanceOf<T>,
+		lock: Option<(u8, LockTier<BlockNumberFor<T>>)>,
+	) -> DispatchResult
+	where
+		BlockNumberFor<T>: From<u32>,
+	{
+		ensure!(
+			StakesPerAccount::<T>::get(&staker_id) < 10,
+			Error::<T>::NoPermission
+		);
+
+		ensure!(
+			amount >= <BalanceOf<T>>::from(100u128) * T::Nominal::get(),
+			ArithmeticError::Underflow
+		);
+		let config = <PalletConfiguration<T>>::get();
+
+		let balance = <<T as Config>::Currency as Inspect<T::AccountId>>::balance(&staker_id);
+
+		// checks that we can freeze `amount` on the `staker` account.
+		ensure!(
+			amount
+				<= match Self::get_frozen_balance(&staker_id) {
+					Some(frozen_by_pallet) => balance
+						.checked_sub(&frozen_by_pallet)
+						.ok_or(ArithmeticError::Underflow)?,
+					None => balance,
+				},
+			ArithmeticError::Underflow
+		);
+
+		Self::add_freeze_balance(&staker_id, amount)?;
+
+		let block_number = T::RelayBlockNumberProvider::current_block_number();
+
+		// Stakes made in the same relay block are merged, so they must have the same lock
+		if <Staked<T>>::contains_key((&staker_id, block_number)) {
+			ensure!(
+				<StakeLocks<T>>::get((&staker_id, block_number)).map(|l| l.tier)
+					== lock.map(|(tier, _)| tier),
+				<Error<T>>::StakeLockConflict
+			);
+		}
+
+		// Calculation of the number of recalculation periods,
+		// after how much the first interest calculation should be performed for the stake
+		let recalculate_after_interval: BlockNumberFor<T> =
+			if block_number % config.recalculation_interval == 0u32.into() {
+				1u32.into()
+			} else {
+				2u32.into()
+			};
+
+		// Сalculation of the number of the relay block
+		// in which it is necessary to accrue remuneration for the stake.
+		let recalc_block = (block_number / config.recalculation_interval
+			+ recalculate_after_interval)
+			* config.recalculation_interval;
+
+		<Staked<T>>::insert((&staker_id, block_number), {
+			let mut balance_and_recalc_block = <Staked<T>>::get((&staker_id, block_number));
+			balance_and_recalc_block.0 = balance_and_recalc_block
+				.0
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			balance_and_recalc_block.1 = recalc_block;
+			balance_and_recalc_block
+		});
+
+		<TotalStaked<T>>::set(
+			<TotalStaked<T>>::get()
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?,
+		);
+
+		StakesPerAccount::<T>::mutate(&staker_id, |stakes| *stakes += 1);
+
+		match lock {
+			Some((tier, lock_tier)) => {
+				let unlock_at = block_number.saturating_add(lock_tier.lock_period);
+				<StakeLocks<T>>::insert(
+					(&staker_id, block_number),
+					StakeLock {
+						tier,
+						reward_multiplier: lock_tier.reward_multiplier,
+						early_unstake_penalty: lock_tier.early_unstake_penalty,
+						unlock_at,
+					},
+				);
+				Self::deposit_event(Event::StakeLocked(staker_id, amount, tier, unlock_at));
+			}
+			None => Self::deposit_event(Event::Stake(staker_id, amount)),
+		}
+
+		Ok(())
+	}
+
+	/// Unfreezes the penalty for unstaking the locked stakes before the locks expire,
+	/// and transfers it to the treasury.
+	///
+	/// - `staker`: staker account.
+	/// - `penalty`: amount of the penalty.
+	fn charge_early_unstake_penalty(
+		staker_id: &T::AccountId,
+		penalty: BalanceOf<T>,
+	) -> DispatchResult {
+		if penalty.is_zero() {
+			return Ok(());
+		}
+
+		let frozen = Self::get_frozen_balance(staker_id).unwrap_or_default();
+		Self::set_freeze_with_result(
+			staker_id,
+			frozen
+				.checked_sub(&penalty)
+				.ok_or(ArithmeticError::Underflow)?,
+		)?;
+		<<T as Config>::Currency as Mutate<T::AccountId>>::transfer(
+			staker_id,
+			&T::TreasuryAccountId::get(),
+			penalty,
+			Preservation::Expendable,
+		)?;
+
+		Self::deposit_event(Event::EarlyUnstakePenalty(staker_id.clone(), penalty));
+
+		Ok(())
+	}
+
+	/// Withdraws the stake delegated by the staker to the collection/contract.
+	///
+	/// - `staker`: staker account.
+	/// - `target`: address of the collection/contract.
+	/// - `amount`: withdrawn amount.
+	fn undelegate_internal(
+		staker_id: &T::AccountId,
+		target: H160,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		<Delegations<T>>::try_mutate_exists(staker_id, |delegations| -> DispatchResult {
+			let list = delegations
+				.as_mut()
+				.ok_or(<Error<T>>::InsufficientDelegatedStake)?;
+			let index = list
+				.iter()
+				.position(|(t, _)| *t == target)
+				.ok_or(<Error<T>>::InsufficientDelegatedStake)?;
+			let delegated = &mut list[index].1;
+			*delegated = delegated
+				.checked_sub(&amount)
+				.ok_or(<Error<T>>::InsufficientDelegatedStake)?;
+			if delegated.is_zero() {
+				list.remove(index);
+			}
+			if list.is_empty() {
+				*delegations = None;
+			}
+			Ok(())
+		})?;
+		<DelegatedStake<T>>::mutate_exists(target, |delegated| {
+			*delegated = (*delegated)
+				.and_then(|d| d.checked_sub(&amount))
+				.filter(|d| !d.is_zero());
+		});
+		<TotalDelegated<T>>::mutate(|delegated| {
+			*delegated = delegated.checked_sub(&amount).unwrap_or_default()
+		});
+
+		Self::deposit_event(Event::StakeUndelegated(staker_id.clone(), target, amount));
+
+		Ok(())
+	}
+
+	/// Proportionally reduces the stake delegated by the staker,
+	/// so that it doesn't exceed the remaining staked amount.
+	///
+	/// - `staker`: staker account.
+	/// - `staked`: amount remaining staked.
+	fn trim_delegations(staker_id: &T::AccountId, staked: BalanceOf<T>) -> DispatchResult {
+		let delegations = <Delegations<T>>::get(staker_id);
+		let delegated = delegations
+			.iter()
+			.fold(<BalanceOf<T>>::default(), |acc, (_, amount)| acc + *amount);
+		if delegated <= staked {
+			return Ok(());
+		}
+
+		let kept = Perbill::from_rational(staked, delegated);
+		delegations.into_iter().try_for_each(|(target, amount)| {
+			Self::undelegate_internal(staker_id, target, amount - kept.mul_floor(amount))
+		})
+	}
+
+	/// Returns the sponsor of the collection/contract.
+	///
+	/// - `target`: address of the collection/contract.
+	fn target_sponsor(target: H160) -> Result<Option<T::AccountId>, DispatchError> {
+		match map_eth_to_id(&target) {
+			Some(collection_id) => T::CollectionHandler::sponsor(collection_id),
+			None => {
+				Ok(T::ContractHandler::sponsor(target)?.map(|sponsor| sponsor.as_sub().clone()))
+			}
+		}
+	}
+
+	/// Returns the part of the pallet balance, which may be spent within a sponsoring
+	/// statistics era on the calls of the collection/contract.
+	///
+	/// The stake delegated to the collection/contract is counted only towards its share,
+	/// while the stake that is not delegated is counted towards the shares of all of them.
+	///
+	/// - `target`: address of the collection/contract.
+	pub fn sponsoring_allowance(target: H160) -> BalanceOf<T> {
+		let budget = <<T as Config>::Currency as Inspect<T::AccountId>>::reducible_balance(
+			&Self::account_id(),
+			Preservation::Protect,
+			Fortitude::Polite,
+		);
+		let total_staked = <TotalStaked<T>>::get();
+		if total_staked.is_zero() {
+			return budget;
+		}
+
+		let undelegated = total_staked.saturating_sub(<TotalDelegated<T>>::get());
+		let share = <DelegatedStake<T>>::get(target)
+			.saturating_add(undelegated)
+			.min(total_staked);
+		Perbill::from_rational(share, total_staked).mul_floor(budget)
+	}
+
+	/// Returns the collections/contracts the stake was delegated to by the staker,
+	/// with the delegated amounts.
+	///
+	/// - `staker`: staker account.
+	pub fn cross_id_delegated_stake(staker: T::CrossAccountId) -> Vec<(H160, BalanceOf<T>)> {
+		<Delegations<T>>::get(staker.as_sub()).into_inner()
+	}
+
+	/// Returns the total stake delegated to the collection/contract.
+	///
+	/// - `target`: address of the collection/contract.
+	pub fn target_delegated_stake(target: H160) -> BalanceOf<T> {
+		<DelegatedStake<T>>::get(target)
+	}
+
+	/// Returns the interest paid to the staker for the last recalculation periods,
+	/// with the relay blocks of the recalculations.
+	///
+	/// - `staker`: staker account.
+	pub fn cross_id_payout_history(
+		staker: T::CrossAccountId,
+	) -> Vec<(BlockNumberFor<T>, BalanceOf<T>)> {
+		<PayoutHistory<T>>::get(staker.as_sub()).into_inner()
+	}
+
+	/// Returns the relay block of the next recalculation and the interest the staker
+	/// will receive in it at the current rate, including the missed recalculations.
+	///
+	/// - `staker`: staker account.
+	pub fn cross_id_projected_payout(
+		staker: T::CrossAccountId,
+	) -> (BlockNumberFor<T>, BalanceOf<T>) {
+		let config = <PalletConfiguration<T>>::get();
+		let next_recalc_block = Self::get_current_recalc_block(
+			T::RelayBlockNumberProvider::current_block_number(),
+			&config,
+		) + config.recalculation_interval;
+		let compound = <RewardDestinations<T>>::get(staker.as_sub()).is_compound();
+
+		let payout = Staked::<T>::iter_prefix((staker.as_sub(),))
+			.filter(|(_, (_, next_recalc_block_for_stake))| {
+				next_recalc_block >= *next_recalc_block_for_stake
+			})
+			.map(|(staked_block, (amount, next_recalc_block_for_stake))| {
+				Self::stake_income(
+					staker.as_sub(),
+					staked_block,
+					amount,
+					next_recalc_block_for_stake,
+					((next_recalc_block - next_recalc_block_for_stake)
+						/ config.recalculation_interval)
+						.saturated_into::<u32>()
+						+ 1,
+					compound,
+					&config,
+				)
+			})
+			.fold(BalanceOf::<T>::zero(), |acc, income| {
+				acc.saturating_add(income)
+			});
+
+		(next_recalc_block, payout)
+	}
+
+	/// Returns where the interest accrued by the stakes of the staker is paid.
+	///
+	/// - `staker`: staker account.
+	pub fn cross_id_reward_destination(
+		staker: T::CrossAccountId,
+	) -> RewardDestination<T::AccountId> {
+		<RewardDestinations<T>>::get(staker.as_sub())
+	}
+
+	/// Returns the treasury balance the interest is paid from, the interest accrued by all stakes
+	/// in one recalculation interval at the current rate, and the number of relay blocks
+	/// the treasury balance lasts at this rate. The number of blocks is `None` if nothing is staked.
+	pub fn reward_pot_runway() -> (BalanceOf<T>, BalanceOf<T>, Option<BlockNumberFor<T>>) {
+		let config = <PalletConfiguration<T>>::get();
+		let pot = <<T as Config>::Currency as Inspect<T::AccountId>>::reducible_balance(
+			&T::TreasuryAccountId::get(),
+			Preservation::Protect,
+			Fortitude::Polite,
+		);
+		let interval_payout = config.interval_income * <TotalStaked<T>>::get();
+
+		let runway = (!interval_payout.is_zero()).then(|| {
+			let intervals: u128 = (pot / interval_payout).saturated_into();
+			intervals
+				.saturated_into::<BlockNumberFor<T>>()
+				.saturating_mul(config.recalculation_interval)
+		});
+
+		(pot, interval_payout, runway)
+	}
+
 	/// Adds the balance to frozen by the pallet.
 	///
 	/// - `staker`: staker account.
@@ -908,16 +1458,25 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Returns all relay block numbers when stake was made,
-	/// the amount of the stake.
+	/// the amount of the stake, and the lock tier with the unlock relay block for the locked stakes.
 	///
 	/// - `staker`: staker account.
 	pub fn total_staked_by_id_per_block(
-		staker: impl EncodeLike<T::AccountId>,
-	) -> Option<Vec<(BlockNumberFor<T>, BalanceOf<T>)>> {
+		staker: &T::AccountId,
+	) -> Option<
+		Vec<(
+			BlockNumberFor<T>,
+			BalanceOf<T>,
+			Option<(u8, BlockNumberFor<T>)>,
+		)>,
+	> {
 		let mut staked = Staked::<T>::iter_prefix((staker,))
-			.map(|(block, (amount, _))| (block, amount))
+			.map(|(block, (amount, _))| {
+				let lock = <StakeLocks<T>>::get((staker, block)).map(|l| (l.tier, l.unlock_at));
+				(block, amount, lock)
+			})
 			.collect::<Vec<_>>();
-		staked.sort_by_key(|(block, _)| *block);
+		staked.sort_by_key(|(block, _, _)| *block);
 		if !staked.is_empty() {
 			Some(staked)
 		} else {
@@ -935,43 +1494,318 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Returns all relay block numbers when stake was made,
-	/// the amount of the stake.
+	/// the amount of the stake, and the lock tier with the unlock relay block for the locked stakes.
 	///
 	/// - `staker`: staker account.
 	pub fn cross_id_total_staked_per_block(
 		staker: T::CrossAccountId,
-	) -> Vec<(BlockNumberFor<T>, BalanceOf<T>)> {
+	) -> Vec<(
+		BlockNumberFor<T>,
+		BalanceOf<T>,
+		Option<(u8, BlockNumberFor<T>)>,
+	)> {
 		Self::total_staked_by_id_per_block(staker.as_sub()).unwrap_or_default()
 	}
 
+	/// Accrues the interest for the `iters` recalculation intervals,
+	/// the first of which ends at the `first_recalc_block`.
+	/// The interest for the intervals started while the stake is locked is multiplied
+	/// by the multiplier of its lock tier. If `compound`, the interest is added to the stake.
+	#[allow(clippy::too_many_arguments)]
 	fn recalculate_and_insert_stake(
 		staker: &T::AccountId,
 		staked_block: BlockNumberFor<T>,
 		next_recalc_block: BlockNumberFor<T>,
 		base: BalanceOf<T>,
+		first_recalc_block: BlockNumberFor<T>,
 		iters: u32,
+		compound: bool,
 		income_acc: &mut BalanceOf<T>,
 	) {
-		let income = Self::calculate_income(base, iters);
+		let config = <PalletConfiguration<T>>::get();
+		let income = Self::stake_income(
+			staker,
+			staked_block,
+			base,
+			first_recalc_block,
+			iters,
+			compound,
+			&config,
+		);
 
 		if let Some(res) = base.checked_add(&income) {
-			<Staked<T>>::insert((staker, staked_block), (res, next_recalc_block));
+			let stake = if compound { res } else { base };
+			<Staked<T>>::insert((staker, staked_block), (stake, next_recalc_block));
 			*income_acc += income;
 		};
 	}
 
-	fn calculate_income<I>(base: I, iters: u32) -> I
+	/// Returns the interest accrued by the stake for the `iters` recalculation intervals,
+	/// the first of which ends at the `first_recalc_block`.
+	fn stake_income(
+		staker: &T::AccountId,
+		staked_block: BlockNumberFor<T>,
+		base: BalanceOf<T>,
+		first_recalc_block: BlockNumberFor<T>,
+		iters: u32,
+		compound: bool,
+		config: &PalletConfiguration<T>,
+	) -> BalanceOf<T> {
+		let lock = <StakeLocks<T>>::get((staker, staked_block));
+		let mut interval_start = first_recalc_block.saturating_sub(config.recalculation_interval);
+		Self::calculate_income(base, iters, compound, || {
+			let interval_income = match &lock {
+				Some(lock) if lock.is_locked(&interval_start) => {
+					lock.boosted_income(config.interval_income)
+				}
+				_ => config.interval_income,
+			};
+			interval_start += config.recalculation_interval;
+			interval_income
+		})
+	}
+
+	/// Adds the interest paid to the staker in the recalculation to its [`PayoutHistory`],
+	/// dropping the oldest records.
+	fn record_payout(staker: &T::AccountId, recalc_block: BlockNumberFor<T>, income: BalanceOf<T>) {
+		<PayoutHistory<T>>::mutate(staker, |history| {
+			let mut records = core::mem::take(history).into_inner();
+			match records.last_mut() {
+				Some((block, paid)) if *block == recalc_block => {
+					*paid = paid.saturating_add(income)
+				}
+				_ => records.push((recalc_block, income)),
+			}
+			let excess = records.len().saturating_sub(MAX_PAYOUT_HISTORY as usize);
+			*history = BoundedVec::truncate_from(records.split_off(excess));
+		});
+	}
+
+	/// Returns the interest accrued on the `base` for the `iters` intervals.
+	/// If `compound`, the interest of each interval accrues the interest in the next ones.
+	fn calculate_income<I>(
+		base: I,
+		iters: u32,
+		compound: bool,
+		mut interval_income: impl FnMut() -> Perbill,
+	) -> I
 	where
 		I: EncodeLike<BalanceOf<T>> + Balance,
 	{
-		let config = <PalletConfiguration<T>>::get();
 		let mut income = base;
 
-		(0..iters).for_each(|_| income += config.interval_income * income);
+		(0..iters).for_each(|_| income += interval_income() * if compound { income } else { base });
 
 		income - base
 	}
 
+	/// Recalculates interest for the `stakers_number` stakers, starting from
+	/// the [`PreviousCalculatedRecord`]. Returns the number of the stakers,
+	/// for which at least one stake was recalculated.
+	fn payout_stakers_batch(mut stakers_number: u8) -> Result<u32, DispatchError> {
+		let config = <PalletConfiguration<T>>::get();
+
+		// calculate the number of the current recalculation block,
+		// this is necessary in order to understand which stakers we should calculate interest
+		let current_recalc_block = Self::get_current_recalc_block(
+			T::RelayBlockNumberProvider::current_block_number(),
+			&config,
+		);
+
+		// calculate the number of the next recalculation block,
+		// this value is set for the stakers to whom the recalculation will be performed
+		let next_recalc_block = current_recalc_block + config.recalculation_interval;
+
+		let next_key = Self::get_next_calculated_key();
+		if next_key.is_none() {
+			PayoutPassStartedAt::<T>::set(Some(current_recalc_block));
+		}
+
+		let storage_iterator = next_key.map_or(Staked::<T>::iter(), Staked::<T>::iter_from);
+
+		// Number of the stakers recalculated in this batch
+		let mut recalculated = 0;
+
+		PreviousCalculatedRecord::<T>::set(None);
+
+		{
+			// Address handled in the last payout loop iteration (below)
+			let last_id = RefCell::new(None);
+			// Block number (as a part of the key) for which calculation was performed in the last payout loop iteration
+			let mut last_staked_calculated_block = Default::default();
+			// Reward balance for the address in the iteration
+			let income_acc = RefCell::new(BalanceOf::<T>::default());
+			// Staked balance for the address in the iteration (before stake is recalculated)
+			let amount_acc = RefCell::new(BalanceOf::<T>::default());
+			// Reward destination of the address in the iteration
+			let destination = RefCell::new(RewardDestination::<T::AccountId>::default());
+
+			// This closure is used to finalize handling single staker address in each of the two conditions: (1) when we break out of the payout
+			// loop because we reached the number of stakes for rewarding, (2) When all stakes by the single address are handled and the payout
+			// loop switches to handling the next staker address:
+			//   1. Transfer full reward amount to the payee (the staker itself, unless the reward destination is another account)
+			//   2. Lock the reward in staking lock, if the reward destination is compounding
+			//   3. Update TotalStaked amount, if the reward destination is compounding
+			//   4. Issue StakingRecalculation event
+			let flush_stake = || -> DispatchResult {
+				if let Some(last_id) = &*last_id.borrow() {
+					if !income_acc.borrow().is_zero() {
+						let payee = match &*destination.borrow() {
+							RewardDestination::Account(payee) => payee.clone(),
+							_ => last_id.clone(),
+						};
+
+						// TO-DO: When moving to ED>0, reconsider the value of preservation
+						<<T as Config>::Currency as Mutate<T::AccountId>>::transfer(
+							&T::TreasuryAccountId::get(),
+							&payee,
+							*income_acc.borrow(),
+							frame_support::traits::tokens::Preservation::Protect,
+						)?;
+
+						if destination.borrow().is_compound() {
+							Self::add_freeze_balance(last_id, *income_acc.borrow())?;
+							<TotalStaked<T>>::try_mutate(|staked| -> DispatchResult {
+								*staked = staked
+									.checked_add(&*income_acc.borrow())
+									.ok_or(ArithmeticError::Overflow)?;
+								Ok(())
+							})?;
+						}
+
+						Self::record_payout(last_id, current_recalc_block, *income_acc.borrow());
+
+						Self::deposit_event(Event::StakingRecalculation(
+							last_id.clone(),
+							*amount_acc.borrow(),
+							*income_acc.borrow(),
+						));
+					}
+
+					*income_acc.borrow_mut() = BalanceOf::<T>::default();
+					*amount_acc.borrow_mut() = BalanceOf::<T>::default();
+				}
+				Ok(())
+			};
+
+			// Reward payment loop. Should loop for no more than config.max_stakers_per_calculation
+			// iterations in one extrinsic call
+			//
+			// stakers_number - keeps the remaining number of iterations (staker addresses to handle)
+			// next_recalc_block_for_stake - is taken from the state and stores the starting relay block from which reward should be paid out
+			// income_acc - stores the reward amount to pay to the staker address (accumulates over all address stake records)
+			for ((current_id, staked_block), (amount, next_recalc_block_for_stake)) in
+				storage_iterator
+			{
+				// last_id is not equal current_id when we switch to handling a new staker address
+				// or just start handling the very first address. In the latter case last_id will be None and
+				// flush_stake will do nothing
+				if last_id.borrow().as_ref() != Some(&current_id) {
+					if stakers_number > 0 {
+						flush_stake()?;
+						*destination.borrow_mut() = <RewardDestinations<T>>::get(&current_id);
+						*last_id.borrow_mut() = Some(current_id.clone());
+						stakers_number -= 1;
+					}
+					// Break out if we reached the address limit
+					else {
+						if let Some(staker) = &*last_id.borrow() {
+							// Save the last calculated record to pick up in the next extrinsic call
+							PreviousCalculatedRecord::<T>::set(Some((
+								staker.clone(),
+								last_staked_calculated_block,
+							)));
+						}
+						break;
+					};
+				};
+
+				// Increase accumulated reward for current address and update current staking record, i.e. (address, staked_block) -> amount
+				if current_recalc_block >= next_recalc_block_for_stake {
+					// The first recalculated stake of the staker
+					if amount_acc.borrow().is_zero() {
+						recalculated += 1;
+					}
+					*amount_acc.borrow_mut() += amount;
+					Self::recalculate_and_insert_stake(
+						&current_id,
+						staked_block,
+						next_recalc_block,
+						amount,
+						next_recalc_block_for_stake,
+						((current_recalc_block - next_recalc_block_for_stake)
+							/ config.recalculation_interval)
+							.saturated_into::<u32>()
+							+ 1,
+						destination.borrow().is_compound(),
+						&mut *income_acc.borrow_mut(),
+					);
+				}
+				last_staked_calculated_block = staked_block;
+			}
+			flush_stake()?;
+		}
+
+		// The pass over all stakers, started in the current recalculation interval, is over
+		if PreviousCalculatedRecord::<T>::get().is_none()
+			&& PayoutPassStartedAt::<T>::get() == Some(current_recalc_block)
+		{
+			PayoutCompletedFor::<T>::set(Some(current_recalc_block));
+		}
+
+		Ok(recalculated)
+	}
+
+	/// Recalculates interest for as many stakers as the idle weight allows,
+	/// but not more than [`AppPromotionIdlePayoutWeightOverride`] per block.
+	fn payout_stakers_on_idle(remaining_weight: Weight) -> Weight {
+		// maintenance mode, weight budget, configuration, relay block number, completed pass
+		let mut consumed = T::DbWeight::get().reads(5);
+		if !consumed.all_lte(remaining_weight) {
+			return Weight::zero();
+		}
+
+		if T::IsMaintenanceModeEnabled::get() {
+			return consumed;
+		}
+
+		let budget = remaining_weight.min(<AppPromotionIdlePayoutWeightOverride<T>>::get());
+		let config = <PalletConfiguration<T>>::get();
+		let current_recalc_block = Self::get_current_recalc_block(
+			T::RelayBlockNumberProvider::current_block_number(),
+			&config,
+		);
+
+		if PayoutCompletedFor::<T>::get() == Some(current_recalc_block) {
+			return consumed;
+		}
+
+		while let Some(stakers_number) = (1..=config.max_stakers_per_calculation).rev().find(|n| {
+			consumed
+				.saturating_add(<T as Config>::WeightInfo::payout_stakers(*n as u32))
+				.all_lte(budget)
+		}) {
+			let result = with_storage_layer(|| Self::payout_stakers_batch(stakers_number));
+			consumed.saturating_accrue(<T as Config>::WeightInfo::payout_stakers(
+				stakers_number as u32,
+			));
+
+			match result {
+				Ok(_) if PreviousCalculatedRecord::<T>::get().is_some() => {}
+				Ok(_) => break,
+				Err(error) => {
+					log::warn!(
+						target: "runtime::app_promotion",
+						"failed to pay out stakers on idle: {error:?}",
+					);
+					break;
+				}
+			}
+		}
+
+		consumed
+	}
+
 	/// Get relay block number rounded down to multiples of config.recalculation_interval.
 	/// We need it to reward stakers in integer parts of recalculation_interval
 	fn get_current_recalc_block(
@@ -1049,16 +1883,27 @@ where
 		// checks that we can do unstake in the block
 		ensure!(!pendings.is_full(), Error::<T>::PendingForBlockOverflow);
 
+		let now = T::RelayBlockNumberProvider::current_block_number();
+		let mut penalty = <BalanceOf<T>>::default();
 		let total_staked: BalanceOf<T> = Staked::<T>::drain_prefix((&staker_id,))
-			.map(|(_, (amount, _))| amount)
+			.map(|(block, (amount, _))| {
+				if let Some(lock) = <StakeLocks<T>>::take((&staker_id, block)) {
+					if lock.is_locked(&now) {
+						penalty += lock.early_unstake_penalty.mul_floor(amount);
+					}
+				}
+				amount
+			})
 			.sum();
 
 		if total_staked.is_zero() {
 			return Ok(());
 		}
 
+		Self::charge_early_unstake_penalty(&staker_id, penalty)?;
+
 		pendings
-			.try_push((staker_id.clone(), total_staked))
+			.try_push((staker_id.clone(), total_staked - penalty))
 			.map_err(|_| Error::<T>::PendingForBlockOverflow)?;
 
 		<PendingUnstake<T>>::insert(block, pendings);
@@ -1070,6 +1915,9 @@ where
 		);
 
 		StakesPerAccount::<T>::remove(&staker_id);
+		<PayoutHistory<T>>::remove(&staker_id);
+
+		Self::trim_delegations(&staker_id, Zero::zero())?;
 
 		Self::deposit_event(Event::Unstake(staker_id, total_staked));
 
@@ -0,0 +1,67 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the App Promotion pallet.
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::traits::CheckedAdd;
+
+use crate::{BalanceOf, Config, Pallet, Staked, TotalStaked};
+
+/// Prepare the existing stakes for the delegation.
+///
+/// The existing stakes are left undelegated, so they keep funding all of the sponsored
+/// collections and contracts. Since the shares of the sponsoring budget are calculated
+/// relative to [`TotalStaked`], it is recalculated from the stake records.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let on_chain_version = <Pallet<T>>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain_version >= 1 {
+		log::info!(
+			target: "runtime::app_promotion",
+			"Stakes are already migrated, skipping",
+		);
+		return weight;
+	}
+
+	let mut stakes = 0u64;
+	let mut total_staked = <BalanceOf<T>>::default();
+	for (_, (amount, _)) in <Staked<T>>::iter() {
+		stakes += 1;
+		total_staked = total_staked.checked_add(&amount).unwrap_or(total_staked);
+	}
+	if total_staked != <TotalStaked<T>>::get() {
+		log::warn!(
+			target: "runtime::app_promotion",
+			"Total staked amount didn't match the stake records, fixed",
+		);
+		<TotalStaked<T>>::put(total_staked);
+	}
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	log::info!(
+		target: "runtime::app_promotion",
+		"Migrated {} stakes for delegation",
+		stakes,
+	);
+
+	weight.saturating_accrue(T::DbWeight::get().reads_writes(stakes + 1, 2));
+	weight
+}
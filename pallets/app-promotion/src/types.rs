@@ -3,10 +3,13 @@ use frame_system::pallet_prelude::*;
 use pallet_common::CollectionHandle;
 use pallet_configuration::AppPromomotionConfigurationOverride;
 use pallet_evm_contract_helpers::{Config as EvmHelpersConfig, Pallet as EvmHelpersPallet};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_core::Get;
-use sp_runtime::{DispatchError, Perbill};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128, PerThing, Perbill};
 use sp_std::borrow::ToOwned;
 use up_data_structs::CollectionId;
+pub use up_data_structs::RewardDestination;
 
 const MAX_NUMBER_PAYOUTS: u8 = 100;
 pub(crate) const DEFAULT_NUMBER_PAYOUTS: u8 = 20;
@@ -140,3 +143,43 @@ impl<T: crate::Config> PalletConfiguration<T> {
 		}
 	}
 }
+
+/// Tier of the stakes locked for a fixed period.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LockTier<BlockNumber> {
+	/// In relay blocks.
+	pub lock_period: BlockNumber,
+	/// Multiplier of the interest accrued for the stake while it is locked.
+	pub reward_multiplier: FixedU128,
+	/// Share of the stake paid to the treasury, if it is unstaked before the lock expires.
+	pub early_unstake_penalty: Perbill,
+}
+
+/// Lock of a stake, with the terms of its tier at the moment of staking.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct StakeLock<BlockNumber> {
+	/// Index of the tier in [`LockTiers`](crate::LockTiers).
+	pub tier: u8,
+	/// Multiplier of the interest accrued for the stake while it is locked.
+	pub reward_multiplier: FixedU128,
+	/// Share of the stake paid to the treasury, if it is unstaked before the lock expires.
+	pub early_unstake_penalty: Perbill,
+	/// Relay block, starting from which the stake is unlocked.
+	pub unlock_at: BlockNumber,
+}
+
+impl<BlockNumber: PartialOrd> StakeLock<BlockNumber> {
+	/// Whether the stake is still locked at the relay block `now`.
+	pub fn is_locked(&self, now: &BlockNumber) -> bool {
+		self.unlock_at > *now
+	}
+
+	/// Interest rate per recalculation interval for the locked stake.
+	pub fn boosted_income(&self, interval_income: Perbill) -> Perbill {
+		Perbill::from_parts(
+			self.reward_multiplier
+				.saturating_mul_int(interval_income.deconstruct())
+				.min(Perbill::ACCURACY),
+		)
+	}
+}
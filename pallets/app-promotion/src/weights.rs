@@ -43,6 +43,11 @@ pub trait WeightInfo {
 	fn stop_sponsoring_collection() -> Weight;
 	fn sponsor_contract() -> Weight;
 	fn stop_sponsoring_contract() -> Weight;
+	fn delegate_stake() -> Weight;
+	fn undelegate_stake() -> Weight;
+	fn set_lock_tiers() -> Weight;
+	fn stake_locked() -> Weight;
+	fn set_reward_destination() -> Weight;
 }
 
 /// Weights for pallet_app_promotion using the Substrate node and recommended hardware.
@@ -100,20 +105,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `AppPromotion::TotalStaked` (r:1 w:1)
 	/// Proof: `AppPromotion::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::PayoutHistory` (r:100 w:100)
+	/// Proof: `AppPromotion::PayoutHistory` (`max_values`: None, `max_size`: Some(1289), added: 3764, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::StakeLocks` (r:1000 w:0)
+	/// Proof: `AppPromotion::StakeLocks` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 100]`.
 	fn payout_stakers(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `564 + b * (641 ±0)`
-		//  Estimated: `3593 + b * (25550 ±0)`
+		//  Estimated: `3593 + b * (55114 ±0)`
 		// Minimum execution time: 126_261_000 picoseconds.
 		Weight::from_parts(127_683_000, 3593)
 			// Standard Error: 28_796
 			.saturating_add(Weight::from_parts(95_359_080, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().reads((13_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads((24_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(T::DbWeight::get().writes((12_u64).saturating_mul(b.into())))
-			.saturating_add(Weight::from_parts(0, 25550).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().writes((13_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 55114).saturating_mul(b.into()))
 	}
 	/// Storage: `AppPromotion::StakesPerAccount` (r:1 w:1)
 	/// Proof: `AppPromotion::StakesPerAccount` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -150,14 +159,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AppPromotion::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `AppPromotion::StakesPerAccount` (r:0 w:1)
 	/// Proof: `AppPromotion::StakesPerAccount` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AppPromotion::StakeLocks` (r:10 w:10)
+	/// Proof: `AppPromotion::StakeLocks` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::Delegations` (r:1 w:1)
+	/// Proof: `AppPromotion::Delegations` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::DelegatedStake` (r:10 w:10)
+	/// Proof: `AppPromotion::DelegatedStake` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalDelegated` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalDelegated` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::PayoutHistory` (r:0 w:1)
+	/// Proof: `AppPromotion::PayoutHistory` (`max_values`: None, `max_size`: Some(1289), added: 3764, mode: `MaxEncodedLen`)
 	fn unstake_all() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `829`
-		//  Estimated: `29095`
+		//  Estimated: `95304`
 		// Minimum execution time: 57_058_000 picoseconds.
-		Weight::from_parts(58_034_000, 29095)
-			.saturating_add(T::DbWeight::get().reads(14_u64))
-			.saturating_add(T::DbWeight::get().writes(13_u64))
+		Weight::from_parts(58_034_000, 95304)
+			.saturating_add(T::DbWeight::get().reads(41_u64))
+			.saturating_add(T::DbWeight::get().writes(39_u64))
 	}
 	/// Storage: `Configuration::AppPromomotionConfigurationOverride` (r:1 w:0)
 	/// Proof: `Configuration::AppPromomotionConfigurationOverride` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
@@ -169,14 +196,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AppPromotion::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `AppPromotion::StakesPerAccount` (r:1 w:1)
 	/// Proof: `AppPromotion::StakesPerAccount` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AppPromotion::StakeLocks` (r:10 w:10)
+	/// Proof: `AppPromotion::StakeLocks` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::Delegations` (r:1 w:1)
+	/// Proof: `AppPromotion::Delegations` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::DelegatedStake` (r:10 w:10)
+	/// Proof: `AppPromotion::DelegatedStake` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalDelegated` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalDelegated` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn unstake_partial() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `829`
-		//  Estimated: `29095`
+		//  Estimated: `95304`
 		// Minimum execution time: 75_191_000 picoseconds.
-		Weight::from_parts(76_053_000, 29095)
-			.saturating_add(T::DbWeight::get().reads(15_u64))
-			.saturating_add(T::DbWeight::get().writes(13_u64))
+		Weight::from_parts(76_053_000, 95304)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(38_u64))
 	}
 	/// Storage: `AppPromotion::Admin` (r:1 w:0)
 	/// Proof: `AppPromotion::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
@@ -230,6 +273,87 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::Delegations` (r:1 w:1)
+	/// Proof: `AppPromotion::Delegations` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::Staked` (r:11 w:0)
+	/// Proof: `AppPromotion::Staked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::DelegatedStake` (r:1 w:1)
+	/// Proof: `AppPromotion::DelegatedStake` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalDelegated` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalDelegated` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn delegate_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `29095`
+		// Minimum execution time: 54_310_000 picoseconds.
+		Weight::from_parts(55_402_000, 29095)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AppPromotion::Delegations` (r:1 w:1)
+	/// Proof: `AppPromotion::Delegations` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::DelegatedStake` (r:1 w:1)
+	/// Proof: `AppPromotion::DelegatedStake` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalDelegated` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalDelegated` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn undelegate_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3874`
+		// Minimum execution time: 21_930_000 picoseconds.
+		Weight::from_parts(22_517_000, 3874)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AppPromotion::LockTiers` (r:0 w:1)
+	/// Proof: `AppPromotion::LockTiers` (`max_values`: Some(1), `max_size`: Some(297), added: 792, mode: `MaxEncodedLen`)
+	fn set_lock_tiers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_212_000 picoseconds.
+		Weight::from_parts(7_518_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AppPromotion::LockTiers` (r:1 w:0)
+	/// Proof: `AppPromotion::LockTiers` (`max_values`: Some(1), `max_size`: Some(297), added: 792, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::StakesPerAccount` (r:1 w:1)
+	/// Proof: `AppPromotion::StakesPerAccount` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AppPromotion::Staked` (r:1 w:1)
+	/// Proof: `AppPromotion::Staked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalStaked` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::StakeLocks` (r:0 w:1)
+	/// Proof: `AppPromotion::StakeLocks` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	fn stake_locked() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389`
+		//  Estimated: `4764`
+		// Minimum execution time: 27_466_000 picoseconds.
+		Weight::from_parts(28_310_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AppPromotion::RewardDestinations` (r:0 w:1)
+	/// Proof: `AppPromotion::RewardDestinations` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	fn set_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_102_000 picoseconds.
+		Weight::from_parts(8_394_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -286,20 +410,24 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `AppPromotion::TotalStaked` (r:1 w:1)
 	/// Proof: `AppPromotion::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::PayoutHistory` (r:100 w:100)
+	/// Proof: `AppPromotion::PayoutHistory` (`max_values`: None, `max_size`: Some(1289), added: 3764, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::StakeLocks` (r:1000 w:0)
+	/// Proof: `AppPromotion::StakeLocks` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 100]`.
 	fn payout_stakers(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `564 + b * (641 ±0)`
-		//  Estimated: `3593 + b * (25550 ±0)`
+		//  Estimated: `3593 + b * (55114 ±0)`
 		// Minimum execution time: 126_261_000 picoseconds.
 		Weight::from_parts(127_683_000, 3593)
 			// Standard Error: 28_796
 			.saturating_add(Weight::from_parts(95_359_080, 0).saturating_mul(b.into()))
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().reads((13_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().reads((24_u64).saturating_mul(b.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((12_u64).saturating_mul(b.into())))
-			.saturating_add(Weight::from_parts(0, 25550).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().writes((13_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 55114).saturating_mul(b.into()))
 	}
 	/// Storage: `AppPromotion::StakesPerAccount` (r:1 w:1)
 	/// Proof: `AppPromotion::StakesPerAccount` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
@@ -336,14 +464,32 @@ impl WeightInfo for () {
 	/// Proof: `AppPromotion::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `AppPromotion::StakesPerAccount` (r:0 w:1)
 	/// Proof: `AppPromotion::StakesPerAccount` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AppPromotion::StakeLocks` (r:10 w:10)
+	/// Proof: `AppPromotion::StakeLocks` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::Delegations` (r:1 w:1)
+	/// Proof: `AppPromotion::Delegations` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::DelegatedStake` (r:10 w:10)
+	/// Proof: `AppPromotion::DelegatedStake` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalDelegated` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalDelegated` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::PayoutHistory` (r:0 w:1)
+	/// Proof: `AppPromotion::PayoutHistory` (`max_values`: None, `max_size`: Some(1289), added: 3764, mode: `MaxEncodedLen`)
 	fn unstake_all() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `829`
-		//  Estimated: `29095`
+		//  Estimated: `95304`
 		// Minimum execution time: 57_058_000 picoseconds.
-		Weight::from_parts(58_034_000, 29095)
-			.saturating_add(RocksDbWeight::get().reads(14_u64))
-			.saturating_add(RocksDbWeight::get().writes(13_u64))
+		Weight::from_parts(58_034_000, 95304)
+			.saturating_add(RocksDbWeight::get().reads(41_u64))
+			.saturating_add(RocksDbWeight::get().writes(39_u64))
 	}
 	/// Storage: `Configuration::AppPromomotionConfigurationOverride` (r:1 w:0)
 	/// Proof: `Configuration::AppPromomotionConfigurationOverride` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
@@ -355,14 +501,30 @@ impl WeightInfo for () {
 	/// Proof: `AppPromotion::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `AppPromotion::StakesPerAccount` (r:1 w:1)
 	/// Proof: `AppPromotion::StakesPerAccount` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AppPromotion::StakeLocks` (r:10 w:10)
+	/// Proof: `AppPromotion::StakeLocks` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::Delegations` (r:1 w:1)
+	/// Proof: `AppPromotion::Delegations` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::DelegatedStake` (r:10 w:10)
+	/// Proof: `AppPromotion::DelegatedStake` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalDelegated` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalDelegated` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn unstake_partial() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `829`
-		//  Estimated: `29095`
+		//  Estimated: `95304`
 		// Minimum execution time: 75_191_000 picoseconds.
-		Weight::from_parts(76_053_000, 29095)
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
-			.saturating_add(RocksDbWeight::get().writes(13_u64))
+		Weight::from_parts(76_053_000, 95304)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(38_u64))
 	}
 	/// Storage: `AppPromotion::Admin` (r:1 w:0)
 	/// Proof: `AppPromotion::Admin` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
@@ -416,5 +578,86 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::Delegations` (r:1 w:1)
+	/// Proof: `AppPromotion::Delegations` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::Staked` (r:11 w:0)
+	/// Proof: `AppPromotion::Staked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::DelegatedStake` (r:1 w:1)
+	/// Proof: `AppPromotion::DelegatedStake` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalDelegated` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalDelegated` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn delegate_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `29095`
+		// Minimum execution time: 54_310_000 picoseconds.
+		Weight::from_parts(55_402_000, 29095)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AppPromotion::Delegations` (r:1 w:1)
+	/// Proof: `AppPromotion::Delegations` (`max_values`: None, `max_size`: Some(409), added: 2884, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::DelegatedStake` (r:1 w:1)
+	/// Proof: `AppPromotion::DelegatedStake` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalDelegated` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalDelegated` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn undelegate_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3874`
+		// Minimum execution time: 21_930_000 picoseconds.
+		Weight::from_parts(22_517_000, 3874)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AppPromotion::LockTiers` (r:0 w:1)
+	/// Proof: `AppPromotion::LockTiers` (`max_values`: Some(1), `max_size`: Some(297), added: 792, mode: `MaxEncodedLen`)
+	fn set_lock_tiers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_212_000 picoseconds.
+		Weight::from_parts(7_518_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AppPromotion::LockTiers` (r:1 w:0)
+	/// Proof: `AppPromotion::LockTiers` (`max_values`: Some(1), `max_size`: Some(297), added: 792, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::StakesPerAccount` (r:1 w:1)
+	/// Proof: `AppPromotion::StakesPerAccount` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(369), added: 2844, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AppPromotion::Staked` (r:1 w:1)
+	/// Proof: `AppPromotion::Staked` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::TotalStaked` (r:1 w:1)
+	/// Proof: `AppPromotion::TotalStaked` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AppPromotion::StakeLocks` (r:0 w:1)
+	/// Proof: `AppPromotion::StakeLocks` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	fn stake_locked() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389`
+		//  Estimated: `4764`
+		// Minimum execution time: 27_466_000 picoseconds.
+		Weight::from_parts(28_310_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AppPromotion::RewardDestinations` (r:0 w:1)
+	/// Proof: `AppPromotion::RewardDestinations` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	fn set_reward_destination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_102_000 picoseconds.
+		Weight::from_parts(8_394_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
 
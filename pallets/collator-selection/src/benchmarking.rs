@@ -37,15 +37,13 @@ use frame_benchmarking::v2::{
 };
 use frame_support::{
 	assert_ok,
-	traits::{
-		fungible::{Inspect, Mutate},
-		EnsureOrigin, Get,
-	},
+	traits::{fungible::Mutate, EnsureOrigin, Get},
 };
 use frame_system::{pallet_prelude::*, EventRecord, RawOrigin};
 use pallet_authorship::EventHandler;
 use pallet_session::{self as session, SessionManager};
 use parity_scale_codec::Decode;
+use sp_runtime::{traits::Zero, Perbill};
 use sp_std::prelude::*;
 
 use super::*;
@@ -166,6 +164,9 @@ mod benchmarks {
 	use super::*;
 	const MAX_COLLATORS: u32 = 10;
 	const MAX_INVULNERABLES: u32 = MAX_COLLATORS - INITIAL_INVULNERABLES;
+	const MAX_DELEGATORS: u32 = 64;
+	const MAX_UNBONDING_REQUESTS: u32 = 16;
+	const MAX_WAITING_CANDIDATES: u32 = 64;
 
 	// todo:collator this and all the following do not work for some reason, going all the way up to 10 in length
 	// Both invulnerables and candidates count together against MaxCollators.
@@ -374,23 +375,18 @@ mod benchmarks {
 	// worst case is paying a non-existing candidate account.
 	#[benchmark]
 	fn note_author() -> Result<(), BenchmarkError> {
-		T::Currency::set_balance(
-			&<CollatorSelection<T>>::account_id(),
-			balance_unit::<T>() * 4u32.into(),
-		);
-		let author = account("author", 0, SEED);
+		let author: T::AccountId = account("author", 0, SEED);
 		let new_block: BlockNumberFor<T> = 10u32.into();
 
 		frame_system::Pallet::<T>::set_block_number(new_block);
-		assert!(T::Currency::balance(&author) == 0u32.into());
 
 		#[block]
 		{
 			<CollatorSelection<T> as EventHandler<_, _>>::note_author(author.clone());
 		}
 
-		assert!(T::Currency::balance(&author) > 0u32.into());
-		assert_eq!(frame_system::Pallet::<T>::block_number(), new_block);
+		assert_eq!(<SessionAuthoredBlocks<T>>::get(&author), 1);
+		assert_eq!(<LastAuthoredBlock<T>>::get(&author), new_block);
 
 		Ok(())
 	}
@@ -446,6 +442,295 @@ mod benchmarks {
 		Ok(())
 	}
 
+	// worst case is every free candidate slot taken by a waiting license holder.
+	#[benchmark]
+	fn promote_waiting_candidates(p: Linear<0, MAX_INVULNERABLES>) -> Result<(), BenchmarkError> {
+		register_validators::<T>(p);
+		get_licenses::<T>(p);
+		for c in 0..p {
+			let who: T::AccountId = account("candidate", c, SEED);
+			<CollatorSelection<T>>::join_waiting_list(RawOrigin::Signed(who).into()).unwrap();
+		}
+		let candidates = <Candidates<T>>::get();
+
+		#[block]
+		{
+			<CollatorSelection<T>>::promote_waiting_candidates(candidates);
+		}
+
+		assert_eq!(<Candidates<T>>::get().len(), p as usize);
+		assert!(<WaitingCandidates<T>>::get().is_empty());
+
+		Ok(())
+	}
+
+	// worst case for end session: every collator authored blocks and gets rewarded,
+	// and the payouts of its delegators are queued.
+	#[benchmark]
+	fn end_session(c: Linear<1, MAX_COLLATORS>) -> Result<(), BenchmarkError> {
+		T::Currency::set_balance(
+			&<CollatorSelection<T>>::account_id(),
+			balance_unit::<T>() * (c * 4).into(),
+		);
+		let authors = (0..c)
+			.map(|i| create_funded_user::<T>("author", i, 1))
+			.collect::<Vec<_>>();
+		for author in authors.iter() {
+			<SessionAuthoredBlocks<T>>::insert(author, 10);
+			<LicenseDepositOf<T>>::insert(author, balance_unit::<T>());
+			<DelegatorCount<T>>::insert(author, 1);
+			<TotalBacking<T>>::insert(author, balance_unit::<T>() * 2u32.into());
+		}
+
+		#[block]
+		{
+			<CollatorSelection<T> as SessionManager<_>>::end_session(0);
+		}
+
+		for author in authors.iter() {
+			assert_eq!(<CollatorRewards<T>>::get(author).session_blocks, 10);
+			assert!(<DelegatorPayouts<T>>::contains_key(0, author));
+		}
+
+		Ok(())
+	}
+
+	// worst case is paying all the delegators of a collator and finishing its payout.
+	#[benchmark]
+	fn pay_delegator_rewards(d: Linear<0, MAX_DELEGATORS>) -> Result<(), BenchmarkError> {
+		let pot = <CollatorSelection<T>>::account_id();
+		T::Currency::set_balance(&pot, balance_unit::<T>() * (d + 1).into());
+		let collator = create_funded_user::<T>("author", 0, 1);
+		for i in 0..d {
+			let delegator = create_funded_user::<T>("delegator", i, 1);
+			<Delegations<T>>::insert(&collator, delegator, balance_unit::<T>());
+		}
+		let shared = balance_unit::<T>() * d.into();
+		<DelegatorPayouts<T>>::insert(
+			0,
+			&collator,
+			DelegatorPayout {
+				shared,
+				backing: balance_unit::<T>() * d.max(1).into(),
+				remaining: shared,
+				last_delegator: None,
+			},
+		);
+		<PendingDelegatorRewards<T>>::put(shared);
+
+		#[block]
+		{
+			<CollatorSelection<T>>::pay_delegator_rewards(d.max(1));
+		}
+
+		assert!(!<DelegatorPayouts<T>>::contains_key(0, &collator));
+		assert!(<PendingDelegatorRewards<T>>::get().is_zero());
+
+		Ok(())
+	}
+
+	// worst case is the license holder with the maximum number of delegators.
+	#[benchmark]
+	fn unbond_delegations(d: Linear<0, MAX_DELEGATORS>) -> Result<(), BenchmarkError> {
+		register_validators::<T>(1);
+		get_licenses::<T>(1);
+		let candidate: T::AccountId = account("candidate", 0, SEED);
+
+		for i in 0..d {
+			let delegator = create_funded_user::<T>("delegator", i, 1);
+			<Delegations<T>>::insert(&candidate, delegator, balance_unit::<T>());
+		}
+		<DelegatorCount<T>>::insert(&candidate, d);
+		<TotalBacking<T>>::insert(&candidate, balance_unit::<T>() * (d + 1).into());
+
+		#[block]
+		{
+			<CollatorSelection<T>>::unbond_delegations(&candidate);
+		}
+
+		assert_eq!(<DelegatorCount<T>>::get(&candidate), 0);
+		assert_eq!(<TotalBacking<T>>::get(&candidate), T::LicenseBond::get());
+
+		Ok(())
+	}
+
+	// worst case is a new delegator of the license holder.
+	#[benchmark]
+	fn delegate() -> Result<(), BenchmarkError> {
+		register_validators::<T>(1);
+		get_licenses::<T>(1);
+		let candidate: T::AccountId = account("candidate", 0, SEED);
+
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::MinDelegation::get().max(balance_unit::<T>());
+		T::Currency::set_balance(&caller, amount * 2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), candidate.clone(), amount);
+
+		assert_last_event::<T>(
+			Event::Delegated {
+				delegator: caller,
+				candidate,
+				amount,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	// worst case is the delegator leaving the license holder.
+	#[benchmark]
+	fn undelegate() -> Result<(), BenchmarkError> {
+		register_validators::<T>(1);
+		get_licenses::<T>(1);
+		let candidate: T::AccountId = account("candidate", 0, SEED);
+
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::MinDelegation::get().max(balance_unit::<T>());
+		T::Currency::set_balance(&caller, amount * 2u32.into());
+		assert_ok!(<CollatorSelection<T>>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			candidate.clone(),
+			amount
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), candidate.clone(), amount);
+
+		assert_last_event::<T>(
+			Event::Undelegated {
+				delegator: caller,
+				candidate,
+				amount,
+				unlock_at: frame_system::Pallet::<T>::block_number() + T::UnbondingDelay::get(),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	// worst case is withdrawing all of the unbonding requests.
+	#[benchmark]
+	fn withdraw_unbonded(r: Linear<1, MAX_UNBONDING_REQUESTS>) -> Result<(), BenchmarkError> {
+		register_validators::<T>(1);
+		get_licenses::<T>(1);
+		let candidate: T::AccountId = account("candidate", 0, SEED);
+
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::MinDelegation::get().max(balance_unit::<T>());
+		T::Currency::set_balance(&caller, amount * (r + 1).into());
+		assert_ok!(<CollatorSelection<T>>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			candidate.clone(),
+			amount * r.into()
+		));
+		for _ in 0..r {
+			assert_ok!(<CollatorSelection<T>>::undelegate(
+				RawOrigin::Signed(caller.clone()).into(),
+				candidate.clone(),
+				amount
+			));
+		}
+
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::UnbondingDelay::get(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(
+			Event::UnbondedWithdrawn {
+				delegator: caller,
+				amount: amount * r.into(),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_commission() -> Result<(), BenchmarkError> {
+		register_validators::<T>(1);
+		get_licenses::<T>(1);
+		let candidate: T::AccountId = account("candidate", 0, SEED);
+		let commission = Perbill::from_percent(10);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(candidate.clone()), commission);
+
+		assert_last_event::<T>(
+			Event::CommissionSet {
+				account_id: candidate,
+				commission,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	// worst case is joining the full waiting list.
+	#[benchmark]
+	fn join_waiting_list(w: Linear<1, MAX_WAITING_CANDIDATES>) -> Result<(), BenchmarkError> {
+		register_validators::<T>(w);
+		get_licenses::<T>(w);
+		for c in 0..w - 1 {
+			let who: T::AccountId = account("candidate", c, SEED);
+			assert_ok!(<CollatorSelection<T>>::join_waiting_list(
+				RawOrigin::Signed(who).into()
+			));
+		}
+
+		let caller: T::AccountId = account("candidate", w - 1, SEED);
+		whitelist!(caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(
+			Event::WaitingListJoined {
+				account_id: caller,
+				position: w,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	// worst case is the last waiting account leaving.
+	#[benchmark]
+	fn leave_waiting_list(w: Linear<1, MAX_WAITING_CANDIDATES>) -> Result<(), BenchmarkError> {
+		register_validators::<T>(w);
+		get_licenses::<T>(w);
+		for c in 0..w {
+			let who: T::AccountId = account("candidate", c, SEED);
+			assert_ok!(<CollatorSelection<T>>::join_waiting_list(
+				RawOrigin::Signed(who).into()
+			));
+		}
+
+		let leaving = <WaitingCandidates<T>>::get().last().unwrap().clone();
+		whitelist!(leaving);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(leaving.clone()));
+
+		assert_last_event::<T>(
+			Event::WaitingListLeft {
+				account_id: leaving,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		CollatorSelection,
 		crate::mock::new_test_ext(),
@@ -62,19 +62,51 @@
 //! Candidates will not be allowed to get kicked or leave_intent if the total number of candidates
 //! fall below MinCandidates. This is for potential disaster recovery scenarios.
 //!
+//! ### Waiting list
+//!
+//! License holders, who cannot `onboard` because there are no free candidate slots, may join the
+//! [`WaitingCandidates`] list. At each session rotation, the slots freed by offboarded or kicked
+//! candidates are taken by the accounts from the waiting list in the order they obtained their
+//! licenses, see [`LicenseObtainedAt`]. While the list is not empty, only its first account may
+//! `onboard` directly.
+//!
+//! The list is ordered by the license time rather than by the backing: it only grants a candidate
+//! slot, while the seats are still given to the most backed candidates by the election. A backing
+//! order would also let a late joiner push the others back indefinitely by delegating to itself.
+//!
+//! ### Strikes
+//!
+//! An elected candidate, who did not author a block within the `KickThreshold`, receives a strike, and
+//! the `StrikeSlashRatio` of its license bond is slashed. After `MaxStrikes` strikes the candidate
+//! is removed and its license is revoked, the rest of its license bond is returned. A strike is
+//! forgiven after each `StrikeDecaySessions` sessions without new strikes.
+//!
+//! ### Delegation
+//!
+//! Token holders may delegate their funds to the license holders. The delegated funds are held
+//! and count towards the total backing of the license holder, together with its license bond.
+//! If there are more candidates than the `DesiredCollators` seats left after the invulnerables,
+//! the candidates with the largest total backing are elected.
+//!
+//! Undelegated funds stay held for the `UnbondingDelay`, after which they can be withdrawn.
+//! When a license is released, all the funds delegated to its holder are undelegated as well.
+//!
 //! ### Rewards
 //!
-//! The Collator Selection pallet maintains an on-chain account (the "Pot"). In each block, the
-//! collator who authored it receives:
+//! The Collator Selection pallet maintains an on-chain account (the "Pot"), which may be funded
+//! by fees or inflation. The pallet counts the blocks authored by each collator within a session,
+//! and at the end of the session the value of the Pot is distributed between the authors
+//! in proportion to the number of blocks they authored.
 //!
-//! - Half the value of the Pot.
-//! - Half the value of the transaction fees within the block. The other half of the transaction
-//!   fees are deposited into the Pot.
+//! Invulnerables receive only the `InvulnerablesRewardShare` of their proportional reward,
+//! the rest of it stays in the Pot for the next sessions.
 //!
-//! To initiate rewards an ED needs to be transferred to the pot address.
+//! A candidate keeps its commission of the reward, and the rest of it is shared between
+//! the candidate and its delegators in proportion to their part in the total backing.
+//! The delegators are paid in the following blocks, at most `MaxDelegatorPayoutsPerBlock`
+//! of them per block, the unpaid rewards are kept in the Pot until then.
 //!
-//! Note: Eventually the Pot distribution may be modified as discussed in
-//! [this issue](https://github.com/paritytech/statemint/issues/21#issuecomment-810481073).
+//! To initiate rewards an ED needs to be transferred to the pot address.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -88,20 +120,61 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 
 use frame_support::traits::fungible::Inspect;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_staking::SessionIndex;
 
 type BalanceOf<T> =
 	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Rewards of a collator for the authored blocks.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CollatorRewardInfo<Balance> {
+	/// The last session the collator was rewarded for.
+	pub session: SessionIndex,
+	/// Number of blocks authored by the collator in that session.
+	pub session_blocks: u32,
+	/// Reward of the collator for that session.
+	pub session_reward: Balance,
+	/// Total reward of the collator for all sessions.
+	pub total_reward: Balance,
+}
+
+/// Reward of the delegators of a collator for a session, which is being paid out.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DelegatorPayout<AccountId, Balance> {
+	/// Part of the collator reward shared with the delegators.
+	pub shared: Balance,
+	/// Total backing of the collator at the session end.
+	pub backing: Balance,
+	/// Part of the reward, which is not paid yet.
+	pub remaining: Balance,
+	/// The last delegator paid, the payout continues after it.
+	pub last_delegator: Option<AccountId>,
+}
+
+/// Strikes of a candidate for the missed authoring windows.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StrikeInfo {
+	/// Number of the active strikes.
+	pub strikes: u32,
+	/// The last session a strike was given or forgiven at.
+	pub last_change: SessionIndex,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
-	use core::ops::Div;
-
 	use frame_support::{
 		dispatch::{DispatchClass, DispatchResultWithPostInfo},
 		pallet_prelude::*,
-		sp_runtime::traits::{AccountIdConversion, CheckedSub, Saturating, Zero},
+		sp_runtime::traits::{
+			AccountIdConversion, CheckedSub, Saturating, UniqueSaturatedInto, Zero,
+		},
 		traits::{
 			fungible::{Balanced, BalancedHold, Inspect, Mutate, MutateHold},
 			tokens::{Precision, Preservation},
@@ -112,7 +185,6 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use pallet_session::SessionManager;
 	use sp_runtime::{traits::Convert, Perbill};
-	use sp_staking::SessionIndex;
 	use sp_std::vec::Vec;
 
 	use super::*;
@@ -173,15 +245,58 @@ pub mod pallet {
 		type LicenseBond: Get<BalanceOf<Self>>;
 
 		type KickThreshold: Get<BlockNumberFor<Self>>;
+
+		/// Share of the reward for the authored blocks, which invulnerables receive.
+		/// The rest of it stays in the Pot.
+		type InvulnerablesRewardShare: Get<Perbill>;
+
+		/// Minimal amount of funds delegated by an account to a license holder.
+		#[pallet::constant]
+		type MinDelegation: Get<BalanceOf<Self>>;
+
+		/// Maximum number of accounts delegating to a single license holder.
+		#[pallet::constant]
+		type MaxDelegatorsPerCandidate: Get<u32>;
+
+		/// Maximum number of pending unbonding requests of a single account.
+		#[pallet::constant]
+		type MaxUnbondingRequests: Get<u32>;
+
+		/// Number of blocks for which the undelegated funds stay held.
+		#[pallet::constant]
+		type UnbondingDelay: Get<BlockNumberFor<Self>>;
+
+		/// Share of the license bond slashed for each strike.
+		type StrikeSlashRatio: Get<Perbill>;
+
+		/// Number of strikes after which the candidate is removed and its license is revoked.
+		type MaxStrikes: Get<u32>;
+
+		/// Number of sessions without strikes after which a strike is forgiven.
+		/// Zero disables forgiving.
+		type StrikeDecaySessions: Get<SessionIndex>;
+
+		/// Maximum number of license holders waiting for a free candidate slot.
+		#[pallet::constant]
+		type MaxWaitingCandidates: Get<u32>;
+
+		/// Maximum number of delegators paid their session rewards in a block.
+		#[pallet::constant]
+		type MaxDelegatorPayoutsPerBlock: Get<u32>;
 	}
 
 	#[pallet::composite_enum]
 	pub enum HoldReason {
 		/// The funds are held as the license bond.
 		LicenseBond,
+		/// The funds are delegated to a license holder or are being unbonded.
+		Delegation,
 	}
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// The invulnerable, fixed collators.
@@ -196,18 +311,116 @@ pub mod pallet {
 	pub type LicenseDepositOf<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	/// Block at which a license holder obtained its license. The waiting list is ordered by it.
+	#[pallet::storage]
+	#[pallet::getter(fn license_obtained_at)]
+	pub type LicenseObtainedAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
+
 	/// The (community, limited) collation candidates.
 	#[pallet::storage]
 	#[pallet::getter(fn candidates)]
 	pub type Candidates<T: Config> =
 		StorageValue<_, BoundedVec<T::AccountId, T::MaxCollators>, ValueQuery>;
 
+	/// License holders waiting for a free candidate slot, in the order they obtained their licenses.
+	#[pallet::storage]
+	#[pallet::getter(fn waiting_candidates)]
+	pub type WaitingCandidates<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxWaitingCandidates>, ValueQuery>;
+
+	/// Candidates elected at the last session rotation. Only they are checked for
+	/// the missed authoring windows.
+	#[pallet::storage]
+	#[pallet::getter(fn elected_candidates)]
+	pub type ElectedCandidates<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxCollators>, ValueQuery>;
+
 	/// Last block authored by collator.
 	#[pallet::storage]
 	#[pallet::getter(fn last_authored_block)]
 	pub type LastAuthoredBlock<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
 
+	/// Number of blocks authored by collator in the current session.
+	#[pallet::storage]
+	#[pallet::getter(fn session_authored_blocks)]
+	pub type SessionAuthoredBlocks<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Rewards received by collator for the authored blocks.
+	#[pallet::storage]
+	#[pallet::getter(fn collator_rewards)]
+	pub type CollatorRewards<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, CollatorRewardInfo<BalanceOf<T>>, ValueQuery>;
+
+	/// Funds delegated to the license holders, by license holder and delegator.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation)]
+	pub type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Number of accounts delegating to a license holder.
+	#[pallet::storage]
+	#[pallet::getter(fn delegator_count)]
+	pub type DelegatorCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Total backing of a license holder: its license bond and the funds delegated to it.
+	#[pallet::storage]
+	#[pallet::getter(fn total_backing)]
+	pub type TotalBacking<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Share of the collator reward a license holder keeps before sharing it with the delegators.
+	#[pallet::storage]
+	#[pallet::getter(fn commission)]
+	pub type Commission<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Perbill, ValueQuery>;
+
+	/// Strikes of the candidates for the missed authoring windows.
+	#[pallet::storage]
+	#[pallet::getter(fn strikes)]
+	pub type Strikes<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, StrikeInfo, OptionQuery>;
+
+	/// Undelegated funds of an account with the blocks they can be withdrawn at.
+	#[pallet::storage]
+	#[pallet::getter(fn unbonding)]
+	pub type Unbonding<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(BalanceOf<T>, BlockNumberFor<T>), T::MaxUnbondingRequests>,
+		ValueQuery,
+	>;
+
+	/// Delegator rewards of the collators, which are not paid yet, by session and collator.
+	#[pallet::storage]
+	#[pallet::getter(fn delegator_payouts)]
+	pub type DelegatorPayouts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::AccountId,
+		DelegatorPayout<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Total of the delegator rewards, which are not paid yet. They are kept in the Pot
+	/// and are not distributed as the session rewards.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_delegator_rewards)]
+	pub type PendingDelegatorRewards<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub invulnerables: Vec<T::AccountId>,
@@ -263,6 +476,59 @@ pub mod pallet {
 		CandidateRemoved {
 			account_id: T::AccountId,
 		},
+		CollatorRewarded {
+			account_id: T::AccountId,
+			blocks: u32,
+			reward: BalanceOf<T>,
+		},
+		SessionRewardsDistributed {
+			session: SessionIndex,
+			blocks: u32,
+			reward: BalanceOf<T>,
+		},
+		Delegated {
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		Undelegated {
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			unlock_at: BlockNumberFor<T>,
+		},
+		UnbondedWithdrawn {
+			delegator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		CommissionSet {
+			account_id: T::AccountId,
+			commission: Perbill,
+		},
+		DelegatorRewarded {
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+			reward: BalanceOf<T>,
+		},
+		CandidateStruck {
+			account_id: T::AccountId,
+			strikes: u32,
+			slashed: BalanceOf<T>,
+		},
+		StrikeForgiven {
+			account_id: T::AccountId,
+			strikes: u32,
+		},
+		WaitingListJoined {
+			account_id: T::AccountId,
+			position: u32,
+		},
+		WaitingListLeft {
+			account_id: T::AccountId,
+		},
+		WaitingCandidatePromoted {
+			account_id: T::AccountId,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -294,10 +560,40 @@ pub mod pallet {
 		NoAssociatedValidatorId,
 		/// Validator ID is not yet registered
 		ValidatorNotRegistered,
+		/// Delegation is below the minimal delegation
+		DelegationTooLow,
+		/// Too many accounts delegate to the license holder
+		TooManyDelegators,
+		/// Account does not delegate enough funds to the license holder
+		InsufficientDelegation,
+		/// Too many pending unbonding requests
+		TooManyUnbondingRequests,
+		/// No unbonded funds to withdraw yet
+		NothingToWithdraw,
+		/// Too many license holders are waiting for a candidate slot
+		TooManyWaitingCandidates,
+		/// User is already waiting for a candidate slot
+		AlreadyWaiting,
+		/// User is not waiting for a candidate slot
+		NotWaiting,
+		/// Other license holders are waiting for a candidate slot before the user
+		WaitingListNotEmpty,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			if Self::pending_delegator_rewards().is_zero() {
+				return T::DbWeight::get().reads(1);
+			}
+			let paid = Self::pay_delegator_rewards(T::MaxDelegatorPayoutsPerBlock::get());
+			<T as Config>::WeightInfo::pay_delegator_rewards(paid)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migration::migrate_to_v1::<T>()
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -326,6 +622,7 @@ pub mod pallet {
 
 			// try to offboard the new invulnerable if it was a collator candidate before
 			let _ = Self::try_remove_candidate(&new);
+			let _ = Self::try_remove_waiting_candidate(&new);
 
 			Self::deposit_event(Event::InvulnerableAdded { invulnerable: new });
 			Ok(().into())
@@ -382,6 +679,8 @@ pub mod pallet {
 
 			T::Currency::hold(&HoldReason::LicenseBond.into(), &who, deposit)?;
 			LicenseDepositOf::<T>::insert(who.clone(), deposit);
+			<LicenseObtainedAt<T>>::insert(&who, frame_system::Pallet::<T>::block_number());
+			<TotalBacking<T>>::mutate(&who, |backing| *backing = backing.saturating_add(deposit));
 
 			Self::deposit_event(Event::LicenseObtained {
 				account_id: who,
@@ -392,6 +691,8 @@ pub mod pallet {
 
 		/// Register this account as a candidate for collators for next sessions.
 		/// The account must already hold a license, and cannot offboard immediately during a session.
+		/// If the account was on the waiting list, it leaves it. While the waiting list is not empty,
+		/// only its first account may onboard.
 		///
 		/// This call is not available to `Invulnerable` collators.
 		#[pallet::call_index(3)]
@@ -405,17 +706,25 @@ pub mod pallet {
 				LicenseDepositOf::<T>::contains_key(&who),
 				Error::<T>::NoLicense
 			);
-			// ensure we are below limit.
+			// ensure we are below limit. When there are more candidates than desired,
+			// the most backed ones are elected.
 			let length = <Candidates<T>>::decode_len().unwrap_or_default()
 				+ <Invulnerables<T>>::decode_len().unwrap_or_default();
 			ensure!(
-				(length as u32) < T::DesiredCollators::get(),
+				(length as u32) < T::MaxCollators::get(),
 				Error::<T>::TooManyCandidates
 			);
 			ensure!(
 				!Self::invulnerables().contains(&who),
 				Error::<T>::AlreadyInvulnerable
 			);
+			// the freed slots are for the waiting accounts, in their order.
+			ensure!(
+				Self::waiting_candidates()
+					.first()
+					.map_or(true, |first| *first == who),
+				Error::<T>::WaitingListNotEmpty
+			);
 
 			let current_count =
 				<Candidates<T>>::try_mutate(|candidates| -> Result<usize, DispatchError> {
@@ -433,6 +742,7 @@ pub mod pallet {
 						Ok(candidates.len())
 					}
 				})?;
+			let _ = Self::try_remove_waiting_candidate(&who);
 
 			Self::deposit_event(Event::CandidateAdded { account_id: who });
 			Ok(Some(<T as Config>::WeightInfo::onboard(current_count as u32)).into())
@@ -454,16 +764,19 @@ pub mod pallet {
 		///
 		/// This call is not available to `Invulnerable` collators.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::release_license(T::MaxCollators::get()))]
+		#[pallet::weight(<T as Config>::WeightInfo::release_license(T::MaxCollators::get())
+			.saturating_add(<T as Config>::WeightInfo::unbond_delegations(T::MaxDelegatorsPerCandidate::get())))]
 		pub fn release_license(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			// leave_intent
 			let who = ensure_signed(origin)?;
 
-			let current_count = Self::try_remove_candidate_and_release_license(&who, false, true)?;
+			let (current_count, delegators) =
+				Self::try_remove_candidate_and_release_license(&who, false, true)?;
 
-			Ok(Some(<T as Config>::WeightInfo::release_license(
-				current_count as u32,
-			))
+			Ok(Some(
+				<T as Config>::WeightInfo::release_license(current_count as u32)
+					.saturating_add(<T as Config>::WeightInfo::unbond_delegations(delegators)),
+			)
 			.into())
 		}
 
@@ -473,7 +786,8 @@ pub mod pallet {
 		///
 		/// This call is, of course, not applicable to `Invulnerable` collators.
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as Config>::WeightInfo::force_release_license(T::MaxCollators::get()))]
+		#[pallet::weight(<T as Config>::WeightInfo::force_release_license(T::MaxCollators::get())
+			.saturating_add(<T as Config>::WeightInfo::unbond_delegations(T::MaxDelegatorsPerCandidate::get())))]
 		pub fn force_release_license(
 			origin: OriginFor<T>,
 			who: T::AccountId,
@@ -481,9 +795,223 @@ pub mod pallet {
 			// leave_intent
 			T::UpdateOrigin::ensure_origin(origin)?;
 
-			let current_count = Self::try_remove_candidate_and_release_license(&who, false, true)?;
+			let (current_count, delegators) =
+				Self::try_remove_candidate_and_release_license(&who, false, true)?;
+
+			Ok(Some(
+				<T as Config>::WeightInfo::force_release_license(current_count as u32)
+					.saturating_add(<T as Config>::WeightInfo::unbond_delegations(delegators)),
+			)
+			.into())
+		}
+
+		/// Delegate `amount` of `origin`'s funds to the license holder `candidate`.
+		/// The funds are held and count towards the total backing of the candidate.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				LicenseDepositOf::<T>::contains_key(&candidate),
+				Error::<T>::NoLicense
+			);
+
+			let delegation = <Delegations<T>>::get(&candidate, &who);
+			ensure!(
+				delegation.saturating_add(amount) >= T::MinDelegation::get(),
+				Error::<T>::DelegationTooLow
+			);
+			if delegation.is_zero() {
+				<DelegatorCount<T>>::try_mutate(&candidate, |count| -> DispatchResult {
+					ensure!(
+						*count < T::MaxDelegatorsPerCandidate::get(),
+						Error::<T>::TooManyDelegators
+					);
+					*count += 1;
+					Ok(())
+				})?;
+			}
+
+			T::Currency::hold(&HoldReason::Delegation.into(), &who, amount)?;
+			<Delegations<T>>::insert(&candidate, &who, delegation.saturating_add(amount));
+			<TotalBacking<T>>::mutate(&candidate, |backing| {
+				*backing = backing.saturating_add(amount)
+			});
+
+			Self::deposit_event(Event::Delegated {
+				delegator: who,
+				candidate,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Undelegate `amount` of `origin`'s funds from `candidate`.
+		/// The funds stay held for the `UnbondingDelay`, use `withdraw_unbonded` afterward.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::undelegate())]
+		pub fn undelegate(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			Ok(Some(<T as Config>::WeightInfo::force_release_license(
+			let delegation = <Delegations<T>>::get(&candidate, &who);
+			ensure!(
+				!amount.is_zero() && amount <= delegation,
+				Error::<T>::InsufficientDelegation
+			);
+			let remaining = delegation - amount;
+			ensure!(
+				remaining.is_zero() || remaining >= T::MinDelegation::get(),
+				Error::<T>::DelegationTooLow
+			);
+
+			let unlock_at = frame_system::Pallet::<T>::block_number() + T::UnbondingDelay::get();
+			<Unbonding<T>>::try_append(&who, (amount, unlock_at))
+				.map_err(|_| Error::<T>::TooManyUnbondingRequests)?;
+
+			if remaining.is_zero() {
+				<Delegations<T>>::remove(&candidate, &who);
+				<DelegatorCount<T>>::mutate_exists(&candidate, |count| {
+					*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+				});
+			} else {
+				<Delegations<T>>::insert(&candidate, &who, remaining);
+			}
+			Self::decrease_backing(&candidate, amount);
+
+			Self::deposit_event(Event::Undelegated {
+				delegator: who,
+				candidate,
+				amount,
+				unlock_at,
+			});
+			Ok(())
+		}
+
+		/// Release `origin`'s undelegated funds, for which the `UnbondingDelay` has passed.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonded(T::MaxUnbondingRequests::get()))]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut unbonding = <Unbonding<T>>::get(&who);
+			let requests = unbonding.len() as u32;
+
+			let mut amount = BalanceOf::<T>::zero();
+			unbonding.retain(|(chunk, unlock_at)| {
+				if *unlock_at <= now {
+					amount = amount.saturating_add(*chunk);
+					false
+				} else {
+					true
+				}
+			});
+			ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+			T::Currency::release(
+				&HoldReason::Delegation.into(),
+				&who,
+				amount,
+				Precision::Exact,
+			)?;
+			if unbonding.is_empty() {
+				<Unbonding<T>>::remove(&who);
+			} else {
+				<Unbonding<T>>::insert(&who, unbonding);
+			}
+
+			Self::deposit_event(Event::UnbondedWithdrawn {
+				delegator: who,
+				amount,
+			});
+			Ok(Some(<T as Config>::WeightInfo::withdraw_unbonded(requests)).into())
+		}
+
+		/// Set the share of the collator rewards `origin` keeps before sharing them
+		/// with its delegators.
+		///
+		/// The account must hold a license.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_commission())]
+		pub fn set_commission(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				LicenseDepositOf::<T>::contains_key(&who),
+				Error::<T>::NoLicense
+			);
+			<Commission<T>>::insert(&who, commission);
+
+			Self::deposit_event(Event::CommissionSet {
+				account_id: who,
+				commission,
+			});
+			Ok(())
+		}
+
+		/// Join the waiting list for a candidate slot. At a session change, the freed
+		/// candidate slots are taken by the waiting accounts in the order they obtained their licenses.
+		///
+		/// The account must hold a license and must not be a candidate.
+		/// This call is not available to `Invulnerable` collators.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::join_waiting_list(T::MaxWaitingCandidates::get()))]
+		pub fn join_waiting_list(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				LicenseDepositOf::<T>::contains_key(&who),
+				Error::<T>::NoLicense
+			);
+			ensure!(
+				!Self::invulnerables().contains(&who),
+				Error::<T>::AlreadyInvulnerable
+			);
+			ensure!(
+				!Self::candidates().contains(&who),
+				Error::<T>::AlreadyCandidate
+			);
+
+			let obtained_at = Self::license_obtained_at(&who);
+			let position =
+				<WaitingCandidates<T>>::try_mutate(|waiting| -> Result<usize, DispatchError> {
+					ensure!(!waiting.contains(&who), Error::<T>::AlreadyWaiting);
+					// the earlier licenses go first, the ones of the same block in the join order.
+					let index = waiting
+						.partition_point(|waiter| Self::license_obtained_at(waiter) <= obtained_at);
+					waiting
+						.try_insert(index, who.clone())
+						.map_err(|_| Error::<T>::TooManyWaitingCandidates)?;
+					Ok(index + 1)
+				})?;
+
+			Self::deposit_event(Event::WaitingListJoined {
+				account_id: who,
+				position: position as u32,
+			});
+			Ok(Some(<T as Config>::WeightInfo::join_waiting_list(
+				position as u32,
+			))
+			.into())
+		}
+
+		/// Leave the waiting list for a candidate slot. The license is kept.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::leave_waiting_list(T::MaxWaitingCandidates::get()))]
+		pub fn leave_waiting_list(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let current_count = Self::try_remove_waiting_candidate(&who)?;
+
+			Ok(Some(<T as Config>::WeightInfo::leave_waiting_list(
 				current_count as u32,
 			))
 			.into())
@@ -498,11 +1026,12 @@ pub mod pallet {
 
 		/// Removes a candidate and their license, optionally slashed and optionally ignoring,
 		/// whether or not they actually are a candidate.
+		/// Returns the number of candidates left and the number of unbonded delegators.
 		fn try_remove_candidate_and_release_license(
 			who: &T::AccountId,
 			should_slash: bool,
 			ignore_if_not_candidate: bool,
-		) -> Result<usize, DispatchError> {
+		) -> Result<(usize, u32), DispatchError> {
 			let current_count = Self::try_remove_candidate(who);
 			let current_count = if ignore_if_not_candidate
 				&& current_count == Err(Error::<T>::NotCandidate.into())
@@ -511,8 +1040,8 @@ pub mod pallet {
 			} else {
 				current_count?
 			};
-			Self::try_release_license(who, should_slash)?;
-			Ok(current_count)
+			let delegators = Self::try_release_license(who, should_slash)?;
+			Ok((current_count, delegators))
 		}
 
 		/// Removes a candidate from the collator pool for the next session if they exist.
@@ -533,8 +1062,82 @@ pub mod pallet {
 			Ok(current_count)
 		}
 
+		/// Removes an account from the waiting list if it is there.
+		fn try_remove_waiting_candidate(who: &T::AccountId) -> Result<usize, DispatchError> {
+			let current_count =
+				<WaitingCandidates<T>>::try_mutate(|waiting| -> Result<usize, DispatchError> {
+					let index = waiting
+						.iter()
+						.position(|w| *w == *who)
+						.ok_or(Error::<T>::NotWaiting)?;
+					waiting.remove(index);
+					Ok(waiting.len())
+				})?;
+			Self::deposit_event(Event::WaitingListLeft {
+				account_id: who.clone(),
+			});
+			Ok(current_count)
+		}
+
+		/// Moves the accounts from the front of the waiting list, which is kept in the order
+		/// of the license time, to the free candidate slots. Returns the updated candidates.
+		pub fn promote_waiting_candidates(
+			mut candidates: BoundedVec<T::AccountId, T::MaxCollators>,
+		) -> BoundedVec<T::AccountId, T::MaxCollators> {
+			let invulnerables = Self::invulnerables();
+			let free_slots = (T::MaxCollators::get() as usize)
+				.saturating_sub(invulnerables.len() + candidates.len());
+			if free_slots == 0 {
+				return candidates;
+			}
+
+			let mut waiting = Self::waiting_candidates().into_inner();
+			if waiting.is_empty() {
+				return candidates;
+			}
+
+			let next_authored_block =
+				frame_system::Pallet::<T>::block_number() + T::KickThreshold::get();
+			let mut promoted = 0;
+			let mut taken = 0;
+			for who in waiting.iter() {
+				if promoted == free_slots {
+					break;
+				}
+				taken += 1;
+				// the list is kept clean, but an account must not be a collator twice anyway.
+				if invulnerables.contains(who) || candidates.contains(who) {
+					continue;
+				}
+				if candidates.try_push(who.clone()).is_err() {
+					break;
+				}
+				promoted += 1;
+				// First authored block is current block plus kick threshold to handle session delay
+				<LastAuthoredBlock<T>>::insert(who.clone(), next_authored_block);
+				Self::deposit_event(Event::WaitingCandidatePromoted {
+					account_id: who.clone(),
+				});
+				Self::deposit_event(Event::CandidateAdded {
+					account_id: who.clone(),
+				});
+			}
+
+			let still_waiting: BoundedVec<_, T::MaxWaitingCandidates> = waiting
+				.split_off(taken)
+				.try_into()
+				.expect("remaining waiting candidates are a subset of the bounded list; qed");
+			<WaitingCandidates<T>>::put(still_waiting);
+			<Candidates<T>>::put(&candidates);
+			candidates
+		}
+
 		/// Removes a candidate if they exist and sends them back their deposit, optionally slashed.
-		fn try_release_license(who: &T::AccountId, should_slash: bool) -> DispatchResult {
+		/// The delegations to the candidate are unbonded, the number of delegators is returned.
+		fn try_release_license(
+			who: &T::AccountId,
+			should_slash: bool,
+		) -> Result<u32, DispatchError> {
 			let mut deposit_returned = BalanceOf::<T>::default();
 			LicenseDepositOf::<T>::try_mutate_exists(who, |deposit| -> DispatchResult {
 				if let Some(deposit) = deposit.take() {
@@ -558,16 +1161,212 @@ pub mod pallet {
 						deposit_returned,
 						Precision::Exact,
 					)?;
+					Self::decrease_backing(who, deposit);
+					<Commission<T>>::remove(who);
+					<Strikes<T>>::remove(who);
+					<LicenseObtainedAt<T>>::remove(who);
 					Ok(())
 				} else {
 					Err(Error::<T>::NoLicense.into())
 				}
 			})?;
+			let _ = Self::try_remove_waiting_candidate(who);
+			let delegators = Self::unbond_delegations(who);
 			Self::deposit_event(Event::LicenseReleased {
 				account_id: who.clone(),
 				deposit_returned,
 			});
-			Ok(())
+			Ok(delegators)
+		}
+
+		/// Undelegates all the funds delegated to `candidate`, they stay held for the
+		/// `UnbondingDelay` as usual. If a delegator has no unbonding requests left, the funds are
+		/// merged into its latest request, which is postponed to the new unlock block.
+		pub(crate) fn unbond_delegations(candidate: &T::AccountId) -> u32 {
+			let unlock_at = frame_system::Pallet::<T>::block_number() + T::UnbondingDelay::get();
+			let mut delegators = 0;
+			for (delegator, amount) in <Delegations<T>>::drain_prefix(candidate) {
+				<Unbonding<T>>::mutate(&delegator, |unbonding| {
+					if let Err(request) = unbonding.try_push((amount, unlock_at)) {
+						if let Some(last) = unbonding.last_mut() {
+							*last = (last.0.saturating_add(request.0), unlock_at);
+						}
+					}
+				});
+				Self::decrease_backing(candidate, amount);
+				Self::deposit_event(Event::Undelegated {
+					delegator,
+					candidate: candidate.clone(),
+					amount,
+					unlock_at,
+				});
+				delegators += 1;
+			}
+			<DelegatorCount<T>>::remove(candidate);
+			delegators
+		}
+
+		/// Decreases the total backing of the license holder, removing it once it drops to zero.
+		fn decrease_backing(who: &T::AccountId, amount: BalanceOf<T>) {
+			<TotalBacking<T>>::mutate_exists(who, |backing| {
+				*backing = backing
+					.map(|b| b.saturating_sub(amount))
+					.filter(|b| !b.is_zero());
+			});
+		}
+
+		/// Elects the candidates for the seats left after the invulnerables. If there are more
+		/// candidates than seats, the ones with the largest total backing are elected,
+		/// ties are resolved in the onboarding order.
+		pub fn elect_candidates(
+			candidates: BoundedVec<T::AccountId, T::MaxCollators>,
+		) -> BoundedVec<T::AccountId, T::MaxCollators> {
+			let seats = T::DesiredCollators::get()
+				.saturating_sub(<Invulnerables<T>>::decode_len().unwrap_or_default() as u32)
+				as usize;
+			if candidates.len() <= seats {
+				return candidates;
+			}
+
+			let mut ranked = candidates
+				.into_iter()
+				.map(|c| (Self::total_backing(&c), c))
+				.collect::<Vec<_>>();
+			// the sort is stable, so the equally backed candidates keep their order.
+			ranked.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+			ranked
+				.into_iter()
+				.take(seats)
+				.map(|(_, c)| c)
+				.collect::<Vec<_>>()
+				.try_into()
+				.expect("elected candidates are a subset of the bounded candidates; qed")
+		}
+
+		/// Stores the elected candidates, so that only they are checked for the missed authoring
+		/// windows. The newly elected candidates, whose window has passed while they were not
+		/// elected, get a new one.
+		pub fn note_elected(elected: &BoundedVec<T::AccountId, T::MaxCollators>) {
+			let now = frame_system::Pallet::<T>::block_number();
+			let previous = Self::elected_candidates();
+			for c in elected.iter().filter(|c| !previous.contains(c)) {
+				if <LastAuthoredBlock<T>>::get(c) < now {
+					// First authored block is current block plus kick threshold to handle session delay
+					<LastAuthoredBlock<T>>::insert(c, now + T::KickThreshold::get());
+				}
+			}
+			<ElectedCandidates<T>>::put(elected);
+		}
+
+		/// Queues the payout of the delegators of `collator`, who get their part of its reward
+		/// after the commission. Returns the part of the reward left to the collator.
+		fn queue_delegator_payout(
+			session: SessionIndex,
+			collator: &T::AccountId,
+			reward: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			let backing = Self::total_backing(collator);
+			if backing.is_zero() || <DelegatorCount<T>>::get(collator) == 0 {
+				return reward;
+			}
+
+			let shared = reward.saturating_sub(Self::commission(collator) * reward);
+			let delegated: u128 = backing
+				.saturating_sub(Self::license_deposit_of(collator))
+				.unique_saturated_into();
+			let total_backing: u128 = backing.unique_saturated_into();
+			let delegators_reward = Perbill::from_rational(delegated, total_backing) * shared;
+			if delegators_reward.is_zero() {
+				return reward;
+			}
+
+			<DelegatorPayouts<T>>::insert(
+				session,
+				collator,
+				DelegatorPayout {
+					shared,
+					backing,
+					remaining: delegators_reward,
+					last_delegator: None,
+				},
+			);
+			<PendingDelegatorRewards<T>>::mutate(|pending| {
+				*pending = pending.saturating_add(delegators_reward)
+			});
+			reward.saturating_sub(delegators_reward)
+		}
+
+		/// Pays at most `limit` delegators their parts of the queued collator rewards, in
+		/// proportion to their delegations. The rest of a payout left after all the delegators
+		/// of the collator are paid returns to the session rewards.
+		/// Returns the number of the delegators processed.
+		pub fn pay_delegator_rewards(limit: u32) -> u32 {
+			let pot = Self::account_id();
+			let mut processed = 0;
+			while processed < limit {
+				let Some((session, collator, mut payout)) = <DelegatorPayouts<T>>::iter().next()
+				else {
+					break;
+				};
+				let delegations = match &payout.last_delegator {
+					Some(last) => <Delegations<T>>::iter_prefix_from(
+						&collator,
+						<Delegations<T>>::hashed_key_for(&collator, last),
+					),
+					None => <Delegations<T>>::iter_prefix(&collator),
+				};
+
+				let total_backing: u128 = payout.backing.unique_saturated_into();
+				let mut released = BalanceOf::<T>::zero();
+				let mut finished = true;
+				for (delegator, delegation) in delegations {
+					if processed == limit {
+						finished = false;
+						break;
+					}
+					processed += 1;
+					payout.last_delegator = Some(delegator.clone());
+
+					let delegation: u128 = delegation.unique_saturated_into();
+					let delegator_reward = (Perbill::from_rational(delegation, total_backing)
+						* payout.shared)
+						.min(payout.remaining);
+					if delegator_reward.is_zero() {
+						continue;
+					}
+					if T::Currency::transfer(
+						&pot,
+						&delegator,
+						delegator_reward,
+						Preservation::Preserve,
+					)
+					.is_err()
+					{
+						log::warn!("Failed to reward delegator {:?}", delegator);
+						continue;
+					}
+
+					payout.remaining = payout.remaining.saturating_sub(delegator_reward);
+					released = released.saturating_add(delegator_reward);
+					Self::deposit_event(Event::DelegatorRewarded {
+						delegator,
+						candidate: collator.clone(),
+						reward: delegator_reward,
+					});
+				}
+
+				if finished {
+					released = released.saturating_add(payout.remaining);
+					<DelegatorPayouts<T>>::remove(session, &collator);
+				} else {
+					<DelegatorPayouts<T>>::insert(session, &collator, payout);
+				}
+				<PendingDelegatorRewards<T>>::mutate(|pending| {
+					*pending = pending.saturating_sub(released)
+				});
+			}
+			processed
 		}
 
 		/// Assemble the current set of candidates and invulnerables into the next collator set.
@@ -581,25 +1380,177 @@ pub mod pallet {
 			collators
 		}
 
-		/// Kicks out candidates that did not produce a block in the kick threshold
-		/// and **confiscates** their deposits to the treasury.
+		/// Distributes the value of the Pot between the collators, who authored blocks in the session,
+		/// in proportion to the number of blocks authored, and queues the payouts of their delegators.
+		/// Returns the number of rewarded collators.
+		pub fn distribute_session_rewards(session: SessionIndex) -> u32 {
+			let authored = <SessionAuthoredBlocks<T>>::drain().collect::<Vec<_>>();
+			let total_blocks = authored
+				.iter()
+				.fold(0u32, |total, (_, blocks)| total.saturating_add(*blocks));
+			if total_blocks == 0 {
+				return 0;
+			}
+
+			let pot = Self::account_id();
+			// assumes an ED will be sent to pot, the delegator rewards not paid yet are kept there too.
+			let reward_pool = T::Currency::balance(&pot)
+				.checked_sub(&T::Currency::minimum_balance())
+				.and_then(|pool| pool.checked_sub(&Self::pending_delegator_rewards()))
+				.unwrap_or_else(Zero::zero);
+			let invulnerables = Self::invulnerables();
+			let invulnerables_share = T::InvulnerablesRewardShare::get();
+
+			let mut remaining = reward_pool;
+			for (collator, blocks) in authored.iter() {
+				let mut reward = Perbill::from_rational(*blocks, total_blocks) * reward_pool;
+				if invulnerables.contains(collator) {
+					reward = invulnerables_share * reward;
+				} else {
+					let reward_before = reward.min(remaining);
+					let collator_reward =
+						Self::queue_delegator_payout(session, collator, reward_before);
+					remaining = remaining.saturating_sub(reward_before - collator_reward);
+					reward = collator_reward;
+				}
+				reward = reward.min(remaining);
+
+				if !reward.is_zero()
+					&& T::Currency::transfer(&pot, collator, reward, Preservation::Preserve)
+						.is_err()
+				{
+					log::warn!("Failed to reward collator {:?}", collator);
+					reward = Zero::zero();
+				}
+				remaining = remaining.saturating_sub(reward);
+
+				<CollatorRewards<T>>::mutate(collator, |info| {
+					info.session = session;
+					info.session_blocks = *blocks;
+					info.session_reward = reward;
+					info.total_reward = info.total_reward.saturating_add(reward);
+				});
+				Self::deposit_event(Event::CollatorRewarded {
+					account_id: collator.clone(),
+					blocks: *blocks,
+					reward,
+				});
+			}
+
+			Self::deposit_event(Event::SessionRewardsDistributed {
+				session,
+				blocks: total_blocks,
+				reward: reward_pool.saturating_sub(remaining),
+			});
+			authored.len() as u32
+		}
+
+		/// Slashes the `ratio` of the license bond of `who` to the treasury.
+		/// Returns the slashed amount.
+		fn slash_license(who: &T::AccountId, ratio: Perbill) -> BalanceOf<T> {
+			let deposit = LicenseDepositOf::<T>::get(who);
+			let to_slash = ratio * deposit;
+			if to_slash.is_zero() {
+				return Zero::zero();
+			}
+
+			let (imbalance, not_slashed) =
+				T::Currency::slash(&HoldReason::LicenseBond.into(), who, to_slash);
+			let slashed = to_slash.saturating_sub(not_slashed);
+			if T::Currency::resolve(&T::TreasuryAccountId::get(), imbalance).is_err() {
+				log::warn!("Failed to deposit the slashed license bond of {:?}", who);
+			}
+
+			LicenseDepositOf::<T>::insert(who, deposit.saturating_sub(slashed));
+			Self::decrease_backing(who, slashed);
+			slashed
+		}
+
+		/// Gives a strike to the candidate, who missed its authoring window, slashing
+		/// a part of its license bond. Returns the number of the candidate's strikes.
+		fn strike_candidate(who: &T::AccountId, session: SessionIndex) -> u32 {
+			let strikes = <Strikes<T>>::mutate(who, |info| {
+				let info = info.get_or_insert_with(Default::default);
+				info.strikes = info.strikes.saturating_add(1);
+				info.last_change = session;
+				info.strikes
+			});
+			let slashed = Self::slash_license(who, T::StrikeSlashRatio::get());
+
+			Self::deposit_event(Event::CandidateStruck {
+				account_id: who.clone(),
+				strikes,
+				slashed,
+			});
+			strikes
+		}
+
+		/// Forgives a strike of the candidate, if it had no strikes for `StrikeDecaySessions`.
+		fn decay_strikes(who: &T::AccountId, session: SessionIndex) {
+			let decay_sessions = T::StrikeDecaySessions::get();
+			if decay_sessions == 0 {
+				return;
+			}
+
+			<Strikes<T>>::mutate_exists(who, |maybe_info| {
+				let Some(info) = maybe_info else {
+					return;
+				};
+				if session.saturating_sub(info.last_change) < decay_sessions {
+					return;
+				}
+
+				info.strikes = info.strikes.saturating_sub(1);
+				info.last_change = session;
+				Self::deposit_event(Event::StrikeForgiven {
+					account_id: who.clone(),
+					strikes: info.strikes,
+				});
+				if info.strikes == 0 {
+					*maybe_info = None;
+				}
+			});
+		}
+
+		/// Gives strikes to the elected candidates that did not produce a block in the kick threshold.
+		/// The candidates who reached `MaxStrikes` are kicked out and the rest of their deposits
+		/// is returned, as each strike has already slashed its `StrikeSlashRatio` to the treasury.
 		pub fn kick_stale_candidates(
+			session: SessionIndex,
 			candidates: BoundedVec<T::AccountId, T::MaxCollators>,
 		) -> BoundedVec<T::AccountId, T::MaxCollators> {
 			let now = frame_system::Pallet::<T>::block_number();
 			let kick_threshold = T::KickThreshold::get();
+			let max_strikes = T::MaxStrikes::get();
+			let elected = Self::elected_candidates();
 			candidates
 				.into_iter()
 				.filter_map(|c| {
+					// the candidates left out by the election could not author blocks.
+					if !elected.contains(&c) {
+						return Some(c);
+					}
 					let last_block = <LastAuthoredBlock<T>>::get(c.clone());
 					let since_last = now.saturating_sub(last_block);
 					if since_last < kick_threshold {
+						Self::decay_strikes(&c, session);
+						Some(c)
+					} else if Self::strike_candidate(&c, session) < max_strikes {
+						// the candidate gets another authoring window.
+						<LastAuthoredBlock<T>>::insert(c.clone(), now);
 						Some(c)
 					} else {
-						let outcome = Self::try_remove_candidate_and_release_license(&c, true, false);
-						if let Err(why) = outcome {
-							log::warn!("Failed to kick collator and release license {:?}", why);
-							debug_assert!(false, "failed to kick collator and release license {why:?}");
+						match Self::try_remove_candidate_and_release_license(&c, false, false) {
+							Ok((_, delegators)) => {
+								frame_system::Pallet::<T>::register_extra_weight_unchecked(
+									<T as Config>::WeightInfo::unbond_delegations(delegators),
+									DispatchClass::Mandatory,
+								);
+							}
+							Err(why) => {
+								log::warn!("Failed to kick collator and release license {:?}", why);
+								debug_assert!(false, "failed to kick collator and release license {why:?}");
+							}
 						}
 						None
 					}
@@ -616,18 +1567,9 @@ pub mod pallet {
 		pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T>
 	{
 		fn note_author(author: T::AccountId) {
-			let pot = Self::account_id();
-			// assumes an ED will be sent to pot.
-			let reward = T::Currency::balance(&pot)
-				.checked_sub(&T::Currency::minimum_balance())
-				.unwrap_or_else(Zero::zero)
-				.div(2u32.into());
-
-			if !reward.is_zero() {
-				// `reward` is half of pot account minus ED, this should never fail.
-				let _success = T::Currency::transfer(&pot, &author, reward, Preservation::Preserve);
-				debug_assert!(_success.is_ok());
-			}
+			<SessionAuthoredBlocks<T>>::mutate(&author, |blocks| {
+				*blocks = blocks.saturating_add(1)
+			});
 			<LastAuthoredBlock<T>>::insert(author, frame_system::Pallet::<T>::block_number());
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
@@ -648,15 +1590,23 @@ pub mod pallet {
 
 			let candidates = Self::candidates();
 			let candidates_len_before = candidates.len();
-			let active_candidates = Self::kick_stale_candidates(candidates);
+			let active_candidates = Self::kick_stale_candidates(index, candidates);
 			let removed = candidates_len_before - active_candidates.len();
-			let result = Self::assemble_collators(active_candidates);
+			let kept = active_candidates.len();
+			let active_candidates = Self::promote_waiting_candidates(active_candidates);
+			let promoted = active_candidates.len() - kept;
+			let elected = Self::elect_candidates(active_candidates);
+			Self::note_elected(&elected);
+			let result = Self::assemble_collators(elected);
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
 				<T as Config>::WeightInfo::new_session(
 					candidates_len_before as u32,
 					removed as u32,
-				),
+				)
+				.saturating_add(<T as Config>::WeightInfo::promote_waiting_candidates(
+					promoted as u32,
+				)),
 				DispatchClass::Mandatory,
 			);
 			Some(result)
@@ -664,8 +1614,13 @@ pub mod pallet {
 		fn start_session(_: SessionIndex) {
 			// we don't care.
 		}
-		fn end_session(_: SessionIndex) {
-			// we don't care.
+		fn end_session(index: SessionIndex) {
+			let collators = Self::distribute_session_rewards(index);
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				<T as Config>::WeightInfo::end_session(collators),
+				DispatchClass::Mandatory,
+			);
 		}
 	}
 }
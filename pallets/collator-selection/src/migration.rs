@@ -0,0 +1,60 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the Collator Selection pallet.
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+use crate::{Candidates, Config, ElectedCandidates, LicenseDepositOf, Pallet, TotalBacking};
+
+/// Seed the total backing of the license holders with their license bonds,
+/// so that they can be ranked against the delegated candidates.
+/// All the candidates were collators before the election, so they are seeded as elected.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let on_chain_version = <Pallet<T>>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain_version >= 1 {
+		log::info!(
+			target: "runtime::collator-selection",
+			"Total backing is already migrated, skipping",
+		);
+		return weight;
+	}
+
+	let mut license_holders = 0u64;
+	for (who, deposit) in <LicenseDepositOf<T>>::iter() {
+		<TotalBacking<T>>::insert(who, deposit);
+		license_holders += 1;
+	}
+	<ElectedCandidates<T>>::put(<Candidates<T>>::get());
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	log::info!(
+		target: "runtime::collator-selection",
+		"Migrated total backing of {} license holders",
+		license_holders,
+	);
+
+	weight.saturating_accrue(T::DbWeight::get().reads_writes(
+		license_holders.saturating_add(1),
+		license_holders.saturating_add(2),
+	));
+	weight
+}
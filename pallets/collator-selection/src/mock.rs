@@ -222,6 +222,17 @@ parameter_types! {
 	pub const PotId: PalletId = PalletId(*b"PotStake");
 	pub const MaxAuthorities: u32 = 100_000;
 	pub const SlashRatio: Perbill = Perbill::one();
+	pub static InvulnerablesRewardShare: Perbill = Perbill::one();
+	pub static DesiredCollators: u32 = MaxCollators::get();
+	pub const MinDelegation: u64 = 5;
+	pub const MaxDelegatorsPerCandidate: u32 = 2;
+	pub const MaxUnbondingRequests: u32 = 16;
+	pub const UnbondingDelay: u32 = 5;
+	pub static StrikeSlashRatio: Perbill = Perbill::from_percent(10);
+	pub static MaxStrikes: u32 = 1;
+	pub static StrikeDecaySessions: u32 = 2;
+	pub static MaxWaitingCandidates: u32 = 64;
+	pub static MaxDelegatorPayoutsPerBlock: u32 = 64;
 }
 
 pub struct IsRegistered;
@@ -243,9 +254,19 @@ impl Config for Test {
 	type ValidatorIdOf = IdentityCollator;
 	type ValidatorRegistration = IsRegistered;
 	type Currency = Balances;
-	type DesiredCollators = MaxCollators;
+	type DesiredCollators = DesiredCollators;
 	type LicenseBond = LicenseBond;
 	type KickThreshold = KickThreshold;
+	type InvulnerablesRewardShare = InvulnerablesRewardShare;
+	type MinDelegation = MinDelegation;
+	type MaxDelegatorsPerCandidate = MaxDelegatorsPerCandidate;
+	type MaxUnbondingRequests = MaxUnbondingRequests;
+	type UnbondingDelay = UnbondingDelay;
+	type StrikeSlashRatio = StrikeSlashRatio;
+	type MaxStrikes = MaxStrikes;
+	type StrikeDecaySessions = StrikeDecaySessions;
+	type MaxWaitingCandidates = MaxWaitingCandidates;
+	type MaxDelegatorPayoutsPerBlock = MaxDelegatorPayoutsPerBlock;
 	type WeightInfo = ();
 }
 
@@ -34,10 +34,14 @@ use frame_support::{
 	assert_noop, assert_ok,
 	traits::{fungible, OnInitialize},
 };
+use pallet_authorship::EventHandler;
+use pallet_session::SessionManager;
 use scale_info::prelude::*;
-use sp_runtime::{traits::BadOrigin, BuildStorage, TokenError};
+use sp_runtime::{traits::BadOrigin, BuildStorage, Perbill, TokenError};
 
-use crate::{self as collator_selection, mock::*, Config, Error};
+use crate::{
+	self as collator_selection, mock::*, CollatorRewardInfo, Config, Error, Event, StrikeInfo,
+};
 
 fn get_license_and_onboard(account_id: <Test as frame_system::Config>::AccountId) {
 	assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(
@@ -133,14 +137,14 @@ fn cannot_onboard_candidate_with_no_license() {
 #[test]
 fn cannot_onboard_candidate_if_too_many() {
 	new_test_ext().execute_with(|| {
-		// can accept desired value of collators.
-		for c in 3u64..=(<Test as Config>::DesiredCollators::get()).into() {
+		// can accept max value of collators.
+		for c in 3u64..=(<Test as Config>::MaxCollators::get()).into() {
 			assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(c)));
 			assert_ok!(CollatorSelection::onboard(RuntimeOrigin::signed(c)));
 		}
 
 		// but no more.
-		let undesired_collator = (<Test as Config>::DesiredCollators::get() + 1) as u64;
+		let undesired_collator = (<Test as Config>::MaxCollators::get() + 1) as u64;
 		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(
 			undesired_collator
 		)));
@@ -364,11 +368,18 @@ fn authorship_event_handler() {
 
 		assert_eq!(CollatorSelection::candidates(), vec![4]);
 		assert_eq!(CollatorSelection::last_authored_block(4), 0);
+		assert_eq!(CollatorSelection::session_authored_blocks(4), 1);
+
+		// nothing is paid until the end of the session.
+		assert_eq!(Balances::free_balance(4), 90);
+		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 105);
+
+		CollatorSelection::end_session(0);
 
-		// half of the pot goes to the collator who's the author (4 in tests).
-		assert_eq!(Balances::free_balance(4), 140);
-		// half + ED stays.
-		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 55);
+		// the whole pot goes to the only author (4 in tests), ED stays.
+		assert_eq!(Balances::free_balance(4), 190);
+		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 5);
+		assert_eq!(CollatorSelection::session_authored_blocks(4), 0);
 	});
 }
 
@@ -387,6 +398,7 @@ fn fees_edgecases() {
 
 		assert_eq!(CollatorSelection::candidates(), vec![4]);
 		assert_eq!(CollatorSelection::last_authored_block(4), 0);
+		CollatorSelection::end_session(0);
 		// Nothing received
 		assert_eq!(Balances::free_balance(4), 90);
 		// all fee stays
@@ -394,6 +406,341 @@ fn fees_edgecases() {
 	});
 }
 
+#[test]
+fn session_rewards_are_proportional_to_authored_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// put 100 in the pot + 5 for ED
+		<Balances as fungible::Mutate<_>>::set_balance(&CollatorSelection::account_id(), 105);
+
+		for _ in 0..3 {
+			CollatorSelection::note_author(4);
+		}
+		CollatorSelection::note_author(3);
+		assert_eq!(CollatorSelection::session_authored_blocks(4), 3);
+
+		CollatorSelection::end_session(1);
+
+		assert_eq!(Balances::free_balance(4), 175);
+		assert_eq!(Balances::free_balance(3), 125);
+		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 5);
+		assert_eq!(
+			CollatorSelection::collator_rewards(4),
+			CollatorRewardInfo {
+				session: 1,
+				session_blocks: 3,
+				session_reward: 75,
+				total_reward: 75,
+			}
+		);
+		System::assert_has_event(
+			Event::CollatorRewarded {
+				account_id: 3,
+				blocks: 1,
+				reward: 25,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::SessionRewardsDistributed {
+				session: 1,
+				blocks: 4,
+				reward: 100,
+			}
+			.into(),
+		);
+
+		// nothing authored, nothing distributed
+		<Balances as fungible::Mutate<_>>::set_balance(&CollatorSelection::account_id(), 105);
+		CollatorSelection::end_session(2);
+		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 105);
+		assert_eq!(CollatorSelection::collator_rewards(4).total_reward, 75);
+	});
+}
+
+#[test]
+fn invulnerables_receive_reward_share() {
+	new_test_ext().execute_with(|| {
+		InvulnerablesRewardShare::set(Perbill::from_percent(50));
+		// put 100 in the pot + 5 for ED
+		<Balances as fungible::Mutate<_>>::set_balance(&CollatorSelection::account_id(), 105);
+
+		// 1 is invulnerable
+		CollatorSelection::note_author(1);
+		CollatorSelection::note_author(4);
+
+		CollatorSelection::end_session(1);
+
+		assert_eq!(Balances::free_balance(1), 125);
+		assert_eq!(Balances::free_balance(4), 150);
+		// the rest of the invulnerable reward stays in the pot
+		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 30);
+	});
+}
+
+#[test]
+fn delegation_counts_towards_backing() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(3)));
+		assert_eq!(CollatorSelection::total_backing(3), 10);
+
+		// can only delegate to license holders.
+		assert_noop!(
+			CollatorSelection::delegate(RuntimeOrigin::signed(5), 4, 20),
+			Error::<Test>::NoLicense,
+		);
+		assert_noop!(
+			CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 3),
+			Error::<Test>::DelegationTooLow,
+		);
+
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 20));
+		System::assert_last_event(
+			Event::Delegated {
+				delegator: 5,
+				candidate: 3,
+				amount: 20,
+			}
+			.into(),
+		);
+		// top-ups below the minimum are fine.
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 1));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(4), 3, 10));
+
+		assert_eq!(Balances::free_balance(5), 79);
+		assert_eq!(CollatorSelection::delegation(3, 5), 21);
+		assert_eq!(CollatorSelection::delegator_count(3), 2);
+		assert_eq!(CollatorSelection::total_backing(3), 41);
+
+		// no more than `MaxDelegatorsPerCandidate`.
+		assert_noop!(
+			CollatorSelection::delegate(RuntimeOrigin::signed(6), 3, 10),
+			Error::<Test>::TooManyDelegators,
+		);
+
+		// the delegations are unbonded with the license.
+		assert_ok!(CollatorSelection::release_license(RuntimeOrigin::signed(3)));
+		assert_eq!(CollatorSelection::total_backing(3), 0);
+		assert_eq!(CollatorSelection::delegation(3, 5), 0);
+		assert_eq!(CollatorSelection::delegator_count(3), 0);
+		assert_eq!(CollatorSelection::unbonding(5).into_inner(), vec![(21, 6)]);
+		assert_eq!(CollatorSelection::unbonding(4).into_inner(), vec![(10, 6)]);
+	});
+}
+
+#[test]
+fn released_license_unbonds_delegations() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 20));
+
+		// fill up the unbonding requests of the delegator.
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 4, 30));
+		for _ in 0..MaxUnbondingRequests::get() {
+			assert_ok!(CollatorSelection::undelegate(
+				RuntimeOrigin::signed(5),
+				4,
+				1
+			));
+		}
+
+		System::set_block_number(2);
+		assert_ok!(CollatorSelection::force_release_license(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3
+		));
+		System::assert_has_event(
+			Event::Undelegated {
+				delegator: 5,
+				candidate: 3,
+				amount: 20,
+				unlock_at: 7,
+			}
+			.into(),
+		);
+		assert_eq!(CollatorSelection::total_backing(3), 0);
+
+		// the funds are merged into the latest request.
+		let unbonding = CollatorSelection::unbonding(5);
+		assert_eq!(unbonding.len() as u32, MaxUnbondingRequests::get());
+		assert_eq!(unbonding.last(), Some(&(21, 7)));
+
+		System::set_block_number(7);
+		assert_ok!(CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(
+			5
+		)));
+		assert_eq!(Balances::free_balance(5), 86);
+	});
+}
+
+#[test]
+fn undelegated_funds_are_unbonded_after_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 3, 20));
+
+		assert_noop!(
+			CollatorSelection::undelegate(RuntimeOrigin::signed(5), 3, 21),
+			Error::<Test>::InsufficientDelegation,
+		);
+		assert_noop!(
+			CollatorSelection::undelegate(RuntimeOrigin::signed(5), 3, 17),
+			Error::<Test>::DelegationTooLow,
+		);
+
+		assert_ok!(CollatorSelection::undelegate(
+			RuntimeOrigin::signed(5),
+			3,
+			10
+		));
+		assert_ok!(CollatorSelection::undelegate(
+			RuntimeOrigin::signed(5),
+			3,
+			10
+		));
+		System::assert_last_event(
+			Event::Undelegated {
+				delegator: 5,
+				candidate: 3,
+				amount: 10,
+				unlock_at: 6,
+			}
+			.into(),
+		);
+		assert_eq!(CollatorSelection::total_backing(3), 10);
+		assert_eq!(CollatorSelection::delegator_count(3), 0);
+
+		// the funds are still held.
+		assert_eq!(Balances::free_balance(5), 80);
+		assert_noop!(
+			CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(5)),
+			Error::<Test>::NothingToWithdraw,
+		);
+
+		System::set_block_number(6);
+		assert_ok!(CollatorSelection::withdraw_unbonded(RuntimeOrigin::signed(
+			5
+		)));
+		System::assert_last_event(
+			Event::UnbondedWithdrawn {
+				delegator: 5,
+				amount: 20,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(5), 100);
+		assert!(CollatorSelection::unbonding(5).is_empty());
+	});
+}
+
+#[test]
+fn most_backed_candidates_are_elected() {
+	new_test_ext().execute_with(|| {
+		// one seat is left after the invulnerables.
+		DesiredCollators::set(3);
+
+		get_license_and_onboard(3);
+		get_license_and_onboard(4);
+		assert_eq!(CollatorSelection::new_session(1), Some(vec![1, 2, 3]));
+
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 4, 10));
+		assert_eq!(CollatorSelection::new_session(2), Some(vec![1, 2, 4]));
+
+		// the candidate left out is not kicked as stale, nor its authoring window is renewed.
+		initialize_to_block(30);
+		assert_eq!(CollatorSelection::candidates(), vec![3, 4]);
+		assert_eq!(CollatorSelection::elected_candidates(), vec![4]);
+		assert_eq!(CollatorSelection::last_authored_block(3), 10);
+		assert_eq!(CollatorSelection::strikes(3), None);
+
+		// once elected, it gets a new authoring window.
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(6), 3, 20));
+		assert_eq!(CollatorSelection::new_session(4), Some(vec![1, 2, 3]));
+		assert_eq!(CollatorSelection::last_authored_block(3), 40);
+	});
+}
+
+#[test]
+fn delegators_share_reward_after_commission() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// put 100 in the pot + 5 for ED
+		<Balances as fungible::Mutate<_>>::set_balance(&CollatorSelection::account_id(), 105);
+
+		get_license_and_onboard(4);
+		assert_noop!(
+			CollatorSelection::set_commission(RuntimeOrigin::signed(5), Perbill::from_percent(20)),
+			Error::<Test>::NoLicense,
+		);
+		assert_ok!(CollatorSelection::set_commission(
+			RuntimeOrigin::signed(4),
+			Perbill::from_percent(20)
+		));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 4, 30));
+
+		CollatorSelection::note_author(4);
+		CollatorSelection::end_session(1);
+
+		// 20 of commission, and 80 shared as 10 to 30 of the backing.
+		assert_eq!(Balances::free_balance(4), 130);
+		// the delegators are paid in the next block.
+		assert_eq!(Balances::free_balance(5), 70);
+		assert_eq!(CollatorSelection::pending_delegator_rewards(), 60);
+		initialize_to_block(2);
+		assert_eq!(Balances::free_balance(5), 130);
+		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 5);
+		assert_eq!(CollatorSelection::pending_delegator_rewards(), 0);
+		System::assert_has_event(
+			Event::DelegatorRewarded {
+				delegator: 5,
+				candidate: 4,
+				reward: 60,
+			}
+			.into(),
+		);
+		assert_eq!(CollatorSelection::collator_rewards(4).session_reward, 40);
+	});
+}
+
+#[test]
+fn delegator_rewards_are_paid_in_pages() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxDelegatorPayoutsPerBlock::set(1);
+		// put 100 in the pot + 5 for ED
+		<Balances as fungible::Mutate<_>>::set_balance(&CollatorSelection::account_id(), 105);
+
+		get_license_and_onboard(4);
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(5), 4, 20));
+		assert_ok!(CollatorSelection::delegate(RuntimeOrigin::signed(6), 4, 10));
+
+		CollatorSelection::note_author(4);
+		CollatorSelection::end_session(1);
+		assert_eq!(Balances::free_balance(4), 115);
+		assert_eq!(CollatorSelection::pending_delegator_rewards(), 75);
+
+		// the pending delegator rewards are not distributed again.
+		CollatorSelection::note_author(4);
+		CollatorSelection::end_session(2);
+		assert_eq!(CollatorSelection::collator_rewards(4).session_reward, 0);
+
+		initialize_to_block(2);
+		assert!(CollatorSelection::delegator_payouts(1, 4).is_some());
+		assert!(CollatorSelection::pending_delegator_rewards() < 75);
+
+		initialize_to_block(3);
+		assert!(CollatorSelection::delegator_payouts(1, 4).is_none());
+		assert_eq!(CollatorSelection::pending_delegator_rewards(), 0);
+		assert_eq!(Balances::free_balance(5), 130);
+		assert_eq!(Balances::free_balance(6), 115);
+		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 5);
+	});
+}
+
 #[test]
 fn session_management_works() {
 	new_test_ext().execute_with(|| {
@@ -455,8 +802,261 @@ fn kick_mechanism() {
 		initialize_to_block(30);
 		// 3 gets kicked after 1 session delay
 		assert_eq!(SessionHandlerCollators::get(), vec![1, 2, 4]);
-		// kicked collator gets their funds slashed, the deposit going to treasury
-		assert_eq!(Balances::free_balance(3), 90);
+		// kicked collator gets back the deposit left after the strike slash
+		assert_eq!(Balances::free_balance(3), 99);
+	});
+}
+
+#[test]
+fn stale_candidates_are_struck_before_kick() {
+	new_test_ext().execute_with(|| {
+		MaxStrikes::set(3);
+		get_license_and_onboard(3);
+		get_license_and_onboard(4);
+
+		initialize_to_block(20);
+		// 3 missed its authoring window, but stays with a strike
+		assert_eq!(CollatorSelection::candidates(), vec![3, 4]);
+		assert_eq!(CollatorSelection::strikes(3).map(|s| s.strikes), Some(1));
+		assert_eq!(CollatorSelection::license_deposit_of(3), 9);
+		assert_eq!(CollatorSelection::total_backing(3), 9);
+		assert_eq!(CollatorSelection::last_authored_block(3), 20);
+		System::assert_has_event(
+			Event::CandidateStruck {
+				account_id: 3,
+				strikes: 1,
+				slashed: 1,
+			}
+			.into(),
+		);
+
+		initialize_to_block(30);
+		assert_eq!(CollatorSelection::candidates(), vec![3, 4]);
+		assert_eq!(CollatorSelection::strikes(3).map(|s| s.strikes), Some(2));
+		assert_eq!(CollatorSelection::license_deposit_of(3), 8);
+
+		initialize_to_block(40);
+		// the third strike gets 3 kicked
+		assert_eq!(CollatorSelection::candidates(), vec![4]);
+		assert_eq!(CollatorSelection::strikes(3), None);
+		assert_eq!(CollatorSelection::license_deposit_of(3), 0);
+		// only the strikes are slashed, the rest of the deposit is returned.
+		assert_eq!(Balances::free_balance(3), 97);
+	});
+}
+
+#[test]
+fn strikes_are_forgiven_over_sessions() {
+	new_test_ext().execute_with(|| {
+		MaxStrikes::set(3);
+		get_license_and_onboard(3);
+
+		initialize_to_block(20);
+		assert_eq!(
+			CollatorSelection::strikes(3),
+			Some(StrikeInfo {
+				strikes: 1,
+				last_change: 3,
+			})
+		);
+
+		initialize_to_block(25);
+		CollatorSelection::note_author(3);
+		initialize_to_block(30);
+		// not enough sessions passed since the strike
+		assert_eq!(CollatorSelection::strikes(3).map(|s| s.strikes), Some(1));
+
+		initialize_to_block(35);
+		CollatorSelection::note_author(3);
+		initialize_to_block(40);
+		assert_eq!(CollatorSelection::strikes(3), None);
+		System::assert_has_event(
+			Event::StrikeForgiven {
+				account_id: 3,
+				strikes: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn waiting_list_join_and_leave() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CollatorSelection::join_waiting_list(RuntimeOrigin::signed(6)),
+			Error::<Test>::NoLicense
+		);
+
+		get_license_and_onboard(3);
+		get_license_and_onboard(4);
+		get_license_and_onboard(5);
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(6)));
+		assert_noop!(
+			CollatorSelection::onboard(RuntimeOrigin::signed(6)),
+			Error::<Test>::TooManyCandidates
+		);
+		assert_noop!(
+			CollatorSelection::join_waiting_list(RuntimeOrigin::signed(3)),
+			Error::<Test>::AlreadyCandidate
+		);
+
+		assert_ok!(CollatorSelection::join_waiting_list(RuntimeOrigin::signed(
+			6
+		)));
+		System::assert_last_event(
+			Event::WaitingListJoined {
+				account_id: 6,
+				position: 1,
+			}
+			.into(),
+		);
+		assert_eq!(CollatorSelection::waiting_candidates(), vec![6]);
+		assert_noop!(
+			CollatorSelection::join_waiting_list(RuntimeOrigin::signed(6)),
+			Error::<Test>::AlreadyWaiting
+		);
+
+		// the list is bounded
+		MaxWaitingCandidates::set(1);
+		assert_ok!(CollatorSelection::offboard(RuntimeOrigin::signed(5)));
+		assert_noop!(
+			CollatorSelection::join_waiting_list(RuntimeOrigin::signed(5)),
+			Error::<Test>::TooManyWaitingCandidates
+		);
+
+		assert_ok!(CollatorSelection::leave_waiting_list(
+			RuntimeOrigin::signed(6)
+		));
+		System::assert_last_event(Event::WaitingListLeft { account_id: 6 }.into());
+		assert!(CollatorSelection::waiting_candidates().is_empty());
+		// the license is kept
+		assert_eq!(CollatorSelection::license_deposit_of(6), 10);
+		assert_noop!(
+			CollatorSelection::leave_waiting_list(RuntimeOrigin::signed(6)),
+			Error::<Test>::NotWaiting
+		);
+	});
+}
+
+#[test]
+fn waiting_candidates_are_promoted_to_free_slots() {
+	new_test_ext().execute_with(|| {
+		get_license_and_onboard(3);
+		get_license_and_onboard(4);
+		get_license_and_onboard(5);
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(6)));
+		assert_ok!(CollatorSelection::join_waiting_list(RuntimeOrigin::signed(
+			6
+		)));
+
+		initialize_to_block(10);
+		// no slots are free yet
+		assert_eq!(CollatorSelection::candidates(), vec![3, 4, 5]);
+		assert_eq!(CollatorSelection::waiting_candidates(), vec![6]);
+
+		assert_ok!(CollatorSelection::offboard(RuntimeOrigin::signed(5)));
+		initialize_to_block(20);
+		assert_eq!(CollatorSelection::candidates(), vec![4, 6]);
+		assert!(CollatorSelection::waiting_candidates().is_empty());
+		// the promoted candidate gets the same grace period as an onboarded one
+		assert_eq!(CollatorSelection::last_authored_block(6), 30);
+		System::assert_has_event(Event::WaitingCandidatePromoted { account_id: 6 }.into());
+
+		initialize_to_block(30);
+		assert_eq!(SessionHandlerCollators::get(), vec![1, 2, 4, 6]);
+	});
+}
+
+#[test]
+fn waiting_list_is_ordered_by_license_time() {
+	new_test_ext().execute_with(|| {
+		get_license_and_onboard(3);
+		get_license_and_onboard(4);
+		System::set_block_number(1);
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(5)));
+		System::set_block_number(2);
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(6)));
+		assert_eq!(CollatorSelection::license_obtained_at(5), 1);
+		assert_eq!(CollatorSelection::license_obtained_at(6), 2);
+
+		assert_ok!(CollatorSelection::join_waiting_list(RuntimeOrigin::signed(
+			6
+		)));
+		// the earlier license goes before the earlier join.
+		assert_ok!(CollatorSelection::join_waiting_list(RuntimeOrigin::signed(
+			5
+		)));
+		System::assert_last_event(
+			Event::WaitingListJoined {
+				account_id: 5,
+				position: 1,
+			}
+			.into(),
+		);
+		assert_eq!(CollatorSelection::waiting_candidates(), vec![5, 6]);
+		assert_noop!(
+			CollatorSelection::onboard(RuntimeOrigin::signed(6)),
+			Error::<Test>::WaitingListNotEmpty
+		);
+
+		// the only free slot is taken by the earliest license holder.
+		initialize_to_block(10);
+		assert_eq!(CollatorSelection::candidates(), vec![3, 4, 5]);
+		assert_eq!(CollatorSelection::waiting_candidates(), vec![6]);
+
+		assert_ok!(CollatorSelection::release_license(RuntimeOrigin::signed(6)));
+		assert_eq!(CollatorSelection::license_obtained_at(6), 0);
+	});
+}
+
+#[test]
+fn only_first_waiting_candidate_can_onboard() {
+	new_test_ext().execute_with(|| {
+		get_license_and_onboard(3);
+		get_license_and_onboard(4);
+		get_license_and_onboard(5);
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(6)));
+		assert_ok!(CollatorSelection::join_waiting_list(RuntimeOrigin::signed(
+			6
+		)));
+
+		// a slot is freed, but the waiting accounts come first.
+		assert_ok!(CollatorSelection::offboard(RuntimeOrigin::signed(5)));
+		assert_ok!(CollatorSelection::join_waiting_list(RuntimeOrigin::signed(
+			5
+		)));
+		assert_noop!(
+			CollatorSelection::onboard(RuntimeOrigin::signed(5)),
+			Error::<Test>::WaitingListNotEmpty
+		);
+
+		assert_ok!(CollatorSelection::onboard(RuntimeOrigin::signed(6)));
+		assert_eq!(CollatorSelection::candidates(), vec![3, 4, 6]);
+		assert_eq!(CollatorSelection::waiting_candidates(), vec![5]);
+
+		// accounts off the list cannot skip it either.
+		assert_ok!(CollatorSelection::offboard(RuntimeOrigin::signed(3)));
+		assert_noop!(
+			CollatorSelection::onboard(RuntimeOrigin::signed(3)),
+			Error::<Test>::WaitingListNotEmpty
+		);
+		assert_ok!(CollatorSelection::onboard(RuntimeOrigin::signed(5)));
+		assert!(CollatorSelection::waiting_candidates().is_empty());
+	});
+}
+
+#[test]
+fn released_license_leaves_waiting_list() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorSelection::get_license(RuntimeOrigin::signed(6)));
+		assert_ok!(CollatorSelection::join_waiting_list(RuntimeOrigin::signed(
+			6
+		)));
+
+		assert_ok!(CollatorSelection::release_license(RuntimeOrigin::signed(6)));
+		assert!(CollatorSelection::waiting_candidates().is_empty());
+		System::assert_has_event(Event::WaitingListLeft { account_id: 6 }.into());
 	});
 }
 
@@ -42,6 +42,16 @@ pub trait WeightInfo {
 	fn force_release_license(c: u32, ) -> Weight;
 	fn note_author() -> Weight;
 	fn new_session(r: u32, c: u32, ) -> Weight;
+	fn promote_waiting_candidates(p: u32, ) -> Weight;
+	fn end_session(c: u32, ) -> Weight;
+	fn pay_delegator_rewards(d: u32, ) -> Weight;
+	fn unbond_delegations(d: u32, ) -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn withdraw_unbonded(r: u32, ) -> Weight;
+	fn set_commission() -> Weight;
+	fn join_waiting_list(w: u32, ) -> Weight;
+	fn leave_waiting_list(w: u32, ) -> Weight;
 }
 
 /// Weights for pallet_collator_selection using the Substrate node and recommended hardware.
@@ -53,6 +63,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[2, 8]`.
 	fn add_invulnerable(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -62,7 +74,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(15_670_195, 3829)
 			// Standard Error: 1_273
 			.saturating_add(Weight::from_parts(143_228, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 45).saturating_mul(b.into()))
 	}
@@ -88,6 +100,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Configuration::CollatorSelectionLicenseBondOverride` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseObtainedAt` (r:0 w:1)
+	/// Proof: `CollatorSelection::LicenseObtainedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 10]`.
 	fn get_license(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -97,8 +113,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(48_724_759, 4119)
 			// Standard Error: 9_236
 			.saturating_add(Weight::from_parts(471_224, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 47).saturating_mul(c.into()))
 	}
 	/// Storage: `CollatorSelection::LicenseDepositOf` (r:1 w:0)
@@ -113,6 +129,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Configuration::CollatorSelectionKickThresholdOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[2, 8]`.
 	fn onboard(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -122,8 +140,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(20_699_457, 3529)
 			// Standard Error: 3_047
 			.saturating_add(Weight::from_parts(220_727, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
@@ -147,8 +165,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Commission` (r:0 w:1)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseObtainedAt` (r:0 w:1)
+	/// Proof: `CollatorSelection::LicenseObtainedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 8]`.
 	fn release_license(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -158,8 +184,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(44_367_374, 3694)
 			// Standard Error: 8_686
 			.saturating_add(Weight::from_parts(626_453, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
@@ -167,8 +193,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Commission` (r:0 w:1)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseObtainedAt` (r:0 w:1)
+	/// Proof: `CollatorSelection::LicenseObtainedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 8]`.
 	fn force_release_license(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -178,29 +212,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(43_827_564, 3694)
 			// Standard Error: 8_984
 			.saturating_add(Weight::from_parts(639_240, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
-	/// Storage: `System::Account` (r:2 w:2)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::SessionAuthoredBlocks` (r:1 w:1)
+	/// Proof: `CollatorSelection::SessionAuthoredBlocks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `System::BlockWeight` (r:1 w:1)
 	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn note_author() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `155`
-		//  Estimated: `6196`
-		// Minimum execution time: 36_824_000 picoseconds.
-		Weight::from_parts(37_535_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `4`
+		//  Estimated: `3509`
+		// Minimum execution time: 12_105_000 picoseconds.
+		Weight::from_parts(12_514_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
 	/// Storage: `Configuration::CollatorSelectionKickThresholdOverride` (r:1 w:0)
 	/// Proof: `Configuration::CollatorSelectionKickThresholdOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:8 w:0)
+	/// Storage: `CollatorSelection::ElectedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::ElectedCandidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:8 w:8)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
@@ -212,22 +248,247 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:8 w:8)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionDesiredCollatorsOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionDesiredCollatorsOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:8 w:0)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionMaxStrikesOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionMaxStrikesOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionStrikeSlashRatioOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionStrikeSlashRatioOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionStrikeDecaySessionsOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionStrikeDecaySessionsOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Strikes` (r:8 w:8)
+	/// Proof: `CollatorSelection::Strikes` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 8]`.
 	/// The range of component `c` is `[1, 8]`.
 	fn new_session(r: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `725 + c * (84 ±0) + r * (239 ±0)`
-		//  Estimated: `26857 + c * (2519 ±0) + r * (2704 ±6)`
-		// Minimum execution time: 15_578_000 picoseconds.
-		Weight::from_parts(15_959_000, 26857)
+		//  Estimated: `27673 + c * (2519 ±0) + r * (2704 ±6)`
+		// Minimum execution time: 16_204_000 picoseconds.
+		Weight::from_parts(16_611_000, 27673)
 			// Standard Error: 332_321
-			.saturating_add(Weight::from_parts(25_325_449, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(33_017_842, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2704).saturating_mul(r.into()))
+	}
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionKickThresholdOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionKickThresholdOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:0 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:8)
+	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 8]`.
+	fn promote_waiting_candidates(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396 + p * (32 ±0)`
+		//  Estimated: `3535`
+		// Minimum execution time: 9_847_000 picoseconds.
+		Weight::from_parts(10_392_644, 3535)
+			// Standard Error: 2_214
+			.saturating_add(Weight::from_parts(3_127_905, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	/// Storage: `CollatorSelection::SessionAuthoredBlocks` (r:11 w:10)
+	/// Proof: `CollatorSelection::SessionAuthoredBlocks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PendingDelegatorRewards` (r:1 w:1)
+	/// Proof: `CollatorSelection::PendingDelegatorRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionInvulnerablesRewardShareOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionInvulnerablesRewardShareOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CollatorRewards` (r:10 w:10)
+	/// Proof: `CollatorSelection::CollatorRewards` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:10 w:0)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorCount` (r:10 w:0)
+	/// Proof: `CollatorSelection::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Commission` (r:10 w:0)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseDepositOf` (r:10 w:0)
+	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorPayouts` (r:0 w:10)
+	/// Proof: `CollatorSelection::DelegatorPayouts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockWeight` (r:1 w:1)
+	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 10]`.
+	fn end_session(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548 + c * (282 ±0)`
+		//  Estimated: `4687 + c * (2603 ±0)`
+		// Minimum execution time: 31_402_000 picoseconds.
+		Weight::from_parts(15_318_724, 4687)
+			// Standard Error: 24_117
+			.saturating_add(Weight::from_parts(24_512_306, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::DelegatorPayouts` (r:2 w:1)
+	/// Proof: `CollatorSelection::DelegatorPayouts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Delegations` (r:65 w:0)
+	/// Proof: `CollatorSelection::Delegations` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:65 w:65)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PendingDelegatorRewards` (r:1 w:1)
+	/// Proof: `CollatorSelection::PendingDelegatorRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 64]`.
+	fn pay_delegator_rewards(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318 + d * (142 ±0)`
+		//  Estimated: `5583 + d * (2603 ±0)`
+		// Minimum execution time: 18_412_000 picoseconds.
+		Weight::from_parts(19_027_553, 5583)
+			// Standard Error: 3_366
+			.saturating_add(Weight::from_parts(16_482_519, 0).saturating_mul(d.into()))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(d.into()))
+	}
+	/// Storage: `CollatorSelection::Delegations` (r:65 w:64)
+	/// Proof: `CollatorSelection::Delegations` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Unbonding` (r:64 w:64)
+	/// Proof: `CollatorSelection::Unbonding` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorCount` (r:0 w:1)
+	/// Proof: `CollatorSelection::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 64]`.
+	fn unbond_delegations(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `170 + d * (167 ±0)`
+		//  Estimated: `3577 + d * (2911 ±0)`
+		// Minimum execution time: 6_874_000 picoseconds.
+		Weight::from_parts(7_120_318, 3577)
+			// Standard Error: 2_907
+			.saturating_add(Weight::from_parts(14_203_117, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2911).saturating_mul(d.into()))
+	}
+	/// Storage: `CollatorSelection::LicenseDepositOf` (r:1 w:0)
+	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Delegations` (r:1 w:1)
+	/// Proof: `CollatorSelection::Delegations` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorCount` (r:1 w:1)
+	/// Proof: `CollatorSelection::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `3694`
+		// Minimum execution time: 38_921_000 picoseconds.
+		Weight::from_parts(39_774_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CollatorSelection::Delegations` (r:1 w:1)
+	/// Proof: `CollatorSelection::Delegations` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Unbonding` (r:1 w:1)
+	/// Proof: `CollatorSelection::Unbonding` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorCount` (r:1 w:1)
+	/// Proof: `CollatorSelection::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `516`
+		//  Estimated: `3901`
+		// Minimum execution time: 24_105_000 picoseconds.
+		Weight::from_parts(24_813_000, 3901)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CollatorSelection::Unbonding` (r:1 w:1)
+	/// Proof: `CollatorSelection::Unbonding` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn withdraw_unbonded(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385 + r * (24 ±0)`
+		//  Estimated: `3901`
+		// Minimum execution time: 31_442_000 picoseconds.
+		Weight::from_parts(32_196_315, 3901)
+			// Standard Error: 2_031
+			.saturating_add(Weight::from_parts(97_412, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::LicenseDepositOf` (r:1 w:0)
+	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Commission` (r:0 w:1)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `285`
+		//  Estimated: `3529`
+		// Minimum execution time: 12_871_000 picoseconds.
+		Weight::from_parts(13_302_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
-			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(c.into()))
-			.saturating_add(Weight::from_parts(0, 2704).saturating_mul(r.into()))
+	}
+	/// Storage: `CollatorSelection::LicenseDepositOf` (r:1 w:0)
+	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseObtainedAt` (r:7 w:0)
+	/// Proof: `CollatorSelection::LicenseObtainedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `w` is `[1, 64]`.
+	fn join_waiting_list(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `472 + w * (32 ±0)`
+		//  Estimated: `19219 + w * (32 ±0)`
+		// Minimum execution time: 29_106_000 picoseconds.
+		Weight::from_parts(30_254_117, 19219)
+			// Standard Error: 1_118
+			.saturating_add(Weight::from_parts(73_904, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(w.into()))
+	}
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// The range of component `w` is `[1, 64]`.
+	fn leave_waiting_list(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `111 + w * (32 ±0)`
+		//  Estimated: `1597 + w * (32 ±0)`
+		// Minimum execution time: 9_472_000 picoseconds.
+		Weight::from_parts(9_914_287, 1597)
+			// Standard Error: 873
+			.saturating_add(Weight::from_parts(81_215, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(w.into()))
 	}
 }
 
@@ -239,6 +500,8 @@ impl WeightInfo for () {
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[2, 8]`.
 	fn add_invulnerable(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -248,7 +511,7 @@ impl WeightInfo for () {
 		Weight::from_parts(15_670_195, 3829)
 			// Standard Error: 1_273
 			.saturating_add(Weight::from_parts(143_228, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 45).saturating_mul(b.into()))
 	}
@@ -274,6 +537,10 @@ impl WeightInfo for () {
 	/// Proof: `Configuration::CollatorSelectionLicenseBondOverride` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseObtainedAt` (r:0 w:1)
+	/// Proof: `CollatorSelection::LicenseObtainedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 10]`.
 	fn get_license(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -283,8 +550,8 @@ impl WeightInfo for () {
 		Weight::from_parts(48_724_759, 4119)
 			// Standard Error: 9_236
 			.saturating_add(Weight::from_parts(471_224, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 47).saturating_mul(c.into()))
 	}
 	/// Storage: `CollatorSelection::LicenseDepositOf` (r:1 w:0)
@@ -299,6 +566,8 @@ impl WeightInfo for () {
 	/// Proof: `Configuration::CollatorSelectionKickThresholdOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[2, 8]`.
 	fn onboard(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -308,8 +577,8 @@ impl WeightInfo for () {
 		Weight::from_parts(20_699_457, 3529)
 			// Standard Error: 3_047
 			.saturating_add(Weight::from_parts(220_727, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
@@ -333,8 +602,16 @@ impl WeightInfo for () {
 	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Commission` (r:0 w:1)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseObtainedAt` (r:0 w:1)
+	/// Proof: `CollatorSelection::LicenseObtainedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 8]`.
 	fn release_license(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -344,8 +621,8 @@ impl WeightInfo for () {
 		Weight::from_parts(44_367_374, 3694)
 			// Standard Error: 8_686
 			.saturating_add(Weight::from_parts(626_453, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
@@ -353,8 +630,16 @@ impl WeightInfo for () {
 	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Commission` (r:0 w:1)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseObtainedAt` (r:0 w:1)
+	/// Proof: `CollatorSelection::LicenseObtainedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 8]`.
 	fn force_release_license(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -364,29 +649,31 @@ impl WeightInfo for () {
 		Weight::from_parts(43_827_564, 3694)
 			// Standard Error: 8_984
 			.saturating_add(Weight::from_parts(639_240, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
-	/// Storage: `System::Account` (r:2 w:2)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::SessionAuthoredBlocks` (r:1 w:1)
+	/// Proof: `CollatorSelection::SessionAuthoredBlocks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `System::BlockWeight` (r:1 w:1)
 	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	fn note_author() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `155`
-		//  Estimated: `6196`
-		// Minimum execution time: 36_824_000 picoseconds.
-		Weight::from_parts(37_535_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `4`
+		//  Estimated: `3509`
+		// Minimum execution time: 12_105_000 picoseconds.
+		Weight::from_parts(12_514_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
 	/// Storage: `Configuration::CollatorSelectionKickThresholdOverride` (r:1 w:0)
 	/// Proof: `Configuration::CollatorSelectionKickThresholdOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:8 w:0)
+	/// Storage: `CollatorSelection::ElectedCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::ElectedCandidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:8 w:8)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
 	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
@@ -398,22 +685,247 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:8 w:8)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionDesiredCollatorsOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionDesiredCollatorsOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:8 w:0)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionMaxStrikesOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionMaxStrikesOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionStrikeSlashRatioOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionStrikeSlashRatioOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionStrikeDecaySessionsOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionStrikeDecaySessionsOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Strikes` (r:8 w:8)
+	/// Proof: `CollatorSelection::Strikes` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 8]`.
 	/// The range of component `c` is `[1, 8]`.
 	fn new_session(r: u32, c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `725 + c * (84 ±0) + r * (239 ±0)`
-		//  Estimated: `26857 + c * (2519 ±0) + r * (2704 ±6)`
-		// Minimum execution time: 15_578_000 picoseconds.
-		Weight::from_parts(15_959_000, 26857)
+		//  Estimated: `27673 + c * (2519 ±0) + r * (2704 ±6)`
+		// Minimum execution time: 16_204_000 picoseconds.
+		Weight::from_parts(16_611_000, 27673)
 			// Standard Error: 332_321
-			.saturating_add(Weight::from_parts(25_325_449, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(33_017_842, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2704).saturating_mul(r.into()))
+	}
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionKickThresholdOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionKickThresholdOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:0 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:8)
+	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 8]`.
+	fn promote_waiting_candidates(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396 + p * (32 ±0)`
+		//  Estimated: `3535`
+		// Minimum execution time: 9_847_000 picoseconds.
+		Weight::from_parts(10_392_644, 3535)
+			// Standard Error: 2_214
+			.saturating_add(Weight::from_parts(3_127_905, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+	}
+	/// Storage: `CollatorSelection::SessionAuthoredBlocks` (r:11 w:10)
+	/// Proof: `CollatorSelection::SessionAuthoredBlocks` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PendingDelegatorRewards` (r:1 w:1)
+	/// Proof: `CollatorSelection::PendingDelegatorRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `Configuration::CollatorSelectionInvulnerablesRewardShareOverride` (r:1 w:0)
+	/// Proof: `Configuration::CollatorSelectionInvulnerablesRewardShareOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CollatorRewards` (r:10 w:10)
+	/// Proof: `CollatorSelection::CollatorRewards` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:10 w:0)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorCount` (r:10 w:0)
+	/// Proof: `CollatorSelection::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Commission` (r:10 w:0)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseDepositOf` (r:10 w:0)
+	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorPayouts` (r:0 w:10)
+	/// Proof: `CollatorSelection::DelegatorPayouts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockWeight` (r:1 w:1)
+	/// Proof: `System::BlockWeight` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 10]`.
+	fn end_session(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548 + c * (282 ±0)`
+		//  Estimated: `4687 + c * (2603 ±0)`
+		// Minimum execution time: 31_402_000 picoseconds.
+		Weight::from_parts(15_318_724, 4687)
+			// Standard Error: 24_117
+			.saturating_add(Weight::from_parts(24_512_306, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::DelegatorPayouts` (r:2 w:1)
+	/// Proof: `CollatorSelection::DelegatorPayouts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Delegations` (r:65 w:0)
+	/// Proof: `CollatorSelection::Delegations` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:65 w:65)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::PendingDelegatorRewards` (r:1 w:1)
+	/// Proof: `CollatorSelection::PendingDelegatorRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 64]`.
+	fn pay_delegator_rewards(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318 + d * (142 ±0)`
+		//  Estimated: `5583 + d * (2603 ±0)`
+		// Minimum execution time: 18_412_000 picoseconds.
+		Weight::from_parts(19_027_553, 5583)
+			// Standard Error: 3_366
+			.saturating_add(Weight::from_parts(16_482_519, 0).saturating_mul(d.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(d.into()))
+	}
+	/// Storage: `CollatorSelection::Delegations` (r:65 w:64)
+	/// Proof: `CollatorSelection::Delegations` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Unbonding` (r:64 w:64)
+	/// Proof: `CollatorSelection::Unbonding` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorCount` (r:0 w:1)
+	/// Proof: `CollatorSelection::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 64]`.
+	fn unbond_delegations(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `170 + d * (167 ±0)`
+		//  Estimated: `3577 + d * (2911 ±0)`
+		// Minimum execution time: 6_874_000 picoseconds.
+		Weight::from_parts(7_120_318, 3577)
+			// Standard Error: 2_907
+			.saturating_add(Weight::from_parts(14_203_117, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2911).saturating_mul(d.into()))
+	}
+	/// Storage: `CollatorSelection::LicenseDepositOf` (r:1 w:0)
+	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Delegations` (r:1 w:1)
+	/// Proof: `CollatorSelection::Delegations` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorCount` (r:1 w:1)
+	/// Proof: `CollatorSelection::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `3694`
+		// Minimum execution time: 38_921_000 picoseconds.
+		Weight::from_parts(39_774_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CollatorSelection::Delegations` (r:1 w:1)
+	/// Proof: `CollatorSelection::Delegations` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Unbonding` (r:1 w:1)
+	/// Proof: `CollatorSelection::Unbonding` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::DelegatorCount` (r:1 w:1)
+	/// Proof: `CollatorSelection::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalBacking` (r:1 w:1)
+	/// Proof: `CollatorSelection::TotalBacking` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `516`
+		//  Estimated: `3901`
+		// Minimum execution time: 24_105_000 picoseconds.
+		Weight::from_parts(24_813_000, 3901)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CollatorSelection::Unbonding` (r:1 w:1)
+	/// Proof: `CollatorSelection::Unbonding` (`max_values`: None, `max_size`: Some(436), added: 2911, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(229), added: 2704, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn withdraw_unbonded(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385 + r * (24 ±0)`
+		//  Estimated: `3901`
+		// Minimum execution time: 31_442_000 picoseconds.
+		Weight::from_parts(32_196_315, 3901)
+			// Standard Error: 2_031
+			.saturating_add(Weight::from_parts(97_412, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollatorSelection::LicenseDepositOf` (r:1 w:0)
+	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Commission` (r:0 w:1)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `285`
+		//  Estimated: `3529`
+		// Minimum execution time: 12_871_000 picoseconds.
+		Weight::from_parts(13_302_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
-			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
-			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(c.into()))
-			.saturating_add(Weight::from_parts(0, 2704).saturating_mul(r.into()))
+	}
+	/// Storage: `CollatorSelection::LicenseDepositOf` (r:1 w:0)
+	/// Proof: `CollatorSelection::LicenseDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::LicenseObtainedAt` (r:7 w:0)
+	/// Proof: `CollatorSelection::LicenseObtainedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `w` is `[1, 64]`.
+	fn join_waiting_list(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `472 + w * (32 ±0)`
+		//  Estimated: `19219 + w * (32 ±0)`
+		// Minimum execution time: 29_106_000 picoseconds.
+		Weight::from_parts(30_254_117, 19219)
+			// Standard Error: 1_118
+			.saturating_add(Weight::from_parts(73_904, 0).saturating_mul(w.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(w.into()))
+	}
+	/// Storage: `CollatorSelection::WaitingCandidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::WaitingCandidates` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	/// The range of component `w` is `[1, 64]`.
+	fn leave_waiting_list(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `111 + w * (32 ±0)`
+		//  Estimated: `1597 + w * (32 ±0)`
+		// Minimum execution time: 9_472_000 picoseconds.
+		Weight::from_parts(9_914_287, 1597)
+			// Standard Error: 873
+			.saturating_add(Weight::from_parts(81_215, 0).saturating_mul(w.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(w.into()))
 	}
 }
 
@@ -8,8 +8,8 @@ use frame_support::{
 	traits::Get,
 };
 use sp_runtime::DispatchError;
-use sp_weights::Weight;
-use up_data_structs::{CollectionId, CreateCollectionData};
+use sp_weights::{Weight, WeightMeter};
+use up_data_structs::{CollectionId, CollectionMode, CreateCollectionData};
 
 use crate::{pallet::Config, CollectionIssuer, CommonCollectionOperations};
 
@@ -86,6 +86,22 @@ pub trait CollectionDispatch<T: Config> {
 	/// * `handle` - Collection handle.
 	fn destroy(sender: T::CrossAccountId, collection_id: CollectionId) -> DispatchResult;
 
+	/// Burn tokens and clear the storage of a collection which is being destroyed,
+	/// spending no more than the weight left in the `meter`.
+	///
+	/// Returns `true` if the collection storage is fully cleared.
+	///
+	/// * `collection_id` - ID of the collection being destroyed.
+	/// * `mode` - Mode of the collection being destroyed.
+	/// * `owner` - Owner of the collection, who receives nested tokens which can't be returned otherwise.
+	/// * `meter` - Weight available for clearing.
+	fn clear_destroyed(
+		collection_id: CollectionId,
+		mode: &CollectionMode,
+		owner: &T::CrossAccountId,
+		meter: &mut WeightMeter,
+	) -> bool;
+
 	/// Get a specialized collection from the handle.
 	///
 	/// * `handle` - Collection handle.
@@ -29,12 +29,13 @@ use pallet_evm_coder_substrate::{
 };
 use sp_std::{vec, vec::Vec};
 use up_data_structs::{
-	CollectionMode, CollectionPermissions, OwnerRestrictedSet, Property, SponsoringRateLimit,
-	SponsorshipState,
+	CollectionAdminRoles, CollectionMode, CollectionPermissions, OwnerRestrictedSet, Property,
+	SponsoringRateLimit, SponsorshipState,
 };
 
 use crate::{
-	eth, weights::WeightInfo, CollectionHandle, CollectionProperties, Config, Pallet, SelfWeightOf,
+	eth, weights::WeightInfo, AdminRoles, CollectionHandle, CollectionProperties, Config, Pallet,
+	PendingOwner, SelfWeightOf,
 };
 
 frontier_contract! {
@@ -684,17 +685,18 @@ where
 		))
 	}
 
-	/// Changes collection owner to another account
+	/// Propose another account to become the collection owner
 	///
-	/// @dev Owner can be changed only by current owner
-	/// @param newOwner new owner account
+	/// @dev Deprecated, use `proposeCollectionOwnerCross`. The ownership is handed over
+	///  only after the proposed account accepts it.
+	/// @param newOwner Proposed owner account
 	#[solidity(hide, rename_selector = "changeCollectionOwner")]
 	fn set_owner(&mut self, caller: Caller, new_owner: Address) -> Result<()> {
 		self.consume_store_writes(1)?;
 
 		let caller = T::CrossAccountId::from_eth(caller);
 		let new_owner = T::CrossAccountId::from_eth(new_owner);
-		self.change_owner(caller, new_owner)
+		self.propose_owner(&caller, new_owner.as_sub().clone(), None)
 			.map_err(dispatch_to_evm::<T>)
 	}
 
@@ -709,10 +711,11 @@ where
 		Ok(result)
 	}
 
-	/// Changes collection owner to another account
+	/// Propose another account to become the collection owner
 	///
-	/// @dev Owner can be changed only by current owner
-	/// @param newOwner new owner cross account
+	/// @dev Deprecated, use `proposeCollectionOwnerCross`. The ownership is handed over
+	///  only after the proposed account accepts it.
+	/// @param newOwner Proposed owner cross account
 	fn change_collection_owner_cross(
 		&mut self,
 		caller: Caller,
@@ -722,9 +725,102 @@ where
 
 		let caller = T::CrossAccountId::from_eth(caller);
 		let new_owner = new_owner.into_sub_cross_account::<T>()?;
-		self.change_owner(caller, new_owner)
+		self.propose_owner(&caller, new_owner.as_sub().clone(), None)
 			.map_err(dispatch_to_evm::<T>)
 	}
+
+	/// Propose another account to become the collection owner.
+	///
+	/// @dev The ownership is handed over only after the proposed account accepts it.
+	/// @param newOwner Proposed owner cross account
+	/// @param expiresAt The last block at which the proposal can be accepted, or 0 if it does not expire
+	fn propose_collection_owner_cross(
+		&mut self,
+		caller: Caller,
+		new_owner: eth::CrossAddress,
+		expires_at: u32,
+	) -> Result<()> {
+		self.consume_store_writes(1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		let new_owner = new_owner.into_sub_cross_account::<T>()?;
+		let expires_at = (expires_at != 0).then(|| expires_at.into());
+		self.propose_owner(&caller, new_owner.as_sub().clone(), expires_at)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Whether there is a pending owner.
+	fn has_collection_pending_owner(&self) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<PendingOwner<T>>::contains_key(self.id))
+	}
+
+	/// Get proposed owner.
+	///
+	/// @return Cross account of the proposed owner, or zero address if there is none.
+	fn collection_pending_owner(&self) -> Result<eth::CrossAddress> {
+		self.consume_store_reads(1)?;
+		Ok(<PendingOwner<T>>::get(self.id)
+			.map(|pending| {
+				eth::CrossAddress::from_sub_cross_account::<T>(&T::CrossAccountId::from_sub(
+					pending.owner,
+				))
+			})
+			.unwrap_or_default())
+	}
+
+	/// Accept the collection ownership.
+	///
+	/// @dev The caller must be proposed as the owner with `proposeCollectionOwnerCross` first.
+	fn accept_collection_ownership(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads_and_writes(1, 2)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		self.accept_ownership(caller.as_sub())
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Cancel the pending collection ownership transfer.
+	///
+	/// @dev Can only be called by the current owner.
+	fn cancel_collection_owner_proposal(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads_and_writes(1, 1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		self.cancel_owner_proposal(&caller)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Set the roles granted to a collection admin.
+	///
+	/// @dev Can only be called by the collection owner.
+	/// @param admin Cross account administrator address
+	/// @param roles Bit set of the roles: 1 - minter, 2 - metadata editor,
+	///  4 - allowlist manager, 8 - sponsor manager, 16 - limit manager
+	fn set_collection_admin_roles_cross(
+		&mut self,
+		caller: Caller,
+		admin: eth::CrossAddress,
+		roles: u8,
+	) -> Result<()> {
+		self.consume_store_reads_and_writes(2, 1)?;
+
+		let caller = T::CrossAccountId::from_eth(caller);
+		let admin = admin.into_sub_cross_account::<T>()?;
+		<Pallet<T>>::set_admin_roles(self, &caller, &admin, CollectionAdminRoles(roles))
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Get the roles granted to a collection admin.
+	///
+	/// @param admin Cross account administrator address
+	/// @return Bit set of the roles, or 0 if the account is not an admin
+	fn collection_admin_roles(&self, admin: eth::CrossAddress) -> Result<u8> {
+		self.consume_store_reads(1)?;
+
+		let admin = admin.into_sub_cross_account::<T>()?;
+		Ok(<AdminRoles<T>>::get((self.id, admin)).0)
+	}
 }
 
 /// Contains static property keys and values.
@@ -27,6 +27,7 @@
 //! The Common pallet provides functions for:
 //!
 //! - Setting and approving collection sponsor.
+//! - Proposing and accepting a new collection owner.
 //! - Get\set\delete allow list.
 //! - Get\set\delete collection properties.
 //! - Get\set\delete collection property permissions.
@@ -38,6 +39,9 @@
 //! **Collection sponsor** - For the collection, you can set a sponsor, at whose expense it will
 //! be possible to mint tokens.
 //!
+//! **Pending owner** - An account proposed by the collection owner to take over the collection.
+//! The ownership is only handed over when the proposed account accepts it.
+//!
 //! **Allow list** - List of users who have the right to minting tokens.
 //!
 //! **Collection properties** - Collection properties are simply key-value stores where various
@@ -48,6 +52,24 @@
 //!
 //! **Collection administrator** - For a collection, you can set administrators who have the right
 //! to most actions on the collection.
+//!
+//! **Admin roles** - Each administrator has a set of roles (see [`CollectionAdminRoles`]),
+//! which limits the actions available to them. Administrators get all of the roles by default.
+//!
+//! **Collection destruction** - A collection which still contains tokens can't be destroyed at once.
+//! Instead, its destruction is started, which removes the collection right away, while its tokens
+//! are burnt in `on_idle` over the following blocks, see [`Pallet::clear_destroying_collection`].
+//!
+//! **Sponsor budget** - The fees paid by the collection sponsor may be limited per period
+//! and per user (see [`SponsorBudget`]). Sponsoring is refused once the budget is exhausted.
+//!
+//! **Public mint sponsoring** - The sponsor may pay for the tokens minted by allowlisted users
+//! under [`mint_mode`](up_data_structs::CollectionPermissions::mint_mode),
+//! within a per-user quota (see [`PublicMintSponsoring`]).
+//!
+//! **Nested tokens sponsoring** - The sponsor may opt in to pay for the calls to the tokens
+//! nested in the tokens of its collection, if the collection of the nested token has no sponsor
+//! (see [`NestedTokensSponsoring`]).
 
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -72,24 +94,30 @@ use frame_support::{
 	},
 	transactional,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 use pallet_evm::{account::CrossAccountId, Pallet as PalletEvm};
 use pallet_evm_coder_substrate::{SubstrateRecorder, WithRecorder};
 use sp_core::H160;
-use sp_runtime::{traits::Zero, ArithmeticError, DispatchError, DispatchResult};
+use sp_io::MultiRemovalResults;
+use sp_runtime::{
+	traits::{SaturatedConversion, Zero},
+	ArithmeticError, DispatchError, DispatchResult,
+};
 use sp_std::vec::Vec;
-use sp_weights::Weight;
+use sp_weights::{Weight, WeightMeter};
 use up_data_structs::{
-	budget::Budget, mapping::TokenAddressMapping, AccessMode, Collection, CollectionId,
-	CollectionLimits, CollectionMode, CollectionPermissions,
+	budget::Budget, mapping::TokenAddressMapping, AccessMode, Collection, CollectionAdminRoles,
+	CollectionId, CollectionLimits, CollectionMode, CollectionPermissions,
 	CollectionProperties as CollectionPropertiesT, CollectionStats, CreateCollectionData,
-	CreateItemData, CreateItemExData, PhantomType, PropertiesError, PropertiesPermissionMap,
-	Property, PropertyKey, PropertyKeyPermission, PropertyPermission, PropertyScope, PropertyValue,
-	RpcCollection, RpcCollectionFlags, SponsoringRateLimit, SponsorshipState, TokenChild,
-	TokenData, TokenId, TokenOwnerError, TokenProperties, TrySetProperty, COLLECTION_ADMINS_LIMIT,
-	COLLECTION_NUMBER_LIMIT, CUSTOM_DATA_LIMIT, FUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
-	MAX_SPONSOR_TIMEOUT, MAX_TOKEN_OWNERSHIP, MAX_TOKEN_PREFIX_LENGTH,
-	NFT_SPONSOR_TRANSFER_TIMEOUT, REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
+	CreateItemData, CreateItemExData, DestroyingCollection, PendingCollectionOwner, PhantomType,
+	PropertiesError, PropertiesPermissionMap, Property, PropertyKey, PropertyKeyPermission,
+	PropertyPermission, PropertyScope, PropertyValue, PublicMintSponsoring, PublicMintUsage,
+	RpcCollection, RpcCollectionFlags, SponsorBudget, SponsorBudgetLeft, SponsorBudgetUsage,
+	SponsoringRateLimit, SponsorshipState, TokenChild, TokenData, TokenId, TokenOwnerError,
+	TokenProperties, TrySetProperty, COLLECTION_ADMINS_LIMIT, COLLECTION_NUMBER_LIMIT,
+	CUSTOM_DATA_LIMIT, FUNGIBLE_SPONSOR_TRANSFER_TIMEOUT, MAX_SPONSOR_TIMEOUT, MAX_TOKEN_OWNERSHIP,
+	MAX_TOKEN_PREFIX_LENGTH, NFT_SPONSOR_TRANSFER_TIMEOUT, REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
 };
 use up_pov_estimate_rpc::PovInfo;
 
@@ -99,6 +127,7 @@ pub mod dispatch;
 pub mod erc;
 pub mod eth;
 pub mod helpers;
+pub mod migration;
 #[allow(missing_docs)]
 pub mod weights;
 
@@ -200,7 +229,7 @@ impl<T: Config> CollectionHandle<T> {
 		sponsor: T::AccountId,
 	) -> DispatchResult {
 		self.check_is_internal()?;
-		self.check_is_owner_or_admin(sender)?;
+		self.check_admin_roles(sender, CollectionAdminRoles::SPONSOR_MANAGER)?;
 
 		self.collection.sponsorship = SponsorshipState::Unconfirmed(sponsor.clone());
 
@@ -267,7 +296,7 @@ impl<T: Config> CollectionHandle<T> {
 	/// Remove collection sponsor.
 	pub fn remove_sponsor(&mut self, sender: &T::CrossAccountId) -> DispatchResult {
 		self.check_is_internal()?;
-		self.check_is_owner_or_admin(sender)?;
+		self.check_admin_roles(sender, CollectionAdminRoles::SPONSOR_MANAGER)?;
 
 		self.collection.sponsorship = SponsorshipState::Disabled;
 
@@ -281,6 +310,67 @@ impl<T: Config> CollectionHandle<T> {
 		self.save()
 	}
 
+	/// Set the limits of the fees paid by the collection sponsor, or remove them with `None`.
+	///
+	/// Can be called either by the sponsor, or by the admins managing the sponsor.
+	pub fn set_sponsor_budget(
+		&self,
+		sender: &T::CrossAccountId,
+		budget: Option<SponsorBudget>,
+	) -> DispatchResult {
+		self.check_is_internal()?;
+		if self.collection.sponsorship.sponsor() != Some(sender.as_sub()) {
+			self.check_admin_roles(sender, CollectionAdminRoles::SPONSOR_MANAGER)?;
+		}
+
+		<SponsorBudgets<T>>::set(self.id, budget);
+
+		<Pallet<T>>::deposit_event(Event::<T>::SponsorBudgetSet(self.id, budget));
+		Ok(())
+	}
+
+	/// Set the sponsoring of the tokens minted by non-admin users, or disable it with `None`.
+	///
+	/// Can be called either by the sponsor, or by the admins managing the sponsor.
+	pub fn set_public_mint_sponsoring(
+		&self,
+		sender: &T::CrossAccountId,
+		sponsoring: Option<PublicMintSponsoring>,
+	) -> DispatchResult {
+		self.check_is_internal()?;
+		if self.collection.sponsorship.sponsor() != Some(sender.as_sub()) {
+			self.check_admin_roles(sender, CollectionAdminRoles::SPONSOR_MANAGER)?;
+		}
+
+		<PublicMintSponsorings<T>>::set(self.id, sponsoring);
+
+		<Pallet<T>>::deposit_event(Event::<T>::PublicMintSponsoringSet(self.id, sponsoring));
+		Ok(())
+	}
+
+	/// Enable or disable sponsoring of the calls to the tokens nested in the tokens of this collection.
+	///
+	/// Can be called either by the sponsor, or by the admins managing the sponsor.
+	pub fn set_nested_tokens_sponsoring(
+		&self,
+		sender: &T::CrossAccountId,
+		enabled: bool,
+	) -> DispatchResult {
+		self.check_is_internal()?;
+		if self.collection.sponsorship.sponsor() != Some(sender.as_sub()) {
+			self.check_admin_roles(sender, CollectionAdminRoles::SPONSOR_MANAGER)?;
+		}
+
+		if enabled {
+			<NestedTokensSponsoring<T>>::insert(self.id, true);
+		} else {
+			<NestedTokensSponsoring<T>>::remove(self.id);
+		}
+
+		<Pallet<T>>::deposit_event(Event::<T>::NestedTokensSponsoringSet(self.id, enabled));
+		Ok(())
+	}
+
 	/// Force remove `sponsor`.
 	///
 	/// Differs from `remove_sponsor` in that
@@ -353,6 +443,23 @@ impl<T: Config> CollectionHandle<T> {
 		Ok(())
 	}
 
+	/// Returns **true** if the `user` is the owner of the collection,
+	/// or its administrator having all of the `roles`.
+	pub fn has_admin_roles(&self, user: &T::CrossAccountId, roles: CollectionAdminRoles) -> bool {
+		*user.as_sub() == self.owner || <AdminRoles<T>>::get((self.id, user)).contains(roles)
+	}
+
+	/// Checks if the `user` is the owner of the collection,
+	/// or its administrator having all of the `roles`.
+	pub fn check_admin_roles(
+		&self,
+		user: &T::CrossAccountId,
+		roles: CollectionAdminRoles,
+	) -> DispatchResult {
+		ensure!(self.has_admin_roles(user, roles), <Error<T>>::NoPermission);
+		Ok(())
+	}
+
 	/// Returns **true** if
 	/// * the `user`is a collection owner or admin
 	/// * the collection limits allow the owner/admins to transfer/burn any collection token
@@ -374,21 +481,37 @@ impl<T: Config> CollectionHandle<T> {
 		Ok(())
 	}
 
-	/// Changes collection owner to another account
+	/// Propose `new_owner` to become the owner of the collection.
+	///
+	/// In order for the ownership to be handed over, it must be accepted through [`Self::accept_ownership`]
+	/// not later than `expires_at` block, if it is specified. A new proposal replaces the previous one.
 	/// #### Store read/writes
 	/// 1 writes
-	pub fn change_owner(
-		&mut self,
-		caller: T::CrossAccountId,
-		new_owner: T::CrossAccountId,
+	pub fn propose_owner(
+		&self,
+		caller: &T::CrossAccountId,
+		new_owner: T::AccountId,
+		expires_at: Option<BlockNumberFor<T>>,
 	) -> DispatchResult {
 		self.check_is_internal()?;
-		self.check_is_owner(&caller)?;
-		self.collection.owner = new_owner.as_sub().clone();
+		self.check_is_owner(caller)?;
+		if let Some(expires_at) = &expires_at {
+			ensure!(
+				*expires_at >= <frame_system::Pallet<T>>::block_number(),
+				<Error<T>>::OwnershipProposalExpired
+			);
+		}
 
-		<Pallet<T>>::deposit_event(Event::<T>::CollectionOwnerChanged(
+		<PendingOwner<T>>::insert(
 			self.id,
-			new_owner.as_sub().clone(),
+			PendingCollectionOwner {
+				owner: new_owner.clone(),
+				expires_at,
+			},
+		);
+
+		<Pallet<T>>::deposit_event(Event::<T>::CollectionOwnerProposed(
+			self.id, new_owner, expires_at,
 		));
 		<PalletEvm<T>>::deposit_log(
 			erc::CollectionHelpersEvents::CollectionChanged {
@@ -396,6 +519,64 @@ impl<T: Config> CollectionHandle<T> {
 			}
 			.to_log(T::ContractAddress::get()),
 		);
+		Ok(())
+	}
+
+	/// Accept the ownership of the collection.
+	///
+	/// The `sender` must be proposed as the new owner via [`Self::propose_owner`],
+	/// and the proposal must not be expired.
+	/// #### Store read/writes
+	/// 1 reads, 2 writes
+	pub fn accept_ownership(&mut self, sender: &T::AccountId) -> DispatchResult {
+		self.check_is_internal()?;
+		let pending = <PendingOwner<T>>::get(self.id)
+			.filter(|pending| pending.owner == *sender)
+			.ok_or(<Error<T>>::AcceptOwnershipFail)?;
+		ensure!(
+			!pending.is_expired(&<frame_system::Pallet<T>>::block_number()),
+			<Error<T>>::OwnershipProposalExpired
+		);
+		<PendingOwner<T>>::remove(self.id);
+
+		self.set_owner_unchecked(pending.owner)
+	}
+
+	/// Cancel the pending ownership transfer.
+	///
+	/// Expired proposals can be cancelled as well, to clean them up.
+	/// #### Store read/writes
+	/// 1 reads, 1 writes
+	pub fn cancel_owner_proposal(&self, caller: &T::CrossAccountId) -> DispatchResult {
+		self.check_is_internal()?;
+		self.check_is_owner(caller)?;
+		ensure!(
+			<PendingOwner<T>>::contains_key(self.id),
+			<Error<T>>::NoPendingOwner
+		);
+
+		<PendingOwner<T>>::remove(self.id);
+
+		<Pallet<T>>::deposit_event(Event::<T>::CollectionOwnerProposalCancelled(self.id));
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(self.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+		Ok(())
+	}
+
+	fn set_owner_unchecked(&mut self, new_owner: T::AccountId) -> DispatchResult {
+		self.collection.owner = new_owner.clone();
+
+		<Pallet<T>>::deposit_event(Event::<T>::CollectionOwnerChanged(self.id, new_owner));
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(self.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
 
 		self.save()
 	}
@@ -450,7 +631,7 @@ pub mod pallet {
 		type CrossTokenAddressMapping: TokenAddressMapping<Self::CrossAccountId>;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 	/// Collection id for native fungible collction.
 	pub const NATIVE_FUNGIBLE_COLLECTION_ID: CollectionId = CollectionId(0);
 
@@ -478,7 +659,20 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
-			StorageVersion::new(1).put::<Pallet<T>>();
+			STORAGE_VERSION.put::<Pallet<T>>();
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migration::migrate_to_v2::<T>().saturating_add(migration::migrate_to_v3::<T>())
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			while Self::clear_destroying_collection(&mut meter) {}
+			meter.consumed()
 		}
 	}
 
@@ -649,6 +843,16 @@ pub mod pallet {
 			T::CrossAccountId,
 		),
 
+		/// Roles of the collection admin were changed.
+		CollectionAdminRolesSet(
+			/// ID of the affected collection.
+			CollectionId,
+			/// Admin address.
+			T::CrossAccountId,
+			/// New roles of the admin.
+			CollectionAdminRoles,
+		),
+
 		/// Collection limits were set.
 		CollectionLimitSet(
 			/// ID of the affected collection.
@@ -690,6 +894,66 @@ pub mod pallet {
 			/// ID of the affected collection.
 			CollectionId,
 		),
+
+		/// New collection owner was proposed.
+		CollectionOwnerProposed(
+			/// ID of the affected collection.
+			CollectionId,
+			/// Proposed owner address.
+			T::AccountId,
+			/// The last block at which the proposal can be accepted.
+			Option<BlockNumberFor<T>>,
+		),
+
+		/// Proposal of the new collection owner was cancelled.
+		CollectionOwnerProposalCancelled(
+			/// ID of the affected collection.
+			CollectionId,
+		),
+
+		/// Collection destruction was started, its tokens will be burnt over the following blocks.
+		CollectionDestructionStarted(
+			/// ID of the collection being destroyed.
+			CollectionId,
+		),
+
+		/// Limits of the fees paid by the collection sponsor were changed.
+		SponsorBudgetSet(
+			/// ID of the affected collection.
+			CollectionId,
+			/// New limits, if any.
+			Option<SponsorBudget>,
+		),
+
+		/// Sponsoring of the tokens minted by non-admin users was changed.
+		PublicMintSponsoringSet(
+			/// ID of the affected collection.
+			CollectionId,
+			/// New sponsoring settings, if any.
+			Option<PublicMintSponsoring>,
+		),
+
+		/// Sponsoring of the tokens nested in the tokens of the collection was enabled or disabled.
+		NestedTokensSponsoringSet(
+			/// ID of the affected collection.
+			CollectionId,
+			/// Whether the nested tokens are sponsored.
+			bool,
+		),
+
+		/// Collection sponsor budget for the current period is exhausted.
+		SponsorBudgetExhausted(
+			/// ID of the affected collection.
+			CollectionId,
+		),
+
+		/// Collection sponsor budget for a user in the current period is exhausted.
+		SponsorUserBudgetExhausted(
+			/// ID of the affected collection.
+			CollectionId,
+			/// User, whose calls are no longer sponsored.
+			T::CrossAccountId,
+		),
 	}
 
 	#[pallet::error]
@@ -790,6 +1054,18 @@ pub mod pallet {
 
 		/// Not Fungible item data used to mint in Fungible collection.
 		NotFungibleDataUsedToMintFungibleCollectionToken,
+
+		/// This address is not proposed as the owner, use proposeCollectionOwner first.
+		AcceptOwnershipFail,
+
+		/// The collection ownership proposal is expired.
+		OwnershipProposalExpired,
+
+		/// There is no pending owner of the collection.
+		NoPendingOwner,
+
+		/// Admin roles contain an unknown role.
+		UnknownAdminRole,
 	}
 
 	/// Storage of the count of created collections. Essentially contains the last collection ID.
@@ -801,6 +1077,81 @@ pub mod pallet {
 	pub type DestroyedCollectionCount<T> =
 		StorageValue<Value = CollectionId, QueryKind = ValueQuery>;
 
+	/// Collections which are being destroyed, but still have tokens to be burnt.
+	#[pallet::storage]
+	pub type DestroyingCollections<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = DestroyingCollection<T::AccountId>,
+		QueryKind = OptionQuery,
+	>;
+
+	/// The collection of [`DestroyingCollections`] cleared last. The next collection to be
+	/// cleared is the one following it, so a collection which takes long to clear
+	/// doesn't hold back the others.
+	#[pallet::storage]
+	pub type DestroyingCollectionsCursor<T> =
+		StorageValue<Value = CollectionId, QueryKind = OptionQuery>;
+
+	/// Limits of the fees paid by collection sponsors.
+	#[pallet::storage]
+	pub type SponsorBudgets<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = SponsorBudget,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Fees paid by collection sponsors within the current budget period.
+	#[pallet::storage]
+	pub type SponsorBudgetSpent<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = SponsorBudgetUsage,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Fees paid by collection sponsors on behalf of a user within the current budget period.
+	#[pallet::storage]
+	pub type SponsorUserBudgetSpent<T: Config> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = SponsorBudgetUsage,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Sponsoring of the tokens minted by non-admin users.
+	#[pallet::storage]
+	pub type PublicMintSponsorings<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = PublicMintSponsoring,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Sponsored tokens minted by a user within the current mint quota period.
+	#[pallet::storage]
+	pub type PublicMintsSponsored<T: Config> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = PublicMintUsage,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Collections, whose sponsor pays for the calls to the tokens nested in their tokens,
+	/// unless the collection of the nested token has its own sponsor.
+	#[pallet::storage]
+	pub type NestedTokensSponsoring<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
 	/// Storage of collection info.
 	#[pallet::storage]
 	pub type CollectionById<T> = StorageMap<
@@ -810,6 +1161,15 @@ pub mod pallet {
 		QueryKind = OptionQuery,
 	>;
 
+	/// Proposed collection owners, awaiting acceptance.
+	#[pallet::storage]
+	pub type PendingOwner<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = PendingCollectionOwner<T::AccountId, BlockNumberFor<T>>,
+		QueryKind = OptionQuery,
+	>;
+
 	/// Storage of collection properties.
 	#[pallet::storage]
 	#[pallet::getter(fn collection_properties)]
@@ -850,6 +1210,17 @@ pub mod pallet {
 		QueryKind = ValueQuery,
 	>;
 
+	/// Roles of collection admins.
+	#[pallet::storage]
+	pub type AdminRoles<T: Config> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, CollectionId>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = CollectionAdminRoles,
+		QueryKind = ValueQuery,
+	>;
+
 	/// Allowlisted collection users.
 	#[pallet::storage]
 	pub type Allowlist<T: Config> = StorageNMap<
@@ -1001,6 +1372,83 @@ impl<T: Config> Pallet<T> {
 			.collect()
 	}
 
+	/// Get a vector of collection admins along with their roles.
+	pub fn adminlist_with_roles(
+		collection: CollectionId,
+	) -> Vec<(T::CrossAccountId, CollectionAdminRoles)> {
+		<IsAdmin<T>>::iter_prefix((collection,))
+			.map(|(a, _)| {
+				let roles = <AdminRoles<T>>::get((collection, &a));
+				(a, roles)
+			})
+			.collect()
+	}
+
+	/// Get the fees the collection sponsor is still ready to pay within the current budget period,
+	/// in total and on behalf of `user`.
+	///
+	/// Returns `None` if the sponsor budget is not limited.
+	pub fn sponsor_budget_left(
+		collection: CollectionId,
+		user: &T::CrossAccountId,
+	) -> Option<SponsorBudgetLeft> {
+		let budget = <SponsorBudgets<T>>::get(collection)?;
+		let left = |limit: Option<u128>, usage: Option<SponsorBudgetUsage>| {
+			let usage = Self::sponsor_budget_usage(&budget, usage);
+			limit.map(|limit| limit.saturating_sub(usage.spent))
+		};
+
+		Some(SponsorBudgetLeft {
+			period: left(
+				budget.period_limit,
+				<SponsorBudgetSpent<T>>::get(collection),
+			),
+			user: left(
+				budget.user_limit,
+				<SponsorUserBudgetSpent<T>>::get((collection, user)),
+			),
+			period_end: (budget.period != 0).then(|| {
+				budget
+					.period_start(Self::sponsor_budget_now())
+					.saturating_add(budget.period - 1)
+			}),
+		})
+	}
+
+	/// Get the sponsor spending within the current period of `budget`,
+	/// resetting the `stored` spending if it belongs to one of the previous periods.
+	pub fn sponsor_budget_usage(
+		budget: &SponsorBudget,
+		stored: Option<SponsorBudgetUsage>,
+	) -> SponsorBudgetUsage {
+		let period_start = budget.period_start(Self::sponsor_budget_now());
+		stored
+			.filter(|usage| usage.period_start == period_start)
+			.unwrap_or(SponsorBudgetUsage {
+				period_start,
+				..Default::default()
+			})
+	}
+
+	/// Get the sponsored mints within the current period of public mint `sponsoring`,
+	/// resetting the `stored` mints if they belong to one of the previous periods.
+	pub fn public_mint_usage(
+		sponsoring: &PublicMintSponsoring,
+		stored: Option<PublicMintUsage>,
+	) -> PublicMintUsage {
+		let period_start = sponsoring.period_start(Self::sponsor_budget_now());
+		stored
+			.filter(|usage| usage.period_start == period_start)
+			.unwrap_or(PublicMintUsage {
+				period_start,
+				..Default::default()
+			})
+	}
+
+	fn sponsor_budget_now() -> u32 {
+		<frame_system::Pallet<T>>::block_number().saturated_into()
+	}
+
 	/// Get a vector of users allowed to mint tokens.
 	pub fn allowlist(collection: CollectionId) -> Vec<T::CrossAccountId> {
 		<Allowlist<T>>::iter_prefix((collection,))
@@ -1013,6 +1461,13 @@ impl<T: Config> Pallet<T> {
 		<Allowlist<T>>::get((collection, user))
 	}
 
+	/// Get the proposed owner of the collection, if any.
+	pub fn pending_owner(
+		collection: CollectionId,
+	) -> Option<PendingCollectionOwner<T::AccountId, BlockNumberFor<T>>> {
+		<PendingOwner<T>>::get(collection)
+	}
+
 	/// Get statistics of collections.
 	pub fn collection_stats() -> CollectionStats {
 		let created = <CreatedCollectionCount<T>>::get();
@@ -1048,6 +1503,9 @@ impl<T: Config> Pallet<T> {
 			owner_can_transfer: Some(limits.owner_can_transfer()),
 			owner_can_destroy: Some(limits.owner_can_destroy()),
 			transfers_enabled: Some(limits.transfers_enabled()),
+			sponsor_burn_timeout: Some(limits.sponsor_burn_timeout()),
+			sponsor_allowance_for_all_timeout: Some(limits.sponsor_allowance_for_all_timeout()),
+			sponsor_repartition_timeout: Some(limits.sponsor_repartition_timeout()),
 		};
 
 		Some(effective_limits)
@@ -1230,6 +1688,7 @@ impl<T: Config> Pallet<T> {
 		for admin in data.admin_list.iter() {
 			if !<IsAdmin<T>>::get((id, admin)) {
 				<IsAdmin<T>>::insert((id, admin), true);
+				<AdminRoles<T>>::insert((id, admin), CollectionAdminRoles::ALL);
 				admin_amount = admin_amount
 					.checked_add(1)
 					.ok_or(<Error<T>>::CollectionAdminCountExceeded)?;
@@ -1265,6 +1724,45 @@ impl<T: Config> Pallet<T> {
 	pub fn destroy_collection(
 		collection: CollectionHandle<T>,
 		sender: &T::CrossAccountId,
+	) -> DispatchResult {
+		Self::remove_collection(&collection, sender)?;
+		let _ = <Allowlist<T>>::clear_prefix((collection.id,), u32::MAX, None);
+		let _ = <SponsorUserBudgetSpent<T>>::clear_prefix((collection.id,), u32::MAX, None);
+		let _ = <PublicMintsSponsored<T>>::clear_prefix((collection.id,), u32::MAX, None);
+
+		Self::deposit_collection_destroyed(collection.id);
+		Ok(())
+	}
+
+	/// Start destroying a collection, which may still contain tokens.
+	///
+	/// The collection is removed right away, so it can no longer be used,
+	/// while its tokens are burnt over the following blocks, see [`Pallet::clear_destroying_collection`].
+	///
+	/// The collection creation fee is not a deposit, so there is nothing to be refunded to the owner.
+	pub fn begin_destroy_collection(
+		collection: CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+	) -> DispatchResult {
+		Self::remove_collection(&collection, sender)?;
+
+		<DestroyingCollections<T>>::insert(
+			collection.id,
+			DestroyingCollection {
+				owner: collection.owner.clone(),
+				mode: collection.mode.clone(),
+			},
+		);
+
+		<Pallet<T>>::deposit_event(Event::CollectionDestructionStarted(collection.id));
+		Ok(())
+	}
+
+	/// Check that the collection can be destroyed by `sender`, and remove it,
+	/// leaving the allow list and the tokens in place.
+	fn remove_collection(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
 	) -> DispatchResult {
 		ensure!(
 			collection.limits.owner_can_destroy(),
@@ -1283,18 +1781,98 @@ impl<T: Config> Pallet<T> {
 		<CollectionById<T>>::remove(collection.id);
 		<AdminAmount<T>>::remove(collection.id);
 		let _ = <IsAdmin<T>>::clear_prefix((collection.id,), u32::MAX, None);
-		let _ = <Allowlist<T>>::clear_prefix((collection.id,), u32::MAX, None);
+		let _ = <AdminRoles<T>>::clear_prefix((collection.id,), u32::MAX, None);
 		<CollectionProperties<T>>::remove(collection.id);
+		<PendingOwner<T>>::remove(collection.id);
+		<SponsorBudgets<T>>::remove(collection.id);
+		<SponsorBudgetSpent<T>>::remove(collection.id);
+		<PublicMintSponsorings<T>>::remove(collection.id);
+		<NestedTokensSponsoring<T>>::remove(collection.id);
+		Ok(())
+	}
 
-		<Pallet<T>>::deposit_event(Event::CollectionDestroyed(collection.id));
+	fn deposit_collection_destroyed(collection_id: CollectionId) {
+		<Pallet<T>>::deposit_event(Event::CollectionDestroyed(collection_id));
 
 		<PalletEvm<T>>::deposit_log(
 			erc::CollectionHelpersEvents::CollectionDestroyed {
-				collection_id: eth::collection_id_to_address(collection.id),
+				collection_id: eth::collection_id_to_address(collection_id),
 			}
 			.to_log(T::ContractAddress::get()),
 		);
-		Ok(())
+	}
+
+	/// Continue clearing the storage of a collection which is being destroyed,
+	/// spending no more than the weight left in the `meter`.
+	///
+	/// Tokens are burnt by the pallet of the collection mode, see [`CollectionDispatch::clear_destroyed`].
+	/// [`Event::CollectionDestroyed`] is deposited once nothing is left.
+	///
+	/// Returns `true` if the collection was cleared completely,
+	/// so the next one may be cleared with the weight left.
+	///
+	/// [`CollectionDispatch::clear_destroyed`]: dispatch::CollectionDispatch::clear_destroyed
+	pub fn clear_destroying_collection(meter: &mut WeightMeter) -> bool {
+		let db_weight = T::DbWeight::get();
+		if meter.try_consume(db_weight.reads_writes(3, 1)).is_err() {
+			return false;
+		}
+		let next = <DestroyingCollectionsCursor<T>>::get()
+			.and_then(|cursor| {
+				<DestroyingCollections<T>>::iter_from(<DestroyingCollections<T>>::hashed_key_for(
+					cursor,
+				))
+				.next()
+			})
+			.or_else(|| <DestroyingCollections<T>>::iter().next());
+		let Some((collection_id, destroying)) = next else {
+			return false;
+		};
+		<DestroyingCollectionsCursor<T>>::put(collection_id);
+		let owner = T::CrossAccountId::from_sub(destroying.owner);
+
+		let cleared = Self::clear_destroyed(meter, |limit| {
+			<Allowlist<T>>::clear_prefix((collection_id,), limit, None)
+		}) && Self::clear_destroyed(meter, |limit| {
+			<SponsorUserBudgetSpent<T>>::clear_prefix((collection_id,), limit, None)
+		}) && Self::clear_destroyed(meter, |limit| {
+			<PublicMintsSponsored<T>>::clear_prefix((collection_id,), limit, None)
+		}) && T::CollectionDispatch::clear_destroyed(
+			collection_id,
+			&destroying.mode,
+			&owner,
+			meter,
+		);
+		if !cleared || meter.try_consume(db_weight.writes(1)).is_err() {
+			return false;
+		}
+
+		<DestroyingCollections<T>>::remove(collection_id);
+		Self::deposit_collection_destroyed(collection_id);
+		true
+	}
+
+	/// Remove the entries of a collection which is being destroyed from a storage map,
+	/// removing no more entries than the weight left in the `meter` allows.
+	///
+	/// Returns `true` if there are no entries left.
+	pub fn clear_destroyed(
+		meter: &mut WeightMeter,
+		clear: impl FnOnce(u32) -> MultiRemovalResults,
+	) -> bool {
+		let entry_weight = T::DbWeight::get().writes(1);
+		let limit = meter
+			.remaining()
+			.checked_div_per_component(&entry_weight)
+			.unwrap_or(u64::MAX)
+			.min(u32::MAX.into()) as u32;
+		if limit == 0 {
+			return false;
+		}
+
+		let result = clear(limit);
+		meter.consume(entry_weight.saturating_mul(result.loops.into()));
+		result.maybe_cursor.is_none()
 	}
 
 	/// This function sets or removes a collection properties according to
@@ -1311,7 +1889,7 @@ impl<T: Config> Pallet<T> {
 		sender: &T::CrossAccountId,
 		properties_updates: impl Iterator<Item = (PropertyKey, Option<PropertyValue>)>,
 	) -> DispatchResult {
-		collection.check_is_owner_or_admin(sender)?;
+		collection.check_admin_roles(sender, CollectionAdminRoles::METADATA_EDITOR)?;
 
 		let mut stored_properties = <CollectionProperties<T>>::get(collection.id);
 
@@ -1523,7 +2101,7 @@ impl<T: Config> Pallet<T> {
 		scope: PropertyScope,
 		property_permission: PropertyKeyPermission,
 	) -> DispatchResult {
-		collection.check_is_owner_or_admin(sender)?;
+		collection.check_admin_roles(sender, CollectionAdminRoles::METADATA_EDITOR)?;
 
 		let all_permissions = CollectionPropertyPermissions::<T>::get(collection.id);
 		let current_permission = all_permissions.get(&property_permission.key);
@@ -1684,7 +2262,7 @@ impl<T: Config> Pallet<T> {
 		user: &T::CrossAccountId,
 		allowed: bool,
 	) -> DispatchResult {
-		collection.check_is_owner_or_admin(sender)?;
+		collection.check_admin_roles(sender, CollectionAdminRoles::ALLOWLIST_MANAGER)?;
 
 		// =========
 
@@ -1747,6 +2325,7 @@ impl<T: Config> Pallet<T> {
 
 			<AdminAmount<T>>::insert(collection.id, amount);
 			<IsAdmin<T>>::insert((collection.id, user), true);
+			<AdminRoles<T>>::insert((collection.id, user), CollectionAdminRoles::ALL);
 
 			Self::deposit_event(Event::<T>::CollectionAdminAdded(
 				collection.id,
@@ -1755,6 +2334,7 @@ impl<T: Config> Pallet<T> {
 		} else {
 			<AdminAmount<T>>::insert(collection.id, amount.saturating_sub(1));
 			<IsAdmin<T>>::remove((collection.id, user));
+			<AdminRoles<T>>::remove((collection.id, user));
 
 			Self::deposit_event(Event::<T>::CollectionAdminRemoved(
 				collection.id,
@@ -1772,6 +2352,45 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Set the roles of the `collection`'s admin `user`.
+	/// #### Store read/writes
+	/// 1 reads, 1 writes
+	pub fn set_admin_roles(
+		collection: &CollectionHandle<T>,
+		sender: &T::CrossAccountId,
+		user: &T::CrossAccountId,
+		roles: CollectionAdminRoles,
+	) -> DispatchResult {
+		collection.check_is_internal()?;
+		collection.check_is_owner(sender)?;
+		ensure!(
+			CollectionAdminRoles::from_bits(roles.0).is_some(),
+			<Error<T>>::UnknownAdminRole
+		);
+		ensure!(
+			<IsAdmin<T>>::get((collection.id, user)),
+			<Error<T>>::UserIsNotCollectionAdmin
+		);
+
+		// =========
+
+		<AdminRoles<T>>::insert((collection.id, user), roles);
+
+		Self::deposit_event(Event::<T>::CollectionAdminRolesSet(
+			collection.id,
+			user.clone(),
+			roles,
+		));
+		<PalletEvm<T>>::deposit_log(
+			erc::CollectionHelpersEvents::CollectionChanged {
+				collection_id: eth::collection_id_to_address(collection.id),
+			}
+			.to_log(T::ContractAddress::get()),
+		);
+
+		Ok(())
+	}
+
 	/// Update collection limits.
 	pub fn update_limits(
 		user: &T::CrossAccountId,
@@ -1779,7 +2398,7 @@ impl<T: Config> Pallet<T> {
 		new_limit: CollectionLimits,
 	) -> DispatchResult {
 		collection.check_is_internal()?;
-		collection.check_is_owner_or_admin(user)?;
+		collection.check_admin_roles(user, CollectionAdminRoles::LIMIT_MANAGER)?;
 
 		collection.limits =
 			Self::clamp_limits(collection.mode.clone(), &collection.limits, new_limit)?;
@@ -1837,6 +2456,18 @@ impl<T: Config> Pallet<T> {
 				<Error<T>>::OwnerPermissionsCantBeReverted,
 			),
 			transfers_enabled => {},
+			sponsor_burn_timeout => ensure!(
+				new_limit <= MAX_SPONSOR_TIMEOUT,
+				<Error<T>>::CollectionLimitBoundsExceeded,
+			),
+			sponsor_allowance_for_all_timeout => ensure!(
+				new_limit <= MAX_SPONSOR_TIMEOUT,
+				<Error<T>>::CollectionLimitBoundsExceeded,
+			),
+			sponsor_repartition_timeout => ensure!(
+				new_limit <= MAX_SPONSOR_TIMEOUT,
+				<Error<T>>::CollectionLimitBoundsExceeded,
+			),
 		);
 		Ok(new_limit)
 	}
@@ -1848,7 +2479,7 @@ impl<T: Config> Pallet<T> {
 		new_permission: CollectionPermissions,
 	) -> DispatchResult {
 		collection.check_is_internal()?;
-		collection.check_is_owner_or_admin(user)?;
+		collection.check_admin_roles(user, CollectionAdminRoles::LIMIT_MANAGER)?;
 		collection.permissions = Self::clamp_permissions(
 			collection.mode.clone(),
 			&collection.permissions,
@@ -2254,6 +2885,13 @@ pub trait CommonCollectionOperations<T: Config> {
 	/// * `token` - The token for which you need to find out the owners.
 	fn token_owners(&self, token: TokenId) -> Vec<T::CrossAccountId>;
 
+	/// Get tokens nested directly into the token.
+	///
+	/// * `token` - The token for which you need to find out the children.
+	fn token_children(&self, _token: TokenId) -> Vec<TokenChild> {
+		Vec::new()
+	}
+
 	/// Get the value of the token property by key.
 	///
 	/// * `token` - Token with the property to get.
@@ -2624,7 +3262,9 @@ impl<T: Config> NewTokenPropertyWriter<T> {
 		PropertyWriter {
 			collection,
 			collection_lazy_info: PropertyWriterLazyCollectionInfo {
-				is_collection_admin: LazyValue::new(|| collection.is_owner_or_admin(sender)),
+				is_collection_admin: LazyValue::new(|| {
+					collection.has_admin_roles(sender, CollectionAdminRoles::MINTER)
+				}),
 				property_permissions: LazyValue::new(|| {
 					<Pallet<T>>::property_permissions(collection.id)
 				}),
@@ -2687,7 +3327,9 @@ impl<T: Config> ExistingTokenPropertyWriter<T> {
 		PropertyWriter {
 			collection,
 			collection_lazy_info: PropertyWriterLazyCollectionInfo {
-				is_collection_admin: LazyValue::new(|| collection.is_owner_or_admin(sender)),
+				is_collection_admin: LazyValue::new(|| {
+					collection.has_admin_roles(sender, CollectionAdminRoles::METADATA_EDITOR)
+				}),
 				property_permissions: LazyValue::new(|| {
 					<Pallet<T>>::property_permissions(collection.id)
 				}),
@@ -2765,7 +3407,8 @@ impl<T: Config> BenchmarkPropertyWriter<T> {
 	where
 		Handle: Deref<Target = CollectionHandle<T>>,
 	{
-		let is_collection_admin = collection_handle.is_owner_or_admin(sender);
+		let is_collection_admin =
+			collection_handle.has_admin_roles(sender, CollectionAdminRoles::METADATA_EDITOR);
 		let property_permissions = <Pallet<T>>::property_permissions(collection_handle.id);
 
 		PropertyWriterLazyCollectionInfo {
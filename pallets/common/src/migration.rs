@@ -0,0 +1,84 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the Common pallet.
+
+use frame_support::traits::{Get, GetStorageVersion, StorageVersion};
+use sp_weights::Weight;
+use up_data_structs::{CollectionAdminRoles, CollectionVersion2};
+
+use crate::{AdminRoles, CollectionById, Config, IsAdmin, Pallet};
+
+/// Grant all of the roles to the admins which were added before admin roles were introduced.
+pub fn migrate_to_v2<T: Config>() -> Weight {
+	let on_chain_version = <Pallet<T>>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain_version >= 2 {
+		log::info!(
+			target: "runtime::common",
+			"Admin roles are already migrated, skipping",
+		);
+		return weight;
+	}
+
+	let mut admins = 0u64;
+	for ((collection, admin), _) in <IsAdmin<T>>::iter() {
+		<AdminRoles<T>>::insert((collection, admin), CollectionAdminRoles::ALL);
+		admins += 1;
+	}
+	StorageVersion::new(2).put::<Pallet<T>>();
+
+	log::info!(
+		target: "runtime::common",
+		"Granted all roles to {} collection admins",
+		admins,
+	);
+
+	weight.saturating_accrue(T::DbWeight::get().reads_writes(admins, admins + 1));
+	weight
+}
+
+/// Extend the limits of the existing collections with the sponsoring timeouts
+/// of burns, allowance for all and repartition.
+pub fn migrate_to_v3<T: Config>() -> Weight {
+	let on_chain_version = <Pallet<T>>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain_version >= 3 {
+		log::info!(
+			target: "runtime::common",
+			"Collection limits are already migrated, skipping",
+		);
+		return weight;
+	}
+
+	let mut collections = 0u64;
+	<CollectionById<T>>::translate::<CollectionVersion2<T::AccountId>, _>(|_, collection| {
+		collections += 1;
+		Some(collection.into())
+	});
+	StorageVersion::new(3).put::<Pallet<T>>();
+
+	log::info!(
+		target: "runtime::common",
+		"Migrated limits of {} collections",
+		collections,
+	);
+
+	weight.saturating_accrue(T::DbWeight::get().reads_writes(collections, collections + 1));
+	weight
+}
@@ -144,4 +144,91 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn set_app_promotion_idle_payout_weight() -> Result<(), BenchmarkError> {
+		let weight: Option<Weight> = Some(Weight::from_parts(1_000_000_000, 0));
+
+		#[block]
+		{
+			assert_ok!(<Pallet<T>>::set_app_promotion_idle_payout_weight(
+				RawOrigin::Root.into(),
+				weight
+			));
+		}
+
+		assert_last_event::<T>(Event::NewAppPromotionIdlePayoutWeight { weight }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_collator_selection_invulnerables_reward_share() -> Result<(), BenchmarkError> {
+		let share: Option<Perbill> = Some(Perbill::from_percent(50));
+
+		#[block]
+		{
+			assert_ok!(
+				<Pallet<T>>::set_collator_selection_invulnerables_reward_share(
+					RawOrigin::Root.into(),
+					share
+				)
+			);
+		}
+
+		assert_last_event::<T>(Event::NewCollatorInvulnerablesRewardShare { share }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_collator_selection_strike_slash_ratio() -> Result<(), BenchmarkError> {
+		let ratio: Option<Perbill> = Some(Perbill::from_percent(10));
+
+		#[block]
+		{
+			assert_ok!(<Pallet<T>>::set_collator_selection_strike_slash_ratio(
+				RawOrigin::Root.into(),
+				ratio
+			));
+		}
+
+		assert_last_event::<T>(Event::NewCollatorStrikeSlashRatio { ratio }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_collator_selection_max_strikes() -> Result<(), BenchmarkError> {
+		let max_strikes: Option<u32> = Some(5);
+
+		#[block]
+		{
+			assert_ok!(<Pallet<T>>::set_collator_selection_max_strikes(
+				RawOrigin::Root.into(),
+				max_strikes
+			));
+		}
+
+		assert_last_event::<T>(Event::NewCollatorMaxStrikes { max_strikes }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_collator_selection_strike_decay_sessions() -> Result<(), BenchmarkError> {
+		let sessions: Option<u32> = Some(10);
+
+		#[block]
+		{
+			assert_ok!(<Pallet<T>>::set_collator_selection_strike_decay_sessions(
+				RawOrigin::Root.into(),
+				sessions
+			));
+		}
+
+		assert_last_event::<T>(Event::NewCollatorStrikeDecaySessions { sessions }.into());
+
+		Ok(())
+	}
 }
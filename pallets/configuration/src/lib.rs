@@ -85,6 +85,17 @@ mod pallet {
 		type DefaultCollatorSelectionLicenseBond: Get<Self::Balance>;
 		#[pallet::constant]
 		type DefaultCollatorSelectionKickThreshold: Get<BlockNumberFor<Self>>;
+		#[pallet::constant]
+		type DefaultCollatorSelectionInvulnerablesRewardShare: Get<Perbill>;
+		#[pallet::constant]
+		type DefaultCollatorSelectionStrikeSlashRatio: Get<Perbill>;
+		#[pallet::constant]
+		type DefaultCollatorSelectionMaxStrikes: Get<u32>;
+		#[pallet::constant]
+		type DefaultCollatorSelectionStrikeDecaySessions: Get<u32>;
+
+		#[pallet::constant]
+		type DefaultAppPromotionIdlePayoutWeight: Get<Weight>;
 
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
@@ -102,6 +113,21 @@ mod pallet {
 		NewCollatorKickThreshold {
 			length_in_blocks: Option<BlockNumberFor<T>>,
 		},
+		NewAppPromotionIdlePayoutWeight {
+			weight: Option<Weight>,
+		},
+		NewCollatorInvulnerablesRewardShare {
+			share: Option<Perbill>,
+		},
+		NewCollatorStrikeSlashRatio {
+			ratio: Option<Perbill>,
+		},
+		NewCollatorMaxStrikes {
+			max_strikes: Option<u32>,
+		},
+		NewCollatorStrikeDecaySessions {
+			sessions: Option<u32>,
+		},
 	}
 
 	fn update_base_fee<T: Config>() {
@@ -185,6 +211,41 @@ mod pallet {
 		OnEmpty = T::DefaultCollatorSelectionKickThreshold,
 	>;
 
+	#[pallet::storage]
+	pub type AppPromotionIdlePayoutWeightOverride<T: Config> = StorageValue<
+		Value = Weight,
+		QueryKind = ValueQuery,
+		OnEmpty = T::DefaultAppPromotionIdlePayoutWeight,
+	>;
+
+	#[pallet::storage]
+	pub type CollatorSelectionInvulnerablesRewardShareOverride<T: Config> = StorageValue<
+		Value = Perbill,
+		QueryKind = ValueQuery,
+		OnEmpty = T::DefaultCollatorSelectionInvulnerablesRewardShare,
+	>;
+
+	#[pallet::storage]
+	pub type CollatorSelectionStrikeSlashRatioOverride<T: Config> = StorageValue<
+		Value = Perbill,
+		QueryKind = ValueQuery,
+		OnEmpty = T::DefaultCollatorSelectionStrikeSlashRatio,
+	>;
+
+	#[pallet::storage]
+	pub type CollatorSelectionMaxStrikesOverride<T: Config> = StorageValue<
+		Value = u32,
+		QueryKind = ValueQuery,
+		OnEmpty = T::DefaultCollatorSelectionMaxStrikes,
+	>;
+
+	#[pallet::storage]
+	pub type CollatorSelectionStrikeDecaySessionsOverride<T: Config> = StorageValue<
+		Value = u32,
+		QueryKind = ValueQuery,
+		OnEmpty = T::DefaultCollatorSelectionStrikeDecaySessions,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
@@ -296,6 +357,86 @@ mod pallet {
 			});
 			Ok(())
 		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_app_promotion_idle_payout_weight())]
+		pub fn set_app_promotion_idle_payout_weight(
+			origin: OriginFor<T>,
+			weight: Option<Weight>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(weight) = weight {
+				<AppPromotionIdlePayoutWeightOverride<T>>::set(weight);
+			} else {
+				<AppPromotionIdlePayoutWeightOverride<T>>::kill();
+			}
+			Self::deposit_event(Event::NewAppPromotionIdlePayoutWeight { weight });
+			Ok(())
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_collator_selection_invulnerables_reward_share())]
+		pub fn set_collator_selection_invulnerables_reward_share(
+			origin: OriginFor<T>,
+			share: Option<Perbill>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(share) = share {
+				<CollatorSelectionInvulnerablesRewardShareOverride<T>>::set(share);
+			} else {
+				<CollatorSelectionInvulnerablesRewardShareOverride<T>>::kill();
+			}
+			Self::deposit_event(Event::NewCollatorInvulnerablesRewardShare { share });
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_collator_selection_strike_slash_ratio())]
+		pub fn set_collator_selection_strike_slash_ratio(
+			origin: OriginFor<T>,
+			ratio: Option<Perbill>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(ratio) = ratio {
+				<CollatorSelectionStrikeSlashRatioOverride<T>>::set(ratio);
+			} else {
+				<CollatorSelectionStrikeSlashRatioOverride<T>>::kill();
+			}
+			Self::deposit_event(Event::NewCollatorStrikeSlashRatio { ratio });
+			Ok(())
+		}
+
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_collator_selection_max_strikes())]
+		pub fn set_collator_selection_max_strikes(
+			origin: OriginFor<T>,
+			max_strikes: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(max_strikes) = max_strikes {
+				<CollatorSelectionMaxStrikesOverride<T>>::set(max_strikes);
+			} else {
+				<CollatorSelectionMaxStrikesOverride<T>>::kill();
+			}
+			Self::deposit_event(Event::NewCollatorMaxStrikes { max_strikes });
+			Ok(())
+		}
+
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_collator_selection_strike_decay_sessions())]
+		pub fn set_collator_selection_strike_decay_sessions(
+			origin: OriginFor<T>,
+			sessions: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(sessions) = sessions {
+				<CollatorSelectionStrikeDecaySessionsOverride<T>>::set(sessions);
+			} else {
+				<CollatorSelectionStrikeDecaySessionsOverride<T>>::kill();
+			}
+			Self::deposit_event(Event::NewCollatorStrikeDecaySessions { sessions });
+			Ok(())
+		}
 	}
 
 	#[pallet::pallet]
@@ -39,6 +39,11 @@ pub trait WeightInfo {
 	fn set_collator_selection_desired_collators() -> Weight;
 	fn set_collator_selection_license_bond() -> Weight;
 	fn set_collator_selection_kick_threshold() -> Weight;
+	fn set_app_promotion_idle_payout_weight() -> Weight;
+	fn set_collator_selection_invulnerables_reward_share() -> Weight;
+	fn set_collator_selection_strike_slash_ratio() -> Weight;
+	fn set_collator_selection_max_strikes() -> Weight;
+	fn set_collator_selection_strike_decay_sessions() -> Weight;
 }
 
 /// Weights for pallet_configuration using the Substrate node and recommended hardware.
@@ -108,6 +113,56 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(4_866_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Configuration::AppPromotionIdlePayoutWeightOverride` (r:0 w:1)
+	/// Proof: `Configuration::AppPromotionIdlePayoutWeightOverride` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_app_promotion_idle_payout_weight() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_700_000 picoseconds.
+		Weight::from_parts(4_870_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Configuration::CollatorSelectionInvulnerablesRewardShareOverride` (r:0 w:1)
+	/// Proof: `Configuration::CollatorSelectionInvulnerablesRewardShareOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_collator_selection_invulnerables_reward_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_650_000 picoseconds.
+		Weight::from_parts(4_812_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Configuration::CollatorSelectionStrikeSlashRatioOverride` (r:0 w:1)
+	/// Proof: `Configuration::CollatorSelectionStrikeSlashRatioOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_collator_selection_strike_slash_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_702_000 picoseconds.
+		Weight::from_parts(4_897_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Configuration::CollatorSelectionMaxStrikesOverride` (r:0 w:1)
+	/// Proof: `Configuration::CollatorSelectionMaxStrikesOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_collator_selection_max_strikes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_655_000 picoseconds.
+		Weight::from_parts(4_831_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Configuration::CollatorSelectionStrikeDecaySessionsOverride` (r:0 w:1)
+	/// Proof: `Configuration::CollatorSelectionStrikeDecaySessionsOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_collator_selection_strike_decay_sessions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_683_000 picoseconds.
+		Weight::from_parts(4_860_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -176,5 +231,55 @@ impl WeightInfo for () {
 		Weight::from_parts(4_866_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Configuration::AppPromotionIdlePayoutWeightOverride` (r:0 w:1)
+	/// Proof: `Configuration::AppPromotionIdlePayoutWeightOverride` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_app_promotion_idle_payout_weight() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_700_000 picoseconds.
+		Weight::from_parts(4_870_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Configuration::CollatorSelectionInvulnerablesRewardShareOverride` (r:0 w:1)
+	/// Proof: `Configuration::CollatorSelectionInvulnerablesRewardShareOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_collator_selection_invulnerables_reward_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_650_000 picoseconds.
+		Weight::from_parts(4_812_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Configuration::CollatorSelectionStrikeSlashRatioOverride` (r:0 w:1)
+	/// Proof: `Configuration::CollatorSelectionStrikeSlashRatioOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_collator_selection_strike_slash_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_702_000 picoseconds.
+		Weight::from_parts(4_897_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Configuration::CollatorSelectionMaxStrikesOverride` (r:0 w:1)
+	/// Proof: `Configuration::CollatorSelectionMaxStrikesOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_collator_selection_max_strikes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_655_000 picoseconds.
+		Weight::from_parts(4_831_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Configuration::CollatorSelectionStrikeDecaySessionsOverride` (r:0 w:1)
+	/// Proof: `Configuration::CollatorSelectionStrikeDecaySessionsOverride` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_collator_selection_strike_decay_sessions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_683_000 picoseconds.
+		Weight::from_parts(4_860_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
 
@@ -45,7 +45,7 @@ use up_sponsorship::SponsorshipHandler;
 
 use crate::{
 	AllowlistEnabled, Config, Owner, Pallet, SponsorBasket, Sponsoring, SponsoringFeeLimit,
-	SponsoringModeT, SponsoringRateLimit,
+	SponsoringModeT, SponsoringQuota, SponsoringQuotaT, SponsoringRateLimit,
 };
 
 frontier_contract! {
@@ -292,6 +292,57 @@ where
 		Ok(get_sponsoring_fee_limit::<T>(contract_address))
 	}
 
+	/// Set contract per-user sponsoring quota
+	/// @dev Sponsoring quota - is maximum amount of sponsored calls, which
+	///  single user could make during window of specified length
+	/// @param contractAddress Contract to change sponsoring quota of
+	/// @param window Quota window length in blocks, zero disables the quota
+	/// @param calls Maximum amount of sponsored calls per user in a single window
+	/// @dev Only contract owner can change this setting
+	fn set_sponsoring_quota(
+		&mut self,
+		caller: Caller,
+		contract_address: Address,
+		window: u32,
+		calls: u32,
+	) -> Result<()> {
+		self.recorder().consume_sload()?;
+		self.recorder().consume_sload()?;
+		self.recorder().consume_sstore()?;
+		self.recorder().consume_sstore()?;
+
+		<Pallet<T>>::ensure_owner(contract_address, caller).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_sponsoring_quota(contract_address, SponsoringQuotaT { window, calls });
+		Ok(())
+	}
+
+	/// Get current contract per-user sponsoring quota
+	/// @param contractAddress Contract to get sponsoring quota of
+	/// @return Quota window length in blocks and amount of sponsored calls
+	///  per user in a single window. Zero window means there is no quota
+	fn sponsoring_quota(&self, contract_address: Address) -> Result<SponsoringQuotaT> {
+		self.recorder().consume_sload()?;
+
+		Ok(<SponsoringQuota<T>>::get(contract_address).unwrap_or_default())
+	}
+
+	/// Get amount of calls, which still could be sponsored for user
+	/// @param contractAddress Contract to check quota of
+	/// @param user User to check
+	/// @return uint32 Amount of sponsored calls left in the current window,
+	///  or uint32 max value if contract has no quota
+	fn sponsored_calls_left(&self, contract_address: Address, user: Address) -> Result<u32> {
+		self.recorder().consume_sload()?;
+		self.recorder().consume_sload()?;
+		self.recorder().consume_sload()?;
+
+		Ok(<Pallet<T>>::sponsored_calls_left(
+			contract_address,
+			user,
+			<frame_system::Pallet<T>>::block_number(),
+		))
+	}
+
 	/// Is specified user present in contract allow list
 	/// @dev Contract owner always implicitly included
 	/// @param contractAddress Contract to check allowlist of
@@ -442,6 +493,10 @@ impl<T: Config> SponsorshipHandler<T::CrossAccountId, CallContext>
 			return None;
 		}
 
+		if !<Pallet<T>>::consume_sponsoring_quota(contract_address, *who.as_eth(), block_number) {
+			return None;
+		}
+
 		<SponsorBasket<T>>::insert(contract_address, who.as_eth(), block_number);
 
 		Some(sponsor)
@@ -144,6 +144,41 @@ pub mod pallet {
 		QueryKind = OptionQuery,
 	>;
 
+	/// Storage for per-user sponsoring quota.
+	///
+	/// * **Key** - contract address.
+	/// * **Value** - [`sponsoring quota`](SponsoringQuotaT).
+	#[pallet::storage]
+	pub(super) type SponsoringQuota<T: Config> =
+		StorageMap<Hasher = Twox128, Key = H160, Value = SponsoringQuotaT, QueryKind = OptionQuery>;
+
+	/// Storage for the generation of the contract sponsoring quota,
+	/// which is changed every time the quota is set.
+	///
+	/// * **Key** - contract address.
+	/// * **Value** - quota generation.
+	#[pallet::storage]
+	pub(super) type SponsoringQuotaGeneration<T: Config> =
+		StorageMap<Hasher = Twox128, Key = H160, Value = u32, QueryKind = ValueQuery>;
+
+	/// Storage for sponsored calls made by user in the current quota window.
+	///
+	/// Calls made in the previous quota generations are not accounted.
+	///
+	/// * **Key1** - contract address.
+	/// * **Key2** - sponsored user address.
+	/// * **Value** - quota generation, block at which current window started,
+	///   and number of sponsored calls in it.
+	#[pallet::storage]
+	pub(super) type SponsoredCalls<T: Config> = StorageDoubleMap<
+		Hasher1 = Twox128,
+		Key1 = H160,
+		Hasher2 = Twox128,
+		Key2 = H160,
+		Value = (u32, BlockNumberFor<T>, u32),
+		QueryKind = OptionQuery,
+	>;
+
 	/// Storege for contracts with [`Allowlisted`](SponsoringModeT::Allowlisted) sponsoring mode.
 	///
 	/// ### Usage
@@ -408,6 +443,72 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Set per-user sponsoring quota, quota with zero window removes it
+		pub fn set_sponsoring_quota(contract: H160, quota: SponsoringQuotaT) {
+			if quota.window == 0 {
+				<SponsoringQuota<T>>::remove(contract);
+			} else {
+				<SponsoringQuota<T>>::insert(contract, quota);
+			}
+			// Calls made under the previous quota are reset lazily
+			<SponsoringQuotaGeneration<T>>::mutate(contract, |generation| {
+				*generation = generation.wrapping_add(1)
+			});
+		}
+
+		/// Get the start of the current quota window of the user, and the number of sponsored calls in it
+		fn sponsored_calls(
+			contract: H160,
+			user: H160,
+			quota: &SponsoringQuotaT,
+			block_number: BlockNumberFor<T>,
+		) -> Option<(BlockNumberFor<T>, u32)> {
+			let generation = <SponsoringQuotaGeneration<T>>::get(contract);
+			match <SponsoredCalls<T>>::get(contract, user) {
+				Some((calls_generation, window_start, used))
+					if calls_generation == generation
+						&& block_number < window_start.saturating_add(quota.window.into()) =>
+				{
+					Some((window_start, used))
+				}
+				_ => None,
+			}
+		}
+
+		/// Get amount of calls, which still could be sponsored for user in the current window
+		pub fn sponsored_calls_left(
+			contract: H160,
+			user: H160,
+			block_number: BlockNumberFor<T>,
+		) -> u32 {
+			let Some(quota) = <SponsoringQuota<T>>::get(contract) else {
+				return u32::MAX;
+			};
+			match Self::sponsored_calls(contract, user, &quota, block_number) {
+				Some((_, used)) => quota.calls.saturating_sub(used),
+				None => quota.calls,
+			}
+		}
+
+		/// Account sponsored call in user quota, returns `false` if quota is exhausted
+		pub fn consume_sponsoring_quota(
+			contract: H160,
+			user: H160,
+			block_number: BlockNumberFor<T>,
+		) -> bool {
+			let Some(quota) = <SponsoringQuota<T>>::get(contract) else {
+				return true;
+			};
+			let (window_start, used) = Self::sponsored_calls(contract, user, &quota, block_number)
+				.unwrap_or((block_number, 0));
+			if used >= quota.calls {
+				return false;
+			}
+			let generation = <SponsoringQuotaGeneration<T>>::get(contract);
+			<SponsoredCalls<T>>::insert(contract, user, (generation, window_start, used + 1));
+			true
+		}
+
 		/// Is user added to allowlist, or he is owner of specified contract
 		pub fn allowed(contract: H160, user: H160) -> bool {
 			<Allowlist<T>>::get(contract, user) || <Owner<T>>::get(contract) == user
@@ -445,3 +546,14 @@ pub enum SponsoringModeT {
 	/// All users will be sponsored
 	Generous,
 }
+
+/// Per-user sponsoring quota
+#[derive(
+	Encode, Decode, Debug, PartialEq, TypeInfo, MaxEncodedLen, Default, AbiCoder, Clone, Copy,
+)]
+pub struct SponsoringQuotaT {
+	/// Length of quota window in blocks
+	pub window: u32,
+	/// Maximum number of sponsored calls per user in a single window
+	pub calls: u32,
+}
@@ -25,7 +25,7 @@ contract ContractHelpersEvents {
 }
 
 /// @title Magic contract, which allows users to reconfigure other contracts
-/// @dev the ERC-165 identifier for this interface is 0x30afad04
+/// @dev the ERC-165 identifier for this interface is 0xa22556bd
 contract ContractHelpers is Dummy, ERC165, ContractHelpersEvents {
 	/// Get user, which deployed specified contract
 	/// @dev May return zero address in case if contract is deployed
@@ -202,6 +202,51 @@ contract ContractHelpers is Dummy, ERC165, ContractHelpersEvents {
 		return 0;
 	}
 
+	/// Set contract per-user sponsoring quota
+	/// @dev Sponsoring quota - is maximum amount of sponsored calls, which
+	///  single user could make during window of specified length
+	/// @param contractAddress Contract to change sponsoring quota of
+	/// @param window Quota window length in blocks, zero disables the quota
+	/// @param calls Maximum amount of sponsored calls per user in a single window
+	/// @dev Only contract owner can change this setting
+	/// @dev EVM selector for this function is: 0x288929ea,
+	///  or in textual repr: setSponsoringQuota(address,uint32,uint32)
+	function setSponsoringQuota(address contractAddress, uint32 window, uint32 calls) public {
+		require(false, stub_error);
+		contractAddress;
+		window;
+		calls;
+		dummy = 0;
+	}
+
+	/// Get current contract per-user sponsoring quota
+	/// @param contractAddress Contract to get sponsoring quota of
+	/// @return Quota window length in blocks and amount of sponsored calls
+	///  per user in a single window. Zero window means there is no quota
+	/// @dev EVM selector for this function is: 0xa9845489,
+	///  or in textual repr: sponsoringQuota(address)
+	function sponsoringQuota(address contractAddress) public view returns (SponsoringQuotaT memory) {
+		require(false, stub_error);
+		contractAddress;
+		dummy;
+		return SponsoringQuotaT(0, 0);
+	}
+
+	/// Get amount of calls, which still could be sponsored for user
+	/// @param contractAddress Contract to check quota of
+	/// @param user User to check
+	/// @return uint32 Amount of sponsored calls left in the current window,
+	///  or uint32 max value if contract has no quota
+	/// @dev EVM selector for this function is: 0x138786da,
+	///  or in textual repr: sponsoredCallsLeft(address,address)
+	function sponsoredCallsLeft(address contractAddress, address user) public view returns (uint32) {
+		require(false, stub_error);
+		contractAddress;
+		user;
+		dummy;
+		return 0;
+	}
+
 	/// Is specified user present in contract allow list
 	/// @dev Contract owner always implicitly included
 	/// @param contractAddress Contract to check allowlist of
@@ -265,6 +310,14 @@ contract ContractHelpers is Dummy, ERC165, ContractHelpersEvents {
 	}
 }
 
+/// Per-user sponsoring quota
+struct SponsoringQuotaT {
+	/// Length of quota window in blocks
+	uint32 window;
+	/// Maximum number of sponsored calls per user in a single window
+	uint32 calls;
+}
+
 /// Available contract sponsoring modes
 enum SponsoringModeT {
 	/// Sponsoring is disabled
@@ -23,20 +23,59 @@ use core::marker::PhantomData;
 use fp_evm::{CheckEvmTransaction, FeeCalculator, TransactionValidationError, WithdrawReason};
 use frame_support::{
 	storage::with_transaction,
-	traits::{Currency, Imbalance, IsSubType, OnUnbalanced},
+	traits::{ConstU32, Currency, Get, Imbalance, IsSubType, OnUnbalanced},
+	weights::{Weight, WeightMeter},
+	BoundedVec,
 };
 pub use pallet::*;
 use pallet_evm::{
 	account::CrossAccountId, EnsureAddressOrigin, NegativeImbalanceOf, OnChargeEVMTransaction,
 	OnCheckEvmTransaction,
 };
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_core::{H160, U256};
-use sp_runtime::{traits::UniqueSaturatedInto, DispatchError, TransactionOutcome};
+use sp_runtime::{
+	traits::{Saturating, UniqueSaturatedInto, Zero},
+	DispatchError, TransactionOutcome,
+};
+use sp_std::vec::Vec;
 use up_sponsorship::SponsorshipHandler;
 
+/// Maximum number of the top sponsored users returned by [`Pallet::sponsored_fees`].
+///
+/// The top is kept up to date on every sponsored call, so it is kept short.
+pub const MAX_TOP_SPONSORED_USERS: u32 = 20;
+
+/// Users, for which the most fees were paid by the sponsor, in descending order of the fees.
+pub type TopSponsoredUsers<CrossAccountId> =
+	BoundedVec<(CrossAccountId, u128), ConstU32<MAX_TOP_SPONSORED_USERS>>;
+
+/// Sponsoring of a call, whose fee is yet to be paid.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SponsoredCall<CrossAccountId> {
+	/// Account, which pays the fee.
+	pub sponsor: CrossAccountId,
+	/// Address of the sponsored collection or contract.
+	pub target: H160,
+	/// Account, on behalf of which the fee is paid.
+	pub who: CrossAccountId,
+}
+
+/// Handler of the fees paid by the sponsors, notified once the actual fee of a call is known.
+pub trait OnSponsoredFee<CrossAccountId> {
+	/// Handle the `fee` paid for the `sponsoring`.
+	fn on_sponsored_fee(sponsoring: &SponsoredCall<CrossAccountId>, fee: u128);
+}
+
+impl<CrossAccountId> OnSponsoredFee<CrossAccountId> for () {
+	fn on_sponsored_fee(_sponsoring: &SponsoredCall<CrossAccountId>, _fee: u128) {}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
-	use sp_std::vec::Vec;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
 
 	use super::*;
 
@@ -52,12 +91,302 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// Overarching event type.
+		type RuntimeEvent: IsType<<Self as frame_system::Config>::RuntimeEvent> + From<Event<Self>>;
+
 		/// Loosly-coupled handlers for evm call sponsoring
 		type EvmSponsorshipHandler: SponsorshipHandler<Self::CrossAccountId, CallContext>;
+
+		/// Handler of the actual fees paid by the sponsors, e.g. to charge the sponsoring budgets.
+		type OnSponsoredFee: OnSponsoredFee<Self::CrossAccountId>;
+
+		/// Length of an era of the sponsoring statistics, in blocks.
+		#[pallet::constant]
+		type SponsoringStatsEraLength: Get<u32>;
+
+		/// Number of the past eras, for which the sponsoring statistics is kept.
+		#[pallet::constant]
+		type SponsoringStatsHistoryDepth: Get<u32>;
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Sponsor paid the fee for a call of the sponsored collection or contract.
+		SponsoredFeePaid {
+			/// Account, which paid the fee.
+			sponsor: T::CrossAccountId,
+			/// Address of the sponsored collection or contract.
+			target: H160,
+			/// Account, on behalf of which the fee was paid.
+			who: T::CrossAccountId,
+			/// Paid fee.
+			fee: u128,
+		},
+	}
+
+	/// Fees paid by the sponsors.
+	///
+	/// * **Key1** - era of the sponsoring statistics.
+	/// * **Key2** - sponsor.
+	/// * **Value** - total fee paid within the era.
+	#[pallet::storage]
+	pub type SponsorFeesPaid<T: Config> = StorageDoubleMap<
+		Hasher1 = Twox64Concat,
+		Key1 = u32,
+		Hasher2 = Blake2_128Concat,
+		Key2 = T::CrossAccountId,
+		Value = u128,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Fees paid for the calls of the sponsored collections and contracts.
+	///
+	/// * **Key1** - era of the sponsoring statistics.
+	/// * **Key2** - address of the sponsored collection or contract.
+	/// * **Value** - total fee paid within the era.
+	#[pallet::storage]
+	pub type SponsoredFeesPaid<T: Config> = StorageDoubleMap<
+		Hasher1 = Twox64Concat,
+		Key1 = u32,
+		Hasher2 = Twox64Concat,
+		Key2 = H160,
+		Value = u128,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Fees paid for the calls of the sponsored collections and contracts on behalf of users.
+	///
+	/// * **Key1** - era of the sponsoring statistics.
+	/// * **Key2** - address of the sponsored collection or contract.
+	/// * **Key3** - sponsored user.
+	/// * **Value** - total fee paid within the era.
+	#[pallet::storage]
+	pub type UserFeesSponsored<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, u32>,
+			Key<Twox64Concat, H160>,
+			Key<Blake2_128Concat, T::CrossAccountId>,
+		),
+		Value = u128,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Sponsored users, for which the most fees were paid within an era.
+	///
+	/// * **Key1** - era of the sponsoring statistics.
+	/// * **Key2** - address of the sponsored collection or contract.
+	/// * **Value** - top users with the fees paid on their behalf, see [`TopSponsoredUsers`].
+	#[pallet::storage]
+	pub type TopUserFeesSponsored<T: Config> = StorageDoubleMap<
+		Hasher1 = Twox64Concat,
+		Key1 = u32,
+		Hasher2 = Twox64Concat,
+		Key2 = H160,
+		Value = TopSponsoredUsers<T::CrossAccountId>,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Oldest era, for which the sponsoring statistics may still be stored.
+	#[pallet::storage]
+	pub(super) type OldestStatsEra<T: Config> = StorageValue<Value = u32, QueryKind = ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			Self::clear_stale_stats(&mut meter);
+			meter.consumed()
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Era of the sponsoring statistics, which contains the current block.
+	pub fn current_stats_era() -> u32 {
+		let now: u32 = <frame_system::Pallet<T>>::block_number().unique_saturated_into();
+		now.checked_div(T::SponsoringStatsEraLength::get())
+			.unwrap_or_default()
+	}
+
+	/// Forget the sponsoring noted for a previous substrate call,
+	/// before looking for the sponsor of the call being applied.
+	pub fn clear_pending_sponsoring() {
+		pending::with(|pending| *pending = None);
+	}
+
+	/// Remember the sponsoring of the substrate call being applied,
+	/// to record it once the fee is paid, see [`Self::take_pending_sponsoring`].
+	pub fn note_sponsoring(sponsor: T::CrossAccountId, target: H160, who: T::CrossAccountId) {
+		let sponsoring = SponsoredCall {
+			sponsor,
+			target,
+			who,
+		};
+		pending::with(|pending| *pending = Some(sponsoring.encode()));
+	}
+
+	/// Address of the collection or contract, whose call sponsoring is noted,
+	/// see [`Self::note_sponsoring`].
+	pub fn pending_sponsoring_target() -> Option<H160> {
+		pending::with(|pending| Self::decode_sponsoring(pending.as_deref()))
+			.map(|sponsoring| sponsoring.target)
+	}
+
+	/// Take the sponsoring of the substrate call being applied, if its fee is paid by `payer`.
+	pub fn take_pending_sponsoring(
+		payer: &T::AccountId,
+	) -> Option<SponsoredCall<T::CrossAccountId>> {
+		pending::with(|pending| Self::decode_sponsoring(pending.take().as_deref()))
+			.filter(|sponsoring| sponsoring.sponsor.as_sub() == payer)
+	}
+
+	fn decode_sponsoring(encoded: Option<&[u8]>) -> Option<SponsoredCall<T::CrossAccountId>> {
+		SponsoredCall::decode(&mut encoded?).ok()
+	}
+
+	/// Account the `fee` paid by the sponsor in the statistics of the current era,
+	/// and pass it to [`Config::OnSponsoredFee`].
+	pub fn record_sponsored_fee(sponsoring: SponsoredCall<T::CrossAccountId>, fee: u128) {
+		if fee.is_zero() {
+			return;
+		}
+		T::OnSponsoredFee::on_sponsored_fee(&sponsoring, fee);
+		let era = Self::current_stats_era();
+		let SponsoredCall {
+			sponsor,
+			target,
+			who,
+		} = sponsoring;
+
+		<SponsorFeesPaid<T>>::mutate(era, &sponsor, |paid| *paid = paid.saturating_add(fee));
+		<SponsoredFeesPaid<T>>::mutate(era, target, |paid| *paid = paid.saturating_add(fee));
+		let user_paid = <UserFeesSponsored<T>>::mutate((era, target, &who), |paid| {
+			*paid = paid.saturating_add(fee);
+			*paid
+		});
+		<TopUserFeesSponsored<T>>::mutate(era, target, |top| {
+			Self::update_top_user(top, &who, user_paid)
+		});
+
+		Self::deposit_event(Event::SponsoredFeePaid {
+			sponsor,
+			target,
+			who,
+			fee,
+		});
+	}
+
+	/// Place `who` into the `top` according to the total fee `paid` on its behalf.
+	///
+	/// As the totals only grow, updating the user whose total has changed
+	/// keeps the `top` exact.
+	fn update_top_user(
+		top: &mut TopSponsoredUsers<T::CrossAccountId>,
+		who: &T::CrossAccountId,
+		paid: u128,
+	) {
+		if let Some(index) = top.iter().position(|(user, _)| user == who) {
+			top.remove(index);
+		}
+		let index = top.partition_point(|(_, user_paid)| *user_paid >= paid);
+		// Users past the end of a full top are dropped
+		let _ = top.force_insert_keep_left(index, (who.clone(), paid));
+	}
+
+	/// Get the fees paid by the `sponsor` within the `era`.
+	pub fn sponsor_fees_paid(sponsor: &T::CrossAccountId, era: u32) -> u128 {
+		<SponsorFeesPaid<T>>::get(era, sponsor)
+	}
+
+	/// Get the fees paid for the calls of the sponsored collection or contract at `target`
+	/// within the `era`, in total and on behalf of the `top` users, for which the most was paid.
+	pub fn sponsored_fees(
+		target: H160,
+		era: u32,
+		top: u32,
+	) -> (u128, Vec<(T::CrossAccountId, u128)>) {
+		let mut users = <TopUserFeesSponsored<T>>::get(era, target).into_inner();
+		users.truncate(top as usize);
+
+		(<SponsoredFeesPaid<T>>::get(era, target), users)
+	}
+
+	/// Remove the statistics of the eras, which are older than
+	/// [`Config::SponsoringStatsHistoryDepth`], within the `meter` limit.
+	fn clear_stale_stats(meter: &mut WeightMeter) {
+		let db_weight = T::DbWeight::get();
+		let entry_weight = db_weight.writes(1);
+		if meter.try_consume(db_weight.reads(1)).is_err() {
+			return;
+		}
+		let current_era = Self::current_stats_era();
+		let mut era = <OldestStatsEra<T>>::get();
+
+		while era.saturating_add(T::SponsoringStatsHistoryDepth::get()) < current_era {
+			let limit = meter
+				.remaining()
+				.checked_div_per_component(&entry_weight)
+				.unwrap_or(u64::MAX)
+				.min(u32::MAX.into()) as u32;
+			if limit <= 1 {
+				return;
+			}
+
+			// Per-user entries are the only ones, which may not fit into a single block
+			let result = <UserFeesSponsored<T>>::clear_prefix((era,), limit - 1, None);
+			meter.consume(entry_weight.saturating_mul(result.loops.into()));
+			if result.maybe_cursor.is_some() {
+				return;
+			}
+			let result = <SponsoredFeesPaid<T>>::clear_prefix(era, u32::MAX, None);
+			meter.consume(entry_weight.saturating_mul(result.loops.into()));
+			let result = <TopUserFeesSponsored<T>>::clear_prefix(era, u32::MAX, None);
+			meter.consume(entry_weight.saturating_mul(result.loops.into()));
+			let result = <SponsorFeesPaid<T>>::clear_prefix(era, u32::MAX, None);
+			meter.consume(entry_weight.saturating_mul(result.loops.into()));
+
+			era = era.saturating_add(1);
+			<OldestStatsEra<T>>::put(era);
+			meter.consume(entry_weight);
+		}
+	}
+}
+
+/// Sponsoring of the substrate call being applied, which is recorded once its fee is paid.
+///
+/// It only lives between the sponsor lookup and the fee payment of a single extrinsic,
+/// so it is kept in memory, and the calls which aren't sponsored don't touch the storage.
+mod pending {
+	use sp_std::{cell::RefCell, vec::Vec};
+
+	#[cfg(feature = "std")]
+	std::thread_local! {
+		static PENDING: RefCell<Option<Vec<u8>>> = RefCell::new(None);
+	}
+
+	/// Access the encoded pending sponsoring.
+	#[cfg(feature = "std")]
+	pub fn with<R>(f: impl FnOnce(&mut Option<Vec<u8>>) -> R) -> R {
+		PENDING.with(|pending| f(&mut pending.borrow_mut()))
+	}
+
+	#[cfg(not(feature = "std"))]
+	struct Pending(RefCell<Option<Vec<u8>>>);
+	// SAFETY: the runtime is executed in a single thread.
+	#[cfg(not(feature = "std"))]
+	unsafe impl Sync for Pending {}
+	#[cfg(not(feature = "std"))]
+	static PENDING: Pending = Pending(RefCell::new(None));
+
+	/// Access the encoded pending sponsoring.
+	#[cfg(not(feature = "std"))]
+	pub fn with<R>(f: impl FnOnce(&mut Option<Vec<u8>>) -> R) -> R {
+		f(&mut PENDING.0.borrow_mut())
+	}
 }
 
 fn who_pays_fee<T: Config>(
@@ -79,6 +408,41 @@ fn who_pays_fee<T: Config>(
 	}
 }
 
+/// Whether calls with the `gas_fee` price may be sponsored at all.
+pub fn accept_gas_fee<T: Config>(gas_fee: U256) -> bool {
+	let (base_fee, _) = T::FeeCalculator::min_gas_price();
+	// Metamask specifies base fee twice as much as chain reported minGasPrice
+	// But we allow further leeway (why?), sponsored base_fee to be 2.1*minGasPrice, thus 21/10.
+	base_fee <= gas_fee && gas_fee <= base_fee * 21 / 10
+}
+
+/// Find the sponsor of the transactional `source` call to `target`, without applying
+/// the effects of the sponsoring.
+pub fn dry_run_sponsor<T: Config>(
+	source: H160,
+	target: H160,
+	input: Vec<u8>,
+	gas_limit: u64,
+	max_fee_per_gas: U256,
+) -> Option<T::CrossAccountId> {
+	let reason = WithdrawReason::Call {
+		target,
+		input,
+		max_fee_per_gas: Some(max_fee_per_gas),
+		gas_limit,
+		is_transactional: true,
+		is_check: true,
+	};
+	get_sponsor::<T>(
+		source,
+		Some(max_fee_per_gas),
+		gas_limit,
+		&reason,
+		true,
+		true,
+	)
+}
+
 fn get_sponsor<T: Config>(
 	source: H160,
 	max_fee_per_gas: Option<U256>,
@@ -87,14 +451,8 @@ fn get_sponsor<T: Config>(
 	is_transactional: bool,
 	is_check: bool,
 ) -> Option<T::CrossAccountId> {
-	let accept_gas_fee = |gas_fee| {
-		let (base_fee, _) = T::FeeCalculator::min_gas_price();
-		// Metamask specifies base fee twice as much as chain reported minGasPrice
-		// But we allow further leeway (why?), sponsored base_fee to be 2.1*minGasPrice, thus 21/10.
-		base_fee <= gas_fee && gas_fee <= base_fee * 21 / 10
-	};
 	let (max_fee_per_gas, may_sponsor) = match (max_fee_per_gas, is_transactional) {
-		(Some(max_fee_per_gas), _) => (max_fee_per_gas, accept_gas_fee(max_fee_per_gas)),
+		(Some(max_fee_per_gas), _) => (max_fee_per_gas, accept_gas_fee::<T>(max_fee_per_gas)),
 		// Gas price check is skipped for non-transactional calls that don't
 		// define a `max_fee_per_gas` input.
 		(None, false) => (Default::default(), true),
@@ -126,6 +484,7 @@ where
 	C: IsSubType<pallet_evm::Call<T>>,
 {
 	fn get_sponsor(who: &T::AccountId, call: &C) -> Option<T::AccountId> {
+		<Pallet<T>>::clear_pending_sponsoring();
 		match call.is_sub_type()? {
 			pallet_evm::Call::call {
 				source,
@@ -156,6 +515,7 @@ where
 				})
 				// FIXME: it may fail with DispatchError in case of depth limit
 				.ok()??;
+				<Pallet<T>>::note_sponsoring(sponsor.clone(), *target, who);
 				Some(sponsor.as_sub().clone())
 			}
 			_ => None,
@@ -235,15 +595,19 @@ where
 	U256: UniqueSaturatedInto<<C as Currency<<T as frame_system::Config>::AccountId>>::Balance>,
 {
 	// Kept type as Option to satisfy bound of Default
-	type LiquidityInfo = (Option<NegativeImbalanceOf<C, T>>, Option<T::CrossAccountId>);
+	type LiquidityInfo = (
+		Option<NegativeImbalanceOf<C, T>>,
+		Option<SponsoredCall<T::CrossAccountId>>,
+	);
 
 	fn withdraw_fee(
 		who: &T::CrossAccountId,
 		reason: WithdrawReason,
 		fee: U256,
 	) -> Result<Self::LiquidityInfo, pallet_evm::Error<T>> {
-		let sponsor = match reason {
+		let sponsoring = match reason {
 			WithdrawReason::Call {
+				target,
 				max_fee_per_gas,
 				gas_limit,
 				is_transactional,
@@ -256,15 +620,22 @@ where
 				&reason,
 				is_transactional,
 				is_check,
-			),
+			)
+			.map(|sponsor| SponsoredCall {
+				sponsor,
+				target,
+				who: who.clone(),
+			}),
 			_ => None,
 		};
 
-		let who = sponsor.as_ref().unwrap_or(who);
+		let payer = sponsoring
+			.as_ref()
+			.map_or(who, |sponsoring| &sponsoring.sponsor);
 		<pallet_evm::EVMCurrencyAdapter<C, OU> as OnChargeEVMTransaction<T>>::withdraw_fee(
-			who, reason, fee,
+			payer, reason, fee,
 		)
-		.map(|li| (li, sponsor))
+		.map(|li| (li, sponsoring))
 	}
 
 	fn correct_and_deposit_fee(
@@ -273,17 +644,25 @@ where
 		base_fee: U256,
 		already_withdrawn: Self::LiquidityInfo,
 	) -> Self::LiquidityInfo {
-		let (already_withdrawn, sponsor) = already_withdrawn;
-		let who = sponsor.as_ref().unwrap_or(who);
-		(
+		let (already_withdrawn, sponsoring) = already_withdrawn;
+		let payer = sponsoring
+			.as_ref()
+			.map_or(who, |sponsoring| &sponsoring.sponsor);
+		let withdrawn: Option<u128> = already_withdrawn
+			.as_ref()
+			.map(|imbalance| imbalance.peek().unique_saturated_into());
+		let tip =
 			<pallet_evm::EVMCurrencyAdapter<C, OU> as OnChargeEVMTransaction<T>>::correct_and_deposit_fee(
-				who,
+				payer,
 				corrected_fee,
 				base_fee,
 				already_withdrawn,
-			),
-			None
-		)
+			);
+		if let (Some(sponsoring), Some(withdrawn)) = (sponsoring, withdrawn) {
+			let corrected_fee: u128 = corrected_fee.unique_saturated_into();
+			<Pallet<T>>::record_sponsored_fee(sponsoring, corrected_fee.min(withdrawn));
+		}
+		(tip, None)
 	}
 
 	fn pay_priority_fee(tip: Self::LiquidityInfo) {
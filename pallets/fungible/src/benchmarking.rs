@@ -194,4 +194,20 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn clear_destroyed_item() -> Result<(), BenchmarkError> {
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub; sender: cross_sub;
+		};
+		<Pallet<T>>::create_item(&collection, &owner, (sender.clone(), 200), &Unlimited)?;
+
+		#[block]
+		{
+			<Pallet<T>>::burn_destroyed_balance(collection.id, sender, 200);
+		}
+
+		Ok(())
+	}
 }
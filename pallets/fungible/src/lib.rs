@@ -81,7 +81,7 @@
 use core::ops::Deref;
 
 use evm_coder::ToLog;
-use frame_support::{dispatch::PostDispatchInfo, ensure, pallet_prelude::*};
+use frame_support::{dispatch::PostDispatchInfo, ensure, pallet_prelude::*, weights::WeightMeter};
 pub use pallet::*;
 use pallet_common::{
 	eth::collection_id_to_address, helpers::add_weight_to_post_info,
@@ -95,8 +95,8 @@ use sp_core::H160;
 use sp_runtime::{ArithmeticError, DispatchError, DispatchResult};
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 use up_data_structs::{
-	budget::Budget, mapping::TokenAddressMapping, AccessMode, CollectionId, Property, PropertyKey,
-	TokenId,
+	budget::Budget, mapping::TokenAddressMapping, AccessMode, CollectionAdminRoles, CollectionId,
+	Property, PropertyKey, TokenId,
 };
 use weights::WeightInfo;
 
@@ -231,6 +231,47 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Burn tokens and clear the storage of a collection which is being destroyed,
+	/// spending no more than the weight left in the `meter`.
+	///
+	/// Returns `true` if the collection storage is fully cleared.
+	pub fn clear_destroyed_collection(id: CollectionId, meter: &mut WeightMeter) -> bool {
+		let balance_weight = <SelfWeightOf<T>>::clear_destroyed_item();
+		loop {
+			if !meter.can_consume(balance_weight) {
+				return false;
+			}
+			let Some((owner, amount)) = <Balance<T>>::iter_prefix((id,)).next() else {
+				break;
+			};
+			meter.consume(balance_weight);
+			Self::burn_destroyed_balance(id, owner, amount);
+		}
+
+		let cleared = <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<Allowance<T>>::clear_prefix((id,), limit, None)
+		});
+		if !cleared || meter.try_consume(T::DbWeight::get().writes(1)).is_err() {
+			return false;
+		}
+
+		<TotalSupply<T>>::remove(id);
+		true
+	}
+
+	/// Burn the balance of `owner` in a collection which is being destroyed, without any checks.
+	pub(crate) fn burn_destroyed_balance(id: CollectionId, owner: T::CrossAccountId, amount: u128) {
+		<Balance<T>>::remove((id, &owner));
+		<PalletStructure<T>>::unnest_if_nested(&owner, id, TokenId::default());
+
+		<PalletCommon<T>>::deposit_event(CommonEvent::ItemDestroyed(
+			id,
+			TokenId::default(),
+			owner,
+			amount,
+		));
+	}
+
 	/// Add properties to the collection.
 	pub fn set_collection_properties(
 		collection: &FungibleHandle<T>,
@@ -412,7 +453,7 @@ impl<T: Config> Pallet<T> {
 		data: BTreeMap<T::CrossAccountId, u128>,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResult {
-		if !collection.is_owner_or_admin(depositor) {
+		if !collection.has_admin_roles(depositor, CollectionAdminRoles::MINTER) {
 			ensure!(
 				collection.permissions.mint_mode(),
 				<CommonError<T>>::PublicMintingNotAllowed
@@ -18,7 +18,7 @@ contract ERC165 is Dummy {
 }
 
 /// @title A contract that allows you to work with collections.
-/// @dev the ERC-165 identifier for this interface is 0xb34d97e9
+/// @dev the ERC-165 identifier for this interface is 0x38df6317
 contract Collection is Dummy, ERC165 {
 	// /// Set collection property.
 	// ///
@@ -414,10 +414,11 @@ contract Collection is Dummy, ERC165 {
 		return CrossAddress(0x0000000000000000000000000000000000000000, 0);
 	}
 
-	// /// Changes collection owner to another account
+	// /// Propose another account to become the collection owner
 	// ///
-	// /// @dev Owner can be changed only by current owner
-	// /// @param newOwner new owner account
+	// /// @dev Deprecated, use `proposeCollectionOwnerCross`. The ownership is handed over
+	// ///  only after the proposed account accepts it.
+	// /// @param newOwner Proposed owner account
 	// /// @dev EVM selector for this function is: 0x4f53e226,
 	// ///  or in textual repr: changeCollectionOwner(address)
 	// function changeCollectionOwner(address newOwner) public {
@@ -438,10 +439,11 @@ contract Collection is Dummy, ERC165 {
 		return new CrossAddress[](0);
 	}
 
-	/// Changes collection owner to another account
+	/// Propose another account to become the collection owner
 	///
-	/// @dev Owner can be changed only by current owner
-	/// @param newOwner new owner cross account
+	/// @dev Deprecated, use `proposeCollectionOwnerCross`. The ownership is handed over
+	///  only after the proposed account accepts it.
+	/// @param newOwner Proposed owner cross account
 	/// @dev EVM selector for this function is: 0x6496c497,
 	///  or in textual repr: changeCollectionOwnerCross((address,uint256))
 	function changeCollectionOwnerCross(CrossAddress memory newOwner) public {
@@ -449,6 +451,88 @@ contract Collection is Dummy, ERC165 {
 		newOwner;
 		dummy = 0;
 	}
+
+	/// Propose another account to become the collection owner.
+	///
+	/// @dev The ownership is handed over only after the proposed account accepts it.
+	/// @param newOwner Proposed owner cross account
+	/// @param expiresAt The last block at which the proposal can be accepted, or 0 if it does not expire
+	/// @dev EVM selector for this function is: 0xb05f0cc4,
+	///  or in textual repr: proposeCollectionOwnerCross((address,uint256),uint32)
+	function proposeCollectionOwnerCross(CrossAddress memory newOwner, uint32 expiresAt) public {
+		require(false, stub_error);
+		newOwner;
+		expiresAt;
+		dummy = 0;
+	}
+
+	/// Whether there is a pending owner.
+	/// @dev EVM selector for this function is: 0x8f17f795,
+	///  or in textual repr: hasCollectionPendingOwner()
+	function hasCollectionPendingOwner() public view returns (bool) {
+		require(false, stub_error);
+		dummy;
+		return false;
+	}
+
+	/// Get proposed owner.
+	///
+	/// @return Cross account of the proposed owner, or zero address if there is none.
+	/// @dev EVM selector for this function is: 0xe71220dd,
+	///  or in textual repr: collectionPendingOwner()
+	function collectionPendingOwner() public view returns (CrossAddress memory) {
+		require(false, stub_error);
+		dummy;
+		return CrossAddress(0x0000000000000000000000000000000000000000, 0);
+	}
+
+	/// Accept the collection ownership.
+	///
+	/// @dev The caller must be proposed as the owner with `proposeCollectionOwnerCross` first.
+	/// @dev EVM selector for this function is: 0xb48773ac,
+	///  or in textual repr: acceptCollectionOwnership()
+	function acceptCollectionOwnership() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+
+	/// Cancel the pending collection ownership transfer.
+	///
+	/// @dev Can only be called by the current owner.
+	/// @dev EVM selector for this function is: 0xe117ff57,
+	///  or in textual repr: cancelCollectionOwnerProposal()
+	function cancelCollectionOwnerProposal() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+
+	/// Set the roles granted to a collection admin.
+	///
+	/// @dev Can only be called by the collection owner.
+	/// @param admin Cross account administrator address
+	/// @param roles Bit set of the roles: 1 - minter, 2 - metadata editor,
+	///  4 - allowlist manager, 8 - sponsor manager, 16 - limit manager
+	/// @dev EVM selector for this function is: 0xcc3dc830,
+	///  or in textual repr: setCollectionAdminRolesCross((address,uint256),uint8)
+	function setCollectionAdminRolesCross(CrossAddress memory admin, uint8 roles) public {
+		require(false, stub_error);
+		admin;
+		roles;
+		dummy = 0;
+	}
+
+	/// Get the roles granted to a collection admin.
+	///
+	/// @param admin Cross account administrator address
+	/// @return Bit set of the roles, or 0 if the account is not an admin
+	/// @dev EVM selector for this function is: 0xca656bb9,
+	///  or in textual repr: collectionAdminRoles((address,uint256))
+	function collectionAdminRoles(CrossAddress memory admin) public view returns (uint8) {
+		require(false, stub_error);
+		admin;
+		dummy;
+		return 0;
+	}
 }
 
 /// Cross account struct
@@ -42,6 +42,7 @@ pub trait WeightInfo {
 	fn check_allowed_raw() -> Weight;
 	fn set_allowance_unchecked_raw() -> Weight;
 	fn burn_from() -> Weight;
+	fn clear_destroyed_item() -> Weight;
 }
 
 /// Weights for pallet_fungible using the Substrate node and recommended hardware.
@@ -164,6 +165,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Fungible::Balance` (r:1 w:1)
+	/// Proof: `Fungible::Balance` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::EquippedIn` (r:1 w:0)
+	/// Proof: `Structure::EquippedIn` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	fn clear_destroyed_item() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `182`
+		//  Estimated: `3589`
+		// Minimum execution time: 10_427_000 picoseconds.
+		Weight::from_parts(10_873_000, 3589)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -285,5 +299,18 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Fungible::Balance` (r:1 w:1)
+	/// Proof: `Fungible::Balance` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::EquippedIn` (r:1 w:0)
+	/// Proof: `Structure::EquippedIn` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	fn clear_destroyed_item() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `182`
+		//  Estimated: `3589`
+		// Minimum execution time: 10_427_000 picoseconds.
+		Weight::from_parts(10_873_000, 3589)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
 
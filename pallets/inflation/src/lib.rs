@@ -25,8 +25,13 @@
 //! ### Dispatchable Functions
 //!
 //! * `start_inflation` - This method sets the inflation start date. Can be only called once.
-//! Inflation start block can be backdated and will catch up. The method will create Treasury
-//! account if it does not exist and perform the first inflation deposit.
+//! Inflation start block can be backdated and will catch up. The method will create beneficiary
+//! accounts if they do not exist and perform the first inflation deposit.
+//! * `set_inflation_schedule` - Schedules new inflation parameters (start and end rates, number of
+//! decay years and the length of the inflation year). The schedule takes effect at the next
+//! inflation recalculation.
+//! * `set_inflation_split` - Sets the shares of the minted inflation for each beneficiary.
+//! By default, the whole inflation goes to the Treasury.
 
 // #![recursion_limit = "1024"]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -34,18 +39,27 @@
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migration;
 #[cfg(test)]
 mod tests;
 
-use frame_support::traits::{
-	fungible::{Balanced, Inspect, Mutate},
-	tokens::Precision,
-	Get,
+use frame_support::{
+	traits::{
+		fungible::{Balanced, Inspect, Mutate},
+		tokens::Precision,
+		ConstU32, Get,
+	},
+	BoundedVec,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
-use sp_runtime::{traits::BlockNumberProvider, Perbill};
-use sp_std::convert::TryInto;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{BlockNumberProvider, Saturating, Zero},
+	PerThing, Perbill, RuntimeDebug,
+};
+use sp_std::{collections::btree_set::BTreeSet, convert::TryInto, vec, vec::Vec};
 
 type BalanceOf<T> =
 	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -53,8 +67,50 @@ type BalanceOf<T> =
 pub const YEAR: u32 = 5_259_600; // 6-second block
 								 // pub const YEAR: u32 = 2_629_800; // 12-second block
 pub const TOTAL_YEARS_UNTIL_FLAT: u32 = 9;
+/// Maximum number of years the inflation rate may decrease over.
+pub const MAX_DECAY_YEARS: u32 = 100;
 pub const START_INFLATION_PERCENT: u32 = 10;
 pub const END_INFLATION_PERCENT: u32 = 4;
+pub const MAX_INFLATION_BENEFICIARIES: u32 = 8;
+pub const MAX_ISSUANCE_HISTORY: u32 = 20;
+
+/// Beneficiaries of the minted inflation with their shares.
+pub type InflationSplitOf<T> = BoundedVec<
+	(<T as frame_system::Config>::AccountId, Perbill),
+	ConstU32<MAX_INFLATION_BENEFICIARIES>,
+>;
+
+/// Recalculation (relay) block, total issuance at the start of the year and the block inflation
+/// for the last recalculations.
+pub type IssuanceHistoryOf<T> =
+	BoundedVec<(BlockNumberFor<T>, BalanceOf<T>, BalanceOf<T>), ConstU32<MAX_ISSUANCE_HISTORY>>;
+
+/// Parameters of the inflation curve.
+///
+/// The inflation rate decreases linearly from `start_inflation` to `end_inflation`
+/// over `decay_years` years and stays flat afterwards.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InflationSchedule<BlockNumber> {
+	/// Annual inflation rate of the first year
+	pub start_inflation: Perbill,
+	/// Annual inflation rate once the decay is over
+	pub end_inflation: Perbill,
+	/// Number of years the rate decreases from `start_inflation` to `end_inflation`
+	pub decay_years: u32,
+	/// Number of (relay) blocks in the inflation year, i.e. between inflation recalculations
+	pub year_length: BlockNumber,
+}
+
+impl<BlockNumber: From<u32>> Default for InflationSchedule<BlockNumber> {
+	fn default() -> Self {
+		Self {
+			start_inflation: Perbill::from_percent(START_INFLATION_PERCENT),
+			end_inflation: Perbill::from_percent(END_INFLATION_PERCENT),
+			decay_years: TOTAL_YEARS_UNTIL_FLAT,
+			year_length: YEAR.into(),
+		}
+	}
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -65,6 +121,8 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		type Currency: Balanced<Self::AccountId>
 			+ Inspect<Self::AccountId>
 			+ Mutate<Self::AccountId>;
@@ -80,9 +138,57 @@ pub mod pallet {
 		type InflationBlockInterval: Get<BlockNumberFor<Self>>;
 	}
 
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New inflation schedule is set, it will be applied at the next recalculation
+		InflationScheduleSet {
+			schedule: InflationSchedule<BlockNumberFor<T>>,
+			effective_from: BlockNumberFor<T>,
+		},
+		/// Pending inflation schedule is applied
+		InflationScheduleApplied {
+			schedule: InflationSchedule<BlockNumberFor<T>>,
+		},
+		/// New inflation split is set
+		InflationSplitSet { split: InflationSplitOf<T> },
+		/// Inflation is minted to the beneficiary
+		InflationMinted {
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Inflation couldn't be minted to the beneficiary
+		InflationMintFailed {
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+			error: DispatchError,
+		},
+		/// Block inflation is recalculated for the new inflation year
+		InflationRecalculated {
+			year: u32,
+			total_issuance: BalanceOf<T>,
+			block_inflation: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Inflation year length must be non-zero
+		InvalidYearLength,
+		/// Inflation decay must take no more than [`MAX_DECAY_YEARS`] years
+		InvalidDecayYears,
+		/// Inflation shares must sum up to 100% with no duplicate beneficiaries
+		InvalidInflationSplit,
+		/// Inflation beneficiary account does not exist
+		BeneficiaryNotFound,
+	}
+
 	/// starting year total issuance
 	#[pallet::storage]
 	pub type StartingYearTotalIssuance<T: Config> =
@@ -107,8 +213,38 @@ pub mod pallet {
 	pub type StartBlock<T: Config> =
 		StorageValue<Value = BlockNumberFor<T>, QueryKind = ValueQuery>;
 
+	/// Current inflation schedule
+	#[pallet::storage]
+	pub type Schedule<T: Config> =
+		StorageValue<Value = InflationSchedule<BlockNumberFor<T>>, QueryKind = ValueQuery>;
+
+	/// Inflation schedule to be applied at the next recalculation
+	#[pallet::storage]
+	pub type PendingSchedule<T: Config> =
+		StorageValue<Value = InflationSchedule<BlockNumberFor<T>>, QueryKind = OptionQuery>;
+
+	/// Index of the inflation year which starts at the next recalculation
+	#[pallet::storage]
+	pub type NextInflationYear<T: Config> = StorageValue<Value = u32, QueryKind = ValueQuery>;
+
+	/// Beneficiaries of the minted inflation with their shares.
+	/// If empty, the whole inflation goes to the Treasury.
+	#[pallet::storage]
+	pub type InflationSplit<T: Config> =
+		StorageValue<Value = InflationSplitOf<T>, QueryKind = ValueQuery>;
+
+	/// Recalculation (relay) block, starting year total issuance and block inflation
+	/// for the last `MAX_ISSUANCE_HISTORY` recalculations, oldest first.
+	#[pallet::storage]
+	pub type IssuanceHistory<T: Config> =
+		StorageValue<Value = IssuanceHistoryOf<T>, QueryKind = ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migration::migrate_to_v1::<T>()
+		}
+
 		fn on_initialize(_: BlockNumberFor<T>) -> Weight
 		where
 			BlockNumberFor<T>: From<u32>,
@@ -134,16 +270,42 @@ pub mod pallet {
 				add_weight(1, 0, Weight::zero());
 				if current_relay_block >= next_recalculation {
 					Self::recalculate_inflation(next_recalculation);
-					add_weight(0, 4, Weight::from_parts(5_000_000, 0));
+					add_weight(4, 7, Weight::from_parts(5_000_000, 0));
 				}
 
-				T::Currency::mint_into(&T::TreasuryAccountId::get(), <BlockInflation<T>>::get())
-					.ok();
+				let split = Self::split_inflation(<BlockInflation<T>>::get());
+				let beneficiaries = split.len() as u64;
+				for (beneficiary, amount) in split {
+					match T::Currency::mint_into(&beneficiary, amount) {
+						Ok(_) => Self::deposit_event(Event::InflationMinted {
+							beneficiary,
+							amount,
+						}),
+						Err(error) => {
+							log::error!(
+								target: "runtime::inflation",
+								"Failed to mint {:?} of inflation to {:?}: {:?}",
+								amount,
+								beneficiary,
+								error,
+							);
+							Self::deposit_event(Event::InflationMintFailed {
+								beneficiary,
+								amount,
+								error,
+							});
+						}
+					}
+				}
 
 				// Update inflation block
 				<NextInflationBlock<T>>::set(next_inflation + block_interval.into());
 
-				add_weight(3, 3, Weight::from_parts(10_000_000, 0));
+				add_weight(
+					3 + beneficiaries,
+					1 + 2 * beneficiaries,
+					Weight::from_parts(10_000_000, 0).saturating_mul(beneficiaries),
+				);
 			}
 
 			consumed_weight
@@ -187,46 +349,206 @@ pub mod pallet {
 				let block_interval: u32 = T::InflationBlockInterval::get().try_into().unwrap_or(0);
 				<NextInflationBlock<T>>::set(inflation_start_relay_block + block_interval.into());
 
-				// First time deposit - create Treasury account so that we can call deposit_into_existing everywhere else
-				let _ = T::Currency::deposit(
-					&T::TreasuryAccountId::get(),
-					<BlockInflation<T>>::get(),
-					Precision::Exact,
-				)?;
+				// First time deposit - create beneficiary accounts so that we can call deposit_into_existing everywhere else
+				for (beneficiary, amount) in Self::split_inflation(<BlockInflation<T>>::get()) {
+					let _ = T::Currency::deposit(&beneficiary, amount, Precision::Exact)?;
+					Self::deposit_event(Event::InflationMinted {
+						beneficiary,
+						amount,
+					});
+				}
 			}
 
 			Ok(())
 		}
+
+		/// Schedule new inflation parameters.
+		///
+		/// The schedule takes effect at the next inflation recalculation, i.e. at
+		/// `NextRecalculationBlock` (or at the inflation start if it has not been started yet).
+		/// The year counter is kept, so the new rates are applied to the current inflation year.
+		///
+		/// # Permissions
+		///
+		/// * Root
+		///
+		/// # Arguments
+		///
+		/// * schedule: New inflation schedule
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_inflation_schedule(
+			origin: OriginFor<T>,
+			schedule: InflationSchedule<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				!schedule.year_length.is_zero(),
+				<Error<T>>::InvalidYearLength
+			);
+			ensure!(
+				schedule.decay_years <= MAX_DECAY_YEARS,
+				<Error<T>>::InvalidDecayYears
+			);
+
+			<PendingSchedule<T>>::put(schedule.clone());
+			Self::deposit_event(Event::InflationScheduleSet {
+				schedule,
+				effective_from: <NextRecalculationBlock<T>>::get(),
+			});
+
+			Ok(())
+		}
+
+		/// Set the shares of the minted inflation for each beneficiary.
+		/// Takes effect starting with the next inflation deposit.
+		///
+		/// # Permissions
+		///
+		/// * Root
+		///
+		/// # Arguments
+		///
+		/// * split: Beneficiaries with their shares, which must sum up to 100%.
+		/// If empty, the whole inflation goes to the Treasury. Once the inflation is started,
+		/// the beneficiary accounts must exist, as `start_inflation` won't create them anymore.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1 + MAX_INFLATION_BENEFICIARIES as u64, 1))]
+		pub fn set_inflation_split(
+			origin: OriginFor<T>,
+			split: InflationSplitOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let total_share = split
+				.iter()
+				.map(|(_, share)| share.deconstruct() as u64)
+				.sum::<u64>();
+			ensure!(
+				split.is_empty() || total_share == Perbill::ACCURACY as u64,
+				<Error<T>>::InvalidInflationSplit
+			);
+			let mut beneficiaries = BTreeSet::new();
+			ensure!(
+				split
+					.iter()
+					.all(|(beneficiary, _)| beneficiaries.insert(beneficiary)),
+				<Error<T>>::InvalidInflationSplit
+			);
+			if !<StartBlock<T>>::get().is_zero() {
+				ensure!(
+					split.iter().all(
+						|(beneficiary, _)| <frame_system::Pallet<T>>::account_exists(beneficiary)
+					),
+					<Error<T>>::BeneficiaryNotFound
+				);
+			}
+
+			<InflationSplit<T>>::put(split.clone());
+			Self::deposit_event(Event::InflationSplitSet { split });
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	pub fn recalculate_inflation(recalculation_block: BlockNumberFor<T>) {
-		let current_year: u32 = ((recalculation_block - <StartBlock<T>>::get())
-			/ BlockNumberFor::<T>::from(YEAR))
-		.try_into()
-		.unwrap_or(0);
-		let block_interval: u32 = T::InflationBlockInterval::get().try_into().unwrap_or(0);
-
-		let one_percent = Perbill::from_percent(1);
+	/// Recalculation (relay) block, starting year total issuance and block inflation
+	/// for the last recalculations, oldest first.
+	pub fn issuance_history() -> Vec<(BlockNumberFor<T>, BalanceOf<T>, BalanceOf<T>)> {
+		<IssuanceHistory<T>>::get().into_inner()
+	}
 
-		if current_year <= TOTAL_YEARS_UNTIL_FLAT {
-			let amount: BalanceOf<T> = Perbill::from_rational(
-				block_interval
-					* (START_INFLATION_PERCENT * TOTAL_YEARS_UNTIL_FLAT
-						- current_year * (START_INFLATION_PERCENT - END_INFLATION_PERCENT)),
-				YEAR * TOTAL_YEARS_UNTIL_FLAT,
-			) * (one_percent * T::Currency::total_issuance());
-			<BlockInflation<T>>::put(amount);
+	/// Beneficiaries of the minted inflation with their shares.
+	pub fn inflation_split() -> Vec<(T::AccountId, Perbill)> {
+		let split = <InflationSplit<T>>::get();
+		if split.is_empty() {
+			vec![(T::TreasuryAccountId::get(), Perbill::one())]
 		} else {
-			let amount: BalanceOf<T> =
-				Perbill::from_rational(block_interval * END_INFLATION_PERCENT, YEAR)
-					* (one_percent * T::Currency::total_issuance());
-			<BlockInflation<T>>::put(amount);
+			split.into_inner()
 		}
-		<StartingYearTotalIssuance<T>>::set(T::Currency::total_issuance());
+	}
+
+	/// Split the inflation amount between the beneficiaries.
+	/// The rounding remainder goes to the last beneficiary.
+	fn split_inflation(amount: BalanceOf<T>) -> Vec<(T::AccountId, BalanceOf<T>)> {
+		let split = Self::inflation_split();
+		let last = split.len() - 1;
+		let mut remaining = amount;
+		split
+			.into_iter()
+			.enumerate()
+			.map(|(i, (beneficiary, share))| {
+				let part = if i == last {
+					remaining
+				} else {
+					(share * amount).min(remaining)
+				};
+				remaining = remaining.saturating_sub(part);
+				(beneficiary, part)
+			})
+			.collect()
+	}
+
+	pub fn recalculate_inflation(recalculation_block: BlockNumberFor<T>) {
+		let schedule = match <PendingSchedule<T>>::take() {
+			Some(schedule) => {
+				<Schedule<T>>::put(schedule.clone());
+				Self::deposit_event(Event::InflationScheduleApplied {
+					schedule: schedule.clone(),
+				});
+				schedule
+			}
+			None => <Schedule<T>>::get(),
+		};
+		let current_year = <NextInflationYear<T>>::get();
+		let block_interval: u128 = T::InflationBlockInterval::get().try_into().unwrap_or(0);
+		let year_length: u128 = schedule.year_length.try_into().unwrap_or(u128::MAX);
+
+		// Block inflation is calculated as a fraction of 1% of the total issuance,
+		// so rounding is the same as with the whole percent rates
+		let one_percent = Perbill::from_percent(1);
+		let percent_parts = (Perbill::ACCURACY / 100) as u128;
+		let start_rate = schedule.start_inflation.deconstruct() as u128;
+		let end_rate = schedule.end_inflation.deconstruct() as u128;
+		let decay_years = schedule.decay_years as u128;
+		let year = current_year as u128;
+
+		let (rate, years) = if year < decay_years {
+			(
+				start_rate
+					.saturating_mul(decay_years)
+					.saturating_add(year.saturating_mul(end_rate))
+					.saturating_sub(year.saturating_mul(start_rate)),
+				decay_years,
+			)
+		} else {
+			(end_rate, 1)
+		};
+		let amount: BalanceOf<T> = Perbill::from_rational(
+			block_interval.saturating_mul(rate),
+			year_length
+				.saturating_mul(years)
+				.saturating_mul(percent_parts),
+		) * (one_percent * T::Currency::total_issuance());
+		let total_issuance = T::Currency::total_issuance();
+		<BlockInflation<T>>::put(amount);
+		<StartingYearTotalIssuance<T>>::set(total_issuance);
+		<NextInflationYear<T>>::put(current_year.saturating_add(1));
+
+		<IssuanceHistory<T>>::mutate(|history| {
+			if history.is_full() {
+				history.remove(0);
+			}
+			let _ = history.try_push((recalculation_block, total_issuance, amount));
+		});
+		Self::deposit_event(Event::InflationRecalculated {
+			year: current_year,
+			total_issuance,
+			block_inflation: amount,
+		});
 
 		// Update recalculation and inflation blocks
-		<NextRecalculationBlock<T>>::set(recalculation_block + YEAR.into());
+		<NextRecalculationBlock<T>>::set(recalculation_block.saturating_add(schedule.year_length));
 	}
 }
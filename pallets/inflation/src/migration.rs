@@ -0,0 +1,68 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the Inflation pallet.
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+use crate::{
+	Config, InflationSchedule, NextInflationYear, NextRecalculationBlock, Pallet, Schedule,
+	StartBlock, YEAR,
+};
+
+/// Move the inflation schedule to the storage.
+///
+/// The schedule is seeded with the previously hardcoded constants, and the index of the next
+/// inflation year is restored from the recalculation block.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let on_chain_version = <Pallet<T>>::on_chain_storage_version();
+	let mut weight = T::DbWeight::get().reads(1);
+
+	if on_chain_version >= 1 {
+		log::info!(
+			target: "runtime::inflation",
+			"Inflation schedule is already migrated, skipping",
+		);
+		return weight;
+	}
+
+	<Schedule<T>>::put(InflationSchedule::<BlockNumberFor<T>>::default());
+
+	let start_block = <StartBlock<T>>::get();
+	let next_recalculation = <NextRecalculationBlock<T>>::get();
+	let next_year: u32 = if next_recalculation > start_block {
+		((next_recalculation - start_block) / BlockNumberFor::<T>::from(YEAR))
+			.try_into()
+			.unwrap_or(0)
+	} else {
+		0
+	};
+	<NextInflationYear<T>>::put(next_year);
+	StorageVersion::new(1).put::<Pallet<T>>();
+
+	log::info!(
+		target: "runtime::inflation",
+		"Migrated inflation schedule, next inflation year is {}",
+		next_year,
+	);
+
+	weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 3));
+	weight
+}
@@ -17,7 +17,7 @@
 #![cfg(test)]
 #![allow(clippy::from_over_into)]
 use frame_support::{
-	assert_ok, parameter_types,
+	assert_noop, assert_ok, parameter_types,
 	traits::{
 		fungible::{Balanced, Inspect},
 		tokens::Precision,
@@ -28,8 +28,8 @@ use frame_support::{
 use frame_system::RawOrigin;
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
-	BuildStorage,
+	traits::{BadOrigin, BlakeTwo256, BlockNumberProvider, IdentityLookup},
+	BuildStorage, Perbill,
 };
 
 use crate as pallet_inflation;
@@ -51,7 +51,7 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 	type Balance = u64;
 	type DustRemoval = ();
-	type RuntimeEvent = ();
+	type RuntimeEvent = RuntimeEvent;
 	type ExistentialDeposit = ExistentialDeposit;
 	type WeightInfo = ();
 	type MaxLocks = MaxLocks;
@@ -92,7 +92,7 @@ impl frame_system::Config for Test {
 	type Hashing = BlakeTwo256;
 	type AccountId = u64;
 	type Lookup = IdentityLookup<Self::AccountId>;
-	type RuntimeEvent = ();
+	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
@@ -120,6 +120,7 @@ impl BlockNumberProvider for MockBlockNumberProvider {
 }
 
 impl pallet_inflation::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type TreasuryAccountId = TreasuryAccountId;
 	type InflationBlockInterval = InflationBlockInterval;
@@ -127,10 +128,12 @@ impl pallet_inflation::Config for Test {
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	<frame_system::GenesisConfig<Test>>::default()
+	let mut ext: sp_io::TestExternalities = <frame_system::GenesisConfig<Test>>::default()
 		.build_storage()
 		.unwrap()
-		.into()
+		.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
 
 macro_rules! block_inflation {
@@ -364,3 +367,245 @@ fn inflation_rate_by_year() {
 		}
 	});
 }
+
+#[test]
+fn inflation_schedule_applies_at_next_recalculation() {
+	new_test_ext().execute_with(|| {
+		let payouts = (YEAR / InflationBlockInterval::get()) as u64;
+		let initial_issuance = 1000 * payouts * 10;
+		let _ = <Balances as Balanced<_>>::deposit(&1234, initial_issuance, Precision::Exact);
+
+		// Start inflation as sudo
+		assert_ok!(Inflation::start_inflation(RawOrigin::Root.into(), 1));
+		assert_eq!(block_inflation!(), 1000);
+
+		// Inflation goes from 20% to 4% in 4 years
+		let schedule = pallet_inflation::InflationSchedule {
+			start_inflation: Perbill::from_percent(20),
+			end_inflation: Perbill::from_percent(4),
+			decay_years: 4,
+			year_length: YEAR,
+		};
+		assert_ok!(Inflation::set_inflation_schedule(
+			RawOrigin::Root.into(),
+			schedule.clone()
+		));
+		System::assert_last_event(
+			pallet_inflation::Event::InflationScheduleSet {
+				schedule: schedule.clone(),
+				effective_from: YEAR + 1,
+			}
+			.into(),
+		);
+
+		// The current year is not affected
+		MockBlockNumberProvider::set(YEAR / 2);
+		Inflation::on_initialize(0);
+		assert_eq!(block_inflation!(), 1000);
+
+		// Year 1 of the new schedule: 20% - 4% = 16%
+		MockBlockNumberProvider::set(YEAR + 1);
+		Inflation::on_initialize(0);
+		assert_eq!(block_inflation!() / 10, 160); // divide by 10 for approx. equality
+		assert_eq!(<pallet_inflation::Schedule<Test>>::get(), schedule);
+		System::assert_has_event(
+			pallet_inflation::Event::InflationScheduleApplied { schedule }.into(),
+		);
+
+		// Flat after 4 years
+		for year in 2..=5 {
+			MockBlockNumberProvider::set(YEAR * year + 1);
+			Inflation::on_initialize(0);
+		}
+		assert_eq!(block_inflation!() / 10, 40);
+	});
+}
+
+#[test]
+fn inflation_schedule_requires_root_and_year_length() {
+	new_test_ext().execute_with(|| {
+		let mut schedule = pallet_inflation::InflationSchedule::default();
+		assert_noop!(
+			Inflation::set_inflation_schedule(RawOrigin::Signed(1).into(), schedule.clone()),
+			BadOrigin
+		);
+
+		schedule.year_length = 0;
+		assert_noop!(
+			Inflation::set_inflation_schedule(RawOrigin::Root.into(), schedule),
+			pallet_inflation::Error::<Test>::InvalidYearLength
+		);
+	});
+}
+
+#[test]
+fn inflation_schedule_decay_years_are_limited() {
+	new_test_ext().execute_with(|| {
+		let mut schedule = pallet_inflation::InflationSchedule::default();
+		schedule.decay_years = pallet_inflation::MAX_DECAY_YEARS + 1;
+		assert_noop!(
+			Inflation::set_inflation_schedule(RawOrigin::Root.into(), schedule.clone()),
+			pallet_inflation::Error::<Test>::InvalidDecayYears
+		);
+
+		// The longest decay with the highest rates doesn't overflow
+		let _ = <Balances as Balanced<_>>::deposit(&1234, 1_000_000, Precision::Exact);
+		schedule.decay_years = pallet_inflation::MAX_DECAY_YEARS;
+		schedule.start_inflation = Perbill::one();
+		schedule.end_inflation = Perbill::one();
+		schedule.year_length = 1;
+		assert_ok!(Inflation::set_inflation_schedule(
+			RawOrigin::Root.into(),
+			schedule
+		));
+		Inflation::recalculate_inflation(1);
+		assert_eq!(block_inflation!(), 10_000);
+	});
+}
+
+#[test]
+fn inflation_split_between_beneficiaries() {
+	new_test_ext().execute_with(|| {
+		let payouts = (YEAR / InflationBlockInterval::get()) as u64;
+		let initial_issuance = 1000 * payouts * 10;
+		let _ = <Balances as Balanced<_>>::deposit(&1234, initial_issuance, Precision::Exact);
+
+		let split = vec![
+			(1234, Perbill::from_percent(50)),
+			(1, Perbill::from_percent(30)),
+			(2, Perbill::from_percent(20)),
+		];
+		assert_ok!(Inflation::set_inflation_split(
+			RawOrigin::Root.into(),
+			split.clone().try_into().unwrap()
+		));
+		assert_eq!(Inflation::inflation_split(), split);
+
+		// Start inflation as sudo, beneficiary accounts are created
+		assert_ok!(Inflation::start_inflation(RawOrigin::Root.into(), 1));
+		assert_eq!(block_inflation!(), 1000);
+		assert_eq!(Balances::free_balance(1234), initial_issuance + 500);
+		assert_eq!(Balances::free_balance(1), 300);
+		assert_eq!(Balances::free_balance(2), 200);
+
+		MockBlockNumberProvider::set(1 + InflationBlockInterval::get());
+		Inflation::on_initialize(0);
+		assert_eq!(Balances::free_balance(1234), initial_issuance + 1000);
+		assert_eq!(Balances::free_balance(1), 600);
+		assert_eq!(Balances::free_balance(2), 400);
+		System::assert_has_event(
+			pallet_inflation::Event::InflationMinted {
+				beneficiary: 1,
+				amount: 300,
+			}
+			.into(),
+		);
+
+		// New beneficiaries must exist once the inflation is started
+		assert_noop!(
+			Inflation::set_inflation_split(
+				RawOrigin::Root.into(),
+				vec![
+					(1234, Perbill::from_percent(50)),
+					(99, Perbill::from_percent(50))
+				]
+				.try_into()
+				.unwrap()
+			),
+			pallet_inflation::Error::<Test>::BeneficiaryNotFound
+		);
+	});
+}
+
+#[test]
+fn inflation_split_must_be_valid() {
+	new_test_ext().execute_with(|| {
+		// Whole inflation goes to the Treasury by default
+		assert_eq!(
+			Inflation::inflation_split(),
+			vec![(TreasuryAccountId::get(), Perbill::one())]
+		);
+
+		assert_noop!(
+			Inflation::set_inflation_split(
+				RawOrigin::Signed(1).into(),
+				vec![(1, Perbill::one())].try_into().unwrap()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Inflation::set_inflation_split(
+				RawOrigin::Root.into(),
+				vec![
+					(1, Perbill::from_percent(50)),
+					(2, Perbill::from_percent(40))
+				]
+				.try_into()
+				.unwrap()
+			),
+			pallet_inflation::Error::<Test>::InvalidInflationSplit
+		);
+		assert_noop!(
+			Inflation::set_inflation_split(
+				RawOrigin::Root.into(),
+				vec![
+					(1, Perbill::from_percent(50)),
+					(1, Perbill::from_percent(50))
+				]
+				.try_into()
+				.unwrap()
+			),
+			pallet_inflation::Error::<Test>::InvalidInflationSplit
+		);
+	});
+}
+
+#[test]
+fn inflation_recalculation_is_recorded() {
+	new_test_ext().execute_with(|| {
+		let payouts = (YEAR / InflationBlockInterval::get()) as u64;
+		let initial_issuance = 1000 * payouts * 10;
+		let _ = <Balances as Balanced<_>>::deposit(&1234, initial_issuance, Precision::Exact);
+
+		// Start inflation as sudo
+		assert_ok!(Inflation::start_inflation(RawOrigin::Root.into(), 1));
+		System::assert_has_event(
+			pallet_inflation::Event::InflationRecalculated {
+				year: 0,
+				total_issuance: initial_issuance,
+				block_inflation: 1000,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			pallet_inflation::Event::InflationMinted {
+				beneficiary: 1234,
+				amount: 1000,
+			}
+			.into(),
+		);
+
+		for year in 1..=(pallet_inflation::MAX_ISSUANCE_HISTORY + 1) {
+			MockBlockNumberProvider::set(YEAR * year + 1);
+			Inflation::on_initialize(0);
+		}
+
+		// Only the last recalculations are kept
+		let history = Inflation::issuance_history();
+		assert_eq!(
+			history.len(),
+			pallet_inflation::MAX_ISSUANCE_HISTORY as usize
+		);
+		assert_eq!(history[0].0, YEAR * 2 + 1);
+		let (block, total_issuance, block_inflation) = *history.last().unwrap();
+		assert_eq!(
+			block,
+			YEAR * (pallet_inflation::MAX_ISSUANCE_HISTORY + 1) + 1
+		);
+		assert_eq!(
+			total_issuance,
+			<pallet_inflation::StartingYearTotalIssuance<Test>>::get()
+		);
+		assert_eq!(block_inflation, block_inflation!());
+	});
+}
@@ -363,4 +363,20 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn clear_destroyed_item() -> Result<(), BenchmarkError> {
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub;
+		};
+		let item = create_max_item(&collection, &owner, owner.clone())?;
+
+		#[block]
+		{
+			<Pallet<T>>::burn_destroyed_token(collection.id, item, owner);
+		}
+
+		Ok(())
+	}
 }
@@ -27,7 +27,7 @@ use sp_runtime::DispatchError;
 use sp_std::{vec, vec::Vec};
 use up_data_structs::{
 	budget::Budget, CollectionId, CreateItemExData, Property, PropertyKey, PropertyKeyPermission,
-	PropertyValue, TokenId, TokenOwnerError,
+	PropertyValue, TokenChild, TokenId, TokenOwnerError,
 };
 
 use crate::{
@@ -469,6 +469,10 @@ impl<T: Config> CommonCollectionOperations<T> for NonfungibleHandle<T> {
 		self.token_owner(token).map_or_else(|_| vec![], |t| vec![t])
 	}
 
+	fn token_children(&self, token: TokenId) -> Vec<TokenChild> {
+		<Pallet<T>>::token_children_ids(self.id, token)
+	}
+
 	fn token_property(&self, token_id: TokenId, key: &PropertyKey) -> Option<PropertyValue> {
 		<Pallet<T>>::token_properties((self.id, token_id))?
 			.get(key)
@@ -40,7 +40,9 @@ use pallet_evm_coder_substrate::{
 	execution::{Error, PreDispatch, Result},
 	frontier_contract, SubstrateRecorder,
 };
-use pallet_structure::{weights::WeightInfo as _, SelfWeightOf as StructureWeight};
+use pallet_structure::{
+	weights::WeightInfo as _, Pallet as PalletStructure, SelfWeightOf as StructureWeight,
+};
 use sp_core::{Get, U256};
 use sp_std::{vec, vec::Vec};
 use up_data_structs::{
@@ -315,6 +317,77 @@ pub enum ERC721Events {
 	},
 }
 
+/// @title A contract that allows to equip nested tokens into slots of their parent tokens.
+#[solidity_interface(name = TokenSlots, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> NonfungibleHandle<T> {
+	/// @notice Equip a token nested directly into `tokenId` into the slot of it.
+	/// @dev Throws error if `msg.sender` is not the owner of `tokenId`.
+	/// @param tokenId ID of the parent token.
+	/// @param slot Slot name, defined by the collection.
+	/// @param childCollection Address of the nested token collection.
+	/// @param childTokenId ID of the nested token.
+	#[weight(<StructureWeight<T>>::equip())]
+	fn equip(
+		&mut self,
+		caller: Caller,
+		token_id: U256,
+		slot: String,
+		child_collection: Address,
+		child_token_id: U256,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token_id = token_id.try_into()?;
+		let slot = <Vec<u8>>::from(slot)
+			.try_into()
+			.map_err(|_| "slot name too long")?;
+		let child_collection = pallet_common::eth::map_eth_to_id(&child_collection)
+			.ok_or_else(|| Error::Revert("Can't convert address into collection id".into()))?;
+		let child_token_id = child_token_id.try_into()?;
+
+		<PalletStructure<T>>::equip_token(
+			&caller,
+			(self.id, token_id),
+			slot,
+			(child_collection, child_token_id),
+			&nesting_budget(&self.recorder),
+		)
+		.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// @notice Remove a token from the slot of `tokenId`, keeping it nested.
+	/// @dev Throws error if `msg.sender` is not the owner of `tokenId`.
+	/// @param tokenId ID of the parent token.
+	/// @param childCollection Address of the equipped token collection.
+	/// @param childTokenId ID of the equipped token.
+	#[weight(<StructureWeight<T>>::unequip())]
+	fn unequip(
+		&mut self,
+		caller: Caller,
+		token_id: U256,
+		child_collection: Address,
+		child_token_id: U256,
+	) -> Result<()> {
+		let caller = T::CrossAccountId::from_eth(caller);
+		let token_id: TokenId = token_id.try_into()?;
+		let child_collection = pallet_common::eth::map_eth_to_id(&child_collection)
+			.ok_or_else(|| Error::Revert("Can't convert address into collection id".into()))?;
+		let child_token_id = child_token_id.try_into()?;
+
+		match <PalletStructure<T>>::equipped_in((child_collection, child_token_id)) {
+			Some((collection_id, parent_id, _))
+				if (collection_id, parent_id) == (self.id, token_id) => {}
+			_ => return Err("token is not equipped into this token".into()),
+		}
+
+		<PalletStructure<T>>::unequip_token(
+			&caller,
+			(child_collection, child_token_id),
+			&nesting_budget(&self.recorder),
+		)
+		.map_err(dispatch_to_evm::<T>)
+	}
+}
+
 /// @title ERC-721 Non-Fungible Token Standard, optional metadata extension
 /// @dev See https://eips.ethereum.org/EIPS/eip-721
 #[solidity_interface(name = ERC721Metadata, expect_selector = 0x5b5e139f, enum(derive(PreDispatch)), enum_attr(weight))]
@@ -1121,6 +1194,7 @@ where
 		ERC721Metadata(if(this.flags.erc721metadata)),
 		Collection(via(common_mut returns CollectionHandle<T>)),
 		TokenProperties,
+		TokenSlots,
 	),
 	enum(derive(PreDispatch)),
 )]
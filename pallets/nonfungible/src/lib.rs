@@ -99,27 +99,33 @@ use frame_support::{
 	ensure, fail,
 	pallet_prelude::*,
 	storage::with_transaction,
-	transactional, BoundedVec,
+	transactional,
+	weights::WeightMeter,
+	BoundedVec,
 };
 pub use pallet::*;
 use pallet_common::{
-	eth::collection_id_to_address, helpers::add_weight_to_post_info,
-	weights::WeightInfo as CommonWeightInfo, CollectionHandle, Error as CommonError,
-	Event as CommonEvent, Pallet as PalletCommon, SelfWeightOf as PalletCommonWeightOf,
+	dispatch::{dispatch_weight, CollectionDispatch},
+	eth::collection_id_to_address,
+	helpers::add_weight_to_post_info,
+	weights::WeightInfo as CommonWeightInfo,
+	CollectionHandle, Error as CommonError, Event as CommonEvent, Pallet as PalletCommon,
+	SelfWeightOf as PalletCommonWeightOf,
 };
 use pallet_evm::{account::CrossAccountId, Pallet as PalletEvm};
 use pallet_evm_coder_substrate::{SubstrateRecorder, WithRecorder};
-use pallet_structure::Pallet as PalletStructure;
+use pallet_structure::{Pallet as PalletStructure, NESTING_BUDGET};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::{Get, H160};
 use sp_runtime::{ArithmeticError, DispatchError, DispatchResult, TransactionOutcome};
 use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 use up_data_structs::{
-	budget::Budget, mapping::TokenAddressMapping, AccessMode, AuxPropertyValue, CollectionId,
-	CreateNftExData, CustomDataLimit, PropertiesPermissionMap, Property, PropertyKey,
-	PropertyKeyPermission, PropertyScope, PropertyValue, TokenChild, TokenId,
-	TokenProperties as TokenPropertiesT,
+	budget::{self, Budget},
+	mapping::TokenAddressMapping,
+	AccessMode, AuxPropertyValue, CollectionAdminRoles, CollectionId, CreateNftExData,
+	CustomDataLimit, PropertiesPermissionMap, Property, PropertyKey, PropertyKeyPermission,
+	PropertyScope, PropertyValue, TokenChild, TokenId, TokenProperties as TokenPropertiesT,
 };
 use weights::WeightInfo;
 #[cfg(feature = "runtime-benchmarks")]
@@ -409,9 +415,232 @@ impl<T: Config> Pallet<T> {
 		let _ = <Allowance<T>>::clear_prefix((id,), u32::MAX, None);
 		let _ = <AccountBalance<T>>::clear_prefix((id,), u32::MAX, None);
 		let _ = <CollectionAllowance<T>>::clear_prefix((id,), u32::MAX, None);
+		<PalletStructure<T>>::clear_slots(id);
 		Ok(())
 	}
 
+	/// Burn tokens and clear the storage of a collection which is being destroyed,
+	/// spending no more than the weight left in the `meter`.
+	///
+	/// Tokens from other collections nested into the burnt tokens are transferred to
+	/// the topmost owner of the burnt token, or to the collection `owner` if it can't be found.
+	///
+	/// Returns `true` if the collection storage is fully cleared.
+	pub fn clear_destroyed_collection(
+		id: CollectionId,
+		owner: &T::CrossAccountId,
+		meter: &mut WeightMeter,
+	) -> bool {
+		let token_weight = <SelfWeightOf<T>>::clear_destroyed_item();
+		loop {
+			if !meter.can_consume(token_weight) {
+				return false;
+			}
+			let Some((token, token_data)) = <TokenData<T>>::iter_prefix((id,)).next() else {
+				break;
+			};
+			if !Self::release_destroyed_children(id, token, &token_data.owner, owner, meter)
+				|| meter.try_consume(token_weight).is_err()
+			{
+				return false;
+			}
+			Self::burn_destroyed_token(id, token, token_data.owner);
+		}
+
+		let cleared = <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<TokenChildren<T>>::clear_prefix((id,), limit, None)
+		}) && <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<AccountBalance<T>>::clear_prefix((id,), limit, None)
+		}) && <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<CollectionAllowance<T>>::clear_prefix((id,), limit, None)
+		}) && <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			// Children of other collections are unequipped when released,
+			// but tokens of the same collection are burnt without unnesting
+			<pallet_structure::EquippedIn<T>>::clear_prefix((id,), limit, None)
+		}) && <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<pallet_structure::SlotOccupancy<T>>::clear_prefix((id,), limit, None)
+		});
+		if !cleared || meter.try_consume(T::DbWeight::get().writes(3)).is_err() {
+			return false;
+		}
+
+		<TokensMinted<T>>::remove(id);
+		<TokensBurnt<T>>::remove(id);
+		<PalletStructure<T>>::clear_slots(id);
+		true
+	}
+
+	/// Burn a token of a collection which is being destroyed, without any checks.
+	pub(crate) fn burn_destroyed_token(id: CollectionId, token: TokenId, owner: T::CrossAccountId) {
+		// Parent token of the same collection is burnt as well, and can't be dispatched to anymore.
+		if !matches!(
+			T::CrossTokenAddressMapping::address_to_token(&owner),
+			Some((parent_collection, _)) if parent_collection == id
+		) {
+			<PalletStructure<T>>::unnest_if_nested(&owner, id, token);
+		}
+
+		<Owned<T>>::remove((id, &owner, token));
+		<TokenData<T>>::remove((id, token));
+		<TokenProperties<T>>::remove((id, token));
+		let _ = <TokenAuxProperties<T>>::clear_prefix((id, token), u32::MAX, None);
+		<Allowance<T>>::remove((id, token));
+
+		<PalletCommon<T>>::deposit_event(CommonEvent::ItemDestroyed(id, token, owner, 1));
+	}
+
+	/// Transfer tokens of other collections, nested into a token of a collection which
+	/// is being destroyed, out of it.
+	///
+	/// If a nested token can't be transferred to the holder of the destroyed token, it is
+	/// transferred to the collection owner instead. If that fails as well, e.g. the transfers
+	/// of its collection are disabled, the nested token is burnt. Tokens of collections, which
+	/// no longer exist, are left to their own collection destruction, and tokens, which can't be
+	/// burnt either, are left in place, so the destruction never stalls.
+	///
+	/// Returns `true` if there are no nested tokens left, `false` if the weight ran out.
+	fn release_destroyed_children(
+		id: CollectionId,
+		token: TokenId,
+		token_owner: &T::CrossAccountId,
+		collection_owner: &T::CrossAccountId,
+		meter: &mut WeightMeter,
+	) -> bool {
+		// The nested token may belong to a collection of any kind
+		let child_weight = dispatch_weight::<T>() + <SelfWeightOf<T>>::transfer_raw();
+		let mut receiver = None;
+		loop {
+			let Some(((child_collection, child_token), _)) =
+				<TokenChildren<T>>::iter_prefix((id, token)).next()
+			else {
+				return true;
+			};
+			if meter.try_consume(child_weight).is_err() {
+				return false;
+			}
+
+			// Tokens of the same collection are burnt as well
+			if child_collection != id
+				&& <pallet_common::CollectionById<T>>::contains_key(child_collection)
+			{
+				let receiver = receiver.get_or_insert_with(|| {
+					Self::destroyed_token_holder(id, token_owner.clone())
+						.unwrap_or_else(|| collection_owner.clone())
+				});
+				let from = T::CrossTokenAddressMapping::token_to_address(id, token);
+				let mut result = Self::release_destroyed_child(
+					from.clone(),
+					receiver.clone(),
+					child_collection,
+					child_token,
+				);
+				if result.is_err() && *receiver != *collection_owner {
+					if meter.try_consume(child_weight).is_err() {
+						return false;
+					}
+					result = Self::release_destroyed_child(
+						from.clone(),
+						collection_owner.clone(),
+						child_collection,
+						child_token,
+					);
+				}
+				if result.is_err() {
+					if meter.try_consume(child_weight).is_err() {
+						return false;
+					}
+					result = Self::burn_destroyed_child(from, child_collection, child_token);
+				}
+				if let Err(e) = result {
+					log::warn!(
+						target: "runtime::nonfungible",
+						"failed to release token {child_token:?} of collection {child_collection:?} nested into destroyed collection {id:?}: {e:?}",
+					);
+				}
+			}
+			<TokenChildren<T>>::remove((id, token, (child_collection, child_token)));
+		}
+	}
+
+	/// Transfer a token nested into a token of a collection which is being destroyed to `to`,
+	/// reverting all changes if the transfer fails.
+	fn release_destroyed_child(
+		from: T::CrossAccountId,
+		to: T::CrossAccountId,
+		child_collection: CollectionId,
+		child_token: TokenId,
+	) -> DispatchResult {
+		with_transaction(|| {
+			let result = T::CollectionDispatch::dispatch(child_collection).and_then(|child| {
+				let child = child.as_dyn();
+				let amount = child.balance(from.clone(), child_token);
+				child
+					.transfer(
+						from,
+						to,
+						child_token,
+						amount,
+						&budget::Value::new(NESTING_BUDGET),
+					)
+					.map(|_| ())
+					.map_err(|e| e.error)
+			});
+			if result.is_ok() {
+				TransactionOutcome::Commit(result)
+			} else {
+				TransactionOutcome::Rollback(result)
+			}
+		})
+	}
+
+	/// Burn a token nested into a token of a collection which is being destroyed,
+	/// reverting all changes if the burn fails.
+	fn burn_destroyed_child(
+		from: T::CrossAccountId,
+		child_collection: CollectionId,
+		child_token: TokenId,
+	) -> DispatchResult {
+		with_transaction(|| {
+			let result = T::CollectionDispatch::dispatch(child_collection).and_then(|child| {
+				let child = child.as_dyn();
+				let amount = child.balance(from.clone(), child_token);
+				child
+					.burn_item(from, child_token, amount)
+					.map(|_| ())
+					.map_err(|e| e.error)
+			});
+			if result.is_ok() {
+				TransactionOutcome::Commit(result)
+			} else {
+				TransactionOutcome::Rollback(result)
+			}
+		})
+	}
+
+	/// Find the account owning a token of a collection which is being destroyed,
+	/// skipping the parent tokens of the same collection.
+	fn destroyed_token_holder(
+		id: CollectionId,
+		mut owner: T::CrossAccountId,
+	) -> Option<T::CrossAccountId> {
+		let budget = budget::Value::new(NESTING_BUDGET);
+		loop {
+			let Some((collection, token)) = T::CrossTokenAddressMapping::address_to_token(&owner)
+			else {
+				return Some(owner);
+			};
+			if collection != id {
+				return <PalletStructure<T>>::find_topmost_owner(collection, token, &budget)
+					.ok()
+					.flatten();
+			}
+			if !budget.consume() {
+				return None;
+			}
+			owner = <TokenData<T>>::get((id, token))?.owner;
+		}
+	}
+
 	/// Burn NFT token
 	///
 	/// `burn` removes `token` from the `collection`, from it's owner and from the parent token
@@ -820,7 +1049,7 @@ impl<T: Config> Pallet<T> {
 		data: Vec<CreateItemData<T>>,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResult {
-		if !collection.is_owner_or_admin(sender) {
+		if !collection.has_admin_roles(sender, CollectionAdminRoles::MINTER) {
 			ensure!(
 				collection.permissions.mint_mode(),
 				<CommonError<T>>::PublicMintingNotAllowed
@@ -1231,7 +1460,8 @@ impl<T: Config> Pallet<T> {
 				<CommonError<T>>::SourceCollectionIsNotAllowedToNest
 			);
 		}
-		Ok(())
+
+		<PalletStructure<T>>::check_slot_nesting(handle.id, from.0)
 	}
 
 	fn nest(under: (CollectionId, TokenId), to_nest: (CollectionId, TokenId)) {
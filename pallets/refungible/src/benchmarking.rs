@@ -576,4 +576,20 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn clear_destroyed_item() -> Result<(), BenchmarkError> {
+		bench_init! {
+			owner: sub; collection: collection(owner);
+			owner: cross_from_sub;
+		};
+		let item = create_max_item(&collection, &owner, [(owner.clone(), 100)])?;
+
+		#[block]
+		{
+			<Pallet<T>>::burn_destroyed_pieces(collection.id, item, owner, 100);
+		}
+
+		Ok(())
+	}
 }
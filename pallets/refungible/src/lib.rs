@@ -90,7 +90,7 @@
 use core::{cmp::Ordering, ops::Deref};
 
 use evm_coder::ToLog;
-use frame_support::{ensure, storage::with_transaction, transactional};
+use frame_support::{ensure, storage::with_transaction, transactional, weights::WeightMeter};
 pub use pallet::*;
 use pallet_common::{
 	eth::collection_id_to_address, Error as CommonError, Event as CommonEvent,
@@ -103,13 +103,13 @@ use sp_core::{Get, H160};
 use sp_runtime::{ArithmeticError, DispatchError, DispatchResult, TransactionOutcome};
 use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 use up_data_structs::{
-	budget::Budget, mapping::TokenAddressMapping, AccessMode, CollectionId,
+	budget::Budget, mapping::TokenAddressMapping, AccessMode, CollectionAdminRoles, CollectionId,
 	CreateRefungibleExMultipleOwners, PropertiesPermissionMap, Property, PropertyKey,
 	PropertyKeyPermission, PropertyScope, PropertyValue, TokenId, TokenOwnerError,
 	TokenProperties as TokenPropertiesT, MAX_REFUNGIBLE_PIECES,
 };
 
-use crate::{erc::ERC721Events, erc_token::ERC20Events};
+use crate::{erc::ERC721Events, erc_token::ERC20Events, weights::WeightInfo};
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 pub mod common;
@@ -324,6 +324,58 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Burn tokens and clear the storage of a collection which is being destroyed,
+	/// spending no more than the weight left in the `meter`.
+	///
+	/// Returns `true` if the collection storage is fully cleared.
+	pub fn clear_destroyed_collection(id: CollectionId, meter: &mut WeightMeter) -> bool {
+		let balance_weight = <SelfWeightOf<T>>::clear_destroyed_item();
+		loop {
+			if !meter.can_consume(balance_weight) {
+				return false;
+			}
+			let Some(((token, owner), amount)) = <Balance<T>>::iter_prefix((id,)).next() else {
+				break;
+			};
+			meter.consume(balance_weight);
+			Self::burn_destroyed_pieces(id, token, owner, amount);
+		}
+
+		let cleared = <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<TotalSupply<T>>::clear_prefix((id,), limit, None)
+		}) && <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<TokenProperties<T>>::clear_prefix((id,), limit, None)
+		}) && <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<Allowance<T>>::clear_prefix((id,), limit, None)
+		}) && <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<AccountBalance<T>>::clear_prefix((id,), limit, None)
+		}) && <PalletCommon<T>>::clear_destroyed(meter, |limit| {
+			<CollectionAllowance<T>>::clear_prefix((id,), limit, None)
+		});
+		if !cleared || meter.try_consume(T::DbWeight::get().writes(2)).is_err() {
+			return false;
+		}
+
+		<TokensMinted<T>>::remove(id);
+		<TokensBurnt<T>>::remove(id);
+		true
+	}
+
+	/// Burn the pieces of a token, owned by `owner`, of a collection which is being destroyed,
+	/// without any checks.
+	pub(crate) fn burn_destroyed_pieces(
+		id: CollectionId,
+		token: TokenId,
+		owner: T::CrossAccountId,
+		amount: u128,
+	) {
+		<PalletStructure<T>>::unnest_if_nested(&owner, id, token);
+		<Balance<T>>::remove((id, token, &owner));
+		<Owned<T>>::remove((id, &owner, token));
+
+		<PalletCommon<T>>::deposit_event(CommonEvent::ItemDestroyed(id, token, owner, amount));
+	}
+
 	fn collection_has_tokens(collection_id: CollectionId) -> bool {
 		<TotalSupply<T>>::iter_prefix((collection_id,))
 			.next()
@@ -791,7 +843,7 @@ impl<T: Config> Pallet<T> {
 		data: Vec<CreateItemData<T>>,
 		nesting_budget: &dyn Budget,
 	) -> DispatchResult {
-		if !collection.is_owner_or_admin(sender) {
+		if !collection.has_admin_roles(sender, CollectionAdminRoles::MINTER) {
 			ensure!(
 				collection.permissions.mint_mode(),
 				<CommonError<T>>::PublicMintingNotAllowed
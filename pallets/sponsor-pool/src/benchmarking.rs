@@ -0,0 +1,153 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_system::RawOrigin;
+use pallet_common::{benchmarking::create_collection_raw, CollectionIssuer};
+use sp_std::vec;
+use up_data_structs::CollectionMode;
+
+use super::*;
+use crate::{Config, Pallet as SponsorPool};
+
+const SEED: u32 = 1;
+
+fn funded_account<T: Config>(name: &'static str) -> T::AccountId {
+	let account: T::AccountId = account(name, 0, SEED);
+	let amount = <T as Config>::Currency::minimum_balance()
+		.saturating_mul(1_000_000u32.into())
+		.saturating_add(1_000_000u32.into())
+		.saturating_add(T::MinimumFirstDeposit::get().saturating_mul(2u32.into()));
+	<T as Config>::Currency::set_balance(&account, amount);
+	account
+}
+
+fn create_funded_pool<T: Config>(manager: &T::AccountId) -> Result<PoolId, BenchmarkError> {
+	<SponsorPool<T>>::create_pool(RawOrigin::Signed(manager.clone()).into())?;
+	let pool_id = <PoolCount<T>>::get();
+	<SponsorPool<T>>::deposit(
+		RawOrigin::Signed(manager.clone()).into(),
+		pool_id,
+		<T as Config>::Currency::minimum_balance()
+			.saturating_mul(1_000u32.into())
+			.max(T::MinimumFirstDeposit::get()),
+	)?;
+	Ok(pool_id)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_pool() -> Result<(), BenchmarkError> {
+		let manager = funded_account::<T>("manager");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(manager));
+
+		assert!(<Pools<T>>::contains_key(<PoolCount<T>>::get()));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deposit() -> Result<(), BenchmarkError> {
+		let manager = funded_account::<T>("manager");
+		let contributor = funded_account::<T>("contributor");
+		let pool_id = create_funded_pool::<T>(&manager)?;
+		let amount = <T as Config>::Currency::minimum_balance().saturating_mul(100u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(contributor.clone()), pool_id, amount);
+
+		assert!(<Shares<T>>::contains_key(pool_id, contributor));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw() -> Result<(), BenchmarkError> {
+		let manager = funded_account::<T>("manager");
+		let pool_id = create_funded_pool::<T>(&manager)?;
+		let shares = <Shares<T>>::get(pool_id, &manager).shares / 2;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(manager.clone()), pool_id, shares);
+
+		assert_eq!(<Shares<T>>::get(pool_id, manager).shares, shares);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn confirm_collection_sponsorship() -> Result<(), BenchmarkError> {
+		let manager = funded_account::<T>("manager");
+		let owner = T::CrossAccountId::from_sub(funded_account::<T>("owner"));
+		let pool_id = create_funded_pool::<T>(&manager)?;
+		let collection = create_collection_raw(
+			owner.clone(),
+			CollectionMode::NFT,
+			|owner: T::CrossAccountId, data| {
+				<pallet_common::Pallet<T>>::init_collection(
+					owner.clone(),
+					CollectionIssuer::User(owner),
+					data,
+				)
+			},
+			|h| h,
+		)?;
+		let collection_id = collection.id;
+		<CollectionHandle<T>>::try_get(collection_id)?
+			.set_sponsor(&owner, <SponsorPool<T>>::pool_account(pool_id))?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(manager), pool_id, collection_id);
+
+		assert_eq!(
+			<CollectionHandle<T>>::try_get(collection_id)?
+				.sponsorship
+				.sponsor(),
+			Some(&<SponsorPool<T>>::pool_account(pool_id))
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn confirm_contract_sponsorship() -> Result<(), BenchmarkError> {
+		let manager = funded_account::<T>("manager");
+		let pool_id = create_funded_pool::<T>(&manager)?;
+		let contract = H160::repeat_byte(0x11);
+		// Contracts without a known owner are owned by the zero address
+		<pallet_evm_contract_helpers::Pallet<T>>::set_sponsor(
+			&T::CrossAccountId::from_eth(H160::zero()),
+			contract,
+			&<SponsorPool<T>>::pool_cross_account(pool_id),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(manager), pool_id, contract);
+
+		assert_eq!(
+			<pallet_evm_contract_helpers::Pallet<T>>::get_sponsor(contract),
+			Some(<SponsorPool<T>>::pool_cross_account(pool_id))
+		);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,507 @@
+// Copyright 2019-2022 Unique Network (Gibraltar) Ltd.
+// This file is part of Unique Network.
+
+// Unique Network is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Unique Network is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Sponsor Pool pallet
+//!
+//! The pallet allows several contributors to share the fees of sponsoring collections and contracts.
+//!
+//! - [`Config`]
+//! - [`Pallet`]
+//! - [`Error`]
+//! - [`Event`]
+//!
+//! ## Overview
+//!
+//! Every pool owns an account, derived from [`Config::PalletId`] and the pool ID,
+//! see [`Pallet::pool_address`]. Anyone may deposit to the pool, receiving the pool shares.
+//! Sponsored fees are paid from the pool account, so the value of the shares decreases
+//! with the spending, and contributors may withdraw what is left of their shares.
+//!
+//! Only the funds deposited via [`deposit`][`Pallet::deposit`] back the shares,
+//! so transferring funds to the pool account directly can't change the value of the shares.
+//! Such funds are spent on sponsoring first. The first deposit into a pool without shares
+//! must be at least [`Config::MinimumFirstDeposit`].
+//!
+//! A pool becomes a sponsor the usual way: its account is passed to `set_collection_sponsor`,
+//! or its address to `ContractHelpers.setSponsor`, and the sponsorship is then confirmed
+//! by the pool manager on behalf of the pool.
+//!
+//! ### Dispatchable Functions
+//!
+//! - [`create_pool`][`Pallet::create_pool`] - creates a new pool, managed by the caller.
+//! - [`deposit`][`Pallet::deposit`] - deposits funds to the pool in exchange for its shares.
+//! - [`withdraw`][`Pallet::withdraw`] - exchanges the pool shares for the unspent funds.
+//! - [`confirm_collection_sponsorship`][`Pallet::confirm_collection_sponsorship`] - confirms
+//! the pool as the collection sponsor.
+//! - [`confirm_contract_sponsorship`][`Pallet::confirm_contract_sponsorship`] - confirms
+//! the pool as the contract sponsor.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	pallet_prelude::*,
+	traits::{
+		fungible::{Inspect, Mutate},
+		tokens::Preservation,
+	},
+	PalletId,
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use pallet_common::CollectionHandle;
+use pallet_evm::account::CrossAccountId;
+use sp_core::H160;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{TrailingZeroInput, UniqueSaturatedInto, Zero},
+	ArithmeticError, Rounding,
+};
+use up_data_structs::CollectionId;
+use weights::WeightInfo;
+
+/// Pool identifier.
+pub type PoolId = u32;
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Sponsor pool.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo<AccountId> {
+	/// Account, which confirms the sponsorships on behalf of the pool.
+	pub manager: AccountId,
+	/// Total number of the pool shares.
+	pub total_shares: u128,
+	/// Deposited funds, which back the shares, minus the withdrawn ones.
+	///
+	/// The actual funds of the pool may be lower, as they are spent on sponsoring.
+	pub assets: u128,
+	/// Incremented each time the pool is found to be spent out, invalidating all of the shares.
+	pub generation: u32,
+}
+
+/// Shares of a pool contributor.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PoolShares {
+	/// Generation of the pool, in which the shares were received.
+	pub generation: u32,
+	/// Number of the shares.
+	pub shares: u128,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_common::Config + pallet_evm_contract_helpers::Config
+	{
+		/// Overarching event type.
+		type RuntimeEvent: IsType<<Self as frame_system::Config>::RuntimeEvent> + From<Event<Self>>;
+
+		/// Type to interact with the native token.
+		type Currency: Mutate<Self::AccountId>;
+
+		/// The pallet id, used for deriving the pool accounts.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Minimum deposit into a pool without shares.
+		#[pallet::constant]
+		type MinimumFirstDeposit: Get<BalanceOf<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Sponsor pool was created.
+		PoolCreated(
+			/// ID of the created pool.
+			PoolId,
+			/// Manager of the pool.
+			T::AccountId,
+			/// Account of the pool, which pays the sponsored fees.
+			T::AccountId,
+		),
+
+		/// Funds were deposited to the pool.
+		Deposited(
+			/// ID of the pool.
+			PoolId,
+			/// Contributor.
+			T::AccountId,
+			/// Deposited amount.
+			BalanceOf<T>,
+			/// Number of the received shares.
+			u128,
+		),
+
+		/// Unspent funds were withdrawn from the pool.
+		Withdrawn(
+			/// ID of the pool.
+			PoolId,
+			/// Contributor.
+			T::AccountId,
+			/// Withdrawn amount.
+			BalanceOf<T>,
+			/// Number of the redeemed shares.
+			u128,
+		),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No pool with the specified ID.
+		PoolNotFound,
+		/// Only the pool manager may perform this action.
+		NoPermission,
+		/// The contributor doesn't own that many shares of the pool.
+		InsufficientShares,
+		/// The deposit is too small to receive any shares.
+		DepositTooSmall,
+		/// The first deposit into the pool is less than [`Config::MinimumFirstDeposit`].
+		FirstDepositTooSmall,
+	}
+
+	/// Number of the created pools, also the ID of the last created one.
+	#[pallet::storage]
+	pub type PoolCount<T> = StorageValue<Value = PoolId, QueryKind = ValueQuery>;
+
+	/// Sponsor pools.
+	///
+	/// * **Key** - pool ID.
+	/// * **Value** - pool info.
+	#[pallet::storage]
+	pub type Pools<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = PoolId,
+		Value = PoolInfo<T::AccountId>,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Shares of the pool contributors.
+	///
+	/// * **Key1** - pool ID.
+	/// * **Key2** - contributor.
+	/// * **Value** - contributor shares.
+	#[pallet::storage]
+	pub type Shares<T: Config> = StorageDoubleMap<
+		Hasher1 = Twox64Concat,
+		Key1 = PoolId,
+		Hasher2 = Blake2_128Concat,
+		Key2 = T::AccountId,
+		Value = PoolShares,
+		QueryKind = ValueQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new sponsor pool, managed by the caller.
+		///
+		/// # Permissions
+		///
+		/// * Anyone.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		pub fn create_pool(origin: OriginFor<T>) -> DispatchResult {
+			let manager = ensure_signed(origin)?;
+			let pool_id = <PoolCount<T>>::get()
+				.checked_add(1)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			<PoolCount<T>>::put(pool_id);
+			<Pools<T>>::insert(
+				pool_id,
+				PoolInfo {
+					manager: manager.clone(),
+					total_shares: 0,
+					assets: 0,
+					generation: 0,
+				},
+			);
+
+			Self::deposit_event(Event::PoolCreated(
+				pool_id,
+				manager,
+				Self::pool_account(pool_id),
+			));
+			Ok(())
+		}
+
+		/// Deposit `amount` to the pool in exchange for its shares.
+		///
+		/// # Permissions
+		///
+		/// * Anyone.
+		///
+		/// # Arguments
+		///
+		/// * `pool_id`: ID of the pool.
+		/// * `amount`: Amount of the native tokens to deposit.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::deposit())]
+		pub fn deposit(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let contributor = ensure_signed(origin)?;
+			let mut pool = <Pools<T>>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_account = Self::pool_account(pool_id);
+			let mut assets = Self::pool_assets(&pool, pool_id);
+
+			if assets.is_zero() && !pool.total_shares.is_zero() {
+				// Everything was spent on sponsoring, the remaining shares are worthless
+				pool.total_shares = 0;
+				pool.generation = pool.generation.wrapping_add(1);
+			}
+
+			let shares = if pool.total_shares.is_zero() {
+				ensure!(
+					amount >= T::MinimumFirstDeposit::get(),
+					Error::<T>::FirstDepositTooSmall
+				);
+				// Funds, which don't back any shares, are left for sponsoring
+				assets = 0;
+				amount.unique_saturated_into()
+			} else {
+				multiply_by_rational_with_rounding(
+					amount.unique_saturated_into(),
+					pool.total_shares,
+					assets,
+					Rounding::Down,
+				)
+				.ok_or(ArithmeticError::Overflow)?
+			};
+			ensure!(!shares.is_zero(), Error::<T>::DepositTooSmall);
+
+			<T as Config>::Currency::transfer(
+				&contributor,
+				&pool_account,
+				amount,
+				Preservation::Preserve,
+			)?;
+
+			pool.total_shares = pool
+				.total_shares
+				.checked_add(shares)
+				.ok_or(ArithmeticError::Overflow)?;
+			pool.assets = assets
+				.checked_add(amount.unique_saturated_into())
+				.ok_or(ArithmeticError::Overflow)?;
+			let mut contribution = Self::shares_of(&pool, pool_id, &contributor);
+			contribution.shares = contribution.shares.saturating_add(shares);
+			<Shares<T>>::insert(pool_id, &contributor, contribution);
+			<Pools<T>>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::Deposited(pool_id, contributor, amount, shares));
+			Ok(())
+		}
+
+		/// Redeem the pool `shares` for the corresponding part of the unspent pool funds.
+		///
+		/// # Permissions
+		///
+		/// * Pool contributor.
+		///
+		/// # Arguments
+		///
+		/// * `pool_id`: ID of the pool.
+		/// * `shares`: Number of the shares to redeem.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw())]
+		pub fn withdraw(origin: OriginFor<T>, pool_id: PoolId, shares: u128) -> DispatchResult {
+			let contributor = ensure_signed(origin)?;
+			let mut pool = <Pools<T>>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let mut contribution = Self::shares_of(&pool, pool_id, &contributor);
+			ensure!(
+				!shares.is_zero() && shares <= contribution.shares,
+				Error::<T>::InsufficientShares
+			);
+
+			let pool_account = Self::pool_account(pool_id);
+			let assets = Self::pool_assets(&pool, pool_id);
+			let amount = multiply_by_rational_with_rounding(
+				assets,
+				shares,
+				pool.total_shares,
+				Rounding::Down,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+			pool.assets = assets - amount;
+			let amount: BalanceOf<T> = amount.unique_saturated_into();
+
+			if !amount.is_zero() {
+				<T as Config>::Currency::transfer(
+					&pool_account,
+					&contributor,
+					amount,
+					Preservation::Expendable,
+				)?;
+			}
+
+			pool.total_shares -= shares;
+			contribution.shares -= shares;
+			if contribution.shares.is_zero() {
+				<Shares<T>>::remove(pool_id, &contributor);
+			} else {
+				<Shares<T>>::insert(pool_id, &contributor, contribution);
+			}
+			<Pools<T>>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::Withdrawn(pool_id, contributor, amount, shares));
+			Ok(())
+		}
+
+		/// Confirm the pool as the sponsor of the collection.
+		///
+		/// The pool account must be set as the pending collection sponsor first,
+		/// see `set_collection_sponsor` of the `unique` pallet.
+		///
+		/// # Permissions
+		///
+		/// * Pool manager.
+		///
+		/// # Arguments
+		///
+		/// * `pool_id`: ID of the pool.
+		/// * `collection_id`: ID of the collection with the pending pool sponsorship.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::confirm_collection_sponsorship())]
+		pub fn confirm_collection_sponsorship(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_manager(pool_id, &sender)?;
+
+			let mut collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			collection.confirm_sponsorship(&Self::pool_account(pool_id))
+		}
+
+		/// Confirm the pool as the sponsor of the contract.
+		///
+		/// The pool address must be set as the pending contract sponsor first,
+		/// see `ContractHelpers.setSponsor`.
+		///
+		/// # Permissions
+		///
+		/// * Pool manager.
+		///
+		/// # Arguments
+		///
+		/// * `pool_id`: ID of the pool.
+		/// * `contract_address`: Address of the contract with the pending pool sponsorship.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::confirm_contract_sponsorship())]
+		pub fn confirm_contract_sponsorship(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			contract_address: H160,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_manager(pool_id, &sender)?;
+
+			<pallet_evm_contract_helpers::Pallet<T>>::confirm_sponsorship(
+				&Self::pool_cross_account(pool_id),
+				contract_address,
+			)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Ethereum address of the pool, which may be used as the contract sponsor.
+	///
+	/// The address is derived from the [`Config::PalletId`] and the pool ID,
+	/// in the same way the substrate sub-accounts are.
+	pub fn pool_address(pool_id: PoolId) -> H160 {
+		let entropy = (b"modl", T::PalletId::get(), pool_id).encode();
+		H160::decode(&mut TrailingZeroInput::new(&entropy))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// The pool as a sponsor.
+	pub fn pool_cross_account(pool_id: PoolId) -> T::CrossAccountId {
+		T::CrossAccountId::from_eth(Self::pool_address(pool_id))
+	}
+
+	/// Account of the pool, which holds its funds and pays the sponsored fees.
+	///
+	/// It is the substrate mirror of the [pool address](Self::pool_address),
+	/// so the pool may be selected as both the collection and the contract sponsor.
+	pub fn pool_account(pool_id: PoolId) -> T::AccountId {
+		Self::pool_cross_account(pool_id).as_sub().clone()
+	}
+
+	/// Get the funds of the pool, which back its shares.
+	///
+	/// These are the deposited funds, unless less is left after the sponsoring.
+	fn pool_assets(pool: &PoolInfo<T::AccountId>, pool_id: PoolId) -> u128 {
+		let balance: u128 =
+			<T as Config>::Currency::balance(&Self::pool_account(pool_id)).unique_saturated_into();
+		balance.min(pool.assets)
+	}
+
+	/// Get the shares of the `contributor`, which are still valid in the current pool generation.
+	fn shares_of(
+		pool: &PoolInfo<T::AccountId>,
+		pool_id: PoolId,
+		contributor: &T::AccountId,
+	) -> PoolShares {
+		Some(<Shares<T>>::get(pool_id, contributor))
+			.filter(|contribution| contribution.generation == pool.generation)
+			.unwrap_or(PoolShares {
+				generation: pool.generation,
+				shares: 0,
+			})
+	}
+
+	/// Get the unspent funds of the pool, which may be withdrawn by the `contributor`.
+	pub fn contributor_balance(pool_id: PoolId, contributor: &T::AccountId) -> BalanceOf<T> {
+		let Some(pool) = <Pools<T>>::get(pool_id) else {
+			return Zero::zero();
+		};
+		let shares = Self::shares_of(&pool, pool_id, contributor).shares;
+		if shares.is_zero() {
+			return Zero::zero();
+		}
+		let assets = Self::pool_assets(&pool, pool_id);
+		multiply_by_rational_with_rounding(assets, shares, pool.total_shares, Rounding::Down)
+			.unwrap_or_default()
+			.unique_saturated_into()
+	}
+
+	fn ensure_manager(pool_id: PoolId, sender: &T::AccountId) -> DispatchResult {
+		let pool = <Pools<T>>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(pool.manager == *sender, Error::<T>::NoPermission);
+		Ok(())
+	}
+}
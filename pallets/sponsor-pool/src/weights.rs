@@ -0,0 +1,188 @@
+// Template adopted from https://github.com/paritytech/substrate/blob/master/.maintain/frame-weight-template.hbs
+
+//! Autogenerated weights for pallet_sponsor_pool
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 29.0.0
+//! DATE: 2023-11-29, STEPS: `50`, REPEAT: `80`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bench-host`, CPU: `Intel(R) Core(TM) i7-8700 CPU @ 3.20GHz`
+//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/unique-collator
+// benchmark
+// pallet
+// --pallet
+// pallet-sponsor-pool
+// --wasm-execution
+// compiled
+// --extrinsic
+// *
+// --template=.maintain/frame-weight-template.hbs
+// --steps=50
+// --repeat=80
+// --heap-pages=4096
+// --output=./pallets/sponsor-pool/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_sponsor_pool.
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn deposit() -> Weight;
+	fn withdraw() -> Weight;
+	fn confirm_collection_sponsorship() -> Weight;
+	fn confirm_contract_sponsorship() -> Weight;
+}
+
+/// Weights for pallet_sponsor_pool using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `SponsorPool::PoolCount` (r:1 w:1)
+	/// Proof: `SponsorPool::PoolCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `SponsorPool::Pools` (r:0 w:1)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1489`
+		// Minimum execution time: 8_614_000 picoseconds.
+		Weight::from_parts(8_901_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SponsorPool::Pools` (r:1 w:1)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SponsorPool::Shares` (r:1 w:1)
+	/// Proof: `SponsorPool::Shares` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `357`
+		//  Estimated: `6196`
+		// Minimum execution time: 47_305_000 picoseconds.
+		Weight::from_parts(48_112_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SponsorPool::Pools` (r:1 w:1)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `SponsorPool::Shares` (r:1 w:1)
+	/// Proof: `SponsorPool::Shares` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `515`
+		//  Estimated: `6196`
+		// Minimum execution time: 49_820_000 picoseconds.
+		Weight::from_parts(50_773_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SponsorPool::Pools` (r:1 w:0)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:1 w:1)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	fn confirm_collection_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1112`
+		//  Estimated: `4325`
+		// Minimum execution time: 17_471_000 picoseconds.
+		Weight::from_parts(17_980_000, 4325)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SponsorPool::Pools` (r:1 w:0)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `EvmContractHelpers::Sponsoring` (r:1 w:1)
+	/// Proof: `EvmContractHelpers::Sponsoring` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	fn confirm_contract_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `347`
+		//  Estimated: `3529`
+		// Minimum execution time: 15_903_000 picoseconds.
+		Weight::from_parts(16_342_000, 3529)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `SponsorPool::PoolCount` (r:1 w:1)
+	/// Proof: `SponsorPool::PoolCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `SponsorPool::Pools` (r:0 w:1)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1489`
+		// Minimum execution time: 8_614_000 picoseconds.
+		Weight::from_parts(8_901_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SponsorPool::Pools` (r:1 w:1)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SponsorPool::Shares` (r:1 w:1)
+	/// Proof: `SponsorPool::Shares` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `357`
+		//  Estimated: `6196`
+		// Minimum execution time: 47_305_000 picoseconds.
+		Weight::from_parts(48_112_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SponsorPool::Pools` (r:1 w:1)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `SponsorPool::Shares` (r:1 w:1)
+	/// Proof: `SponsorPool::Shares` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `515`
+		//  Estimated: `6196`
+		// Minimum execution time: 49_820_000 picoseconds.
+		Weight::from_parts(50_773_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SponsorPool::Pools` (r:1 w:0)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:1 w:1)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	fn confirm_collection_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1112`
+		//  Estimated: `4325`
+		// Minimum execution time: 17_471_000 picoseconds.
+		Weight::from_parts(17_980_000, 4325)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SponsorPool::Pools` (r:1 w:0)
+	/// Proof: `SponsorPool::Pools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `EvmContractHelpers::Sponsoring` (r:1 w:1)
+	/// Proof: `EvmContractHelpers::Sponsoring` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	fn confirm_contract_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `347`
+		//  Estimated: `3529`
+		// Minimum execution time: 15_903_000 picoseconds.
+		Weight::from_parts(16_342_000, 3529)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
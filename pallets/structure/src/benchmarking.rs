@@ -14,19 +14,86 @@
 // You should have received a copy of the GNU General Public License
 // along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
 
-use frame_benchmarking::v2::{account, benchmarks, BenchmarkError};
+use frame_benchmarking::v2::{account, benchmarks, BenchmarkError, Linear};
 use frame_support::traits::{fungible::Balanced, tokens::Precision, Get};
+use frame_system::RawOrigin;
 use pallet_common::{CollectionIssuer, Config as CommonConfig};
 use pallet_evm::account::CrossAccountId;
-use sp_std::vec;
+use sp_std::{collections::btree_set::BTreeSet, vec};
 use up_data_structs::{
-	budget::Unlimited, CollectionMode, CreateCollectionData, CreateItemData, CreateNftData,
+	budget::Unlimited, CollectionMode, CollectionPermissions, CreateCollectionData, CreateItemData,
+	CreateNftData, NestingPermissions, MAX_COLLECTION_SLOTS, MAX_SLOT_SOURCE_COLLECTIONS,
 };
 
 use super::*;
 
 const SEED: u32 = 1;
 
+fn create_nft_collection<T: Config>(owner: &T::CrossAccountId) -> Result<(), DispatchError> {
+	let _ = <T as CommonConfig>::Currency::deposit(
+		owner.as_sub(),
+		T::CollectionCreationPrice::get(),
+		Precision::Exact,
+	)
+	.unwrap();
+	T::CollectionDispatch::create(
+		owner.clone(),
+		CollectionIssuer::User(owner.clone()),
+		CreateCollectionData {
+			mode: CollectionMode::NFT,
+			permissions: Some(CollectionPermissions {
+				nesting: Some(NestingPermissions {
+					token_owner: false,
+					collection_admin: false,
+					restricted: None,
+					permissive: true,
+				}),
+				..Default::default()
+			}),
+			..Default::default()
+		},
+	)?;
+	Ok(())
+}
+
+fn create_token<T: Config>(
+	collection: CollectionId,
+	sender: &T::CrossAccountId,
+	owner: T::CrossAccountId,
+) -> Result<(), DispatchError> {
+	let dispatch = T::CollectionDispatch::dispatch(collection)?;
+	let dispatch = dispatch.as_dyn();
+
+	dispatch.create_item(
+		sender.clone(),
+		owner,
+		CreateItemData::NFT(CreateNftData::default()),
+		&Unlimited,
+	)?;
+	Ok(())
+}
+
+fn slot_name(index: u32) -> SlotName {
+	index.to_le_bytes().to_vec().try_into().unwrap()
+}
+
+fn set_single_slot<T: Config>(
+	owner: &T::CrossAccountId,
+	collection: CollectionId,
+	source: CollectionId,
+) -> Result<(), DispatchError> {
+	let slot = TokenSlot {
+		name: slot_name(0),
+		allowed_collections: [source]
+			.into_iter()
+			.collect::<BTreeSet<_>>()
+			.try_into()
+			.unwrap(),
+		max_count: 1,
+	};
+	<Pallet<T>>::set_slots(owner, collection, vec![slot].try_into().unwrap())
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -68,4 +135,80 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn set_collection_slots(s: Linear<0, MAX_COLLECTION_SLOTS>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let caller_cross = T::CrossAccountId::from_sub(caller.clone());
+		create_nft_collection::<T>(&caller_cross)?;
+
+		let slots = (0..s)
+			.map(|i| TokenSlot {
+				name: slot_name(i),
+				allowed_collections: (0..MAX_SLOT_SOURCE_COLLECTIONS)
+					.map(CollectionId)
+					.collect::<BTreeSet<_>>()
+					.try_into()
+					.unwrap(),
+				max_count: u32::MAX,
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), CollectionId(1), slots);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn equip() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let caller_cross = T::CrossAccountId::from_sub(caller.clone());
+		create_nft_collection::<T>(&caller_cross)?;
+		create_nft_collection::<T>(&caller_cross)?;
+		set_single_slot::<T>(&caller_cross, CollectionId(1), CollectionId(2))?;
+
+		create_token::<T>(CollectionId(1), &caller_cross, caller_cross.clone())?;
+		let parent = T::CrossTokenAddressMapping::token_to_address(CollectionId(1), TokenId(1));
+		create_token::<T>(CollectionId(2), &caller_cross, parent)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			CollectionId(1),
+			TokenId(1),
+			slot_name(0),
+			CollectionId(2),
+			TokenId(1),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unequip() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let caller_cross = T::CrossAccountId::from_sub(caller.clone());
+		create_nft_collection::<T>(&caller_cross)?;
+		create_nft_collection::<T>(&caller_cross)?;
+		set_single_slot::<T>(&caller_cross, CollectionId(1), CollectionId(2))?;
+
+		create_token::<T>(CollectionId(1), &caller_cross, caller_cross.clone())?;
+		let parent = T::CrossTokenAddressMapping::token_to_address(CollectionId(1), TokenId(1));
+		create_token::<T>(CollectionId(2), &caller_cross, parent)?;
+		<Pallet<T>>::equip_token(
+			&caller_cross,
+			(CollectionId(1), TokenId(1)),
+			slot_name(0),
+			(CollectionId(2), TokenId(1)),
+			&Unlimited,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), CollectionId(2), TokenId(1));
+
+		Ok(())
+	}
 }
@@ -40,6 +40,10 @@
 //!
 //! - **Owner:** Account that owns the token and all nested tokens.
 //!
+//! - **Slot:** Named place defined by a parent collection, into which a limited number of nested
+//!   tokens of specific collections could be equipped. If a collection defines slots, only tokens
+//!   of collections allowed in any of them could be nested into its tokens.
+//!
 //! ## Interface
 //!
 //! ### Available Functions
@@ -47,20 +51,31 @@
 //! - `find_parent` - Find parent of the token. It could be an account or another token.
 //! - `parent_chain` - Find chain of parents of the token.
 //! - `find_topmost_owner` - Find account or token in the end of the chain of parents.
+//! - `find_topmost` - Find the topmost token in the chain of parents along with its owner.
+//! - `token_tree` - Find all tokens nested in the token, level by level.
 //! - `check_nesting` - Check if the token could be nested in the other token
 //! - `nest_if_sent_to_token` - Nest the token in the other token
 //! - `unnest_if_nested` - Unnest the token from the other token
+//!
+//! ### Dispatchables
+//!
+//! - `set_collection_slots` - Define slots for tokens of a collection.
+//! - `equip` - Equip a nested token into a slot of its parent.
+//! - `unequip` - Remove a token from the slot it is equipped into.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{dispatch::DispatchResult, fail, pallet_prelude::*};
 use pallet_common::{
-	dispatch::CollectionDispatch, erc::CrossAccountId, eth::is_collection,
-	CommonCollectionOperations,
+	dispatch::CollectionDispatch, erc::CrossAccountId, eth::is_collection, CollectionHandle,
+	CommonCollectionOperations, Error as CommonError,
 };
-use sp_std::collections::btree_set::BTreeSet;
+use sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec};
 use up_data_structs::{
-	budget::Budget, mapping::TokenAddressMapping, CollectionId, TokenId, TokenOwnerError,
+	budget::{self, Budget},
+	mapping::TokenAddressMapping,
+	CollectionAdminRoles, CollectionId, CollectionMode, PropertyKey, SlotName, TokenChild, TokenId,
+	TokenOwnerError, TokenSlot, TokenSlots, TokenTreeNode,
 };
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -71,9 +86,14 @@ pub use pallet::*;
 
 pub type SelfWeightOf<T> = <T as crate::Config>::WeightInfo;
 
+/// Maximum depth of the parent chain walked when the caller doesn't provide a nesting budget,
+/// e.g. while checking ownership in slot calls.
+pub const NESTING_BUDGET: u32 = 5;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{dispatch::GetDispatchInfo, traits::UnfilteredDispatchable, Parameter};
+	use frame_system::pallet_prelude::*;
 
 	use super::*;
 
@@ -89,12 +109,59 @@ pub mod pallet {
 		TokenNotFound,
 		/// Tried to nest token under collection contract address, instead of token address
 		CantNestTokenUnderCollection,
+		/// Parent collection defines slots, but none of them accepts tokens of the source collection.
+		NoSlotForSourceCollection,
+		/// Slot with the specified name is not defined by the parent collection.
+		SlotNotFound,
+		/// Slot names must be unique within the collection.
+		DuplicateSlotName,
+		/// Tokens of the source collection can't be equipped into this slot.
+		SourceCollectionIsNotAllowedInSlot,
+		/// Slot of the parent token already holds the maximum number of tokens.
+		SlotIsFull,
+		/// Token should be nested directly into the parent token to be equipped.
+		TokenIsNotNestedInParent,
+		/// Token is already equipped into a slot.
+		TokenAlreadyEquipped,
+		/// Token is not equipped into any slot.
+		TokenNotEquipped,
 	}
 
 	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T> {
 		/// Executed call on behalf of the token.
 		Executed(DispatchResult),
+
+		/// Slots of the collection were redefined.
+		CollectionSlotsSet(
+			/// ID of the affected collection.
+			CollectionId,
+		),
+
+		/// Nested token was equipped into a slot of its parent.
+		TokenEquipped {
+			/// Parent collection ID.
+			collection_id: CollectionId,
+			/// Parent token ID.
+			token_id: TokenId,
+			/// Slot the token was equipped into.
+			slot: SlotName,
+			/// Equipped token address.
+			child: TokenChild,
+		},
+
+		/// Token was removed from the slot of its parent.
+		TokenUnequipped {
+			/// Parent collection ID.
+			collection_id: CollectionId,
+			/// Parent token ID.
+			token_id: TokenId,
+			/// Slot the token was equipped into.
+			slot: SlotName,
+			/// Unequipped token address.
+			child: TokenChild,
+		},
 	}
 
 	#[pallet::config]
@@ -109,8 +176,122 @@ pub mod pallet {
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// Slots defined for tokens of a collection.
+	#[pallet::storage]
+	pub type CollectionSlots<T> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = TokenSlots,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Number of tokens equipped into the slot of a token.
+	#[pallet::storage]
+	pub type SlotOccupancy<T> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, CollectionId>,
+			Key<Blake2_128Concat, TokenId>,
+			Key<Blake2_128Concat, SlotName>,
+		),
+		Value = u32,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Parent token and slot a nested token is equipped into.
+	#[pallet::storage]
+	pub type EquippedIn<T> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, CollectionId>,
+			Key<Blake2_128Concat, TokenId>,
+		),
+		Value = (CollectionId, TokenId, SlotName),
+		QueryKind = OptionQuery,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Define slots for tokens of an NFT collection, replacing the previous definition.
+		///
+		/// Tokens equipped into slots that are no longer defined stay equipped until unequipped.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `slots`: New slot definitions, an empty list removes slot restrictions.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_collection_slots(slots.len() as u32))]
+		pub fn set_collection_slots(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			slots: TokenSlots,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			Self::set_slots(&sender, collection_id, slots)
+		}
+
+		/// Equip a token into a slot of the token it is directly nested in.
+		///
+		/// # Permissions
+		///
+		/// * Owner of the parent token
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the parent collection.
+		/// * `token_id`: ID of the parent token.
+		/// * `slot`: Name of the slot defined by the parent collection.
+		/// * `child_collection_id`: ID of the collection of the equipped token.
+		/// * `child_token_id`: ID of the equipped token.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<SelfWeightOf<T>>::equip())]
+		pub fn equip(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			slot: SlotName,
+			child_collection_id: CollectionId,
+			child_token_id: TokenId,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			Self::equip_token(
+				&sender,
+				(collection_id, token_id),
+				slot,
+				(child_collection_id, child_token_id),
+				&budget::Value::new(NESTING_BUDGET),
+			)
+		}
+
+		/// Remove a token from the slot it is equipped into, keeping it nested.
+		///
+		/// # Permissions
+		///
+		/// * Owner of the token
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection of the equipped token.
+		/// * `token_id`: ID of the equipped token.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<SelfWeightOf<T>>::unequip())]
+		pub fn unequip(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResult {
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			Self::unequip_token(
+				&sender,
+				(collection_id, token_id),
+				&budget::Value::new(NESTING_BUDGET),
+			)
+		}
+
 		// #[pallet::weight({
 		// 	let dispatch_info = call.get_dispatch_info();
 
@@ -213,21 +394,92 @@ impl<T: Config> Pallet<T> {
 		token: TokenId,
 		budget: &dyn Budget,
 	) -> Result<Option<T::CrossAccountId>, DispatchError> {
-		let owner = Self::parent_chain(collection, token)
-			.take_while(|_| budget.consume())
-			.find(|p| {
-				matches!(
-					p,
-					Ok(Parent::User(_) | Parent::TokenNotFound | Parent::MultipleOwners)
-				)
-			})
-			.ok_or(<Error<T>>::DepthLimit)??;
-
-		Ok(match owner {
-			Parent::User(v) => Some(v),
-			Parent::MultipleOwners => None,
-			_ => fail!(<Error<T>>::TokenNotFound),
-		})
+		Self::find_topmost(collection, token, budget).map(|(_, owner)| owner)
+	}
+
+	/// Find the topmost token, which the `token` is nested in, and the owner of that token
+	///
+	/// The token is `None` if the `token` is not nested,
+	/// the owner is `None` if the topmost token has multiple owners.
+	///
+	/// - `budget`: Limit for searching parents in depth.
+	pub fn find_topmost(
+		collection: CollectionId,
+		token: TokenId,
+		budget: &dyn Budget,
+	) -> Result<(Option<(CollectionId, TokenId)>, Option<T::CrossAccountId>), DispatchError> {
+		let mut topmost = None;
+		for parent in Self::parent_chain(collection, token).take_while(|_| budget.consume()) {
+			match parent? {
+				Parent::Token(collection, token) => topmost = Some((collection, token)),
+				Parent::User(owner) => return Ok((topmost, Some(owner))),
+				Parent::MultipleOwners => return Ok((topmost, None)),
+				Parent::TokenNotFound => fail!(<Error<T>>::TokenNotFound),
+			}
+		}
+		fail!(<Error<T>>::DepthLimit)
+	}
+
+	/// Get the tree of tokens nested in the `token`, including the `token` itself
+	///
+	/// Tokens are returned level by level, starting from the root with depth 0.
+	/// Every level below the root consumes the budget, the tree is truncated
+	/// once the budget or `max_depth` is exhausted.
+	///
+	/// - `keys`: Properties to return for every token, all properties if `None`.
+	/// - `budget`: Limit for searching children in depth.
+	pub fn token_tree(
+		collection: CollectionId,
+		token: TokenId,
+		max_depth: Option<u32>,
+		keys: Option<Vec<PropertyKey>>,
+		budget: &dyn Budget,
+	) -> Result<Vec<TokenTreeNode<T::CrossAccountId>>, DispatchError> {
+		let root = TokenChild { collection, token };
+		let dispatch = T::CollectionDispatch::dispatch(collection)?;
+		if !dispatch.as_dyn().token_exists(token) {
+			fail!(<Error<T>>::TokenNotFound);
+		}
+
+		let mut visited = BTreeSet::new();
+		visited.insert((collection, token));
+
+		let mut tree = Vec::new();
+		let mut level = vec![(root, None)];
+		let mut depth = 0;
+		loop {
+			let mut next_level = Vec::new();
+			for (node, parent) in level {
+				let dispatch = T::CollectionDispatch::dispatch(node.collection)?;
+				let dispatch = dispatch.as_dyn();
+
+				for child in dispatch.token_children(node.token) {
+					if !visited.insert((child.collection, child.token)) {
+						fail!(<Error<T>>::OuroborosDetected);
+					}
+					next_level.push((child, Some(node.clone())));
+				}
+
+				tree.push(TokenTreeNode {
+					owner: dispatch.token_owner(node.token).ok(),
+					properties: dispatch.token_properties(node.token, keys.clone()),
+					token: node,
+					parent,
+					depth,
+				});
+			}
+
+			if next_level.is_empty()
+				|| max_depth.map_or(false, |max_depth| depth >= max_depth)
+				|| !budget.consume()
+			{
+				break;
+			}
+			depth += 1;
+			level = next_level;
+		}
+
+		Ok(tree)
 	}
 
 	/// Find the topmost parent and check that assigning `for_nest` token as a child for
@@ -293,6 +545,168 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Replace slots defined for tokens of `collection_id`
+	///
+	/// Only NFT collections could define slots, as only NFTs could be parents.
+	pub fn set_slots(
+		sender: &T::CrossAccountId,
+		collection_id: CollectionId,
+		slots: TokenSlots,
+	) -> DispatchResult {
+		let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+		collection.check_is_internal()?;
+		collection.check_admin_roles(sender, CollectionAdminRoles::LIMIT_MANAGER)?;
+		ensure!(
+			collection.mode == CollectionMode::NFT,
+			<CommonError<T>>::UnsupportedOperation
+		);
+
+		let mut names = BTreeSet::new();
+		for slot in slots.iter() {
+			ensure!(
+				names.insert(slot.name.clone()),
+				<Error<T>>::DuplicateSlotName
+			);
+		}
+
+		if slots.is_empty() {
+			<CollectionSlots<T>>::remove(collection_id);
+		} else {
+			<CollectionSlots<T>>::insert(collection_id, slots);
+		}
+
+		Self::deposit_event(Event::CollectionSlotsSet(collection_id));
+		Ok(())
+	}
+
+	/// Remove slot definitions of a destroyed collection.
+	pub fn clear_slots(collection_id: CollectionId) {
+		<CollectionSlots<T>>::remove(collection_id);
+	}
+
+	/// Find slot `name` defined by `collection_id`
+	pub fn slot(collection_id: CollectionId, name: &SlotName) -> Option<TokenSlot> {
+		<CollectionSlots<T>>::get(collection_id)
+			.into_iter()
+			.find(|slot| &slot.name == name)
+	}
+
+	/// Get the parent token and the slot `child` token is equipped into
+	pub fn equipped_in(
+		child: (CollectionId, TokenId),
+	) -> Option<(CollectionId, TokenId, SlotName)> {
+		<EquippedIn<T>>::get(child)
+	}
+
+	/// Check that tokens of `source` collection could be nested into tokens of `collection_id`
+	///
+	/// Returns OK if `collection_id` doesn't define any slots.
+	pub fn check_slot_nesting(collection_id: CollectionId, source: CollectionId) -> DispatchResult {
+		let slots = <CollectionSlots<T>>::get(collection_id);
+		ensure!(
+			slots.is_empty()
+				|| slots
+					.iter()
+					.any(|slot| slot.allowed_collections.contains(&source)),
+			<Error<T>>::NoSlotForSourceCollection
+		);
+		Ok(())
+	}
+
+	/// Equip `child` token into the `slot` of `parent` token
+	///
+	/// `child` should be nested directly into `parent`, and `sender` should be the owner of `parent`.
+	///
+	/// - `budget`: Limit for searching parents in depth.
+	pub fn equip_token(
+		sender: &T::CrossAccountId,
+		parent: (CollectionId, TokenId),
+		slot: SlotName,
+		child: (CollectionId, TokenId),
+		budget: &dyn Budget,
+	) -> DispatchResult {
+		let definition = Self::slot(parent.0, &slot).ok_or(<Error<T>>::SlotNotFound)?;
+		ensure!(
+			definition.allowed_collections.contains(&child.0),
+			<Error<T>>::SourceCollectionIsNotAllowedInSlot
+		);
+		ensure!(
+			!<EquippedIn<T>>::contains_key(child),
+			<Error<T>>::TokenAlreadyEquipped
+		);
+		ensure!(
+			Self::find_parent(child.0, child.1)? == Parent::Token(parent.0, parent.1),
+			<Error<T>>::TokenIsNotNestedInParent
+		);
+		ensure!(
+			Self::check_indirectly_owned(sender.clone(), parent.0, parent.1, None, budget)?,
+			<CommonError<T>>::NoPermission
+		);
+
+		let occupancy = <SlotOccupancy<T>>::get((parent.0, parent.1, &slot));
+		ensure!(occupancy < definition.max_count, <Error<T>>::SlotIsFull);
+
+		<SlotOccupancy<T>>::insert((parent.0, parent.1, &slot), occupancy + 1);
+		<EquippedIn<T>>::insert(child, (parent.0, parent.1, slot.clone()));
+
+		Self::deposit_event(Event::TokenEquipped {
+			collection_id: parent.0,
+			token_id: parent.1,
+			slot,
+			child: TokenChild {
+				collection: child.0,
+				token: child.1,
+			},
+		});
+		Ok(())
+	}
+
+	/// Remove `child` token from the slot it is equipped into
+	///
+	/// `sender` should be the owner of `child`.
+	///
+	/// - `budget`: Limit for searching parents in depth.
+	pub fn unequip_token(
+		sender: &T::CrossAccountId,
+		child: (CollectionId, TokenId),
+		budget: &dyn Budget,
+	) -> DispatchResult {
+		let (collection_id, token_id, _) =
+			<EquippedIn<T>>::get(child).ok_or(<Error<T>>::TokenNotEquipped)?;
+		ensure!(
+			Self::check_indirectly_owned(sender.clone(), collection_id, token_id, None, budget)?,
+			<CommonError<T>>::NoPermission
+		);
+
+		Self::unequip_if_equipped((collection_id, token_id), child);
+		Ok(())
+	}
+
+	/// Remove `child` token from the slot of `parent`, if it is equipped there.
+	fn unequip_if_equipped(parent: (CollectionId, TokenId), child: (CollectionId, TokenId)) {
+		let Some((collection_id, token_id, slot)) = <EquippedIn<T>>::get(child) else {
+			return;
+		};
+		if (collection_id, token_id) != parent {
+			return;
+		}
+
+		<EquippedIn<T>>::remove(child);
+		<SlotOccupancy<T>>::mutate_exists((collection_id, token_id, &slot), |occupancy| {
+			*occupancy = occupancy.and_then(|o| o.checked_sub(1)).filter(|o| *o > 0);
+		});
+
+		Self::deposit_event(Event::TokenUnequipped {
+			collection_id,
+			token_id,
+			slot,
+			child: TokenChild {
+				collection: child.0,
+				token: child.1,
+			},
+		});
+	}
+
 	/// Checks that `under` is valid token and that `token_id` could be nested under it
 	/// and that `from` is `under`'s owner
 	///
@@ -346,11 +760,16 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Unnests `token_id` from `owner`.
+	///
+	/// Token is also removed from the slot of `owner`, if it was equipped.
 	pub fn unnest_if_nested(
 		owner: &T::CrossAccountId,
 		collection_id: CollectionId,
 		token_id: TokenId,
 	) {
+		if let Some(parent) = T::CrossTokenAddressMapping::address_to_token(owner) {
+			Self::unequip_if_equipped(parent, (collection_id, token_id));
+		}
 		if let Err(e) = Self::try_exec_if_token(owner, |collection, parent_id| {
 			collection.unnest(parent_id, (collection_id, token_id));
 			Ok(())
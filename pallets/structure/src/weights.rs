@@ -34,6 +34,9 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_structure.
 pub trait WeightInfo {
 	fn find_parent() -> Weight;
+	fn set_collection_slots(s: u32, ) -> Weight;
+	fn equip() -> Weight;
+	fn unequip() -> Weight;
 }
 
 /// Weights for pallet_structure using the Substrate node and recommended hardware.
@@ -51,6 +54,58 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(10_753_000, 4325)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::CollectionSlots` (r:0 w:1)
+	/// Proof: `Structure::CollectionSlots` (`max_values`: None, `max_size`: Some(8739), added: 11214, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 16]`.
+	fn set_collection_slots(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `4325`
+		// Minimum execution time: 9_872_000 picoseconds.
+		Weight::from_parts(10_391_000, 4325)
+			// Standard Error: 2_817
+			.saturating_add(Weight::from_parts(1_104_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Structure::CollectionSlots` (r:1 w:0)
+	/// Proof: `Structure::CollectionSlots` (`max_values`: None, `max_size`: Some(8739), added: 11214, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::EquippedIn` (r:1 w:1)
+	/// Proof: `Structure::EquippedIn` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:2 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Nonfungible::TokenData` (r:2 w:0)
+	/// Proof: `Nonfungible::TokenData` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::SlotOccupancy` (r:1 w:1)
+	/// Proof: `Structure::SlotOccupancy` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	fn equip() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1426`
+		//  Estimated: `12204`
+		// Minimum execution time: 41_263_000 picoseconds.
+		Weight::from_parts(42_118_000, 12204)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Structure::EquippedIn` (r:1 w:1)
+	/// Proof: `Structure::EquippedIn` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Nonfungible::TokenData` (r:1 w:0)
+	/// Proof: `Nonfungible::TokenData` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::SlotOccupancy` (r:1 w:1)
+	/// Proof: `Structure::SlotOccupancy` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	fn unequip() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1098`
+		//  Estimated: `4325`
+		// Minimum execution time: 27_904_000 picoseconds.
+		Weight::from_parts(28_517_000, 4325)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -67,5 +122,57 @@ impl WeightInfo for () {
 		Weight::from_parts(10_753_000, 4325)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::CollectionSlots` (r:0 w:1)
+	/// Proof: `Structure::CollectionSlots` (`max_values`: None, `max_size`: Some(8739), added: 11214, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 16]`.
+	fn set_collection_slots(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `4325`
+		// Minimum execution time: 9_872_000 picoseconds.
+		Weight::from_parts(10_391_000, 4325)
+			// Standard Error: 2_817
+			.saturating_add(Weight::from_parts(1_104_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Structure::CollectionSlots` (r:1 w:0)
+	/// Proof: `Structure::CollectionSlots` (`max_values`: None, `max_size`: Some(8739), added: 11214, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::EquippedIn` (r:1 w:1)
+	/// Proof: `Structure::EquippedIn` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:2 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Nonfungible::TokenData` (r:2 w:0)
+	/// Proof: `Nonfungible::TokenData` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::SlotOccupancy` (r:1 w:1)
+	/// Proof: `Structure::SlotOccupancy` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	fn equip() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1426`
+		//  Estimated: `12204`
+		// Minimum execution time: 41_263_000 picoseconds.
+		Weight::from_parts(42_118_000, 12204)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Structure::EquippedIn` (r:1 w:1)
+	/// Proof: `Structure::EquippedIn` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Nonfungible::TokenData` (r:1 w:0)
+	/// Proof: `Nonfungible::TokenData` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Structure::SlotOccupancy` (r:1 w:1)
+	/// Proof: `Structure::SlotOccupancy` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	fn unequip() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1098`
+		//  Estimated: `4325`
+		// Minimum execution time: 27_904_000 picoseconds.
+		Weight::from_parts(28_517_000, 4325)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
 
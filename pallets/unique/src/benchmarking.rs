@@ -27,8 +27,9 @@ use pallet_common::{
 use sp_runtime::DispatchError;
 use sp_std::vec;
 use up_data_structs::{
-	CollectionId, CollectionLimits, CollectionMode, MAX_COLLECTION_DESCRIPTION_LENGTH,
-	MAX_COLLECTION_NAME_LENGTH, MAX_TOKEN_PREFIX_LENGTH,
+	CollectionAdminRoles, CollectionId, CollectionLimits, CollectionMode, PublicMintSponsoring,
+	SponsorBudget, MAX_COLLECTION_DESCRIPTION_LENGTH, MAX_COLLECTION_NAME_LENGTH,
+	MAX_TOKEN_PREFIX_LENGTH,
 };
 
 use super::*;
@@ -112,6 +113,17 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn begin_destroy_collection() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn add_to_allow_list() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -161,6 +173,59 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn propose_collection_owner() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let new_owner: T::AccountId = account("admin", 0, SEED);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection,
+			new_owner,
+			Some(1000u32.into()),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_collection_ownership() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let new_owner: T::AccountId = account("admin", 0, SEED);
+		<Pallet<T>>::propose_collection_owner(
+			RawOrigin::Signed(caller).into(),
+			collection,
+			new_owner.clone(),
+			Some(1000u32.into()),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(new_owner), collection);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_collection_owner_proposal() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let new_owner: T::AccountId = account("admin", 0, SEED);
+		<Pallet<T>>::propose_collection_owner(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			new_owner,
+			None,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn add_collection_admin() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -198,6 +263,28 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_collection_admin_roles() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let new_admin: T::AccountId = account("admin", 0, SEED);
+		<Pallet<T>>::add_collection_admin(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection,
+			T::CrossAccountId::from_sub(new_admin.clone()),
+		)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection,
+			T::CrossAccountId::from_sub(new_admin),
+			CollectionAdminRoles(CollectionAdminRoles::MINTER),
+		);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_collection_sponsor() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -242,6 +329,49 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_collection_sponsor_budget() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let budget = SponsorBudget {
+			period: 100,
+			period_limit: Some(u128::MAX),
+			user_limit: Some(u128::MAX),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, Some(budget));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_public_mint_sponsoring() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+		let sponsoring = PublicMintSponsoring {
+			period: 100,
+			mints_per_user: 10,
+			max_properties_size: 1024,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, Some(sponsoring));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_nested_tokens_sponsoring() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let collection = create_nft_collection::<T>(caller.clone())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection, true);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_transfers_enabled_flag() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = account("caller", 0, SEED);
@@ -268,6 +398,9 @@ mod benchmarks {
 			owner_can_transfer: Some(true),
 			sponsored_data_rate_limit: None,
 			transfers_enabled: Some(true),
+			sponsor_burn_timeout: Some(0),
+			sponsor_allowance_for_all_timeout: Some(0),
+			sponsor_repartition_timeout: Some(0),
 		};
 
 		#[extrinsic_call]
@@ -36,8 +36,8 @@ use pallet_evm_coder_substrate::{
 };
 use sp_std::vec::Vec;
 use up_data_structs::{
-	CollectionDescription, CollectionMode, CollectionName, CollectionPermissions,
-	CollectionTokenPrefix, CreateCollectionData, NestingPermissions,
+	CollectionAdminRoles, CollectionDescription, CollectionMode, CollectionName,
+	CollectionPermissions, CollectionTokenPrefix, CreateCollectionData, NestingPermissions,
 };
 
 use crate::{weights::WeightInfo, Config, Pallet, SelfWeightOf};
@@ -381,7 +381,7 @@ where
 
 		self.recorder().consume_sstore()?;
 		collection
-			.check_is_owner_or_admin(&caller)
+			.check_admin_roles(&caller, CollectionAdminRoles::METADATA_EDITOR)
 			.map_err(dispatch_to_evm::<T>)?;
 
 		if collection.flags.erc721metadata {
@@ -37,14 +37,22 @@
 //! - `create_collection` - Create a collection of tokens. **Deprecated**, use `create_collection_ex`.
 //! - `create_collection_ex` - Create a collection of tokens with explicit parameters.
 //! - `destroy_collection` - Destroy a collection if no tokens exist within.
+//! - `begin_destroy_collection` - Start destroying a collection, which may still contain tokens.
 //! - `add_to_allow_list` - Add an address to allow list.
 //! - `remove_from_allow_list` - Remove an address from allow list.
 //! - `change_collection_owner` - Change the owner of the collection.
+//! - `propose_collection_owner` - Propose a new owner of the collection.
+//! - `accept_collection_ownership` - Accept the proposed ownership of a collection, becoming the owner.
+//! - `cancel_collection_owner_proposal` - Cancel the pending ownership transfer of a collection.
 //! - `add_collection_admin` - Add an admin to a collection.
 //! - `remove_collection_admin` - Remove admin of a collection.
+//! - `set_collection_admin_roles` - Restrict a collection admin to a subset of administrative roles.
 //! - `set_collection_sponsor` - Invite a new collection sponsor.
 //! - `confirm_sponsorship` - Confirm own sponsorship of a collection, becoming the sponsor.
 //! - `remove_collection_sponsor` - Remove a sponsor from a collection.
+//! - `set_collection_sponsor_budget` - Limit the fees paid by the collection sponsor.
+//! - `set_public_mint_sponsoring` - Sponsor the tokens minted by allowlisted users.
+//! - `set_nested_tokens_sponsoring` - Sponsor the calls to the tokens nested in the collection tokens.
 //! - `create_item` - Create an item within a collection.
 //! - `create_multiple_items` - Create multiple items within a collection.
 //! - `set_collection_properties` - Add or change collection properties.
@@ -101,12 +109,12 @@ pub mod pallet {
 	use scale_info::TypeInfo;
 	use sp_std::{vec, vec::Vec};
 	use up_data_structs::{
-		budget, CollectionId, CollectionLimits, CollectionMode, CollectionPermissions,
-		CreateCollectionData, CreateItemData, CreateItemExData, Property, PropertyKey,
-		PropertyKeyPermission, TokenId, COLLECTION_ADMINS_LIMIT, MAX_COLLECTION_DESCRIPTION_LENGTH,
-		MAX_COLLECTION_NAME_LENGTH, MAX_COLLECTION_PROPERTIES_SIZE, MAX_PROPERTIES_PER_ITEM,
-		MAX_PROPERTY_KEY_LENGTH, MAX_PROPERTY_VALUE_LENGTH, MAX_TOKEN_PREFIX_LENGTH,
-		MAX_TOKEN_PROPERTIES_SIZE,
+		budget, CollectionAdminRoles, CollectionId, CollectionLimits, CollectionMode,
+		CollectionPermissions, CreateCollectionData, CreateItemData, CreateItemExData, Property,
+		PropertyKey, PropertyKeyPermission, PublicMintSponsoring, SponsorBudget, TokenId,
+		COLLECTION_ADMINS_LIMIT, MAX_COLLECTION_DESCRIPTION_LENGTH, MAX_COLLECTION_NAME_LENGTH,
+		MAX_COLLECTION_PROPERTIES_SIZE, MAX_PROPERTIES_PER_ITEM, MAX_PROPERTY_KEY_LENGTH,
+		MAX_PROPERTY_VALUE_LENGTH, MAX_TOKEN_PREFIX_LENGTH, MAX_TOKEN_PROPERTIES_SIZE,
 	};
 	use weights::WeightInfo;
 
@@ -266,6 +274,42 @@ pub mod pallet {
 		QueryKind = OptionQuery,
 	>;
 
+	/// Last sponsoring of token burning in a collection, by the token owner
+	///
+	/// Burnt tokens are gone, so the burns are limited per owner rather than per token.
+	#[pallet::storage]
+	#[pallet::getter(fn burn_basket)]
+	pub type BurnBasket<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = CollectionId,
+		Hasher2 = Twox64Concat,
+		Key2 = T::AccountId,
+		Value = BlockNumberFor<T>,
+		QueryKind = OptionQuery,
+	>;
+	/// Last sponsoring of setting allowance for all tokens in a collection
+	#[pallet::storage]
+	#[pallet::getter(fn allowance_for_all_basket)]
+	pub type AllowanceForAllBasket<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = CollectionId,
+		Hasher2 = Twox64Concat,
+		Key2 = T::AccountId,
+		Value = BlockNumberFor<T>,
+		QueryKind = OptionQuery,
+	>;
+	/// Last sponsoring of RFT repartition in a collection
+	#[pallet::storage]
+	#[pallet::getter(fn refungible_repartition_basket)]
+	pub type RefungibleRepartitionBasket<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = CollectionId,
+		Hasher2 = Blake2_128Concat,
+		Key2 = TokenId,
+		Value = BlockNumberFor<T>,
+		QueryKind = OptionQuery,
+	>;
+
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
 		/// A maximum number of levels of depth in the token nesting tree.
@@ -493,7 +537,11 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Change the owner of the collection.
+		/// Propose a new owner of the collection, which never expires.
+		///
+		/// Deprecated, use [`propose_collection_owner`][`Pallet::propose_collection_owner`].
+		/// The ownership is handed over only after the new owner accepts it,
+		/// see [`accept_collection_ownership`][`Pallet::accept_collection_ownership`].
 		///
 		/// # Permissions
 		///
@@ -502,7 +550,7 @@ pub mod pallet {
 		/// # Arguments
 		///
 		/// * `collection_id`: ID of the modified collection.
-		/// * `new_owner`: ID of the account that will become the owner.
+		/// * `new_owner`: ID of the account of the owner-to-be.
 		#[pallet::call_index(5)]
 		#[pallet::weight(<SelfWeightOf<T>>::change_collection_owner())]
 		pub fn change_collection_owner(
@@ -514,9 +562,8 @@ pub mod pallet {
 				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
 			}
 			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
-			let new_owner = T::CrossAccountId::from_sub(new_owner);
-			let mut target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
-			target_collection.change_owner(sender, new_owner)
+			let target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			target_collection.propose_owner(&sender, new_owner, None)
 		}
 
 		/// Add an admin to a collection.
@@ -1329,6 +1376,244 @@ pub mod pallet {
 			ensure_root(origin)?;
 			dispatch_tx::<T, _>(collection_id, |d| d.repair_item(item_id))
 		}
+
+		/// Propose a new owner of the collection.
+		///
+		/// If successful, acceptance from the owner-to-be will be pending,
+		/// see [`accept_collection_ownership`][`Pallet::accept_collection_ownership`].
+		/// A new proposal replaces the previous one.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `new_owner`: ID of the account of the owner-to-be.
+		/// * `expires_at`: The last block at which the proposal can be accepted.
+		/// The proposal never expires if not specified.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<SelfWeightOf<T>>::change_collection_owner())]
+		pub fn propose_collection_owner(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			new_owner: T::AccountId,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			target_collection.propose_owner(&sender, new_owner, expires_at)
+		}
+
+		/// Accept the proposed ownership of a collection, becoming the owner.
+		///
+		/// A proposal must be pending and not expired,
+		/// see [`propose_collection_owner`][`Pallet::propose_collection_owner`].
+		///
+		/// # Permissions
+		///
+		/// * Owner-to-be
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection with the pending owner.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<SelfWeightOf<T>>::change_collection_owner()
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+		pub fn accept_collection_ownership(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = ensure_signed(origin)?;
+			let mut target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			target_collection.accept_ownership(&sender)
+		}
+
+		/// Cancel the pending ownership transfer of a collection.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the collection with the pending owner.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<SelfWeightOf<T>>::change_collection_owner()
+			.saturating_add(T::DbWeight::get().reads(1)))]
+		pub fn cancel_collection_owner_proposal(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let target_collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			target_collection.cancel_owner_proposal(&sender)
+		}
+
+		/// Set the roles granted to a collection admin.
+		///
+		/// Admins are granted all of the roles when added. Roles can then be narrowed down,
+		/// so that, e.g., a minting server is not able to change collection limits or sponsorship.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `admin`: Address of the existing collection admin.
+		/// * `roles`: Bit set of the roles to grant, see [`CollectionAdminRoles`].
+		#[pallet::call_index(35)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_collection_admin_roles())]
+		pub fn set_collection_admin_roles(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			admin: T::CrossAccountId,
+			roles: CollectionAdminRoles,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::set_admin_roles(&collection, &sender, &admin, roles)
+		}
+
+		/// Start destroying a collection, which may still contain tokens.
+		///
+		/// The collection becomes unavailable right away, while its tokens
+		/// are burnt in the following blocks, using the spare block weight.
+		/// Tokens of other collections nested into the destroyed tokens
+		/// are transferred to the owner of the topmost destroyed token,
+		/// or to the collection owner.
+		///
+		/// # Permissions
+		///
+		/// * Collection owner
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: Collection to destroy.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<SelfWeightOf<T>>::begin_destroy_collection())]
+		pub fn begin_destroy_collection(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::begin_destroy_collection(collection, &sender)?;
+			Self::clear_sponsoring_baskets(collection_id);
+
+			Ok(())
+		}
+
+		/// Limit the fees paid by the collection sponsor, per period and per user.
+		///
+		/// Once the limit is reached, the calls are no longer sponsored
+		/// until the next budget period starts.
+		///
+		/// # Permissions
+		///
+		/// * Collection sponsor
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `budget`: New limits of the sponsor fees, or `None` to remove the limits.
+		#[pallet::call_index(37)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_collection_sponsor_budget())]
+		pub fn set_collection_sponsor_budget(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			budget: Option<SponsorBudget>,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			collection.set_sponsor_budget(&sender, budget)
+		}
+
+		/// Sponsor the tokens minted by the allowlisted users, who are neither
+		/// the collection owner nor admins, while the collection mint mode is enabled.
+		///
+		/// Each user gets a quota of sponsored mints per period, and only the tokens
+		/// with small enough initial properties are sponsored.
+		///
+		/// # Permissions
+		///
+		/// * Collection sponsor
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `sponsoring`: New mint sponsoring settings, or `None` to stop sponsoring public mints.
+		#[pallet::call_index(38)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_public_mint_sponsoring())]
+		pub fn set_public_mint_sponsoring(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			sponsoring: Option<PublicMintSponsoring>,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			collection.set_public_mint_sponsoring(&sender, sponsoring)
+		}
+
+		/// Sponsor the calls to the tokens nested in the tokens of the collection,
+		/// if the collections of the nested tokens have no sponsor.
+		///
+		/// The calls are sponsored within the limits and the budget of this collection.
+		///
+		/// # Permissions
+		///
+		/// * Collection sponsor
+		/// * Collection owner
+		/// * Collection admin
+		///
+		/// # Arguments
+		///
+		/// * `collection_id`: ID of the modified collection.
+		/// * `enabled`: Whether the nested tokens should be sponsored.
+		#[pallet::call_index(39)]
+		#[pallet::weight(<SelfWeightOf<T>>::set_nested_tokens_sponsoring())]
+		pub fn set_nested_tokens_sponsoring(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			enabled: bool,
+		) -> DispatchResult {
+			if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
+				fail!(<pallet_common::Error<T>>::UnsupportedOperation);
+			}
+			let sender = T::CrossAccountId::from_sub(ensure_signed(origin)?);
+			let collection = <CollectionHandle<T>>::try_get(collection_id)?;
+			collection.set_nested_tokens_sponsoring(&sender, enabled)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -1368,7 +1653,12 @@ pub mod pallet {
 			collection_id: CollectionId,
 		) -> DispatchResult {
 			T::CollectionDispatch::destroy(sender, collection_id)?;
+			Self::clear_sponsoring_baskets(collection_id);
 
+			Ok(())
+		}
+
+		fn clear_sponsoring_baskets(collection_id: CollectionId) {
 			// TODO: basket cleanup should be moved elsewhere
 			// Maybe runtime dispatch.rs should perform it?
 
@@ -1380,7 +1670,9 @@ pub mod pallet {
 			let _ = <FungibleApproveBasket<T>>::clear_prefix(collection_id, u32::MAX, None);
 			let _ = <RefungibleApproveBasket<T>>::clear_prefix((collection_id,), u32::MAX, None);
 
-			Ok(())
+			let _ = <BurnBasket<T>>::clear_prefix(collection_id, u32::MAX, None);
+			let _ = <AllowanceForAllBasket<T>>::clear_prefix(collection_id, u32::MAX, None);
+			let _ = <RefungibleRepartitionBasket<T>>::clear_prefix(collection_id, u32::MAX, None);
 		}
 
 		fn structure_nesting_budget() -> budget::Value {
@@ -35,14 +35,19 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn create_collection() -> Weight;
 	fn destroy_collection() -> Weight;
+	fn begin_destroy_collection() -> Weight;
 	fn add_to_allow_list() -> Weight;
 	fn remove_from_allow_list() -> Weight;
 	fn change_collection_owner() -> Weight;
 	fn add_collection_admin() -> Weight;
 	fn remove_collection_admin() -> Weight;
+	fn set_collection_admin_roles() -> Weight;
 	fn set_collection_sponsor() -> Weight;
 	fn confirm_sponsorship() -> Weight;
 	fn remove_collection_sponsor() -> Weight;
+	fn set_collection_sponsor_budget() -> Weight;
+	fn set_public_mint_sponsoring() -> Weight;
+	fn set_nested_tokens_sponsoring() -> Weight;
 	fn set_transfers_enabled_flag() -> Weight;
 	fn set_collection_limits() -> Weight;
 	fn force_repair_collection() -> Weight;
@@ -97,6 +102,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	/// Storage: `Common::CollectionById` (r:1 w:1)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::DestroyedCollectionCount` (r:1 w:1)
+	/// Proof: `Common::DestroyedCollectionCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::DestroyingCollections` (r:0 w:1)
+	/// Proof: `Common::DestroyingCollections` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `Common::AdminAmount` (r:0 w:1)
+	/// Proof: `Common::AdminAmount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionProperties` (r:0 w:1)
+	/// Proof: `Common::CollectionProperties` (`max_values`: None, `max_size`: Some(40992), added: 43467, mode: `MaxEncodedLen`)
+	fn begin_destroy_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1000`
+		//  Estimated: `4325`
+		// Minimum execution time: 41_903_000 picoseconds.
+		Weight::from_parts(42_511_000, 4325)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 	/// Storage: `Common::CollectionById` (r:1 w:0)
 	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
 	/// Storage: `Common::Allowlist` (r:0 w:1)
@@ -140,6 +164,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Common::IsAdmin` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	/// Storage: `Common::AdminAmount` (r:1 w:1)
 	/// Proof: `Common::AdminAmount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::AdminRoles` (r:0 w:1)
+	/// Proof: `Common::AdminRoles` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	fn add_collection_admin() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1012`
@@ -147,7 +173,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 22_058_000 picoseconds.
 		Weight::from_parts(22_578_000, 4325)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Common::CollectionById` (r:1 w:0)
 	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
@@ -155,6 +181,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Common::IsAdmin` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	/// Storage: `Common::AdminAmount` (r:1 w:1)
 	/// Proof: `Common::AdminAmount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::AdminRoles` (r:0 w:1)
+	/// Proof: `Common::AdminRoles` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	fn remove_collection_admin() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1107`
@@ -162,7 +190,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 23_528_000 picoseconds.
 		Weight::from_parts(23_955_000, 4325)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::IsAdmin` (r:1 w:0)
+	/// Proof: `Common::IsAdmin` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Common::AdminRoles` (r:0 w:1)
+	/// Proof: `Common::AdminRoles` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	fn set_collection_admin_roles() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1107`
+		//  Estimated: `4325`
+		// Minimum execution time: 16_482_000 picoseconds.
+		Weight::from_parts(16_937_000, 4325)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Common::CollectionById` (r:1 w:1)
 	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
@@ -197,6 +240,45 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::SponsorBudgets` (r:0 w:1)
+	/// Proof: `Common::SponsorBudgets` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn set_collection_sponsor_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1000`
+		//  Estimated: `4325`
+		// Minimum execution time: 13_204_000 picoseconds.
+		Weight::from_parts(13_611_000, 4325)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::PublicMintSponsorings` (r:0 w:1)
+	/// Proof: `Common::PublicMintSponsorings` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn set_public_mint_sponsoring() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1000`
+		//  Estimated: `4325`
+		// Minimum execution time: 13_087_000 picoseconds.
+		Weight::from_parts(13_492_000, 4325)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::NestedTokensSponsoring` (r:0 w:1)
+	/// Proof: `Common::NestedTokensSponsoring` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_nested_tokens_sponsoring() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1000`
+		//  Estimated: `4325`
+		// Minimum execution time: 12_871_000 picoseconds.
+		Weight::from_parts(13_258_000, 4325)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Common::CollectionById` (r:1 w:1)
 	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
 	fn set_transfers_enabled_flag() -> Weight {
@@ -280,6 +362,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
+	/// Storage: `Common::CollectionById` (r:1 w:1)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::DestroyedCollectionCount` (r:1 w:1)
+	/// Proof: `Common::DestroyedCollectionCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::DestroyingCollections` (r:0 w:1)
+	/// Proof: `Common::DestroyingCollections` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `Common::AdminAmount` (r:0 w:1)
+	/// Proof: `Common::AdminAmount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::CollectionProperties` (r:0 w:1)
+	/// Proof: `Common::CollectionProperties` (`max_values`: None, `max_size`: Some(40992), added: 43467, mode: `MaxEncodedLen`)
+	fn begin_destroy_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1000`
+		//  Estimated: `4325`
+		// Minimum execution time: 41_903_000 picoseconds.
+		Weight::from_parts(42_511_000, 4325)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 	/// Storage: `Common::CollectionById` (r:1 w:0)
 	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
 	/// Storage: `Common::Allowlist` (r:0 w:1)
@@ -323,6 +424,8 @@ impl WeightInfo for () {
 	/// Proof: `Common::IsAdmin` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	/// Storage: `Common::AdminAmount` (r:1 w:1)
 	/// Proof: `Common::AdminAmount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::AdminRoles` (r:0 w:1)
+	/// Proof: `Common::AdminRoles` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	fn add_collection_admin() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1012`
@@ -330,7 +433,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 22_058_000 picoseconds.
 		Weight::from_parts(22_578_000, 4325)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Common::CollectionById` (r:1 w:0)
 	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
@@ -338,6 +441,8 @@ impl WeightInfo for () {
 	/// Proof: `Common::IsAdmin` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	/// Storage: `Common::AdminAmount` (r:1 w:1)
 	/// Proof: `Common::AdminAmount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Common::AdminRoles` (r:0 w:1)
+	/// Proof: `Common::AdminRoles` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	fn remove_collection_admin() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1107`
@@ -345,7 +450,22 @@ impl WeightInfo for () {
 		// Minimum execution time: 23_528_000 picoseconds.
 		Weight::from_parts(23_955_000, 4325)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::IsAdmin` (r:1 w:0)
+	/// Proof: `Common::IsAdmin` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Common::AdminRoles` (r:0 w:1)
+	/// Proof: `Common::AdminRoles` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	fn set_collection_admin_roles() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1107`
+		//  Estimated: `4325`
+		// Minimum execution time: 16_482_000 picoseconds.
+		Weight::from_parts(16_937_000, 4325)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Common::CollectionById` (r:1 w:1)
 	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
@@ -380,6 +500,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::SponsorBudgets` (r:0 w:1)
+	/// Proof: `Common::SponsorBudgets` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn set_collection_sponsor_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1000`
+		//  Estimated: `4325`
+		// Minimum execution time: 13_204_000 picoseconds.
+		Weight::from_parts(13_611_000, 4325)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::PublicMintSponsorings` (r:0 w:1)
+	/// Proof: `Common::PublicMintSponsorings` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn set_public_mint_sponsoring() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1000`
+		//  Estimated: `4325`
+		// Minimum execution time: 13_087_000 picoseconds.
+		Weight::from_parts(13_492_000, 4325)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Common::CollectionById` (r:1 w:0)
+	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
+	/// Storage: `Common::NestedTokensSponsoring` (r:0 w:1)
+	/// Proof: `Common::NestedTokensSponsoring` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_nested_tokens_sponsoring() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1000`
+		//  Estimated: `4325`
+		// Minimum execution time: 12_871_000 picoseconds.
+		Weight::from_parts(13_258_000, 4325)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Common::CollectionById` (r:1 w:1)
 	/// Proof: `Common::CollectionById` (`max_values`: None, `max_size`: Some(860), added: 3335, mode: `MaxEncodedLen`)
 	fn set_transfers_enabled_flag() -> Weight {
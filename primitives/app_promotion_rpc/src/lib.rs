@@ -17,16 +17,18 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use parity_scale_codec::Decode;
+use sp_core::H160;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Member},
 	DispatchError,
 };
 use sp_std::vec::Vec;
+use up_data_structs::RewardDestination;
 
 type Result<T> = core::result::Result<T, DispatchError>;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(2)]
+	#[api_version(6)]
 	/// Trait for generate rpc.
 	pub trait AppPromotionApi<BlockNumber ,CrossAccountId, AccountId> where
 		BlockNumber: Decode + Member + AtLeast32BitUnsigned,
@@ -34,8 +36,19 @@ sp_api::decl_runtime_apis! {
 		CrossAccountId: pallet_evm::account::CrossAccountId<AccountId>,
 	{
 		fn total_staked(staker: Option<CrossAccountId>) -> Result<u128>;
+		fn total_staked_per_block(staker: CrossAccountId) -> Result<Vec<(BlockNumber, u128, Option<(u8, BlockNumber)>)>>;
+
+		#[changed_in(4)]
 		fn total_staked_per_block(staker: CrossAccountId) -> Result<Vec<(BlockNumber, u128)>>;
+
 		fn pending_unstake(staker: Option<CrossAccountId>) -> Result<u128>;
 		fn pending_unstake_per_block(staker: CrossAccountId) -> Result<Vec<(BlockNumber, u128)>>;
+		fn delegated_stake(staker: CrossAccountId) -> Result<Vec<(H160, u128)>>;
+		fn target_delegated_stake(target: H160) -> Result<u128>;
+		fn sponsoring_allowance(target: H160) -> Result<u128>;
+		fn payout_history(staker: CrossAccountId) -> Result<Vec<(BlockNumber, u128)>>;
+		fn projected_payout(staker: CrossAccountId) -> Result<(BlockNumber, u128)>;
+		fn reward_pot_runway() -> Result<(u128, u128, Option<BlockNumber>)>;
+		fn reward_destination(staker: CrossAccountId) -> Result<RewardDestination<AccountId>>;
 	}
 }
@@ -98,6 +98,15 @@ pub const REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT: u32 = 5;
 /// Default timeout for sponsored approving.
 pub const SPONSOR_APPROVE_TIMEOUT: u32 = 5;
 
+/// Default timeout for sponsored burning.
+pub const SPONSOR_BURN_TIMEOUT: u32 = 5;
+
+/// Default timeout for sponsored setting of allowance for all tokens.
+pub const SPONSOR_ALLOWANCE_FOR_ALL_TIMEOUT: u32 = 5;
+
+/// Default timeout for sponsored repartition of refungible tokens.
+pub const SPONSOR_REPARTITION_TIMEOUT: u32 = 5;
+
 // Schema limits
 pub const OFFCHAIN_SCHEMA_LIMIT: u32 = 8192;
 pub const VARIABLE_ON_CHAIN_SCHEMA_LIMIT: u32 = 8192;
@@ -137,6 +146,15 @@ pub const MAX_TOKEN_PROPERTIES_SIZE: u32 = 32768;
 /// create_many call.
 pub const MAX_ITEMS_PER_BATCH: u32 = 120;
 
+/// Maximum number of slots a collection can define.
+pub const MAX_COLLECTION_SLOTS: u32 = 16;
+
+/// Maximum length of a slot name.
+pub const MAX_SLOT_NAME_LENGTH: u32 = 32;
+
+/// Maximum number of source collections allowed in a single slot.
+pub const MAX_SLOT_SOURCE_COLLECTIONS: u32 = 16;
+
 /// Used for limit bounded types of token custom data.
 pub type CustomDataLimit = ConstU32<CUSTOM_DATA_LIMIT>;
 
@@ -328,6 +346,37 @@ pub struct Ownership<AccountId> {
 	pub fraction: u128,
 }
 
+/// Where the interest accrued by the app promotion stakes is paid.
+#[derive(
+	Encode,
+	Decode,
+	Debug,
+	Default,
+	Clone,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub enum RewardDestination<AccountId> {
+	/// The interest is added to the stakes, and accrues the interest itself.
+	#[default]
+	Compound,
+	/// The interest is paid to the staker as a free balance.
+	Liquid,
+	/// The interest is paid to the account as a free balance.
+	Account(AccountId),
+}
+
+impl<AccountId> RewardDestination<AccountId> {
+	/// Whether the interest is added to the stakes.
+	pub fn is_compound(&self) -> bool {
+		matches!(self, Self::Compound)
+	}
+}
+
 /// The state of collection sponsorship.
 #[derive(
 	Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
@@ -371,6 +420,284 @@ impl<T> Default for SponsorshipState<T> {
 	}
 }
 
+/// Roles of a collection admin, stored as a bitmask.
+///
+/// Each role allows the admin to perform a specific group of the collection owner's actions.
+#[derive(
+	Encode,
+	Decode,
+	Debug,
+	Default,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct CollectionAdminRoles(pub u8);
+
+impl CollectionAdminRoles {
+	/// Mint tokens.
+	pub const MINTER: Self = Self(1 << 0);
+	/// Change collection and token properties and token property permissions.
+	pub const METADATA_EDITOR: Self = Self(1 << 1);
+	/// Add and remove addresses from the allow list.
+	pub const ALLOWLIST_MANAGER: Self = Self(1 << 2);
+	/// Set and remove the collection sponsor.
+	pub const SPONSOR_MANAGER: Self = Self(1 << 3);
+	/// Change collection limits and permissions.
+	pub const LIMIT_MANAGER: Self = Self(1 << 4);
+	/// All of the roles, which is what an admin gets by default.
+	pub const ALL: Self = Self(
+		Self::MINTER.0
+			| Self::METADATA_EDITOR.0
+			| Self::ALLOWLIST_MANAGER.0
+			| Self::SPONSOR_MANAGER.0
+			| Self::LIMIT_MANAGER.0,
+	);
+
+	/// Create a role set from the bitmask, if it contains only known roles.
+	pub fn from_bits(bits: u8) -> Option<Self> {
+		(bits & !Self::ALL.0 == 0).then_some(Self(bits))
+	}
+
+	/// Whether all of the `roles` are present in this set.
+	pub fn contains(self, roles: Self) -> bool {
+		self.0 & roles.0 == roles.0
+	}
+
+	/// Whether there are no roles in this set.
+	pub fn is_empty(self) -> bool {
+		self.0 == 0
+	}
+}
+
+/// Collection ownership transfer, proposed by the current owner.
+///
+/// Like [`SponsorshipState::Unconfirmed`], the proposal has no effect
+/// until the proposed account accepts it.
+#[derive(
+	Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+pub struct PendingCollectionOwner<AccountId, BlockNumber> {
+	/// The account which is proposed to become the owner.
+	pub owner: AccountId,
+	/// The last block at which the proposal can be accepted, if limited.
+	pub expires_at: Option<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber: PartialOrd> PendingCollectionOwner<AccountId, BlockNumber> {
+	/// Whether the proposal can no longer be accepted at block `now`.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		matches!(&self.expires_at, Some(expires_at) if expires_at < now)
+	}
+}
+
+/// Collection which is being destroyed, while its tokens are burnt over multiple blocks.
+#[derive(
+	Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+pub struct DestroyingCollection<AccountId> {
+	/// Owner of the collection at the moment the destruction was started.
+	pub owner: AccountId,
+	/// Mode of the collection, which defines the storage to be cleared.
+	pub mode: CollectionMode,
+}
+
+/// Limits of the fees paid by the collection sponsor.
+///
+/// Fees are counted within periods of `period` blocks, counted from the genesis.
+/// Zero `period` means the fees are counted over the whole life of the collection.
+#[derive(
+	Encode,
+	Decode,
+	Debug,
+	Default,
+	Clone,
+	Copy,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct SponsorBudget {
+	/// Length of a budget period, in blocks.
+	pub period: u32,
+	/// Maximum total fee paid by the sponsor within a period, if limited.
+	pub period_limit: Option<u128>,
+	/// Maximum fee paid by the sponsor on behalf of a single user within a period, if limited.
+	pub user_limit: Option<u128>,
+}
+
+impl SponsorBudget {
+	/// First block of the budget period which contains the block `now`.
+	pub fn period_start(&self, now: u32) -> u32 {
+		if self.period == 0 {
+			return 0;
+		}
+		now - now % self.period
+	}
+}
+
+/// Fees paid by the collection sponsor within a budget period.
+#[derive(
+	Encode,
+	Decode,
+	Debug,
+	Default,
+	Clone,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct SponsorBudgetUsage {
+	/// First block of the period, see [`SponsorBudget::period_start`].
+	pub period_start: u32,
+	/// Total fee paid within the period.
+	pub spent: u128,
+	/// Whether a call was already refused sponsoring within the period, because of the limit.
+	pub exhausted: bool,
+}
+
+impl SponsorBudgetUsage {
+	/// Whether paying `fee` keeps the spending within `limit`.
+	pub fn allows(&self, limit: Option<u128>, fee: u128) -> bool {
+		limit.map_or(true, |limit| self.spent.saturating_add(fee) <= limit)
+	}
+}
+
+/// Sponsoring of the tokens minted by the users, who are neither the collection owner nor admins,
+/// while [`mint_mode`](CollectionPermissions::mint_mode) is enabled.
+///
+/// Mints are counted within periods of `period` blocks, counted from the genesis.
+/// Zero `period` means the mints are counted over the whole life of the collection.
+#[derive(
+	Encode,
+	Decode,
+	Debug,
+	Default,
+	Clone,
+	Copy,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct PublicMintSponsoring {
+	/// Length of a mint quota period, in blocks.
+	pub period: u32,
+	/// Maximum number of sponsored tokens minted by a single user within a period.
+	pub mints_per_user: u32,
+	/// Maximum total size of the keys and values of the initial properties of a sponsored token.
+	pub max_properties_size: u32,
+}
+
+impl PublicMintSponsoring {
+	/// First block of the quota period which contains the block `now`.
+	pub fn period_start(&self, now: u32) -> u32 {
+		if self.period == 0 {
+			return 0;
+		}
+		now - now % self.period
+	}
+}
+
+/// Sponsored tokens minted by a user within a mint quota period.
+#[derive(
+	Encode,
+	Decode,
+	Debug,
+	Default,
+	Clone,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct PublicMintUsage {
+	/// First block of the period, see [`PublicMintSponsoring::period_start`].
+	pub period_start: u32,
+	/// Number of sponsored tokens minted within the period.
+	pub minted: u32,
+}
+
+/// Fees the collection sponsor is still ready to pay within the current budget period.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, Serialize, Deserialize)]
+pub struct SponsorBudgetLeft<Balance = u128> {
+	/// Fees left for all of the users, if limited.
+	pub period: Option<Balance>,
+	/// Fees left for the requested user, if limited.
+	pub user: Option<Balance>,
+	/// The last block of the current period, if periods are used.
+	pub period_end: Option<u32>,
+}
+
+/// Block production and reward statistics of a collator.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, Serialize, Deserialize)]
+pub struct CollatorStats<BlockNumber, Balance = u128> {
+	/// Number of blocks authored by the collator in the current session.
+	pub session_blocks: u32,
+	/// The last block authored by the collator, if any.
+	pub last_authored_block: Option<BlockNumber>,
+	/// The last session the collator was rewarded for.
+	pub last_rewarded_session: u32,
+	/// Number of blocks authored by the collator in the last rewarded session.
+	pub last_session_blocks: u32,
+	/// Reward of the collator for the last rewarded session.
+	pub last_session_reward: Balance,
+	/// Total reward of the collator for all sessions.
+	pub total_reward: Balance,
+}
+
+/// Reason why a call would or would not be sponsored, see [`SponsoringDryRun`].
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq, TypeInfo, Serialize, Deserialize)]
+pub enum SponsoringReason {
+	/// The call would be sponsored.
+	Sponsored,
+	/// The call isn't a signed extrinsic, so its caller is unknown.
+	Unsigned,
+	/// The call target has no confirmed sponsor.
+	NoSponsor,
+	/// The call isn't sponsored by its target, or the sponsoring rate limit hasn't passed yet.
+	NotEligible,
+	/// The sponsor budget doesn't allow paying the fee, see [`SponsorBudget`].
+	BudgetExhausted,
+	/// The gas price of the EVM call is out of the range accepted for sponsoring.
+	GasPriceNotSponsored,
+	/// The sponsor can't afford the fee, so the transaction would be rejected.
+	SponsorBalanceTooLow,
+}
+
+/// Result of checking whether a call would be sponsored, without executing it.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, Serialize, Deserialize)]
+pub struct SponsoringDryRun<AccountId, Balance = u128> {
+	/// The account which would pay the fee instead of the caller.
+	pub sponsor: Option<AccountId>,
+	/// Fee of the call, as known before its execution.
+	pub fee: Balance,
+	/// Reason why the sponsor was or wasn't chosen.
+	pub reason: SponsoringReason,
+}
+
+/// Fees paid for the calls of a sponsored collection or contract within an era.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, Serialize, Deserialize)]
+pub struct SponsoredFees<AccountId, Balance = u128> {
+	/// Era of the sponsoring statistics.
+	pub era: u32,
+	/// Total fee paid within the era.
+	pub total: Balance,
+	/// Users, on behalf of which the most was paid, with the fees paid for them.
+	pub top_users: Vec<(AccountId, Balance)>,
+}
+
 pub type CollectionName = BoundedVec<u16, ConstU32<MAX_COLLECTION_NAME_LENGTH>>;
 pub type CollectionDescription = BoundedVec<u16, ConstU32<MAX_COLLECTION_DESCRIPTION_LENGTH>>;
 pub type CollectionTokenPrefix = BoundedVec<u8, ConstU32<MAX_TOKEN_PREFIX_LENGTH>>;
@@ -405,7 +732,7 @@ impl CollectionFlags {
 ///
 /// #### Note
 /// Collection parameters, used in storage (see [`RpcCollection`] for the RPC version).
-#[struct_versioning::versioned(version = 2, upper)]
+#[struct_versioning::versioned(version = 3, upper)]
 #[derive(Encode, Decode, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
 pub struct Collection<AccountId> {
 	/// Collection owner account.
@@ -440,6 +767,11 @@ pub struct Collection<AccountId> {
 	pub sponsorship: SponsorshipState<AccountId>,
 
 	/// Collection limits.
+	#[version(..3)]
+	pub limits: CollectionLimitsVersion1,
+
+	/// Collection limits.
+	#[version(3.., upper(limits.into()))]
 	pub limits: CollectionLimits,
 
 	/// Collection permissions.
@@ -468,7 +800,7 @@ pub struct RpcCollectionFlags {
 }
 
 /// Collection parameters, used in RPC calls (see [`Collection`] for the storage version).
-#[struct_versioning::versioned(version = 2, upper)]
+#[struct_versioning::versioned(version = 3, upper)]
 #[derive(Debug, Encode, Decode, Clone, PartialEq, TypeInfo, Serialize, Deserialize)]
 pub struct RpcCollection<AccountId> {
 	/// Collection owner account.
@@ -490,6 +822,11 @@ pub struct RpcCollection<AccountId> {
 	pub sponsorship: SponsorshipState<AccountId>,
 
 	/// Collection limits.
+	#[version(..3)]
+	pub limits: CollectionLimitsVersion1,
+
+	/// Collection limits.
+	#[version(3.., upper(limits.into()))]
 	pub limits: CollectionLimits,
 
 	/// Collection permissions.
@@ -531,7 +868,7 @@ impl<AccountId> From<CollectionVersion1<AccountId>> for RpcCollection<AccountId>
 			mode,
 			token_prefix: token_prefix.into_inner(),
 			sponsorship,
-			limits,
+			limits: limits.into(),
 			permissions: CollectionPermissions {
 				access: Some(access),
 				mint_mode: Some(mint_mode),
@@ -627,6 +964,7 @@ pub type CollectionPropertiesVec = BoundedVec<Property, ConstU32<MAX_PROPERTIES_
 ///
 /// Update with `pallet_common::Pallet::clamp_limits`.
 // IMPORTANT: When adding/removing fields from this struct - don't forget to also
+#[struct_versioning::versioned(version = 2, upper)]
 #[derive(
 	Encode,
 	Decode,
@@ -700,6 +1038,27 @@ pub struct CollectionLimits {
 	///
 	/// * Default - **true**.
 	pub transfers_enabled: Option<bool>,
+
+	/// Timeout for sponsoring a burn in passed blocks.
+	///
+	/// * Default - [`SPONSOR_BURN_TIMEOUT`].
+	/// * Limit - [`MAX_SPONSOR_TIMEOUT`].
+	#[version(2.., upper(None))]
+	pub sponsor_burn_timeout: Option<u32>,
+
+	/// Timeout for sponsoring setting of allowance for all tokens in passed blocks.
+	///
+	/// * Default - [`SPONSOR_ALLOWANCE_FOR_ALL_TIMEOUT`].
+	/// * Limit - [`MAX_SPONSOR_TIMEOUT`].
+	#[version(2.., upper(None))]
+	pub sponsor_allowance_for_all_timeout: Option<u32>,
+
+	/// Timeout for sponsoring a refungible token repartition in passed blocks.
+	///
+	/// * Default - [`SPONSOR_REPARTITION_TIMEOUT`].
+	/// * Limit - [`MAX_SPONSOR_TIMEOUT`].
+	#[version(2.., upper(None))]
+	pub sponsor_repartition_timeout: Option<u32>,
 }
 
 impl CollectionLimits {
@@ -718,6 +1077,9 @@ impl CollectionLimits {
 			owner_can_transfer: Some(false),
 			owner_can_destroy: Some(true),
 			transfers_enabled: Some(true),
+			sponsor_burn_timeout: Some(SPONSOR_BURN_TIMEOUT),
+			sponsor_allowance_for_all_timeout: Some(SPONSOR_ALLOWANCE_FOR_ALL_TIMEOUT),
+			sponsor_repartition_timeout: Some(SPONSOR_REPARTITION_TIMEOUT),
 		}
 	}
 
@@ -757,6 +1119,27 @@ impl CollectionLimits {
 			.min(MAX_SPONSOR_TIMEOUT)
 	}
 
+	/// Get effective value for [`sponsor_burn_timeout`](self.sponsor_burn_timeout).
+	pub fn sponsor_burn_timeout(&self) -> u32 {
+		self.sponsor_burn_timeout
+			.unwrap_or(SPONSOR_BURN_TIMEOUT)
+			.min(MAX_SPONSOR_TIMEOUT)
+	}
+
+	/// Get effective value for [`sponsor_allowance_for_all_timeout`](self.sponsor_allowance_for_all_timeout).
+	pub fn sponsor_allowance_for_all_timeout(&self) -> u32 {
+		self.sponsor_allowance_for_all_timeout
+			.unwrap_or(SPONSOR_ALLOWANCE_FOR_ALL_TIMEOUT)
+			.min(MAX_SPONSOR_TIMEOUT)
+	}
+
+	/// Get effective value for [`sponsor_repartition_timeout`](self.sponsor_repartition_timeout).
+	pub fn sponsor_repartition_timeout(&self) -> u32 {
+		self.sponsor_repartition_timeout
+			.unwrap_or(SPONSOR_REPARTITION_TIMEOUT)
+			.min(MAX_SPONSOR_TIMEOUT)
+	}
+
 	/// Get effective value for [`owner_can_transfer`](self.owner_can_transfer).
 	pub fn owner_can_transfer(&self) -> bool {
 		self.owner_can_transfer.unwrap_or(false)
@@ -915,6 +1298,30 @@ pub struct NestingPermissions {
 	pub permissive: bool,
 }
 
+/// Name of a token slot.
+pub type SlotName = BoundedVec<u8, ConstU32<MAX_SLOT_NAME_LENGTH>>;
+
+/// Slot, defined by a parent collection, into which nested tokens may be equipped.
+#[derive(
+	Encode, Decode, Clone, PartialEq, TypeInfo, MaxEncodedLen, Derivative, Serialize, Deserialize,
+)]
+#[derivative(Debug)]
+pub struct TokenSlot {
+	/// Slot name, unique within the collection.
+	#[serde(with = "bounded::vec_serde")]
+	#[derivative(Debug(format_with = "bounded::vec_debug"))]
+	pub name: SlotName,
+	/// Collections, tokens of which can be equipped into the slot.
+	#[serde(with = "bounded::set_serde")]
+	#[derivative(Debug(format_with = "bounded::set_debug"))]
+	pub allowed_collections: BoundedBTreeSet<CollectionId, ConstU32<MAX_SLOT_SOURCE_COLLECTIONS>>,
+	/// Maximum number of tokens equipped into the slot of a single parent token.
+	pub max_count: u32,
+}
+
+/// Slots defined by a collection.
+pub type TokenSlots = BoundedVec<TokenSlot, ConstU32<MAX_COLLECTION_SLOTS>>;
+
 /// Enum denominating how often can sponsoring occur if it is enabled.
 ///
 /// Used for [`collection limits`](CollectionLimits).
@@ -1107,6 +1514,26 @@ pub struct TokenChild {
 	pub collection: CollectionId,
 }
 
+/// Single token of a nesting tree.
+#[derive(Encode, Decode, Clone, PartialEq, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct TokenTreeNode<CrossAccountId> {
+	/// Token address.
+	pub token: TokenChild,
+
+	/// Token this one is nested into, `None` for the root of the tree.
+	pub parent: Option<TokenChild>,
+
+	/// Nesting level relative to the root of the tree, the root itself has depth 0.
+	pub depth: u32,
+
+	/// Direct token owner, which is the parent token address for nested tokens.
+	/// `None` if the token has multiple owners.
+	pub owner: Option<CrossAccountId>,
+
+	/// Token properties, limited to the requested keys.
+	pub properties: Vec<Property>,
+}
+
 /// Collection statistics.
 #[derive(
 	Encode, Decode, MaxEncodedLen, PartialEq, Clone, Debug, TypeInfo, Serialize, Deserialize,
@@ -19,17 +19,19 @@
 extern crate alloc;
 
 use parity_scale_codec::Decode;
+use sp_core::{H160, U256};
 use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
 use up_data_structs::{
-	CollectionId, CollectionLimits, CollectionStats, Property, PropertyKeyPermission,
-	RpcCollection, TokenChild, TokenData, TokenId,
+	CollectionAdminRoles, CollectionId, CollectionLimits, CollectionStats, PendingCollectionOwner,
+	Property, PropertyKeyPermission, RpcCollection, SponsorBudgetLeft, SponsoredFees,
+	SponsoringDryRun, TokenChild, TokenData, TokenId, TokenTreeNode,
 };
 
 type Result<T> = core::result::Result<T, DispatchError>;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(3)]
+	#[api_version(5)]
 	/// Trait for generate rpc.
 	pub trait UniqueApi<CrossAccountId, AccountId> where
 		AccountId: Decode,
@@ -53,6 +55,14 @@ sp_api::decl_runtime_apis! {
 		/// Get nested tokens for the specified item.
 		fn token_children(collection: CollectionId, token: TokenId) -> Result<Vec<TokenChild>>;
 
+		/// Get the tree of tokens nested into the specified item, including the item itself.
+		fn token_tree(
+			collection: CollectionId,
+			token: TokenId,
+			max_depth: Option<u32>,
+			keys: Option<Vec<Vec<u8>>>
+		) -> Result<Vec<TokenTreeNode<CrossAccountId>>>;
+
 		/// Get collection properties.
 		fn collection_properties(collection: CollectionId, properties: Option<Vec<Vec<u8>>>) -> Result<Vec<Property>>;
 
@@ -103,6 +113,9 @@ sp_api::decl_runtime_apis! {
 		/// Get list of collection admins.
 		fn adminlist(collection: CollectionId) -> Result<Vec<CrossAccountId>>;
 
+		/// Get list of collection admins together with the roles granted to them.
+		fn adminlist_with_roles(collection: CollectionId) -> Result<Vec<(CrossAccountId, CollectionAdminRoles)>>;
+
 		/// Get list of users that allowet to mint tikens in collection.
 		fn allowlist(collection: CollectionId) -> Result<Vec<CrossAccountId>>;
 
@@ -118,15 +131,46 @@ sp_api::decl_runtime_apis! {
 		#[changed_in(3)]
 		fn collection_by_id(collection: CollectionId) -> Result<Option<up_data_structs::RawEncoded>>;
 
+		#[changed_in(5)]
+		fn collection_by_id(collection: CollectionId) -> Result<Option<up_data_structs::RpcCollectionVersion2<AccountId>>>;
+
+		/// Get the proposed owner of the collection, awaiting acceptance.
+		fn collection_pending_owner(collection: CollectionId) -> Result<Option<PendingCollectionOwner<AccountId, u32>>>;
+
 		/// Get collection stats.
 		fn collection_stats() -> Result<CollectionStats>;
 
 		/// Get the number of blocks through which sponsorship will be available.
 		fn next_sponsored(collection: CollectionId, account: CrossAccountId, token: TokenId) -> Result<Option<u64>>;
 
+		/// Get the fees the collection sponsor is still ready to pay in the current budget period.
+		fn sponsor_budget_left(collection: CollectionId, account: CrossAccountId) -> Result<Option<SponsorBudgetLeft>>;
+
+		/// Check whether the encoded signed extrinsic would be sponsored, and by whom.
+		fn sponsoring_dry_run(uxt: Vec<u8>) -> Result<SponsoringDryRun<CrossAccountId>>;
+
+		/// Check whether the EVM call would be sponsored, and by whom.
+		fn evm_sponsoring_dry_run(
+			source: H160,
+			target: H160,
+			input: Vec<u8>,
+			gas_limit: u64,
+			max_fee_per_gas: U256,
+		) -> Result<SponsoringDryRun<CrossAccountId>>;
+
+		/// Get the fees paid by the sponsor within the era, or within the current one.
+		fn sponsor_fees_paid(sponsor: CrossAccountId, era: Option<u32>) -> Result<u128>;
+
+		/// Get the fees paid for the calls of the sponsored collection or contract within the era,
+		/// or within the current one, together with the `top` sponsored users.
+		fn sponsored_fees(target: H160, era: Option<u32>, top: u32) -> Result<SponsoredFees<CrossAccountId>>;
+
 		/// Get effective colletion limits.
 		fn effective_collection_limits(collection_id: CollectionId) -> Result<Option<CollectionLimits>>;
 
+		#[changed_in(5)]
+		fn effective_collection_limits(collection_id: CollectionId) -> Result<Option<up_data_structs::CollectionLimitsVersion1>>;
+
 		/// Get total pieces of token.
 		fn total_pieces(collection_id: CollectionId, token_id: TokenId) -> Result<Option<u128>>;
 
@@ -15,6 +15,7 @@ use crate::{
 		config::sponsoring::DefaultSponsoringRateLimit,
 		dispatch::CollectionDispatchT,
 		ethereum::{precompiles::UniquePrecompiles, sponsoring::EvmSponsorshipHandler},
+		sponsoring::SponsorBudgetCharger,
 		DealWithFees,
 	},
 	Aura, Balances, ChainId, Runtime, RuntimeEvent,
@@ -136,6 +137,15 @@ impl pallet_evm_contract_helpers::Config for Runtime {
 
 impl pallet_evm_coder_substrate::Config for Runtime {}
 
+parameter_types! {
+	pub const SponsoringStatsEraLength: u32 = 7 * DAYS;
+	pub const SponsoringStatsHistoryDepth: u32 = 52;
+}
+
 impl pallet_evm_transaction_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type EvmSponsorshipHandler = EvmSponsorshipHandler;
+	type OnSponsoredFee = SponsorBudgetCharger<Self>;
+	type SponsoringStatsEraLength = SponsoringStatsEraLength;
+	type SponsoringStatsHistoryDepth = SponsoringStatsHistoryDepth;
 }
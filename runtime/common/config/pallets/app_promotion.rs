@@ -14,16 +14,33 @@
 // You should have received a copy of the GNU General Public License
 // along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
 
-use frame_support::{parameter_types, PalletId};
+use core::marker::PhantomData;
+
+use frame_support::{
+	dispatch::GetDispatchInfo, parameter_types, storage::with_transaction, PalletId,
+};
+use pallet_evm::account::CrossAccountId as _;
+use pallet_evm_transaction_payment::{CallContext, SponsoredFeesPaid};
+use parity_scale_codec::Encode;
+use sp_core::H160;
+use sp_runtime::{traits::Saturating, DispatchError, TransactionOutcome};
 use up_common::{
 	constants::{DAYS, RELAY_DAYS, UNIQUE},
-	types::Balance,
+	types::{AccountId, Balance},
 };
+use up_sponsorship::SponsorshipHandler;
 
 use super::AppPromotionDailyRate;
 use crate::{
-	runtime_common::config::pallets::{RelayChainBlockNumberProvider, TreasuryAccountId},
-	Balances, BlockNumber, EvmContractHelpers, Maintenance, Runtime, RuntimeEvent, Unique,
+	runtime_common::{
+		config::{
+			ethereum::CrossAccountId,
+			pallets::{RelayChainBlockNumberProvider, TreasuryAccountId},
+		},
+		sponsoring::sponsored_call_fee,
+	},
+	AppPromotion, Balances, BlockNumber, EvmContractHelpers, EvmTransactionPayment, Maintenance,
+	Runtime, RuntimeEvent, Unique,
 };
 
 parameter_types! {
@@ -31,6 +48,7 @@ parameter_types! {
 	pub const RecalculationInterval: BlockNumber = RELAY_DAYS;
 	pub const PendingInterval: BlockNumber = 7 * DAYS;
 	pub const Nominal: Balance = UNIQUE;
+	pub const PayoutTip: Balance = UNIQUE / 10;
 	pub const HoldAndFreezeIdentifier: [u8; 16] = *b"appstakeappstake";
 	pub MaintenanceMode: bool =  Maintenance::is_enabled();
 }
@@ -50,4 +68,74 @@ impl pallet_app_promotion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type FreezeIdentifier = HoldAndFreezeIdentifier;
 	type IsMaintenanceModeEnabled = MaintenanceMode;
+	type PayoutTip = PayoutTip;
+}
+
+/// Wraps the sponsorship handler `H`, to limit the fees paid by the app promotion pallet
+/// for the calls of each collection/contract within a sponsoring statistics era,
+/// see [`pallet_app_promotion::Pallet::sponsoring_allowance`].
+pub struct AppPromotionSponsoringLimit<H>(PhantomData<H>);
+
+impl<H, C> SponsorshipHandler<AccountId, C> for AppPromotionSponsoringLimit<H>
+where
+	H: SponsorshipHandler<AccountId, C>,
+	C: GetDispatchInfo + Encode,
+{
+	fn get_sponsor(who: &AccountId, call: &C) -> Option<AccountId> {
+		limit_sponsoring(
+			|| H::get_sponsor(who, call),
+			|sponsor| sponsor,
+			// The sponsored collection is noted by the unique sponsorship handler
+			EvmTransactionPayment::pending_sponsoring_target,
+			|| sponsored_call_fee::<Runtime, C>(call),
+		)
+	}
+}
+
+impl<H> SponsorshipHandler<CrossAccountId, CallContext> for AppPromotionSponsoringLimit<H>
+where
+	H: SponsorshipHandler<CrossAccountId, CallContext>,
+{
+	fn get_sponsor(who: &CrossAccountId, call_context: &CallContext) -> Option<CrossAccountId> {
+		limit_sponsoring(
+			|| H::get_sponsor(who, call_context),
+			|sponsor| sponsor.as_sub(),
+			|| Some(call_context.contract_address),
+			// Gas which is left unused is not returned to the allowance
+			|| call_context.max_fee.try_into().unwrap_or(u128::MAX),
+		)
+	}
+}
+
+/// Refuse the sponsoring by the app promotion pallet, and revert its effects,
+/// if the fee would exceed the allowance of the sponsored collection/contract.
+fn limit_sponsoring<S>(
+	get_sponsor: impl FnOnce() -> Option<S>,
+	sponsor_account: impl FnOnce(&S) -> &AccountId,
+	target: impl FnOnce() -> Option<H160>,
+	fee: impl FnOnce() -> u128,
+) -> Option<S> {
+	with_transaction(|| {
+		let sponsor = get_sponsor();
+		let exceeded = match (&sponsor, target()) {
+			(Some(sponsor), Some(target))
+				if *sponsor_account(sponsor) == AppPromotion::account_id() =>
+			{
+				let spent = <SponsoredFeesPaid<Runtime>>::get(
+					EvmTransactionPayment::current_stats_era(),
+					target,
+				);
+				spent.saturating_add(fee()) > AppPromotion::sponsoring_allowance(target)
+			}
+			_ => false,
+		};
+
+		if exceeded {
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(None))
+		} else {
+			TransactionOutcome::Commit(Ok(sponsor))
+		}
+	})
+	.ok()
+	.flatten()
 }
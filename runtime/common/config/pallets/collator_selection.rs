@@ -18,10 +18,13 @@ use frame_support::{parameter_types, PalletId};
 #[cfg(not(feature = "governance"))]
 use frame_system::EnsureRoot;
 use pallet_configuration::{
-	CollatorSelectionDesiredCollatorsOverride, CollatorSelectionKickThresholdOverride,
-	CollatorSelectionLicenseBondOverride,
+	CollatorSelectionDesiredCollatorsOverride, CollatorSelectionInvulnerablesRewardShareOverride,
+	CollatorSelectionKickThresholdOverride, CollatorSelectionLicenseBondOverride,
+	CollatorSelectionMaxStrikesOverride, CollatorSelectionStrikeDecaySessionsOverride,
+	CollatorSelectionStrikeSlashRatioOverride,
 };
 use sp_runtime::Perbill;
+use up_common::constants::{DAYS, UNIQUE};
 
 #[cfg(feature = "governance")]
 use crate::config::governance;
@@ -61,11 +64,21 @@ parameter_types! {
 	pub LicenseBond: Balance =  CollatorSelectionLicenseBondOverride::<Runtime>::get();
 	pub DesiredCollators: u32 = CollatorSelectionDesiredCollatorsOverride::<Runtime>::get();
 	pub KickThreshold: BlockNumber = CollatorSelectionKickThresholdOverride::<Runtime>::get();
+	pub InvulnerablesRewardShare: Perbill = CollatorSelectionInvulnerablesRewardShareOverride::<Runtime>::get();
+	pub StrikeSlashRatio: Perbill = CollatorSelectionStrikeSlashRatioOverride::<Runtime>::get();
+	pub MaxStrikes: u32 = CollatorSelectionMaxStrikesOverride::<Runtime>::get();
+	pub StrikeDecaySessions: u32 = CollatorSelectionStrikeDecaySessionsOverride::<Runtime>::get();
 }
 
 parameter_types! {
 	pub const PotId: PalletId = PalletId(*b"PotStake");
 	pub const SlashRatio: Perbill = Perbill::from_percent(100);
+	pub const MinDelegation: Balance = 100 * UNIQUE;
+	pub const MaxDelegatorsPerCandidate: u32 = 64;
+	pub const MaxUnbondingRequests: u32 = 16;
+	pub const UnbondingDelay: BlockNumber = 7 * DAYS;
+	pub const MaxWaitingCandidates: u32 = 64;
+	pub const MaxDelegatorPayoutsPerBlock: u32 = 64;
 }
 
 impl pallet_collator_selection::Config for Runtime {
@@ -95,4 +108,14 @@ impl pallet_collator_selection::Config for Runtime {
 	type DesiredCollators = DesiredCollators;
 	type LicenseBond = LicenseBond;
 	type KickThreshold = KickThreshold;
+	type InvulnerablesRewardShare = InvulnerablesRewardShare;
+	type MinDelegation = MinDelegation;
+	type MaxDelegatorsPerCandidate = MaxDelegatorsPerCandidate;
+	type MaxUnbondingRequests = MaxUnbondingRequests;
+	type UnbondingDelay = UnbondingDelay;
+	type StrikeSlashRatio = StrikeSlashRatio;
+	type MaxStrikes = MaxStrikes;
+	type StrikeDecaySessions = StrikeDecaySessions;
+	type MaxWaitingCandidates = MaxWaitingCandidates;
+	type MaxDelegatorPayoutsPerBlock = MaxDelegatorPayoutsPerBlock;
 }
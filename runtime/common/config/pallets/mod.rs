@@ -19,6 +19,8 @@ use alloc::string::{String, ToString};
 use frame_support::{
 	parameter_types,
 	traits::{ConstU32, ConstU64, Currency},
+	weights::Weight,
+	PalletId,
 };
 use sp_arithmetic::Perbill;
 use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider};
@@ -137,6 +139,7 @@ impl BlockNumberProvider for OnInitializeBlockNumberProvider {
 
 /// Used for the pallet inflation
 impl pallet_inflation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type TreasuryAccountId = TreasuryAccountId;
 	type InflationBlockInterval = InflationBlockInterval;
@@ -156,6 +159,11 @@ parameter_types! {
 	pub const LicenseBond: Balance = GENESIS_LICENSE_BOND;
 
 	pub const DayRelayBlocks: BlockNumber = RELAY_DAYS;
+	pub AppPromotionIdlePayoutWeight: Weight = Perbill::from_percent(10) * MAXIMUM_BLOCK_WEIGHT;
+	pub const CollatorInvulnerablesRewardShare: Perbill = Perbill::one();
+	pub const CollatorStrikeSlashRatio: Perbill = Perbill::from_percent(10);
+	pub const CollatorMaxStrikes: u32 = 3;
+	pub const CollatorStrikeDecaySessions: u32 = 4;
 }
 
 #[cfg(not(feature = "session-test-timings"))]
@@ -176,9 +184,14 @@ impl pallet_configuration::Config for Runtime {
 	type DefaultCollatorSelectionMaxCollators = MaxCollators;
 	type DefaultCollatorSelectionKickThreshold = SessionPeriod;
 	type DefaultCollatorSelectionLicenseBond = LicenseBond;
+	type DefaultCollatorSelectionInvulnerablesRewardShare = CollatorInvulnerablesRewardShare;
+	type DefaultCollatorSelectionStrikeSlashRatio = CollatorStrikeSlashRatio;
+	type DefaultCollatorSelectionMaxStrikes = CollatorMaxStrikes;
+	type DefaultCollatorSelectionStrikeDecaySessions = CollatorStrikeDecaySessions;
 	type MaxXcmAllowedLocations = ConstU32<16>;
 	type AppPromotionDailyRate = AppPromotionDailyRate;
 	type DayRelayBlocks = DayRelayBlocks;
+	type DefaultAppPromotionIdlePayoutWeight = AppPromotionIdlePayoutWeight;
 	type WeightInfo = pallet_configuration::weights::SubstrateWeight<Self>;
 }
 
@@ -201,3 +214,16 @@ impl pallet_maintenance::Config for Runtime {
 	type Preimages = ();
 	type WeightInfo = pallet_maintenance::weights::SubstrateWeight<Self>;
 }
+
+parameter_types! {
+	pub const SponsorPoolPalletId: PalletId = PalletId(*b"sponpool");
+	pub const SponsorPoolMinimumFirstDeposit: Balance = UNIQUE;
+}
+
+impl pallet_sponsor_pool::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PalletId = SponsorPoolPalletId;
+	type MinimumFirstDeposit = SponsorPoolMinimumFirstDeposit;
+	type WeightInfo = pallet_sponsor_pool::weights::SubstrateWeight<Self>;
+}
@@ -18,6 +18,8 @@ use frame_support::parameter_types;
 use sp_core::U256;
 use up_common::{constants::*, types::BlockNumber};
 
+#[cfg(feature = "app-promotion")]
+use crate::runtime_common::config::pallets::app_promotion::AppPromotionSponsoringLimit;
 use crate::{runtime_common::sponsoring::UniqueSponsorshipHandler, Runtime};
 
 parameter_types! {
@@ -25,6 +27,12 @@ parameter_types! {
 	pub const DefaultSponsoringFeeLimit: U256 = U256::MAX;
 }
 
+#[cfg(feature = "app-promotion")]
+type SponsorshipHandler = (
+	AppPromotionSponsoringLimit<UniqueSponsorshipHandler<Runtime>>,
+	pallet_evm_transaction_payment::BridgeSponsorshipHandler<Runtime>,
+);
+#[cfg(not(feature = "app-promotion"))]
 type SponsorshipHandler = (
 	UniqueSponsorshipHandler<Runtime>,
 	pallet_evm_transaction_payment::BridgeSponsorshipHandler<Runtime>,
@@ -41,9 +41,9 @@ use sp_std::vec;
 use up_common::{constants::*, types::*};
 
 use crate::{
-	runtime_common::DealWithFees, Balances, Block, OriginCaller, PalletInfo, Runtime, RuntimeCall,
-	RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, SS58Prefix, System,
-	Treasury, Version,
+	runtime_common::{sponsoring::SponsoredFeeRecorder, DealWithFees},
+	Balances, Block, OriginCaller, PalletInfo, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, SS58Prefix, System, Treasury, Version,
 };
 
 parameter_types! {
@@ -190,7 +190,8 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, DealWithFees>;
+	type OnChargeTransaction =
+		SponsoredFeeRecorder<pallet_transaction_payment::CurrencyAdapter<Balances, DealWithFees>>;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type WeightToFee = pallet_configuration::WeightToFee<Self, Balance>;
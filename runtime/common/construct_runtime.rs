@@ -134,6 +134,8 @@ macro_rules! construct_runtime {
 
 				Utility: pallet_utility = 156,
 
+				SponsorPool: pallet_sponsor_pool = 157,
+
 				#[cfg(feature = "pallet-test-utils")]
 				TestUtils: pallet_test_utils = 255,
 			}
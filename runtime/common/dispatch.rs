@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
 
-use frame_support::{dispatch::DispatchResult, ensure, fail};
+use frame_support::{dispatch::DispatchResult, ensure, fail, weights::WeightMeter};
 use pallet_balances_adapter::NativeFungibleHandle;
 pub use pallet_common::dispatch::CollectionDispatch;
 use pallet_common::{
@@ -110,6 +110,25 @@ where
 		Ok(())
 	}
 
+	fn clear_destroyed(
+		collection_id: CollectionId,
+		mode: &CollectionMode,
+		owner: &T::CrossAccountId,
+		meter: &mut WeightMeter,
+	) -> bool {
+		match mode {
+			CollectionMode::ReFungible => {
+				PalletRefungible::<T>::clear_destroyed_collection(collection_id, meter)
+			}
+			CollectionMode::Fungible(_) => {
+				PalletFungible::<T>::clear_destroyed_collection(collection_id, meter)
+			}
+			CollectionMode::NFT => {
+				PalletNonfungible::<T>::clear_destroyed_collection(collection_id, owner, meter)
+			}
+		}
+	}
+
 	fn dispatch(collection_id: CollectionId) -> Result<Self, DispatchError> {
 		if collection_id == pallet_common::NATIVE_FUNGIBLE_COLLECTION_ID {
 			return Ok(Self::NativeFungible(
@@ -21,15 +21,16 @@ use core::{convert::TryInto, marker::PhantomData};
 use evm_coder::Call;
 use pallet_common::{eth::map_eth_to_id, CollectionHandle};
 use pallet_evm::account::CrossAccountId;
+use pallet_evm_contract_helpers::SponsoringModeT;
 use pallet_evm_transaction_payment::CallContext;
 use pallet_fungible::{
-	erc::{ERC20Call, UniqueFungibleCall},
+	erc::{ERC20Call, ERC20UniqueExtensionsCall, UniqueFungibleCall},
 	Config as FungibleConfig,
 };
 use pallet_nonfungible::{
 	erc::{
-		ERC721Call, ERC721UniqueExtensionsCall, ERC721UniqueMintableCall, TokenPropertiesCall,
-		UniqueNFTCall,
+		ERC721BurnableCall, ERC721Call, ERC721UniqueExtensionsCall, ERC721UniqueMintableCall,
+		TokenPropertiesCall, UniqueNFTCall,
 	},
 	Config as NonfungibleConfig, NonfungibleHandle, Pallet as NonfungiblePallet,
 };
@@ -39,9 +40,11 @@ use pallet_refungible::{
 	Config as RefungibleConfig, RefungibleHandle,
 };
 use pallet_unique::Config as UniqueConfig;
+use sp_core::{H160, U256};
 use sp_std::prelude::*;
 use up_data_structs::{
-	mapping::TokenAddressMapping, CollectionMode, CreateItemData, CreateNftData, TokenId,
+	mapping::TokenAddressMapping, CollectionId, CollectionMode, CreateItemData, CreateNftData,
+	SponsoringDryRun, SponsoringReason, TokenId,
 };
 use up_sponsorship::SponsorshipHandler;
 
@@ -49,6 +52,13 @@ use crate::{runtime_common::sponsoring::*, Runtime};
 
 mod refungible;
 
+#[cfg(feature = "app-promotion")]
+pub type EvmSponsorshipHandler =
+	crate::runtime_common::config::pallets::app_promotion::AppPromotionSponsoringLimit<(
+		UniqueEthSponsorshipHandler<Runtime>,
+		pallet_evm_contract_helpers::HelpersContractSponsoring<Runtime>,
+	)>;
+#[cfg(not(feature = "app-promotion"))]
 pub type EvmSponsorshipHandler = (
 	UniqueEthSponsorshipHandler<Runtime>,
 	pallet_evm_contract_helpers::HelpersContractSponsoring<Runtime>,
@@ -64,6 +74,93 @@ where
 		who: &T::CrossAccountId,
 		call_context: &CallContext,
 	) -> Option<T::CrossAccountId> {
+		let collection_id = target_collection::<T>(&call_context.contract_address)?;
+		// The budget is checked against the maximum fee, but charged with the actual one
+		let fee = call_context.max_fee.try_into().unwrap_or(u128::MAX);
+
+		withdraw_sponsor_budget::<T, _>(collection_id, who, fee, || {
+			Self::withdraw(who, call_context)
+		})
+	}
+}
+
+/// Size of the keys and values of the initial token `properties` passed to EVM.
+fn eth_properties_size(properties: &[pallet_common::eth::Property]) -> usize {
+	properties
+		.iter()
+		.map(|p| p.key().len() + p.value().len())
+		.sum()
+}
+
+/// Size of the initial token property, which stores the `token_uri` passed to EVM.
+fn token_uri_size(token_uri: &str) -> usize {
+	pallet_common::erc::static_property::key::url().len() + token_uri.len()
+}
+
+/// Check whether the call of `source` to `target` would be sponsored, without applying
+/// any of its effects.
+pub fn evm_sponsoring_dry_run<T>(
+	source: H160,
+	target: H160,
+	input: Vec<u8>,
+	gas_limit: u64,
+	max_fee_per_gas: U256,
+) -> SponsoringDryRun<T::CrossAccountId>
+where
+	T: Config + pallet_evm_transaction_payment::Config + pallet_evm_contract_helpers::Config,
+{
+	let max_fee = max_fee_per_gas.saturating_mul(gas_limit.into());
+	let fee = max_fee.try_into().unwrap_or(u128::MAX);
+	let sponsor = pallet_evm_transaction_payment::dry_run_sponsor::<T>(
+		source,
+		target,
+		input,
+		gas_limit,
+		max_fee_per_gas,
+	);
+	let reason = match &sponsor {
+		Some(sponsor) => {
+			if <pallet_evm::Pallet<T>>::account_basic_by_id(sponsor)
+				.0
+				.balance < max_fee
+			{
+				SponsoringReason::SponsorBalanceTooLow
+			} else {
+				SponsoringReason::Sponsored
+			}
+		}
+		None if !pallet_evm_transaction_payment::accept_gas_fee::<T>(max_fee_per_gas) => {
+			SponsoringReason::GasPriceNotSponsored
+		}
+		None => match target_collection::<T>(&target) {
+			Some(collection_id) => collection_refusal_reason::<T>(
+				collection_id,
+				&T::CrossAccountId::from_eth(source),
+				fee,
+			),
+			None if <pallet_evm_contract_helpers::Pallet<T>>::sponsoring_mode(target)
+				== SponsoringModeT::Disabled
+				|| <pallet_evm_contract_helpers::Pallet<T>>::get_sponsor(target).is_none() =>
+			{
+				SponsoringReason::NoSponsor
+			}
+			None => SponsoringReason::NotEligible,
+		},
+	};
+
+	SponsoringDryRun {
+		sponsor,
+		fee,
+		reason,
+	}
+}
+
+impl<T: UniqueConfig + FungibleConfig + NonfungibleConfig + RefungibleConfig>
+	UniqueEthSponsorshipHandler<T>
+where
+	T::AccountId: From<[u8; 32]>,
+{
+	fn withdraw(who: &T::CrossAccountId, call_context: &CallContext) -> Option<T::CrossAccountId> {
 		if let Some(collection_id) = map_eth_to_id(&call_context.contract_address) {
 			let collection = <CollectionHandle<T>>::new(collection_id)?;
 			let sponsor = collection.sponsorship.sponsor()?.clone();
@@ -116,6 +213,16 @@ where
 								withdraw_transfer::<T>(&collection, who, &token_id)
 									.map(|()| sponsor)
 							}
+							ERC721UniqueExtensionsCall::MintCross { properties, .. }
+								if public_mint_sponsored::<T>(&collection, who) =>
+							{
+								withdraw_public_mint::<T>(
+									&collection,
+									who,
+									[eth_properties_size(&properties)],
+								)
+								.map(|()| sponsor)
+							}
 							ERC721UniqueExtensionsCall::MintCross { properties, .. } => {
 								withdraw_create_item::<T>(
 									&collection,
@@ -125,27 +232,33 @@ where
 
 								let token_id =
 									<NonfungiblePallet<T>>::next_token_id(&collection).ok()?;
-								let data_size: usize = properties
-									.into_iter()
-									.map(|p| p.key().len() + p.value().len())
-									.sum();
-
-								withdraw_set_token_property::<T>(&collection, &token_id, data_size)
-									.map(|()| sponsor)
+								withdraw_set_token_property::<T>(
+									&collection,
+									&token_id,
+									eth_properties_size(&properties),
+								)
+								.map(|()| sponsor)
+							}
+							ERC721UniqueExtensionsCall::MintBulkCross { data } => {
+								withdraw_public_mint::<T>(
+									&collection,
+									who,
+									data.iter()
+										.map(|token| eth_properties_size(&token.properties)),
+								)
+								.map(|()| sponsor)
 							}
 							_ => None,
 						},
 						UniqueNFTCall::ERC721UniqueMintable(
 							ERC721UniqueMintableCall::Mint { .. }
-							| ERC721UniqueMintableCall::MintCheckId { .. }
-							| ERC721UniqueMintableCall::MintWithTokenUri { .. }
-							| ERC721UniqueMintableCall::MintWithTokenUriCheckId { .. },
-						) => withdraw_create_item::<T>(
-							&collection,
-							who,
-							&CreateItemData::NFT(CreateNftData::default()),
-						)
-						.map(|()| sponsor),
+							| ERC721UniqueMintableCall::MintCheckId { .. },
+						) => withdraw_mint::<T>(&collection, who, 0).map(|()| sponsor),
+						UniqueNFTCall::ERC721UniqueMintable(
+							ERC721UniqueMintableCall::MintWithTokenUri { token_uri, .. }
+							| ERC721UniqueMintableCall::MintWithTokenUriCheckId { token_uri, .. },
+						) => withdraw_mint::<T>(&collection, who, token_uri_size(&token_uri))
+							.map(|()| sponsor),
 						UniqueNFTCall::ERC721(ERC721Call::TransferFrom {
 							token_id, from, ..
 						}) => {
@@ -158,6 +271,28 @@ where
 							withdraw_approve::<T>(&collection, who.as_sub(), &token_id)
 								.map(|()| sponsor)
 						}
+						UniqueNFTCall::ERC721(ERC721Call::SetApprovalForAll { .. }) => {
+							withdraw_set_allowance_for_all::<T>(&collection, who.as_sub())
+								.map(|()| sponsor)
+						}
+						UniqueNFTCall::ERC721Burnable(ERC721BurnableCall::Burn { token_id }) => {
+							let token_id: TokenId = token_id.try_into().ok()?;
+							withdraw_burn::<T>(&collection, who, &token_id).map(|()| sponsor)
+						}
+						UniqueNFTCall::ERC721UniqueExtensions(
+							ERC721UniqueExtensionsCall::BurnFrom { from, token_id },
+						) => {
+							let token_id: TokenId = token_id.try_into().ok()?;
+							let from = T::CrossAccountId::from_eth(from);
+							withdraw_burn::<T>(&collection, &from, &token_id).map(|()| sponsor)
+						}
+						UniqueNFTCall::ERC721UniqueExtensions(
+							ERC721UniqueExtensionsCall::BurnFromCross { from, token_id },
+						) => {
+							let token_id: TokenId = token_id.try_into().ok()?;
+							let from = from.into_sub_cross_account::<T>().ok()?;
+							withdraw_burn::<T>(&collection, &from, &token_id).map(|()| sponsor)
+						}
 						_ => None,
 					}
 				}
@@ -182,6 +317,20 @@ where
 							withdraw_approve::<T>(&collection, who.as_sub(), &TokenId::default())
 								.map(|()| sponsor)
 						}
+						UniqueFungibleCall::ERC20UniqueExtensions(
+							ERC20UniqueExtensionsCall::BurnFrom { from, .. },
+						) => {
+							let from = T::CrossAccountId::from_eth(from);
+							withdraw_burn::<T>(&collection, &from, &TokenId::default())
+								.map(|()| sponsor)
+						}
+						UniqueFungibleCall::ERC20UniqueExtensions(
+							ERC20UniqueExtensionsCall::BurnFromCross { from, .. },
+						) => {
+							let from = from.into_sub_cross_account::<T>().ok()?;
+							withdraw_burn::<T>(&collection, &from, &TokenId::default())
+								.map(|()| sponsor)
+						}
 						_ => None,
 					}
 				}
@@ -33,9 +33,9 @@ use pallet_refungible::{
 	Config as RefungibleConfig,
 };
 use pallet_unique::Config as UniqueConfig;
-use up_data_structs::{CreateItemData, CreateNftData, TokenId};
+use up_data_structs::TokenId;
 
-use super::common;
+use super::{common, eth_properties_size, token_uri_size};
 use crate::runtime_common::sponsoring::*;
 
 pub fn call_sponsor<T>(
@@ -45,6 +45,7 @@ pub fn call_sponsor<T>(
 ) -> Option<()>
 where
 	T: UniqueConfig + FungibleConfig + NonfungibleConfig + RefungibleConfig,
+	T::AccountId: From<[u8; 32]>,
 {
 	use UniqueRefungibleCall::*;
 
@@ -110,6 +111,7 @@ pub fn token_call_sponsor<T>(
 ) -> Option<()>
 where
 	T: UniqueConfig + FungibleConfig + NonfungibleConfig + RefungibleConfig,
+	T::AccountId: From<[u8; 32]>,
 {
 	use UniqueRefungibleTokenCall::*;
 
@@ -129,7 +131,7 @@ mod erc721 {
 	pub fn call_sponsor<T>(
 		call: ERC721Call<T>,
 		collection: CollectionHandle<T>,
-		_who: &T::CrossAccountId,
+		who: &T::CrossAccountId,
 	) -> Option<()>
 	where
 		T: UniqueConfig + FungibleConfig + NonfungibleConfig + RefungibleConfig,
@@ -145,9 +147,11 @@ mod erc721 {
 			| IsApprovedForAll { .. } => None,
 
 			// Not sponsored
-			SafeTransferFromWithData { .. }
-			| SafeTransferFrom { .. }
-			| SetApprovalForAll { .. } => None,
+			SafeTransferFromWithData { .. } | SafeTransferFrom { .. } => None,
+
+			SetApprovalForAll { .. } => {
+				withdraw_set_allowance_for_all::<T>(&collection, who.as_sub())
+			}
 
 			TransferFrom { token_id, from, .. } => {
 				let token_id = TokenId::try_from(token_id).ok()?;
@@ -180,8 +184,8 @@ mod erc721 {
 
 	pub fn burnable_call_sponsor<T>(
 		call: ERC721BurnableCall<T>,
-		_collection: CollectionHandle<T>,
-		_who: &T::CrossAccountId,
+		collection: CollectionHandle<T>,
+		who: &T::CrossAccountId,
 	) -> Option<()>
 	where
 		T: UniqueConfig + FungibleConfig + NonfungibleConfig + RefungibleConfig,
@@ -192,8 +196,10 @@ mod erc721 {
 			// Readonly
 			ERC165Call(_, _) => None,
 
-			// Not sponsored
-			Burn { .. } => None,
+			Burn { token_id } => {
+				let token_id = TokenId::try_from(token_id).ok()?;
+				withdraw_burn::<T>(&collection, who, &token_id)
+			}
 		}
 	}
 
@@ -220,6 +226,7 @@ mod erc721 {
 	) -> Option<()>
 	where
 		T: UniqueConfig + FungibleConfig + NonfungibleConfig + RefungibleConfig,
+		T::AccountId: From<[u8; 32]>,
 	{
 		use ERC721UniqueExtensionsCall::*;
 
@@ -238,18 +245,31 @@ mod erc721 {
 			| CollectionHelperAddress => None,
 
 			// Not sponsored
-			BurnFrom { .. }
-			| BurnFromCross { .. }
-			| MintBulk { .. }
-			| MintBulkCross { .. }
-			| MintBulkWithTokenUri { .. } => None,
+			MintBulk { .. } | MintBulkWithTokenUri { .. } => None,
 
-			MintCross { .. } => withdraw_create_item::<T>(
+			MintBulkCross { tokens_data } => withdraw_public_mint::<T>(
 				&collection,
 				who,
-				&CreateItemData::NFT(CreateNftData::default()),
+				tokens_data
+					.iter()
+					.map(|token| eth_properties_size(&token.properties)),
 			),
 
+			BurnFrom { from, token_id } => {
+				let token_id = TokenId::try_from(token_id).ok()?;
+				let from = T::CrossAccountId::from_eth(from);
+				withdraw_burn::<T>(&collection, &from, &token_id)
+			}
+			BurnFromCross { from, token_id } => {
+				let token_id = TokenId::try_from(token_id).ok()?;
+				let from = from.into_sub_cross_account::<T>().ok()?;
+				withdraw_burn::<T>(&collection, &from, &token_id)
+			}
+
+			MintCross { properties, .. } => {
+				withdraw_mint::<T>(&collection, who, eth_properties_size(&properties))
+			}
+
 			TransferCross { token_id, .. }
 			| TransferFromCross { token_id, .. }
 			| Transfer { token_id, .. } => {
@@ -274,14 +294,10 @@ mod erc721 {
 			ERC165Call(_, _) => None,
 
 			// Sponsored
-			Mint { .. }
-			| MintCheckId { .. }
-			| MintWithTokenUri { .. }
-			| MintWithTokenUriCheckId { .. } => withdraw_create_item::<T>(
-				&collection,
-				who,
-				&CreateItemData::NFT(CreateNftData::default()),
-			),
+			Mint { .. } | MintCheckId { .. } => withdraw_mint::<T>(&collection, who, 0),
+			MintWithTokenUri { token_uri, .. } | MintWithTokenUriCheckId { token_uri, .. } => {
+				withdraw_mint::<T>(&collection, who, token_uri_size(&token_uri))
+			}
 		}
 	}
 }
@@ -336,19 +352,28 @@ mod erc20 {
 	) -> Option<()>
 	where
 		T: UniqueConfig + FungibleConfig + NonfungibleConfig + RefungibleConfig,
+		T::AccountId: From<[u8; 32]>,
 	{
 		use ERC20UniqueExtensionsCall::*;
 
 		match call {
 			// Readonly
-			ERC165Call(_, _) => None,
+			ERC165Call(_, _) | AllowanceCross { .. } | BalanceOfCross { .. } => None,
 
-			// Not sponsored
-			AllowanceCross { .. }
-			| BalanceOfCross { .. }
-			| BurnFrom { .. }
-			| BurnFromCross { .. }
-			| Repartition { .. } => None,
+			BurnFrom { from, .. } => {
+				let RefungibleTokenHandle(handle, token_id) = token;
+				let from = T::CrossAccountId::from_eth(from);
+				withdraw_burn::<T>(&handle, &from, &token_id)
+			}
+			BurnFromCross { from, .. } => {
+				let RefungibleTokenHandle(handle, token_id) = token;
+				let from = from.into_sub_cross_account::<T>().ok()?;
+				withdraw_burn::<T>(&handle, &from, &token_id)
+			}
+			Repartition { .. } => {
+				let RefungibleTokenHandle(handle, token_id) = token;
+				withdraw_repartition::<T>(&handle, who, &token_id)
+			}
 
 			TransferCross { .. } | TransferFromCross { .. } => {
 				let RefungibleTokenHandle(handle, token_id) = token;
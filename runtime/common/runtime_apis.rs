@@ -37,7 +37,7 @@ macro_rules! impl_common_runtime_apis {
 		use sp_api::impl_runtime_apis;
 		use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H256, U256, H160};
 		use sp_runtime::{
-			Permill,
+			Perbill, Permill,
 			traits::{Block as BlockT},
 			transaction_validity::{TransactionSource, TransactionValidity},
 			ApplyExtrinsicResult, DispatchError,
@@ -91,6 +91,19 @@ macro_rules! impl_common_runtime_apis {
 				fn token_children(collection: CollectionId, token: TokenId) -> Result<Vec<TokenChild>, DispatchError> {
 					Ok(<pallet_nonfungible::Pallet<Runtime>>::token_children_ids(collection, token))
 				}
+				fn token_tree(
+					collection: CollectionId,
+					token: TokenId,
+					max_depth: Option<u32>,
+					keys: Option<Vec<Vec<u8>>>
+				) -> Result<Vec<TokenTreeNode<CrossAccountId>>, DispatchError> {
+					let keys = keys.map(
+						|keys| Common::bytes_keys_to_property_keys(keys)
+					).transpose()?;
+					let budget = budget::Value::new(pallet_structure::NESTING_BUDGET);
+
+					<pallet_structure::Pallet<Runtime>>::token_tree(collection, token, max_depth, keys, &budget)
+				}
 				fn collection_properties(
 					collection: CollectionId,
 					keys: Option<Vec<Vec<u8>>>
@@ -160,6 +173,9 @@ macro_rules! impl_common_runtime_apis {
 				fn adminlist(collection: CollectionId) -> Result<Vec<CrossAccountId>, DispatchError> {
 					Ok(<pallet_common::Pallet<Runtime>>::adminlist(collection))
 				}
+				fn adminlist_with_roles(collection: CollectionId) -> Result<Vec<(CrossAccountId, CollectionAdminRoles)>, DispatchError> {
+					Ok(<pallet_common::Pallet<Runtime>>::adminlist_with_roles(collection))
+				}
 				fn allowlist(collection: CollectionId) -> Result<Vec<CrossAccountId>, DispatchError> {
 					Ok(<pallet_common::Pallet<Runtime>>::allowlist(collection))
 				}
@@ -172,6 +188,9 @@ macro_rules! impl_common_runtime_apis {
 				fn collection_by_id(collection: CollectionId) -> Result<Option<RpcCollection<AccountId>>, DispatchError> {
 					Ok(<pallet_common::Pallet<Runtime>>::rpc_collection(collection))
 				}
+				fn collection_pending_owner(collection: CollectionId) -> Result<Option<PendingCollectionOwner<AccountId, u32>>, DispatchError> {
+					Ok(<pallet_common::Pallet<Runtime>>::pending_owner(collection))
+				}
 				fn collection_stats() -> Result<CollectionStats, DispatchError> {
 					Ok(<pallet_common::Pallet<Runtime>>::collection_stats())
 				}
@@ -183,6 +202,56 @@ macro_rules! impl_common_runtime_apis {
 					))
 				}
 
+				fn sponsor_budget_left(collection: CollectionId, account: CrossAccountId) -> Result<Option<SponsorBudgetLeft>, DispatchError> {
+					Ok(<pallet_common::Pallet<Runtime>>::sponsor_budget_left(collection, &account))
+				}
+
+				fn sponsoring_dry_run(uxt: Vec<u8>) -> Result<SponsoringDryRun<CrossAccountId>, DispatchError> {
+					use parity_scale_codec::Decode;
+					use sp_runtime::traits::StaticLookup;
+
+					let len = uxt.len() as u32;
+					let uxt = <<Block as BlockT>::Extrinsic as Decode>::decode(&mut &*uxt)
+						.map_err(|_| DispatchError::Other("failed to decode the extrinsic"))?;
+					// Self-contained ethereum transactions are unsigned, use `evm_sponsoring_dry_run` for them
+					let who = uxt.0.signature
+						.map(|(address, _, _)| <Runtime as frame_system::Config>::Lookup::lookup(address))
+						.transpose()?;
+
+					Ok(runtime_common::sponsoring::sponsoring_dry_run::<
+						Runtime,
+						<Runtime as pallet_charge_transaction::Config>::SponsorshipHandler,
+						RuntimeCall,
+					>(who.as_ref(), &uxt.0.function, len))
+				}
+
+				fn evm_sponsoring_dry_run(
+					source: H160,
+					target: H160,
+					input: Vec<u8>,
+					gas_limit: u64,
+					max_fee_per_gas: U256,
+				) -> Result<SponsoringDryRun<CrossAccountId>, DispatchError> {
+					Ok(runtime_common::ethereum::sponsoring::evm_sponsoring_dry_run::<Runtime>(
+						source,
+						target,
+						input,
+						gas_limit,
+						max_fee_per_gas,
+					))
+				}
+
+				fn sponsor_fees_paid(sponsor: CrossAccountId, era: Option<u32>) -> Result<u128, DispatchError> {
+					let era = era.unwrap_or_else(<pallet_evm_transaction_payment::Pallet<Runtime>>::current_stats_era);
+					Ok(<pallet_evm_transaction_payment::Pallet<Runtime>>::sponsor_fees_paid(&sponsor, era))
+				}
+
+				fn sponsored_fees(target: H160, era: Option<u32>, top: u32) -> Result<SponsoredFees<CrossAccountId>, DispatchError> {
+					let era = era.unwrap_or_else(<pallet_evm_transaction_payment::Pallet<Runtime>>::current_stats_era);
+					let (total, top_users) = <pallet_evm_transaction_payment::Pallet<Runtime>>::sponsored_fees(target, era, top);
+					Ok(SponsoredFees { era, total, top_users })
+				}
+
 				fn effective_collection_limits(collection: CollectionId) -> Result<Option<CollectionLimits>, DispatchError> {
 					Ok(<pallet_common::Pallet<Runtime>>::effective_collection_limits(collection))
 				}
@@ -207,7 +276,7 @@ macro_rules! impl_common_runtime_apis {
 				}
 
 				#[allow(unused_variables)]
-				fn total_staked_per_block(staker: CrossAccountId) -> Result<Vec<(BlockNumber, u128)>, DispatchError> {
+				fn total_staked_per_block(staker: CrossAccountId) -> Result<Vec<(BlockNumber, u128, Option<(u8, BlockNumber)>)>, DispatchError> {
 					#[cfg(not(feature = "app-promotion"))]
 					return unsupported!();
 
@@ -232,6 +301,108 @@ macro_rules! impl_common_runtime_apis {
 					#[cfg(feature = "app-promotion")]
 					return Ok(<pallet_app_promotion::Pallet<Runtime>>::cross_id_pending_unstake_per_block(staker))
 				}
+
+				#[allow(unused_variables)]
+				fn delegated_stake(staker: CrossAccountId) -> Result<Vec<(H160, u128)>, DispatchError> {
+					#[cfg(not(feature = "app-promotion"))]
+					return unsupported!();
+
+					#[cfg(feature = "app-promotion")]
+					return Ok(<pallet_app_promotion::Pallet<Runtime>>::cross_id_delegated_stake(staker));
+				}
+
+				#[allow(unused_variables)]
+				fn target_delegated_stake(target: H160) -> Result<u128, DispatchError> {
+					#[cfg(not(feature = "app-promotion"))]
+					return unsupported!();
+
+					#[cfg(feature = "app-promotion")]
+					return Ok(<pallet_app_promotion::Pallet<Runtime>>::target_delegated_stake(target));
+				}
+
+				#[allow(unused_variables)]
+				fn sponsoring_allowance(target: H160) -> Result<u128, DispatchError> {
+					#[cfg(not(feature = "app-promotion"))]
+					return unsupported!();
+
+					#[cfg(feature = "app-promotion")]
+					return Ok(<pallet_app_promotion::Pallet<Runtime>>::sponsoring_allowance(target));
+				}
+
+				#[allow(unused_variables)]
+				fn payout_history(staker: CrossAccountId) -> Result<Vec<(BlockNumber, u128)>, DispatchError> {
+					#[cfg(not(feature = "app-promotion"))]
+					return unsupported!();
+
+					#[cfg(feature = "app-promotion")]
+					return Ok(<pallet_app_promotion::Pallet<Runtime>>::cross_id_payout_history(staker));
+				}
+
+				#[allow(unused_variables)]
+				fn projected_payout(staker: CrossAccountId) -> Result<(BlockNumber, u128), DispatchError> {
+					#[cfg(not(feature = "app-promotion"))]
+					return unsupported!();
+
+					#[cfg(feature = "app-promotion")]
+					return Ok(<pallet_app_promotion::Pallet<Runtime>>::cross_id_projected_payout(staker));
+				}
+
+				fn reward_pot_runway() -> Result<(u128, u128, Option<BlockNumber>), DispatchError> {
+					#[cfg(not(feature = "app-promotion"))]
+					return unsupported!();
+
+					#[cfg(feature = "app-promotion")]
+					return Ok(<pallet_app_promotion::Pallet<Runtime>>::reward_pot_runway());
+				}
+
+				#[allow(unused_variables)]
+				fn reward_destination(staker: CrossAccountId) -> Result<RewardDestination<AccountId>, DispatchError> {
+					#[cfg(not(feature = "app-promotion"))]
+					return unsupported!();
+
+					#[cfg(feature = "app-promotion")]
+					return Ok(<pallet_app_promotion::Pallet<Runtime>>::cross_id_reward_destination(staker));
+				}
+			}
+
+			impl inflation_rpc::InflationApi<Block, BlockNumber, AccountId> for Runtime {
+				fn inflation_split() -> Result<Vec<(AccountId, Perbill)>, DispatchError> {
+					Ok(<pallet_inflation::Pallet<Runtime>>::inflation_split())
+				}
+
+				fn issuance_history() -> Result<Vec<(BlockNumber, u128, u128)>, DispatchError> {
+					Ok(<pallet_inflation::Pallet<Runtime>>::issuance_history())
+				}
+			}
+
+			impl collator_selection_rpc::CollatorSelectionApi<Block, BlockNumber, AccountId> for Runtime {
+				#[allow(unused_variables)]
+				fn collator_stats(collator: AccountId) -> Result<up_data_structs::CollatorStats<BlockNumber>, DispatchError> {
+					#[cfg(not(feature = "collator-selection"))]
+					return unsupported!();
+
+					#[cfg(feature = "collator-selection")]
+					{
+						let last_authored_block = CollatorSelection::last_authored_block(&collator);
+						let rewards = CollatorSelection::collator_rewards(&collator);
+						return Ok(up_data_structs::CollatorStats {
+							session_blocks: CollatorSelection::session_authored_blocks(&collator),
+							last_authored_block: (last_authored_block != 0).then_some(last_authored_block),
+							last_rewarded_session: rewards.session,
+							last_session_blocks: rewards.session_blocks,
+							last_session_reward: rewards.session_reward,
+							total_reward: rewards.total_reward,
+						});
+					}
+				}
+
+				fn waiting_candidates() -> Result<Vec<AccountId>, DispatchError> {
+					#[cfg(not(feature = "collator-selection"))]
+					return unsupported!();
+
+					#[cfg(feature = "collator-selection")]
+					return Ok(CollatorSelection::waiting_candidates().into_inner());
+				}
 			}
 
 			impl sp_api::Core<Block> for Runtime {
@@ -565,6 +736,7 @@ macro_rules! impl_common_runtime_apis {
 					list_benchmark!(list, extra, pallet_foreign_assets, ForeignAssets);
 
 					list_benchmark!(list, extra, pallet_maintenance, Maintenance);
+					list_benchmark!(list, extra, pallet_sponsor_pool, SponsorPool);
 
 					// list_benchmark!(list, extra, pallet_evm_coder_substrate, EvmCoderSubstrate);
 
@@ -629,6 +801,7 @@ macro_rules! impl_common_runtime_apis {
 					add_benchmark!(params, batches, pallet_foreign_assets, ForeignAssets);
 
 					add_benchmark!(params, batches, pallet_maintenance, Maintenance);
+					add_benchmark!(params, batches, pallet_sponsor_pool, SponsorPool);
 
 					// add_benchmark!(params, batches, pallet_evm_coder_substrate, EvmCoderSubstrate);
 
@@ -16,22 +16,53 @@
 
 use core::marker::PhantomData;
 
-use frame_support::traits::IsSubType;
+use frame_support::{
+	dispatch::{DispatchInfo, GetDispatchInfo},
+	storage::with_transaction,
+	traits::{
+		fungible::Inspect,
+		tokens::{Fortitude, Preservation},
+		IsSubType,
+	},
+};
 use frame_system::pallet_prelude::*;
-use pallet_common::CollectionHandle;
+use pallet_common::{
+	eth::{collection_id_to_address, map_eth_to_id},
+	Allowlist, CollectionHandle, Event as CommonEvent, NestedTokensSponsoring,
+	Pallet as PalletCommon, PublicMintSponsorings, PublicMintsSponsored, SponsorBudgetSpent,
+	SponsorBudgets, SponsorUserBudgetSpent,
+};
 use pallet_evm::account::CrossAccountId;
+use pallet_evm_transaction_payment::{
+	Config as EvmTransactionPaymentConfig, OnSponsoredFee, Pallet as PalletEvmTransactionPayment,
+	SponsoredCall,
+};
 use pallet_fungible::Config as FungibleConfig;
 use pallet_nonfungible::Config as NonfungibleConfig;
 use pallet_refungible::Config as RefungibleConfig;
+use pallet_structure::{Call as StructureCall, Pallet as PalletStructure, NESTING_BUDGET};
+use pallet_transaction_payment::OnChargeTransaction;
 use pallet_unique::{
-	Call as UniqueCall, Config as UniqueConfig, CreateItemBasket, FungibleApproveBasket,
-	FungibleTransferBasket, NftApproveBasket, NftTransferBasket, ReFungibleTransferBasket,
-	RefungibleApproveBasket, TokenPropertyBasket,
+	AllowanceForAllBasket, BurnBasket, Call as UniqueCall, Config as UniqueConfig,
+	CreateItemBasket, FungibleApproveBasket, FungibleTransferBasket, NftApproveBasket,
+	NftTransferBasket, ReFungibleTransferBasket, RefungibleApproveBasket,
+	RefungibleRepartitionBasket, TokenPropertyBasket,
+};
+use parity_scale_codec::Encode;
+use sp_core::H160;
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, Saturating, UniqueSaturatedInto, Zero,
+	},
+	transaction_validity::TransactionValidityError,
+	DispatchError, TransactionOutcome,
 };
-use sp_runtime::traits::Saturating;
+use sp_std::{vec, vec::Vec};
 use up_data_structs::{
-	CollectionId, CollectionMode, CreateItemData, TokenId, FUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
-	NFT_SPONSOR_TRANSFER_TIMEOUT, REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
+	budget, mapping::TokenAddressMapping, CollectionAdminRoles, CollectionId, CollectionMode,
+	CreateItemData, CreateItemExData, CreateNftData, Property, SponsoringDryRun, SponsoringReason,
+	TokenId, FUNGIBLE_SPONSOR_TRANSFER_TIMEOUT, NFT_SPONSOR_TRANSFER_TIMEOUT,
+	REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
 };
 use up_sponsorship::SponsorshipHandler;
 
@@ -94,12 +125,12 @@ pub fn withdraw_set_token_property<T: Config>(
 	Some(())
 }
 
-pub fn withdraw_transfer<T: Config>(
+/// Check that `who` owns the token (or its pieces) which is being spent.
+fn owns_token<T: Config>(
 	collection: &CollectionHandle<T>,
 	who: &T::CrossAccountId,
 	item_id: &TokenId,
 ) -> Option<()> {
-	// preliminary sponsoring correctness check
 	match collection.mode {
 		CollectionMode::NFT => {
 			let owner = pallet_nonfungible::TokenData::<T>::get((collection.id, item_id))?.owner;
@@ -122,6 +153,51 @@ pub fn withdraw_transfer<T: Config>(
 		}
 	}
 
+	Some(())
+}
+
+pub fn withdraw_transfer<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::CrossAccountId,
+	item_id: &TokenId,
+) -> Option<()> {
+	// preliminary sponsoring correctness check
+	owns_token(collection, who, item_id)?;
+
+	withdraw_transfer_timeout(collection, who, item_id)
+}
+
+/// Sponsor equipping the nested token `item_id` into a slot of its parent token,
+/// or removing it from the slot, by `who`, which owns the whole nesting tree.
+///
+/// Such equipment moves share the sponsor timeout with the transfers of the token.
+pub fn withdraw_equip<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::CrossAccountId,
+	item_id: &TokenId,
+) -> Option<()> {
+	// preliminary sponsoring correctness check
+	if !<PalletStructure<T>>::check_indirectly_owned(
+		who.clone(),
+		collection.id,
+		*item_id,
+		None,
+		&budget::Value::new(NESTING_BUDGET),
+	)
+	.ok()?
+	{
+		return None;
+	}
+
+	withdraw_transfer_timeout(collection, who, item_id)
+}
+
+/// Check and renew the sponsor timeout of the transfers of the `item_id` by `who`.
+fn withdraw_transfer_timeout<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::CrossAccountId,
+	item_id: &TokenId,
+) -> Option<()> {
 	// sponsor timeout
 	let block_number = <frame_system::Pallet<T>>::block_number() as BlockNumberFor<T>;
 	let limit = collection
@@ -168,6 +244,15 @@ pub fn withdraw_create_item<T: Config>(
 	who: &T::CrossAccountId,
 	properties: &CreateItemData,
 ) -> Option<()> {
+	if public_mint_sponsored::<T>(collection, who) {
+		let data_size = match properties {
+			CreateItemData::NFT(data) => properties_size(&data.properties),
+			CreateItemData::ReFungible(data) => properties_size(&data.properties),
+			CreateItemData::Fungible(_) => 0,
+		};
+		return withdraw_public_mint::<T>(collection, who, [data_size]);
+	}
+
 	// sponsor timeout
 	let block_number = <frame_system::Pallet<T>>::block_number() as BlockNumberFor<T>;
 	let limit = collection
@@ -189,6 +274,93 @@ pub fn withdraw_create_item<T: Config>(
 	Some(())
 }
 
+/// Sponsor minting of a single token by `who`, with initial properties of `data_size` bytes.
+pub fn withdraw_mint<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::CrossAccountId,
+	data_size: usize,
+) -> Option<()> {
+	if public_mint_sponsored::<T>(collection, who) {
+		return withdraw_public_mint::<T>(collection, who, [data_size]);
+	}
+	withdraw_create_item::<T>(
+		collection,
+		who,
+		&CreateItemData::NFT(CreateNftData::default()),
+	)
+}
+
+/// Whether the tokens minted by `who` are sponsored according to
+/// the collection [`PublicMintSponsoring`](up_data_structs::PublicMintSponsoring).
+pub fn public_mint_sponsored<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::CrossAccountId,
+) -> bool {
+	<PublicMintSponsorings<T>>::contains_key(collection.id)
+		&& !collection.has_admin_roles(who, CollectionAdminRoles::MINTER)
+}
+
+/// Sponsor minting of tokens by `who` within the public mint quota,
+/// each of the tokens having initial properties of `data_sizes` bytes.
+pub fn withdraw_public_mint<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::CrossAccountId,
+	data_sizes: impl IntoIterator<Item = usize>,
+) -> Option<()> {
+	let sponsoring = <PublicMintSponsorings<T>>::get(collection.id)?;
+	if !collection.permissions.mint_mode()
+		|| collection.has_admin_roles(who, CollectionAdminRoles::MINTER)
+		|| !<Allowlist<T>>::get((collection.id, who))
+	{
+		return None;
+	}
+
+	let mut minted = 0u32;
+	for data_size in data_sizes {
+		if data_size > sponsoring.max_properties_size as usize {
+			return None;
+		}
+		minted += 1;
+	}
+
+	let mut usage = <PalletCommon<T>>::public_mint_usage(
+		&sponsoring,
+		<PublicMintsSponsored<T>>::get((collection.id, who)),
+	);
+	usage.minted = usage.minted.checked_add(minted)?;
+	if minted == 0 || usage.minted > sponsoring.mints_per_user {
+		return None;
+	}
+	<PublicMintsSponsored<T>>::insert((collection.id, who), usage);
+
+	Some(())
+}
+
+/// Initial properties of each of the tokens created with `data`, in bytes.
+pub fn create_item_ex_data_sizes<CrossAccountId>(
+	data: &CreateItemExData<CrossAccountId>,
+) -> Vec<usize> {
+	match data {
+		CreateItemExData::NFT(tokens) => tokens
+			.iter()
+			.map(|token| properties_size(&token.properties))
+			.collect(),
+		CreateItemExData::Fungible(owners) => owners.iter().map(|_| 0).collect(),
+		CreateItemExData::RefungibleMultipleItems(tokens) => tokens
+			.iter()
+			.map(|token| properties_size(&token.properties))
+			.collect(),
+		CreateItemExData::RefungibleMultipleOwners(token) => {
+			vec![properties_size(&token.properties)]
+		}
+	}
+}
+
+fn properties_size(properties: &[Property]) -> usize {
+	// No overflow may happen, as data larger than usize can't reach here
+	properties.iter().map(|p| p.key.len() + p.value.len()).sum()
+}
+
 pub fn withdraw_approve<T: Config>(
 	collection: &CollectionHandle<T>,
 	who: &T::AccountId,
@@ -226,81 +398,512 @@ pub fn withdraw_approve<T: Config>(
 	Some(())
 }
 
+pub fn withdraw_burn<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::CrossAccountId,
+	item_id: &TokenId,
+) -> Option<()> {
+	// preliminary sponsoring correctness check
+	owns_token(collection, who, item_id)?;
+
+	// sponsor timeout
+	let block_number = <frame_system::Pallet<T>>::block_number() as BlockNumberFor<T>;
+	let limit = collection.limits.sponsor_burn_timeout();
+
+	if let Some(last_tx_block) = <BurnBasket<T>>::get(collection.id, who.as_sub()) {
+		let timeout = last_tx_block + limit.into();
+		if block_number < timeout {
+			return None;
+		}
+	}
+	<BurnBasket<T>>::insert(collection.id, who.as_sub(), block_number);
+
+	Some(())
+}
+
+pub fn withdraw_set_allowance_for_all<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::AccountId,
+) -> Option<()> {
+	// preliminary sponsoring correctness check
+	if let CollectionMode::Fungible(_) = collection.mode {
+		// Fungible collections have no allowance for all
+		return None;
+	}
+
+	// sponsor timeout
+	let block_number = <frame_system::Pallet<T>>::block_number() as BlockNumberFor<T>;
+	let limit = collection.limits.sponsor_allowance_for_all_timeout();
+
+	if let Some(last_tx_block) = <AllowanceForAllBasket<T>>::get(collection.id, who) {
+		let timeout = last_tx_block + limit.into();
+		if block_number < timeout {
+			return None;
+		}
+	}
+	<AllowanceForAllBasket<T>>::insert(collection.id, who, block_number);
+
+	Some(())
+}
+
+pub fn withdraw_repartition<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::CrossAccountId,
+	item_id: &TokenId,
+) -> Option<()> {
+	// preliminary sponsoring correctness check
+	if collection.mode != CollectionMode::ReFungible {
+		return None;
+	}
+	// Only the owner of all of the pieces can repartition the token
+	let balance = <pallet_refungible::Balance<T>>::get((collection.id, item_id, who));
+	if balance == 0 || balance != <pallet_refungible::TotalSupply<T>>::get((collection.id, item_id))
+	{
+		return None;
+	}
+
+	// sponsor timeout
+	let block_number = <frame_system::Pallet<T>>::block_number() as BlockNumberFor<T>;
+	let limit = collection.limits.sponsor_repartition_timeout();
+
+	if let Some(last_tx_block) = <RefungibleRepartitionBasket<T>>::get(collection.id, item_id) {
+		let timeout = last_tx_block + limit.into();
+		if block_number < timeout {
+			return None;
+		}
+	}
+	<RefungibleRepartitionBasket<T>>::insert(collection.id, item_id, block_number);
+
+	Some(())
+}
+
+/// Check that the collection sponsor budget allows paying the estimated `fee` on behalf of `who`,
+/// before the sponsoring is `withdraw`n.
+///
+/// The budget is charged with the actual fee once the call is executed,
+/// see [`SponsorBudgetCharger`].
+pub fn withdraw_sponsor_budget<T: Config, R>(
+	collection_id: CollectionId,
+	who: &T::CrossAccountId,
+	fee: u128,
+	withdraw: impl FnOnce() -> Option<R>,
+) -> Option<R> {
+	let Some(budget) = <SponsorBudgets<T>>::get(collection_id) else {
+		return withdraw();
+	};
+	let mut usage = <PalletCommon<T>>::sponsor_budget_usage(
+		&budget,
+		<SponsorBudgetSpent<T>>::get(collection_id),
+	);
+	let mut user_usage = <PalletCommon<T>>::sponsor_budget_usage(
+		&budget,
+		<SponsorUserBudgetSpent<T>>::get((collection_id, who)),
+	);
+
+	if !usage.allows(budget.period_limit, fee) {
+		if !usage.exhausted {
+			usage.exhausted = true;
+			<SponsorBudgetSpent<T>>::insert(collection_id, usage);
+			<PalletCommon<T>>::deposit_event(CommonEvent::SponsorBudgetExhausted(collection_id));
+		}
+		return None;
+	}
+	if !user_usage.allows(budget.user_limit, fee) {
+		if !user_usage.exhausted {
+			user_usage.exhausted = true;
+			<SponsorUserBudgetSpent<T>>::insert((collection_id, who), user_usage);
+			<PalletCommon<T>>::deposit_event(CommonEvent::SponsorUserBudgetExhausted(
+				collection_id,
+				who.clone(),
+			));
+		}
+		return None;
+	}
+
+	withdraw()
+}
+
+/// Charge the collection sponsor budget with the `fee` paid on behalf of `who`.
+pub fn charge_sponsor_budget<T: Config>(
+	collection_id: CollectionId,
+	who: &T::CrossAccountId,
+	fee: u128,
+) {
+	let Some(budget) = <SponsorBudgets<T>>::get(collection_id) else {
+		return;
+	};
+	if budget.period_limit.is_some() {
+		let mut usage = <PalletCommon<T>>::sponsor_budget_usage(
+			&budget,
+			<SponsorBudgetSpent<T>>::get(collection_id),
+		);
+		usage.spent = usage.spent.saturating_add(fee);
+		<SponsorBudgetSpent<T>>::insert(collection_id, usage);
+	}
+	if budget.user_limit.is_some() {
+		let mut user_usage = <PalletCommon<T>>::sponsor_budget_usage(
+			&budget,
+			<SponsorUserBudgetSpent<T>>::get((collection_id, who)),
+		);
+		user_usage.spent = user_usage.spent.saturating_add(fee);
+		<SponsorUserBudgetSpent<T>>::insert((collection_id, who), user_usage);
+	}
+}
+
+/// Charges the budget of the sponsored collection with the actual fee paid by its sponsor,
+/// for both substrate and EVM calls.
+pub struct SponsorBudgetCharger<T>(PhantomData<T>);
+impl<T: Config> OnSponsoredFee<T::CrossAccountId> for SponsorBudgetCharger<T> {
+	fn on_sponsored_fee(sponsoring: &SponsoredCall<T::CrossAccountId>, fee: u128) {
+		if let Some(collection_id) = target_collection::<T>(&sponsoring.target) {
+			charge_sponsor_budget::<T>(collection_id, &sponsoring.who, fee);
+		}
+	}
+}
+
+/// Collection which is called via its own or its token address.
+pub fn target_collection<T: UniqueConfig>(address: &H160) -> Option<CollectionId> {
+	map_eth_to_id(address).or_else(|| {
+		T::EvmTokenAddressMapping::address_to_token(address).map(|(collection_id, _)| collection_id)
+	})
+}
+
+/// Check that the collection sponsor budget allows paying `fee` on behalf of `who`,
+/// without charging it.
+fn sponsor_budget_allows<T: Config>(
+	collection_id: CollectionId,
+	who: &T::CrossAccountId,
+	fee: u128,
+) -> bool {
+	let Some(budget) = <SponsorBudgets<T>>::get(collection_id) else {
+		return true;
+	};
+	<PalletCommon<T>>::sponsor_budget_usage(&budget, <SponsorBudgetSpent<T>>::get(collection_id))
+		.allows(budget.period_limit, fee)
+		&& <PalletCommon<T>>::sponsor_budget_usage(
+			&budget,
+			<SponsorUserBudgetSpent<T>>::get((collection_id, who)),
+		)
+		.allows(budget.user_limit, fee)
+}
+
+/// Explain why the collection sponsor refused to sponsor `who`.
+pub fn collection_refusal_reason<T: Config>(
+	collection_id: CollectionId,
+	who: &T::CrossAccountId,
+	fee: u128,
+) -> SponsoringReason {
+	if load::<T>(collection_id).is_none() {
+		SponsoringReason::NoSponsor
+	} else if !sponsor_budget_allows::<T>(collection_id, who, fee) {
+		SponsoringReason::BudgetExhausted
+	} else {
+		SponsoringReason::NotEligible
+	}
+}
+
+/// Fee of the `call` with the extrinsic length `len`, as estimated before its execution.
+pub fn call_fee<T, C>(call: &C, len: u32) -> u128
+where
+	T: pallet_transaction_payment::Config,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	C: GetDispatchInfo,
+{
+	<pallet_transaction_payment::Pallet<T>>::compute_fee(
+		len,
+		&call.get_dispatch_info(),
+		Zero::zero(),
+	)
+	.unique_saturated_into()
+}
+
+/// Fee of the `call`, which is checked against the sponsoring budgets and limits
+/// before its execution.
+///
+/// The extrinsic length isn't known to the sponsorship handlers,
+/// so the length of the encoded call is used in its place.
+pub fn sponsored_call_fee<T, C>(call: &C) -> u128
+where
+	T: pallet_transaction_payment::Config,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	C: GetDispatchInfo + Encode,
+{
+	call_fee::<T, C>(call, call.encoded_size() as u32)
+}
+
+/// Collection which sponsors the `call`, if the call is sponsorable at all,
+/// along with the token affected by the call.
+fn sponsored_target<T, C>(call: &C) -> Option<(CollectionId, Option<TokenId>)>
+where
+	T: Config,
+	C: IsSubType<UniqueCall<T>> + IsSubType<StructureCall<T>>,
+{
+	if let Some(unique_call) = IsSubType::<UniqueCall<T>>::is_sub_type(call) {
+		return Some((
+			sponsored_collection::<T>(unique_call)?,
+			target_token::<T>(unique_call),
+		));
+	}
+	match IsSubType::<StructureCall<T>>::is_sub_type(call)? {
+		StructureCall::equip {
+			child_collection_id,
+			child_token_id,
+			..
+		} => Some((*child_collection_id, Some(*child_token_id))),
+		StructureCall::unequip {
+			collection_id,
+			token_id,
+		} => Some((*collection_id, Some(*token_id))),
+		_ => None,
+	}
+}
+
+/// Collection which sponsors the `call`, if the call is sponsorable at all.
+fn sponsored_collection<T: Config>(call: &UniqueCall<T>) -> Option<CollectionId> {
+	match call {
+		UniqueCall::set_token_properties { collection_id, .. }
+		| UniqueCall::create_item { collection_id, .. }
+		| UniqueCall::create_multiple_items_ex { collection_id, .. }
+		| UniqueCall::transfer { collection_id, .. }
+		| UniqueCall::transfer_from { collection_id, .. }
+		| UniqueCall::approve { collection_id, .. }
+		| UniqueCall::set_allowance_for_all { collection_id, .. }
+		| UniqueCall::burn_item { collection_id, .. }
+		| UniqueCall::burn_from { collection_id, .. }
+		| UniqueCall::repartition { collection_id, .. } => Some(*collection_id),
+		_ => None,
+	}
+}
+
 fn load<T: UniqueConfig>(id: CollectionId) -> Option<(T::AccountId, CollectionHandle<T>)> {
 	let collection = CollectionHandle::new(id)?;
 	let sponsor = collection.sponsorship.sponsor().cloned()?;
 	Some((sponsor, collection))
 }
 
+/// Token which is affected by the `call`.
+fn target_token<T: Config>(call: &UniqueCall<T>) -> Option<TokenId> {
+	match call {
+		UniqueCall::set_token_properties { token_id, .. }
+		| UniqueCall::repartition { token_id, .. } => Some(*token_id),
+		UniqueCall::transfer { item_id, .. }
+		| UniqueCall::transfer_from { item_id, .. }
+		| UniqueCall::approve { item_id, .. }
+		| UniqueCall::burn_item { item_id, .. }
+		| UniqueCall::burn_from { item_id, .. } => Some(*item_id),
+		_ => None,
+	}
+}
+
+/// Load the sponsor of the call to the `token` of the collection `id`,
+/// with the ID of the collection paying for it.
+///
+/// If the collection has no sponsor, the call to a nested token is paid by the collection
+/// of the topmost token it is nested in, if that collection sponsors nested tokens.
+/// The limits of the paying collection apply in that case.
+fn load_call_sponsor<T: Config>(
+	id: CollectionId,
+	token: Option<TokenId>,
+) -> Option<(T::AccountId, CollectionId, CollectionHandle<T>)> {
+	let mut collection = CollectionHandle::new(id)?;
+	if let Some(sponsor) = collection.sponsorship.sponsor().cloned() {
+		return Some((sponsor, id, collection));
+	}
+
+	let token = token?;
+	let (Some((parent_id, _)), _) =
+		<PalletStructure<T>>::find_topmost(id, token, &budget::Value::new(NESTING_BUDGET)).ok()?
+	else {
+		return None;
+	};
+	if !<NestedTokensSponsoring<T>>::get(parent_id) {
+		return None;
+	}
+	let (sponsor, parent) = load::<T>(parent_id)?;
+	collection.limits = parent.limits.clone();
+
+	Some((sponsor, parent_id, collection))
+}
+
 pub struct UniqueSponsorshipHandler<T>(PhantomData<T>);
 impl<T, C> SponsorshipHandler<T::AccountId, C> for UniqueSponsorshipHandler<T>
 where
-	T: Config,
-	C: IsSubType<UniqueCall<T>>,
+	T: Config + pallet_transaction_payment::Config + EvmTransactionPaymentConfig,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	C: IsSubType<UniqueCall<T>> + IsSubType<StructureCall<T>> + GetDispatchInfo + Encode,
 {
 	fn get_sponsor(who: &T::AccountId, call: &C) -> Option<T::AccountId> {
-		match IsSubType::<UniqueCall<T>>::is_sub_type(call)? {
-			UniqueCall::set_token_properties {
-				collection_id,
-				token_id,
-				properties,
-				..
-			} => {
-				let (sponsor, collection) = load::<T>(*collection_id)?;
-				withdraw_set_existing_token_property(
+		// The sponsoring left from a previous call shouldn't be attributed to this one
+		<PalletEvmTransactionPayment<T>>::clear_pending_sponsoring();
+		let (collection_id, token) = sponsored_target::<T, C>(call)?;
+		let (sponsor, collection_id, collection) = load_call_sponsor::<T>(collection_id, token)?;
+		let cross_who = T::CrossAccountId::from_sub(who.clone());
+		let fee = sponsored_call_fee::<T, C>(call);
+
+		withdraw_sponsor_budget::<T, _>(collection_id, &cross_who, fee, || {
+			let Some(unique_call) = IsSubType::<UniqueCall<T>>::is_sub_type(call) else {
+				// Only the equipment moves are sponsored among the structure calls
+				return withdraw_equip::<T>(&collection, &cross_who, &token?);
+			};
+			match unique_call {
+				UniqueCall::set_token_properties {
+					token_id,
+					properties,
+					..
+				} => withdraw_set_existing_token_property(
 					&collection,
-					&T::CrossAccountId::from_sub(who.clone()),
+					&cross_who,
 					token_id,
 					// No overflow may happen, as data larger than usize can't reach here
 					properties.iter().map(|p| p.key.len() + p.value.len()).sum(),
-				)
-				.map(|()| sponsor)
-			}
-			UniqueCall::create_item {
-				collection_id,
-				data,
-				..
-			} => {
-				let (sponsor, collection) = load(*collection_id)?;
-				withdraw_create_item::<T>(
-					&collection,
-					&T::CrossAccountId::from_sub(who.clone()),
-					data,
-				)
-				.map(|()| sponsor)
-			}
-			UniqueCall::transfer {
-				collection_id,
-				item_id,
-				..
-			} => {
-				let (sponsor, collection) = load(*collection_id)?;
-				withdraw_transfer::<T>(
+				),
+				UniqueCall::create_item { data, .. } => {
+					withdraw_create_item::<T>(&collection, &cross_who, data)
+				}
+				UniqueCall::create_multiple_items_ex { data, .. } => withdraw_public_mint::<T>(
 					&collection,
-					&T::CrossAccountId::from_sub(who.clone()),
-					item_id,
-				)
-				.map(|()| sponsor)
+					&cross_who,
+					create_item_ex_data_sizes(data),
+				),
+				UniqueCall::transfer { item_id, .. } => {
+					withdraw_transfer::<T>(&collection, &cross_who, item_id)
+				}
+				UniqueCall::transfer_from { item_id, from, .. } => {
+					withdraw_transfer::<T>(&collection, from, item_id)
+				}
+				UniqueCall::approve { item_id, .. } => {
+					withdraw_approve::<T>(&collection, who, item_id)
+				}
+				UniqueCall::set_allowance_for_all { .. } => {
+					withdraw_set_allowance_for_all::<T>(&collection, who)
+				}
+				UniqueCall::burn_item { item_id, .. } => {
+					withdraw_burn::<T>(&collection, &cross_who, item_id)
+				}
+				UniqueCall::burn_from { item_id, from, .. } => {
+					withdraw_burn::<T>(&collection, from, item_id)
+				}
+				UniqueCall::repartition { token_id, .. } => {
+					withdraw_repartition::<T>(&collection, &cross_who, token_id)
+				}
+				_ => None,
 			}
-			UniqueCall::transfer_from {
-				collection_id,
-				item_id,
-				from,
-				..
-			} => {
-				let (sponsor, collection) = load(*collection_id)?;
-				withdraw_transfer::<T>(&collection, from, item_id).map(|()| sponsor)
-			}
-			UniqueCall::approve {
-				collection_id,
-				item_id,
-				..
-			} => {
-				let (sponsor, collection) = load(*collection_id)?;
-				withdraw_approve::<T>(&collection, who, item_id).map(|()| sponsor)
+		})?;
+
+		<PalletEvmTransactionPayment<T>>::note_sponsoring(
+			T::CrossAccountId::from_sub(sponsor.clone()),
+			collection_id_to_address(collection_id),
+			cross_who,
+		);
+		Some(sponsor)
+	}
+}
+
+/// Wraps the `OnChargeTransaction` implementation `O`,
+/// to record the fees paid by the sponsors of the substrate calls.
+pub struct SponsoredFeeRecorder<O>(PhantomData<O>);
+impl<T, O> OnChargeTransaction<T> for SponsoredFeeRecorder<O>
+where
+	T: pallet_transaction_payment::Config + EvmTransactionPaymentConfig,
+	O: OnChargeTransaction<T>,
+{
+	type Balance = O::Balance;
+	type LiquidityInfo = (O::LiquidityInfo, Option<SponsoredCall<T::CrossAccountId>>);
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		// The sponsoring is noted by the sponsorship handler, called just before the fee withdrawal
+		let sponsoring = <PalletEvmTransactionPayment<T>>::take_pending_sponsoring(who);
+		O::withdraw_fee(who, call, dispatch_info, fee, tip).map(|li| (li, sponsoring))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let (already_withdrawn, sponsoring) = already_withdrawn;
+		O::correct_and_deposit_fee(
+			who,
+			dispatch_info,
+			post_info,
+			corrected_fee,
+			tip,
+			already_withdrawn,
+		)?;
+		if let Some(sponsoring) = sponsoring {
+			<PalletEvmTransactionPayment<T>>::record_sponsored_fee(
+				sponsoring,
+				corrected_fee.unique_saturated_into(),
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Check whether the `call` of `who`, encoded in an extrinsic of `len` bytes,
+/// would be sponsored by the `H` handler chain, without applying any of its effects.
+pub fn sponsoring_dry_run<T, H, C>(
+	who: Option<&T::AccountId>,
+	call: &C,
+	len: u32,
+) -> SponsoringDryRun<T::CrossAccountId>
+where
+	T: Config + pallet_transaction_payment::Config,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	H: SponsorshipHandler<T::AccountId, C>,
+	C: IsSubType<UniqueCall<T>> + IsSubType<StructureCall<T>> + GetDispatchInfo + Encode,
+{
+	let fee = call_fee::<T, C>(call, len);
+	let Some(who) = who else {
+		return SponsoringDryRun {
+			sponsor: None,
+			fee,
+			reason: SponsoringReason::Unsigned,
+		};
+	};
+
+	let sponsor = with_transaction(|| {
+		TransactionOutcome::Rollback(Ok::<_, DispatchError>(H::get_sponsor(who, call)))
+	})
+	.ok()
+	.flatten();
+	let reason = match &sponsor {
+		Some(sponsor) => {
+			let balance: u128 = <T as pallet_common::Config>::Currency::reducible_balance(
+				sponsor,
+				Preservation::Protect,
+				Fortitude::Polite,
+			)
+			.unique_saturated_into();
+			if balance < fee {
+				SponsoringReason::SponsorBalanceTooLow
+			} else {
+				SponsoringReason::Sponsored
 			}
-			_ => None,
 		}
+		None => match sponsored_target::<T, C>(call) {
+			Some((collection_id, _)) => collection_refusal_reason::<T>(
+				collection_id,
+				&T::CrossAccountId::from_sub(who.clone()),
+				sponsored_call_fee::<T, C>(call),
+			),
+			None => SponsoringReason::NotEligible,
+		},
+	};
+
+	SponsoringDryRun {
+		sponsor: sponsor.map(T::CrossAccountId::from_sub),
+		fee,
+		reason,
 	}
 }
 
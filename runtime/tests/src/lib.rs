@@ -21,6 +21,7 @@ use frame_support::{
 	parameter_types,
 	traits::{fungible::Inspect, ConstU32, ConstU64, Everything},
 	weights::IdentityFee,
+	PalletId,
 };
 use frame_system as system;
 use pallet_ethereum::PostLogContent;
@@ -66,6 +67,8 @@ frame_support::construct_runtime!(
 		TransactionPayment: pallet_transaction_payment,
 		Ethereum: pallet_ethereum,
 		EVM: pallet_evm,
+		EvmContractHelpers: pallet_evm_contract_helpers,
+		SponsorPool: pallet_sponsor_pool,
 	}
 );
 
@@ -240,6 +243,31 @@ impl pallet_evm::Config for Test {
 }
 impl pallet_evm_coder_substrate::Config for Test {}
 
+parameter_types! {
+	pub const HelpersContractAddress: H160 = H160([
+		0x84, 0x28, 0x99, 0xec, 0xf3, 0x80, 0x55, 0x3e, 0x8a, 0x4d, 0xe7, 0x5b, 0xf5, 0x34, 0xcd, 0xf6, 0xfb, 0xf6, 0x40, 0x49,
+	]);
+}
+
+impl pallet_evm_contract_helpers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ContractAddress = HelpersContractAddress;
+	type DefaultSponsoringRateLimit = ConstU32<7200>;
+}
+
+parameter_types! {
+	pub const SponsorPoolPalletId: PalletId = PalletId(*b"sponpool");
+	pub const SponsorPoolMinimumFirstDeposit: u64 = 1000;
+}
+
+impl pallet_sponsor_pool::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type PalletId = SponsorPoolPalletId;
+	type MinimumFirstDeposit = SponsorPoolMinimumFirstDeposit;
+	type WeightInfo = ();
+}
+
 impl pallet_common::Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
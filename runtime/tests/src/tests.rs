@@ -15,21 +15,26 @@
 // along with Unique Network. If not, see <http://www.gnu.org/licenses/>.
 
 // Tests to be written here
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	traits::Hooks,
+	weights::{Weight, WeightMeter},
+};
 use pallet_common::Error as CommonError;
 use pallet_evm::account::CrossAccountId;
 use pallet_unique::Error as UniqueError;
 use sp_std::convert::TryInto;
 use up_data_structs::{
-	AccessMode, CollectionId, CollectionMode, CollectionPermissions,
+	AccessMode, CollectionAdminRoles, CollectionId, CollectionMode, CollectionPermissions,
 	CollectionPropertiesPermissionsVec, CollectionPropertiesVec, CreateCollectionData,
 	CreateFungibleData, CreateItemData, CreateNftData, CreateReFungibleData, Property,
-	PropertyKeyPermission, PropertyPermission, TokenId, COLLECTION_ADMINS_LIMIT,
-	COLLECTION_NUMBER_LIMIT, MAX_DECIMAL_POINTS, MAX_TOKEN_OWNERSHIP,
+	PropertyKeyPermission, PropertyPermission, SponsorBudget, SponsorBudgetLeft,
+	SponsorBudgetUsage, TokenId, COLLECTION_ADMINS_LIMIT, COLLECTION_NUMBER_LIMIT,
+	MAX_DECIMAL_POINTS, MAX_TOKEN_OWNERSHIP,
 };
 
 use crate::{
-	new_test_ext, CollectionCreationPrice, RuntimeOrigin, Test, TestCrossAccountId, Unique,
+	new_test_ext, CollectionCreationPrice, RuntimeOrigin, System, Test, TestCrossAccountId, Unique,
 };
 
 fn add_balance(user: u64, value: u64) {
@@ -1011,12 +1016,115 @@ fn change_collection_owner() {
 
 		let origin1 = RuntimeOrigin::signed(1);
 		assert_ok!(Unique::change_collection_owner(origin1, collection_id, 2));
+		// the ownership is only proposed
+		assert_eq!(
+			<pallet_common::CollectionById<Test>>::get(collection_id)
+				.unwrap()
+				.owner,
+			1
+		);
+
+		assert_ok!(Unique::accept_collection_ownership(
+			RuntimeOrigin::signed(2),
+			collection_id
+		));
+		assert_eq!(
+			<pallet_common::CollectionById<Test>>::get(collection_id)
+				.unwrap()
+				.owner,
+			2
+		);
+	});
+}
+
+#[test]
+fn collection_ownership_transfer_requires_acceptance() {
+	new_test_ext().execute_with(|| {
+		let collection_id = create_test_collection(&CollectionMode::NFT, CollectionId(1));
+
+		assert_ok!(Unique::propose_collection_owner(
+			RuntimeOrigin::signed(1),
+			collection_id,
+			2,
+			None
+		));
+		assert_eq!(
+			<pallet_common::CollectionById<Test>>::get(collection_id)
+				.unwrap()
+				.owner,
+			1
+		);
+
+		assert_noop!(
+			Unique::accept_collection_ownership(RuntimeOrigin::signed(3), collection_id),
+			CommonError::<Test>::AcceptOwnershipFail
+		);
+		assert_ok!(Unique::accept_collection_ownership(
+			RuntimeOrigin::signed(2),
+			collection_id
+		));
 		assert_eq!(
 			<pallet_common::CollectionById<Test>>::get(collection_id)
 				.unwrap()
 				.owner,
 			2
 		);
+		assert!(<pallet_common::PendingOwner<Test>>::get(collection_id).is_none());
+	});
+}
+
+#[test]
+fn collection_ownership_proposal_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		let collection_id = create_test_collection(&CollectionMode::NFT, CollectionId(1));
+
+		assert_ok!(Unique::propose_collection_owner(
+			RuntimeOrigin::signed(1),
+			collection_id,
+			2,
+			None
+		));
+		assert_noop!(
+			Unique::cancel_collection_owner_proposal(RuntimeOrigin::signed(2), collection_id),
+			CommonError::<Test>::NoPermission
+		);
+		assert_ok!(Unique::cancel_collection_owner_proposal(
+			RuntimeOrigin::signed(1),
+			collection_id
+		));
+		assert_noop!(
+			Unique::accept_collection_ownership(RuntimeOrigin::signed(2), collection_id),
+			CommonError::<Test>::AcceptOwnershipFail
+		);
+		assert_noop!(
+			Unique::cancel_collection_owner_proposal(RuntimeOrigin::signed(1), collection_id),
+			CommonError::<Test>::NoPendingOwner
+		);
+	});
+}
+
+#[test]
+fn collection_ownership_proposal_expires() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let collection_id = create_test_collection(&CollectionMode::NFT, CollectionId(1));
+
+		assert_ok!(Unique::propose_collection_owner(
+			RuntimeOrigin::signed(1),
+			collection_id,
+			2,
+			Some(10)
+		));
+
+		System::set_block_number(11);
+		assert_noop!(
+			Unique::accept_collection_ownership(RuntimeOrigin::signed(2), collection_id),
+			CommonError::<Test>::OwnershipProposalExpired
+		);
+		assert_noop!(
+			Unique::propose_collection_owner(RuntimeOrigin::signed(1), collection_id, 2, Some(10)),
+			CommonError::<Test>::OwnershipProposalExpired
+		);
 	});
 }
 
@@ -1030,6 +1138,172 @@ fn destroy_collection() {
 	});
 }
 
+fn clear_destroying_collections() {
+	<pallet_common::Pallet<Test> as Hooks<_>>::on_idle(1, Weight::MAX);
+}
+
+#[test]
+fn begin_destroy_non_empty_collection() {
+	new_test_ext().execute_with(|| {
+		let nft_collection_id = create_test_collection(&CollectionMode::NFT, CollectionId(1));
+		let rft_collection_id =
+			create_test_collection(&CollectionMode::ReFungible, CollectionId(2));
+		let ft_collection_id =
+			create_test_collection(&CollectionMode::Fungible(3), CollectionId(3));
+		create_test_item(nft_collection_id, &default_nft_data().into());
+		create_test_item(nft_collection_id, &default_nft_data().into());
+		create_test_item(rft_collection_id, &default_re_fungible_data().into());
+		create_test_item(ft_collection_id, &default_fungible_data().into());
+
+		let origin1 = RuntimeOrigin::signed(1);
+		assert_noop!(
+			Unique::destroy_collection(origin1.clone(), nft_collection_id),
+			CommonError::<Test>::CantDestroyNotEmptyCollection
+		);
+		for collection_id in [nft_collection_id, rft_collection_id, ft_collection_id] {
+			assert_ok!(Unique::begin_destroy_collection(
+				origin1.clone(),
+				collection_id
+			));
+			assert!(<pallet_common::DestroyingCollections<Test>>::contains_key(
+				collection_id
+			));
+		}
+
+		// The collection is unavailable while its tokens are being burnt
+		assert_noop!(
+			Unique::transfer(
+				origin1.clone(),
+				account(2),
+				nft_collection_id,
+				TokenId(1),
+				1
+			)
+			.map_err(|e| e.error),
+			CommonError::<Test>::CollectionNotFound
+		);
+		assert_noop!(
+			Unique::begin_destroy_collection(origin1, nft_collection_id),
+			CommonError::<Test>::CollectionNotFound
+		);
+
+		clear_destroying_collections();
+
+		for collection_id in [nft_collection_id, rft_collection_id, ft_collection_id] {
+			assert!(!<pallet_common::DestroyingCollections<Test>>::contains_key(
+				collection_id
+			));
+		}
+		assert!(
+			<pallet_nonfungible::TokenData<Test>>::iter_prefix((nft_collection_id,))
+				.next()
+				.is_none()
+		);
+		assert_eq!(
+			<pallet_nonfungible::AccountBalance<Test>>::get((nft_collection_id, account(1))),
+			0
+		);
+		assert!(
+			<pallet_refungible::TotalSupply<Test>>::iter_prefix((rft_collection_id,))
+				.next()
+				.is_none()
+		);
+		assert_eq!(
+			<pallet_fungible::TotalSupply<Test>>::get(ft_collection_id),
+			0
+		);
+		assert_eq!(
+			<pallet_fungible::Balance<Test>>::get((ft_collection_id, account(1))),
+			0
+		);
+	});
+}
+
+#[test]
+fn begin_destroy_collection_is_limited_by_weight() {
+	new_test_ext().execute_with(|| {
+		let collection_id = create_test_collection(&CollectionMode::NFT, CollectionId(1));
+		create_test_item(collection_id, &default_nft_data().into());
+
+		assert_ok!(Unique::begin_destroy_collection(
+			RuntimeOrigin::signed(1),
+			collection_id
+		));
+
+		<pallet_common::Pallet<Test> as Hooks<_>>::on_idle(1, Weight::zero());
+		assert!(<pallet_common::DestroyingCollections<Test>>::contains_key(
+			collection_id
+		));
+		assert!(<pallet_nonfungible::TokenData<Test>>::contains_key((
+			collection_id,
+			TokenId(1)
+		)));
+
+		clear_destroying_collections();
+		assert!(!<pallet_common::DestroyingCollections<Test>>::contains_key(
+			collection_id
+		));
+	});
+}
+
+#[test]
+fn destroying_collections_are_cleared_in_turn() {
+	new_test_ext().execute_with(|| {
+		let first = create_test_collection(&CollectionMode::NFT, CollectionId(1));
+		let second = create_test_collection(&CollectionMode::NFT, CollectionId(2));
+		for collection_id in [first, second] {
+			create_test_item(collection_id, &default_nft_data().into());
+			assert_ok!(Unique::begin_destroy_collection(
+				RuntimeOrigin::signed(1),
+				collection_id
+			));
+		}
+
+		// The collection following the cursor is cleared, not the first one
+		let (head, _) = <pallet_common::DestroyingCollections<Test>>::iter()
+			.next()
+			.unwrap();
+		let other = if head == first { second } else { first };
+		<pallet_common::DestroyingCollectionsCursor<Test>>::put(head);
+
+		let mut meter = WeightMeter::with_limit(Weight::MAX);
+		assert!(<pallet_common::Pallet<Test>>::clear_destroying_collection(
+			&mut meter
+		));
+		assert!(<pallet_common::DestroyingCollections<Test>>::contains_key(
+			head
+		));
+		assert!(!<pallet_common::DestroyingCollections<Test>>::contains_key(
+			other
+		));
+		assert_eq!(
+			<pallet_common::DestroyingCollectionsCursor<Test>>::get(),
+			Some(other)
+		);
+
+		// The cursor wraps around to the remaining collection
+		assert!(<pallet_common::Pallet<Test>>::clear_destroying_collection(
+			&mut meter
+		));
+		assert!(!<pallet_common::DestroyingCollections<Test>>::contains_key(
+			head
+		));
+	});
+}
+
+#[test]
+fn begin_destroy_collection_requires_owner() {
+	new_test_ext().execute_with(|| {
+		let collection_id = create_test_collection(&CollectionMode::NFT, CollectionId(1));
+		create_test_item(collection_id, &default_nft_data().into());
+
+		assert_noop!(
+			Unique::begin_destroy_collection(RuntimeOrigin::signed(2), collection_id),
+			CommonError::<Test>::NoPermission
+		);
+	});
+}
+
 #[test]
 fn burn_nft_item() {
 	new_test_ext().execute_with(|| {
@@ -1306,6 +1580,116 @@ fn remove_collection_admin() {
 	});
 }
 
+#[test]
+fn collection_admin_roles_restrict_admin() {
+	new_test_ext().execute_with(|| {
+		let collection_id =
+			create_test_collection_for_owner(&CollectionMode::NFT, 1, CollectionId(1));
+		let origin1 = RuntimeOrigin::signed(1);
+		let origin2 = RuntimeOrigin::signed(2);
+
+		assert_ok!(Unique::add_collection_admin(
+			origin1.clone(),
+			collection_id,
+			account(2)
+		));
+		assert_eq!(
+			<pallet_common::AdminRoles<Test>>::get((collection_id, account(2))),
+			CollectionAdminRoles::ALL
+		);
+
+		assert_ok!(Unique::set_collection_admin_roles(
+			origin1,
+			collection_id,
+			account(2),
+			CollectionAdminRoles::MINTER
+		));
+
+		// Minting is still allowed
+		assert_ok!(Unique::create_item(
+			origin2.clone(),
+			collection_id,
+			account(2),
+			default_nft_data().into()
+		));
+
+		// But other administrative actions are not
+		assert_noop!(
+			Unique::set_collection_sponsor(origin2.clone(), collection_id, 3),
+			CommonError::<Test>::NoPermission
+		);
+		assert_noop!(
+			Unique::set_collection_permissions(
+				origin2,
+				collection_id,
+				CollectionPermissions {
+					mint_mode: Some(true),
+					access: None,
+					nesting: None,
+				}
+			),
+			CommonError::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn collection_admin_roles_are_set_by_owner() {
+	new_test_ext().execute_with(|| {
+		let collection_id =
+			create_test_collection_for_owner(&CollectionMode::NFT, 1, CollectionId(1));
+		let origin1 = RuntimeOrigin::signed(1);
+
+		assert_noop!(
+			Unique::set_collection_admin_roles(
+				origin1.clone(),
+				collection_id,
+				account(2),
+				CollectionAdminRoles::MINTER
+			),
+			CommonError::<Test>::UserIsNotCollectionAdmin
+		);
+
+		assert_ok!(Unique::add_collection_admin(
+			origin1.clone(),
+			collection_id,
+			account(2)
+		));
+		assert_noop!(
+			Unique::set_collection_admin_roles(
+				RuntimeOrigin::signed(2),
+				collection_id,
+				account(2),
+				CollectionAdminRoles::ALL
+			),
+			CommonError::<Test>::NoPermission
+		);
+		assert_noop!(
+			Unique::set_collection_admin_roles(
+				origin1.clone(),
+				collection_id,
+				account(2),
+				CollectionAdminRoles(1 << 7)
+			),
+			CommonError::<Test>::UnknownAdminRole
+		);
+
+		// Roles are not kept after the admin is removed
+		assert_ok!(Unique::set_collection_admin_roles(
+			origin1.clone(),
+			collection_id,
+			account(2),
+			CollectionAdminRoles::MINTER
+		));
+		assert_ok!(Unique::remove_collection_admin(
+			origin1,
+			collection_id,
+			account(2)
+		));
+		assert!(<pallet_common::AdminRoles<Test>>::get((collection_id, account(2))).is_empty());
+	});
+}
+
 #[test]
 fn balance_of() {
 	new_test_ext().execute_with(|| {
@@ -2621,6 +3005,78 @@ fn collection_sponsoring() {
 	});
 }
 
+#[test]
+fn collection_sponsor_budget() {
+	new_test_ext().execute_with(|| {
+		let collection_id = create_test_collection(&CollectionMode::NFT, CollectionId(1));
+		assert_ok!(Unique::set_collection_sponsor(
+			RuntimeOrigin::signed(1),
+			collection_id,
+			2
+		));
+		assert_ok!(Unique::confirm_sponsorship(
+			RuntimeOrigin::signed(2),
+			collection_id
+		));
+		let budget = SponsorBudget {
+			period: 10,
+			period_limit: Some(1000),
+			user_limit: Some(100),
+		};
+
+		assert_noop!(
+			Unique::set_collection_sponsor_budget(
+				RuntimeOrigin::signed(3),
+				collection_id,
+				Some(budget)
+			),
+			CommonError::<Test>::NoPermission
+		);
+		assert_ok!(Unique::set_collection_sponsor_budget(
+			RuntimeOrigin::signed(2),
+			collection_id,
+			Some(budget)
+		));
+
+		System::set_block_number(15);
+		<pallet_common::SponsorBudgetSpent<Test>>::insert(
+			collection_id,
+			SponsorBudgetUsage {
+				period_start: 10,
+				spent: 300,
+				exhausted: false,
+			},
+		);
+		// Spending of the previous period is not counted
+		<pallet_common::SponsorUserBudgetSpent<Test>>::insert(
+			(collection_id, account(3)),
+			SponsorBudgetUsage {
+				period_start: 0,
+				spent: 100,
+				exhausted: true,
+			},
+		);
+		assert_eq!(
+			<pallet_common::Pallet<Test>>::sponsor_budget_left(collection_id, &account(3)),
+			Some(SponsorBudgetLeft {
+				period: Some(700),
+				user: Some(100),
+				period_end: Some(19),
+			})
+		);
+
+		assert_ok!(Unique::set_collection_sponsor_budget(
+			RuntimeOrigin::signed(1),
+			collection_id,
+			None
+		));
+		assert_eq!(
+			<pallet_common::Pallet<Test>>::sponsor_budget_left(collection_id, &account(3)),
+			None
+		);
+	});
+}
+
 mod check_token_permissions {
 	use pallet_common::LazyValue;
 
@@ -2683,3 +3139,151 @@ mod check_token_permissions {
 		});
 	}
 }
+
+mod sponsor_pool {
+	use pallet_sponsor_pool::{Error as SponsorPoolError, Pools, Shares};
+
+	use super::*;
+	use crate::SponsorPool;
+
+	const POOL: u32 = 1;
+
+	fn create_pool() -> u64 {
+		assert_ok!(SponsorPool::create_pool(RuntimeOrigin::signed(1)));
+		SponsorPool::pool_account(POOL)
+	}
+
+	fn deposit(contributor: u64, amount: u64) {
+		assert_ok!(SponsorPool::deposit(
+			RuntimeOrigin::signed(contributor),
+			POOL,
+			amount
+		));
+	}
+
+	fn shares(contributor: u64) -> u128 {
+		<Shares<Test>>::get(POOL, contributor).shares
+	}
+
+	fn free_balance(account: u64) -> u64 {
+		<pallet_balances::Pallet<Test>>::free_balance(account)
+	}
+
+	fn set_pool_balance(pool_account: u64, balance: u64) {
+		assert_ok!(<pallet_balances::Pallet<Test>>::force_set_balance(
+			RuntimeOrigin::root(),
+			pool_account,
+			balance,
+		));
+	}
+
+	#[test]
+	fn first_deposit_is_limited_by_minimum() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+			add_balance(2, 10_000);
+
+			assert_noop!(
+				SponsorPool::deposit(RuntimeOrigin::signed(2), POOL, 999),
+				SponsorPoolError::<Test>::FirstDepositTooSmall
+			);
+			deposit(2, 1000);
+			assert_eq!(shares(2), 1000);
+		});
+	}
+
+	#[test]
+	fn shares_are_minted_in_proportion_to_pool_assets() {
+		new_test_ext().execute_with(|| {
+			let pool_account = create_pool();
+			add_balance(2, 10_000);
+			add_balance(3, 10_000);
+			add_balance(4, 10_000);
+
+			deposit(2, 1000);
+			deposit(3, 500);
+			assert_eq!(shares(3), 500);
+
+			// Half of the pool is spent on sponsoring
+			set_pool_balance(pool_account, 750);
+			deposit(4, 300);
+			assert_eq!(shares(4), 600);
+			assert_eq!(<Pools<Test>>::get(POOL).unwrap().total_shares, 2100);
+			assert_eq!(<Pools<Test>>::get(POOL).unwrap().assets, 1050);
+		});
+	}
+
+	#[test]
+	fn shares_are_burnt_for_part_of_pool_assets() {
+		new_test_ext().execute_with(|| {
+			let pool_account = create_pool();
+			add_balance(2, 10_000);
+			add_balance(3, 10_000);
+
+			deposit(2, 1000);
+			deposit(3, 1000);
+			set_pool_balance(pool_account, 1000);
+
+			assert_noop!(
+				SponsorPool::withdraw(RuntimeOrigin::signed(2), POOL, 1001),
+				SponsorPoolError::<Test>::InsufficientShares
+			);
+			assert_ok!(SponsorPool::withdraw(RuntimeOrigin::signed(2), POOL, 400));
+			assert_eq!(free_balance(2), 9000 + 200);
+			assert_eq!(shares(2), 600);
+
+			assert_ok!(SponsorPool::withdraw(RuntimeOrigin::signed(3), POOL, 1000));
+			assert_eq!(free_balance(3), 9000 + 500);
+			assert!(!<Shares<Test>>::contains_key(POOL, 3));
+
+			let pool = <Pools<Test>>::get(POOL).unwrap();
+			assert_eq!(pool.total_shares, 600);
+			assert_eq!(pool.assets, 300);
+			assert_eq!(free_balance(pool_account), 300);
+		});
+	}
+
+	#[test]
+	fn direct_transfer_does_not_change_share_price() {
+		new_test_ext().execute_with(|| {
+			let pool_account = create_pool();
+			add_balance(2, 1_000_000);
+			add_balance(3, 10_000);
+
+			deposit(2, 1000);
+			assert_ok!(<pallet_balances::Pallet<Test>>::transfer_allow_death(
+				RuntimeOrigin::signed(2),
+				pool_account,
+				500_000,
+			));
+
+			deposit(3, 1000);
+			assert_eq!(shares(3), 1000);
+
+			assert_ok!(SponsorPool::withdraw(RuntimeOrigin::signed(2), POOL, 1000));
+			assert_eq!(free_balance(2), 1_000_000 - 500_000);
+			assert_eq!(SponsorPool::contributor_balance(POOL, &3), 1000);
+		});
+	}
+
+	#[test]
+	fn spent_out_pool_invalidates_shares() {
+		new_test_ext().execute_with(|| {
+			let pool_account = create_pool();
+			add_balance(2, 10_000);
+			add_balance(3, 10_000);
+
+			deposit(2, 1000);
+			set_pool_balance(pool_account, 0);
+			assert_eq!(SponsorPool::contributor_balance(POOL, &2), 0);
+
+			deposit(3, 1000);
+			assert_eq!(<Pools<Test>>::get(POOL).unwrap().generation, 1);
+			assert_eq!(shares(3), 1000);
+			assert_noop!(
+				SponsorPool::withdraw(RuntimeOrigin::signed(2), POOL, 1000),
+				SponsorPoolError::<Test>::InsufficientShares
+			);
+		});
+	}
+}